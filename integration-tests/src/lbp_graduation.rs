@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+
+use frame_support::{assert_noop, assert_ok};
+use hydradx_runtime::{Omnipool, RuntimeOrigin, LBP};
+use hydradx_traits::AMM;
+use orml_traits::MultiCurrency;
+use pallet_lbp::{GraduationBeneficiary, GraduationSettings, WeightCurveType};
+use primitives::asset::AssetPair;
+use primitives::BlockNumber;
+use sp_runtime::traits::CheckedMul;
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
+use xcm_emulator::TestExt;
+
+const SALE_START: Option<BlockNumber> = Some(10);
+const SALE_END: Option<BlockNumber> = Some(40);
+
+const ACCUMULATED_AMOUNT: Balance = 100 * UNITS;
+const SOLD_AMOUNT: Balance = 200 * UNITS;
+
+fn lbp_pool_account(asset_a: AssetId, asset_b: AssetId) -> AccountId {
+	LBP::get_pair_id(AssetPair {
+		asset_in: asset_a,
+		asset_out: asset_b,
+	})
+}
+
+fn create_graduating_lbp_pool(
+	accumulated_asset: AssetId,
+	sold_asset: AssetId,
+	settings: GraduationSettings,
+) -> AccountId {
+	assert_ok!(LBP::create_pool(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		accumulated_asset,
+		ACCUMULATED_AMOUNT,
+		sold_asset,
+		SOLD_AMOUNT,
+		20_000_000,
+		80_000_000,
+		WeightCurveType::Linear,
+		(2, 1_000),
+		CHARLIE.into(),
+		0,
+	));
+
+	let pool_id = lbp_pool_account(accumulated_asset, sold_asset);
+
+	assert_ok!(LBP::update_pool_data(
+		RuntimeOrigin::signed(ALICE.into()),
+		pool_id.clone(),
+		None,
+		SALE_START,
+		SALE_END,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+
+	assert_ok!(LBP::set_graduation(
+		RuntimeOrigin::signed(ALICE.into()),
+		pool_id.clone(),
+		Some(settings)
+	));
+	assert_ok!(LBP::approve_graduation(RuntimeOrigin::root(), pool_id.clone(), true));
+
+	pool_id
+}
+
+#[test]
+fn graduate_should_list_sold_asset_in_omnipool_at_final_spot_price() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+
+		let pool_id = create_graduating_lbp_pool(
+			DAI,
+			DOT,
+			GraduationSettings {
+				weight_cap: Permill::from_percent(100),
+				beneficiary: GraduationBeneficiary::Owner,
+			},
+		);
+
+		set_relaychain_block_number(SALE_END.unwrap() + 1);
+
+		let alice_dai_balance = hydradx_runtime::Tokens::free_balance(DAI, &AccountId::from(ALICE));
+		let position_id = Omnipool::next_position_id();
+
+		assert_ok!(LBP::graduate(RuntimeOrigin::signed(BOB.into()), pool_id.clone()));
+
+		// final weights are 80% DAI and 20% DOT
+		let dai_price = Omnipool::load_asset_state(DAI).unwrap().price().unwrap();
+		let expected_price = dai_price.checked_mul(&FixedU128::from_rational(1, 8)).unwrap();
+
+		let position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(position.asset_id, DOT);
+		assert_eq!(position.amount, SOLD_AMOUNT);
+		assert_eq!(position.price, (expected_price.into_inner(), FixedU128::DIV));
+
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(DOT, &Omnipool::protocol_account()),
+			SOLD_AMOUNT
+		);
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(DAI, &AccountId::from(ALICE)),
+			alice_dai_balance + ACCUMULATED_AMOUNT
+		);
+		assert_eq!(hydradx_runtime::Tokens::free_balance(DOT, &pool_id), 0);
+		assert!(LBP::pool_data(pool_id).is_none());
+	});
+}
+
+#[test]
+fn graduate_should_sacrifice_position_to_protocol() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+
+		let pool_id = create_graduating_lbp_pool(
+			DAI,
+			DOT,
+			GraduationSettings {
+				weight_cap: Permill::from_percent(100),
+				beneficiary: GraduationBeneficiary::Protocol,
+			},
+		);
+
+		set_relaychain_block_number(SALE_END.unwrap() + 1);
+
+		let position_id = Omnipool::next_position_id();
+
+		assert_ok!(LBP::graduate(RuntimeOrigin::signed(BOB.into()), pool_id));
+
+		assert!(Omnipool::positions(position_id).is_none());
+
+		let asset_state = Omnipool::load_asset_state(DOT).unwrap();
+		assert_eq!(asset_state.shares, SOLD_AMOUNT);
+		assert_eq!(asset_state.protocol_shares, SOLD_AMOUNT);
+	});
+}
+
+#[test]
+fn graduate_should_fail_when_accumulated_asset_is_not_in_omnipool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+
+		let pool_id = create_graduating_lbp_pool(
+			DOT,
+			DAI,
+			GraduationSettings {
+				weight_cap: Permill::from_percent(100),
+				beneficiary: GraduationBeneficiary::Owner,
+			},
+		);
+
+		set_relaychain_block_number(SALE_END.unwrap() + 1);
+
+		assert_noop!(
+			LBP::graduate(RuntimeOrigin::signed(BOB.into()), pool_id),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::AssetNotFound
+		);
+	});
+}
@@ -7,6 +7,7 @@ mod dust;
 mod dust_removal_whitelist;
 mod dynamic_fees;
 mod exchange_asset;
+mod lbp_graduation;
 mod non_native_fee;
 mod omnipool_init;
 mod omnipool_liquidity_mining;
@@ -18,6 +19,7 @@ mod router;
 mod staking;
 mod transact_call_filter;
 mod vesting;
+mod xcm_rate_limiter;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -5,10 +5,12 @@ use crate::polkadot_test_net::*;
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use hydradx_adapters::OraclePriceProviderAdapterForOmnipool;
-use hydradx_runtime::{Omnipool, RuntimeOrigin, Tokens};
-use hydradx_traits::{OraclePeriod, PriceOracle};
+use hydradx_runtime::{Omnipool, RuntimeOrigin, Tokens, LBP};
+use hydradx_traits::{pools::SpotPriceProvider, OraclePeriod, PriceOracle, AMM};
+use pallet_lbp::WeightCurveType;
+use primitives::asset::AssetPair;
 use primitives::{AssetId, Balance};
-use sp_runtime::{FixedU128, Permill};
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
 use xcm_emulator::TestExt;
 
 #[test]
@@ -71,6 +73,96 @@ fn omnipool_oracle_adapter_should_return_price_for_when_lrna_is_asset_b() {
 	});
 }
 
+#[test]
+fn omnipool_oracle_adapter_should_return_lbp_price_when_asset_is_not_in_omnipool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		init_omnipol();
+
+		assert_ok!(LBP::create_pool(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			DAI,
+			100 * UNITS,
+			DOT,
+			200 * UNITS,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			(2, 1_000),
+			CHARLIE.into(),
+			0,
+		));
+
+		let pool_id = LBP::get_pair_id(AssetPair {
+			asset_in: DAI,
+			asset_out: DOT,
+		});
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE.into()),
+			pool_id,
+			None,
+			Some(10),
+			Some(40),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		set_relaychain_block_number(11);
+
+		assert_ok!(LBP::buy(
+			RuntimeOrigin::signed(BOB.into()),
+			DOT,
+			DAI,
+			UNITS,
+			1_000 * UNITS,
+		));
+
+		set_relaychain_block_number(12);
+
+		let price =
+			OraclePriceProviderAdapterForOmnipool::<AssetId, hydradx_runtime::EmaOracle, hydradx_runtime::LRNA>::price(
+				DOT,
+				DAI,
+				OraclePeriod::Short,
+			);
+		assert!(price.is_some());
+
+		// DCA and transaction fee payment use the oracle price of the LBP pool as the spot price
+		let last_block_price = OraclePriceProviderAdapterForOmnipool::<
+			AssetId,
+			hydradx_runtime::EmaOracle,
+			hydradx_runtime::LRNA,
+		>::price(DOT, DAI, OraclePeriod::LastBlock)
+		.unwrap();
+		assert_eq!(
+			<hydradx_runtime::Runtime as pallet_dca::Config>::SpotPriceProvider::spot_price(DOT, DAI),
+			FixedU128::checked_from_rational(last_block_price.n, last_block_price.d)
+		);
+		assert_eq!(
+			<hydradx_runtime::Runtime as pallet_transaction_multi_payment::Config>::SpotPriceProvider::spot_price(
+				DOT, DAI
+			),
+			FixedU128::checked_from_rational(last_block_price.n, last_block_price.d)
+		);
+
+		// there is no LBP pool for the pair
+		let price =
+			OraclePriceProviderAdapterForOmnipool::<AssetId, hydradx_runtime::EmaOracle, hydradx_runtime::LRNA>::price(
+				DOT,
+				HDX,
+				OraclePeriod::Short,
+			);
+		assert!(price.is_none());
+	});
+}
+
 pub fn init_omnipol() {
 	let native_price = FixedU128::from_float(0.5);
 	let stable_price = FixedU128::from_float(0.7);
@@ -20,9 +20,10 @@
 //                                          http://www.apache.org/licenses/LICENSE-2.0
 #![cfg(test)]
 use crate::polkadot_test_net::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use orml_traits::NamedMultiReservableCurrency;
-use pallet_otc::NAMED_RESERVE_ID;
+use pallet_circuit_breaker::PoolLimits;
+use pallet_otc::{NAMED_RESERVE_ID, OTC_SOURCE};
 use xcm_emulator::TestExt;
 
 #[test]
@@ -139,3 +140,45 @@ fn cancel_order_should_work() {
 		);
 	});
 }
+
+#[test]
+fn fill_order_should_fail_when_circuit_breaker_pool_limit_is_reached() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		// Arrange
+		// 40% of the HDX reserved by all open orders
+		assert_ok!(hydradx_runtime::CircuitBreaker::set_pool_limits(
+			hydradx_runtime::RuntimeOrigin::root(),
+			(OTC_SOURCE, hydradx_runtime::OTC::pool_account()),
+			HDX,
+			Some(PoolLimits {
+				trade_volume_limit: (4_000, 10_000),
+				add_liquidity_limit: None,
+				remove_liquidity_limit: None,
+			}),
+		));
+
+		for _ in 0..2 {
+			assert_ok!(hydradx_runtime::OTC::place_order(
+				hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+				DAI,
+				HDX,
+				20 * UNITS,
+				100 * UNITS,
+				true,
+			));
+		}
+		assert_eq!(hydradx_runtime::OTC::asset_liquidity(HDX), 200 * UNITS);
+
+		// Act & Assert
+		assert_noop!(
+			hydradx_runtime::OTC::fill_order(hydradx_runtime::RuntimeOrigin::signed(BOB.into()), 0),
+			pallet_circuit_breaker::Error::<hydradx_runtime::Runtime>::TokenOutflowLimitReached
+		);
+		assert_ok!(hydradx_runtime::OTC::partial_fill_order(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			0,
+			10 * UNITS
+		));
+	});
+}
@@ -574,6 +574,7 @@ fn create_lbp_pool(accumulated_asset: u32, distributed_asset: u32) {
 		None,
 		None,
 		None,
+		None,
 	));
 }
 
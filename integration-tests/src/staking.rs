@@ -858,3 +858,72 @@ fn stake_should_fail_when_tokens_are_already_staked() {
 		);
 	});
 }
+
+#[test]
+fn democracy_vote_should_not_lock_balance_when_vote_is_backed_by_stake() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		System::set_block_number(0);
+		init_omnipool();
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+			0,
+		));
+		let r = begin_referendum();
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			1_000 * UNITS
+		));
+
+		assert_ok!(Democracy::vote(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			r,
+			aye(1_000 * UNITS)
+		));
+
+		let locks = Balances::locks(AccountId32::from(ALICE));
+		assert!(!locks.iter().any(|lock| lock.id == *b"democrac"));
+		assert!(locks
+			.iter()
+			.any(|lock| lock.id == pallet_staking::STAKING_LOCK_ID && lock.amount == 1_000 * UNITS));
+		end_referendum();
+	});
+}
+
+#[test]
+fn democracy_vote_should_lock_balance_when_vote_exceeds_stake() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		System::set_block_number(0);
+		init_omnipool();
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+			0,
+		));
+		let r = begin_referendum();
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			1_000 * UNITS
+		));
+
+		assert_ok!(Democracy::vote(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			r,
+			aye(2_000 * UNITS)
+		));
+
+		let locks = Balances::locks(AccountId32::from(ALICE));
+		assert!(locks
+			.iter()
+			.any(|lock| lock.id == *b"democrac" && lock.amount == 2_000 * UNITS));
+		end_referendum();
+	});
+}
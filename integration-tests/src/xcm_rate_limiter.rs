@@ -0,0 +1,165 @@
+#![cfg(test)]
+use crate::polkadot_test_net::*;
+
+use frame_support::{assert_ok, traits::OnIdle, weights::Weight};
+use orml_traits::currency::MultiCurrency;
+use pallet_asset_registry::AssetType;
+use polkadot_xcm::{latest::prelude::*, v3::WeightLimit};
+use pretty_assertions::assert_eq;
+use xcm_emulator::TestExt;
+
+#[test]
+fn incoming_transfer_should_be_deferred_when_rate_limit_is_exceeded() {
+	// Arrange
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			hydradx_runtime::RuntimeOrigin::root(),
+			ACA,
+			hydradx_runtime::AssetLocation(MultiLocation::new(1, X2(Parachain(ACALA_PARA_ID), GeneralIndex(0))))
+		));
+		assert_ok!(hydradx_runtime::AssetRegistry::update(
+			hydradx_runtime::RuntimeOrigin::root(),
+			ACA,
+			b"ACA".to_vec(),
+			AssetType::Token,
+			None,
+			Some(10 * UNITS),
+		));
+	});
+
+	Acala::execute_with(|| {
+		// Act
+		assert_ok!(hydradx_runtime::XTokens::transfer(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			0,
+			30 * UNITS,
+			Box::new(
+				MultiLocation::new(
+					1,
+					X2(
+						Junction::Parachain(HYDRA_PARA_ID),
+						Junction::AccountId32 { id: BOB, network: None }
+					)
+				)
+				.into()
+			),
+			WeightLimit::Limited(Weight::from_ref_time(399_600_000_000))
+		));
+
+		assert_eq!(
+			hydradx_runtime::Balances::free_balance(&AccountId::from(ALICE)),
+			ALICE_INITIAL_NATIVE_BALANCE - 30 * UNITS
+		);
+	});
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_eq!(hydradx_runtime::Tokens::free_balance(ACA, &AccountId::from(BOB)), 0);
+	});
+}
+
+#[test]
+fn outgoing_transfer_should_be_deferred_when_rate_limit_is_exceeded() {
+	// Arrange
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			hydradx_runtime::RuntimeOrigin::root(),
+			DOT,
+			hydradx_runtime::AssetLocation(MultiLocation::parent())
+		));
+		assert_ok!(hydradx_runtime::AssetRegistry::update(
+			hydradx_runtime::RuntimeOrigin::root(),
+			DOT,
+			b"DOT".to_vec(),
+			AssetType::Token,
+			None,
+			Some(UNITS),
+		));
+
+		// Act
+		assert_ok!(hydradx_runtime::XTokens::transfer(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DOT,
+			3 * UNITS,
+			Box::new(MultiLocation::new(1, X1(Junction::AccountId32 { id: BOB, network: None })).into()),
+			WeightLimit::Unlimited,
+		));
+
+		// Assert
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(DOT, &AccountId::from(ALICE)),
+			ALICE_INITIAL_DOT_BALANCE - 3 * UNITS
+		);
+		assert_eq!(hydradx_runtime::XcmRateLimiter::deferred_messages_count(), 1);
+		assert_eq!(
+			hydradx_runtime::XcmRateLimiter::outgoing_accumulated_amount(DOT).amount,
+			3 * UNITS
+		);
+	});
+
+	PolkadotRelay::execute_with(|| {
+		assert_eq!(polkadot_runtime::Balances::free_balance(&AccountId::from(BOB)), 0);
+	});
+}
+
+#[test]
+fn deferred_outgoing_transfer_should_be_sent_when_defer_duration_elapsed() {
+	// Arrange
+	TestNet::reset();
+	let amount = 6 * UNITS + 60_000_000_000;
+
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			hydradx_runtime::RuntimeOrigin::root(),
+			DOT,
+			hydradx_runtime::AssetLocation(MultiLocation::parent())
+		));
+		assert_ok!(hydradx_runtime::AssetRegistry::update(
+			hydradx_runtime::RuntimeOrigin::root(),
+			DOT,
+			b"DOT".to_vec(),
+			AssetType::Token,
+			None,
+			Some(6 * UNITS),
+		));
+
+		set_relaychain_block_number(10);
+
+		// exceeds the rate limit by 1%, so it is deferred by 1% of `DeferDuration`, i.e. 60 relay chain blocks
+		assert_ok!(hydradx_runtime::XTokens::transfer(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DOT,
+			amount,
+			Box::new(MultiLocation::new(1, X1(Junction::AccountId32 { id: BOB, network: None })).into()),
+			WeightLimit::Unlimited,
+		));
+		assert_eq!(
+			hydradx_runtime::XcmRateLimiter::deferred_message(0)
+				.unwrap()
+				.deferred_until,
+			70
+		);
+
+		set_relaychain_block_number(69);
+		hydradx_runtime::XcmRateLimiter::on_idle(69, Weight::MAX);
+		assert_eq!(hydradx_runtime::XcmRateLimiter::deferred_messages_count(), 1);
+
+		// Act
+		set_relaychain_block_number(70);
+		hydradx_runtime::XcmRateLimiter::on_idle(70, Weight::MAX);
+
+		// Assert
+		assert_eq!(hydradx_runtime::XcmRateLimiter::deferred_messages_count(), 0);
+	});
+
+	PolkadotRelay::execute_with(|| {
+		assert_eq!(
+			polkadot_runtime::Balances::free_balance(&AccountId::from(BOB)),
+			amount - 362_529_000 // amount - fee
+		);
+	});
+}
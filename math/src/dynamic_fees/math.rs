@@ -1,5 +1,6 @@
 use super::types::*;
 use crate::dynamic_fees::types::NetVolumeDirection::{InOut, OutIn};
+use crate::types::Balance;
 use num_traits::Zero;
 use sp_arithmetic::traits::Saturating;
 use sp_arithmetic::{FixedPointOperand, FixedU128, PerThing};
@@ -40,6 +41,64 @@ where
 	recalculate_fee(volume, previous_fee, last_block_diff, params, InOut)
 }
 
+/// Calculate imbalance of a stableswap pool.
+///
+/// Imbalance is the distance of the pool's reserves from the balanced point, where all reserves are equal to `d / n`,
+/// relative to `d`: `sum(|x_i - d / n|) / d`.
+///
+/// `reserves` are the pool's reserves.
+/// `d` is the pool's invariant calculated by `stableswap::calculate_d`.
+pub fn calculate_stableswap_imbalance(reserves: &[Balance], d: Balance) -> FixedU128 {
+	if reserves.is_empty() || d.is_zero() {
+		return FixedU128::zero();
+	}
+
+	let balanced_reserve = d / reserves.len() as u128;
+	let distance = reserves.iter().fold(Balance::zero(), |acc, reserve| {
+		acc.saturating_add(reserve.abs_diff(balanced_reserve))
+	});
+
+	FixedU128::from_rational(distance, d)
+}
+
+/// Recalculate stableswap pool's trade fee given previously calculated fee, pool imbalance and oracle data.
+///
+/// The fee rises immediately with the imbalance and the volume of the pool and decays by `decay` per block otherwise.
+///
+/// `imbalance` is the pool's imbalance calculated by `calculate_stableswap_imbalance`.
+/// `volume` is the pool's volume data provided by the oracle.
+/// `previous_fee` is the previous calculated trade fee.
+/// `last_block_diff` is the difference between the current block height and the previous block height when the fee was calculated.
+/// `params` is the fee parameters, such as minimum fee, maximum fee, decay and amplification.
+pub fn recalculate_stableswap_fee<Fee: PerThing>(
+	imbalance: FixedU128,
+	volume: OracleEntry,
+	previous_fee: Fee,
+	last_block_diff: u128,
+	params: FeeParams<Fee>,
+) -> Fee
+where
+	<Fee as PerThing>::Inner: FixedPointOperand,
+{
+	let decaying = params.decay.saturating_mul(FixedU128::from(last_block_diff));
+	let decayed_fee = FixedU128::from(previous_fee).saturating_sub(decaying);
+
+	// x = imbalance + (Vi + Vo) / L
+	let volume_ratio = if !volume.liquidity.is_zero() {
+		FixedU128::from_rational(volume.amount_in.saturating_add(volume.amount_out), volume.liquidity)
+	} else {
+		FixedU128::zero()
+	};
+	let x = imbalance.saturating_add(volume_ratio);
+
+	let target_fee = FixedU128::from(params.min_fee).saturating_add(params.amplification.saturating_mul(x));
+
+	decayed_fee
+		.max(target_fee)
+		.into_clamped_perthing::<Fee>()
+		.clamp(params.min_fee, params.max_fee)
+}
+
 fn recalculate_fee<Fee: PerThing>(
 	volume: OracleEntry,
 	previous_fee: Fee,
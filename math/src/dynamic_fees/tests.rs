@@ -1,5 +1,7 @@
 use super::types::*;
-use crate::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
+use crate::dynamic_fees::{
+	calculate_stableswap_imbalance, recalculate_asset_fee, recalculate_protocol_fee, recalculate_stableswap_fee,
+};
 use num_traits::Zero;
 use sp_arithmetic::{FixedU128, Permill};
 
@@ -232,3 +234,100 @@ fn protocol_fee_should_clamp_to_max_fee() {
 	let calculated_fee = recalculate_protocol_fee(volume, previous_fee, last_block_diff, params);
 	assert_eq!(calculated_fee, expected_fee);
 }
+
+#[test]
+fn stableswap_imbalance_should_be_zero_when_pool_is_balanced() {
+	let reserves = vec![1_000, 1_000, 1_000];
+	let d = 3_000;
+
+	assert_eq!(calculate_stableswap_imbalance(&reserves, d), FixedU128::zero());
+}
+
+#[test]
+fn stableswap_imbalance_should_be_distance_from_balanced_reserves() {
+	let reserves = vec![1_500, 500];
+	let d = 2_000;
+
+	// (|1500 - 1000| + |500 - 1000|) / 2000
+	assert_eq!(
+		calculate_stableswap_imbalance(&reserves, d),
+		FixedU128::from_rational(1, 2)
+	);
+}
+
+#[test]
+fn stableswap_fee_should_increase_with_imbalance_and_volume() {
+	// Test parameters
+	let volume = OracleEntry {
+		amount_in: 10,
+		amount_out: 10,
+		liquidity: 1000,
+	};
+	let imbalance = FixedU128::from_rational(1, 100);
+	let previous_fee = Permill::from_percent(1);
+	let last_block_diff = 1;
+	let params = FeeParams {
+		amplification: FixedU128::from(2),
+		decay: FixedU128::zero(),
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(30),
+	};
+
+	// 1% + 2 * (1% + 2%)
+	let expected_fee = Permill::from_percent(7);
+
+	let calculated_fee = recalculate_stableswap_fee(imbalance, volume, previous_fee, last_block_diff, params);
+	assert_eq!(calculated_fee, expected_fee);
+}
+
+#[test]
+fn stableswap_fee_should_decay_when_pool_is_balanced_and_there_is_no_volume() {
+	// Test parameters
+	let volume = OracleEntry {
+		amount_in: 0,
+		amount_out: 0,
+		liquidity: 1000,
+	};
+	let previous_fee = Permill::from_percent(10);
+	let last_block_diff = 3;
+	let params = FeeParams {
+		amplification: FixedU128::from(2),
+		decay: FixedU128::from_rational(1, 100),
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(30),
+	};
+
+	let expected_fee = Permill::from_percent(7);
+
+	let calculated_fee = recalculate_stableswap_fee(FixedU128::zero(), volume, previous_fee, last_block_diff, params);
+	assert_eq!(calculated_fee, expected_fee);
+}
+
+#[test]
+fn stableswap_fee_should_clamp_to_max_fee() {
+	// Test parameters
+	let volume = OracleEntry {
+		amount_in: 500,
+		amount_out: 500,
+		liquidity: 1000,
+	};
+	let previous_fee = Permill::from_percent(1);
+	let last_block_diff = 1;
+	let params = FeeParams {
+		amplification: FixedU128::from(2),
+		decay: FixedU128::zero(),
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(15),
+	};
+
+	let expected_fee = Permill::from_percent(15);
+
+	let calculated_fee = recalculate_stableswap_fee(
+		FixedU128::from_rational(1, 2),
+		volume,
+		previous_fee,
+		last_block_diff,
+		params,
+	);
+	assert_eq!(calculated_fee, expected_fee);
+}
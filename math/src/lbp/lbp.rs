@@ -11,6 +11,7 @@ use num_traits::Zero;
 use sp_std::ops::Div;
 
 use crate::types::{Balance, FixedBalance, LBPWeight, HYDRA_ONE};
+use sp_arithmetic::Permill;
 
 /// Calculating spot price given reserve of selling asset and reserve of buying asset.
 /// Formula : BUY_RESERVE * AMOUNT / SELL_RESERVE
@@ -224,3 +225,168 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 
 	to_lbp_weight!(result)
 }
+
+/// Calculating weight at any given block in an interval using exponential interpolation.
+///
+/// Most of the weight change happens at the beginning of the interval. The shape of the curve is
+/// `(1 - e^(-k * p)) / (1 - e^(-k))` where `p` is the progress of the interval and `k` is the rate.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `rate` - steepness of the curve multiplied by 1_000 ( e.g. 2_500 corresponds to 2.5 )
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	rate: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let d1 = end_x.checked_sub(&at).ok_or(Overflow)?;
+	let d2 = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let dx = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let dx: u32 = dx.try_into().map_err(|_| Overflow)?;
+	let d1: u128 = d1.try_into().map_err(|_| Overflow)?;
+	let d2: u128 = d2.try_into().map_err(|_| Overflow)?;
+
+	ensure!(dx != 0, ZeroDuration);
+
+	// zero rate degenerates to a straight line
+	if rate.is_zero() {
+		return calculate_linear_weights(0u128, dx.into(), start_y, end_y, d2);
+	}
+
+	if d1.is_zero() {
+		return Ok(end_y);
+	}
+
+	let k = FixedBalance::from_num(rate)
+		.checked_div(FixedBalance::from_num(1_000u32))
+		.ok_or(Overflow)?;
+	let progress = FixedBalance::from_num(d2)
+		.checked_div(FixedBalance::from_num(dx))
+		.ok_or(Overflow)?;
+
+	let exp_neg = |x: FixedBalance| -> Result<FixedBalance, MathError> {
+		let e: FixedBalance = crate::transcendental::exp(x, false).map_err(|_| Overflow)?;
+		FixedBalance::from_num(1u32).checked_div(e).ok_or(Overflow)
+	};
+
+	let numerator_exp = exp_neg(k.checked_mul(progress).ok_or(Overflow)?)?;
+	let denominator_exp = exp_neg(k)?;
+
+	let numerator = FixedBalance::from_num(1u32)
+		.checked_sub(numerator_exp)
+		.ok_or(Overflow)?;
+	let denominator = FixedBalance::from_num(1u32)
+		.checked_sub(denominator_exp)
+		.ok_or(Overflow)?;
+
+	let ratio = numerator.checked_div(denominator).ok_or(Overflow)?;
+
+	let delta = FixedBalance::from_num(start_y.abs_diff(end_y))
+		.checked_mul(ratio)
+		.ok_or(Overflow)?;
+	let delta: LBPWeight = delta.int().checked_to_num().ok_or(Overflow)?;
+
+	if start_y <= end_y {
+		start_y.checked_add(delta).ok_or(Overflow)
+	} else {
+		start_y.checked_sub(delta).ok_or(Overflow)
+	}
+}
+
+/// Calculating weight at any given block in an interval using a step function.
+///
+/// The interval is split into `steps` periods of the same length. The weight is constant within
+/// a period, it is equal to `start_y` in the first period and to `end_y` in the last one.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `steps` - number of periods, has to be at least 2
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_stepped_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	steps: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let _ = end_x.checked_sub(&at).ok_or(Overflow)?;
+	let d2 = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let dx = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let dx: u32 = dx.try_into().map_err(|_| Overflow)?;
+	let d2: u128 = d2.try_into().map_err(|_| Overflow)?;
+
+	ensure!(dx != 0, ZeroDuration);
+	ensure!(steps > 1, ZeroDuration);
+
+	let step = d2
+		.checked_mul(steps.into())
+		.ok_or(Overflow)?
+		.checked_div(dx.into())
+		.ok_or(Overflow)?
+		.min(u128::from(steps - 1));
+
+	calculate_linear_weights(0u128, u128::from(steps - 1), start_y, end_y, step)
+}
+
+/// Calculating weight at any given block in an interval using piecewise linear interpolation.
+///
+/// The curve goes through `(start_x, start_y)`, all the `points` and `(end_x, end_y)`.
+/// Each point is a pair of the progress of the interval and the weight at that progress.
+/// Points have to be sorted by progress.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `points` - intermediate points of the curve
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_piecewise_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	points: &[(Permill, LBPWeight)],
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let _ = end_x.checked_sub(&at).ok_or(Overflow)?;
+	let d2 = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let dx = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let dx: u32 = dx.try_into().map_err(|_| Overflow)?;
+	let d2: u128 = d2.try_into().map_err(|_| Overflow)?;
+
+	ensure!(dx != 0, ZeroDuration);
+
+	let dx = u128::from(dx);
+
+	let mut previous = (0u128, start_y);
+	for (x, y) in points
+		.iter()
+		.map(|(progress, weight)| (progress.mul_floor(dx), *weight))
+		.chain(core::iter::once((dx, end_y)))
+	{
+		ensure!(x >= previous.0, Overflow);
+
+		if d2 <= x {
+			if x == previous.0 {
+				return Ok(y);
+			}
+			return calculate_linear_weights(previous.0, x, previous.1, y, d2);
+		}
+
+		previous = (x, y);
+	}
+
+	Err(Overflow)
+}
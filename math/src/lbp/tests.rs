@@ -3,6 +3,7 @@ use crate::lbp::lbp;
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
 use crate::MathError::{Overflow, ZeroDuration, ZeroReserve, ZeroWeight};
 
+use sp_arithmetic::Permill;
 use std::vec;
 
 #[test]
@@ -226,3 +227,245 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			150u32,
+			Ok(1_731),
+			"Easy case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			2_000u32,
+			150u32,
+			Ok(1_269),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			170u32,
+			Ok(1_871),
+			"Change is front-loaded",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			500u32,
+			170u32,
+			Ok(1_750),
+			"Low rate is close to linear",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			0u32,
+			170u32,
+			Ok(1_700),
+			"Zero rate is linear",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			2_000u32,
+			2_000u32,
+			170u32,
+			Ok(2_000),
+			"Constant case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			100u32,
+			Ok(1_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			200u32,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			2_000u32,
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn stepped_weights_should_work() {
+	let cases = vec![
+		(100u32, 200u32, 1_000u32, 2_000u32, 4u32, 170u32, Ok(1_666), "Easy case"),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			170u32,
+			Ok(1_333),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			124u32,
+			Ok(1_000),
+			"First step",
+		),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4u32, 175u32, Ok(2_000), "Last step"),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			200u32,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			1u32,
+			170u32,
+			Err(ZeroDuration),
+			"Not enough steps",
+		),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			10u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_stepped_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn piecewise_weights_should_work() {
+	let points = vec![
+		(Permill::from_percent(20), 5_000u32),
+		(Permill::from_percent(50), 1_500u32),
+	];
+
+	let cases = vec![
+		(100u32, 200u32, 1_000u32, 2_000u32, 110u32, Ok(3_000), "First segment"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 120u32, Ok(5_000), "First point"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 135u32, Ok(3_250), "Second segment"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 175u32, Ok(1_750), "Last segment"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 100u32, Ok(1_000), "Initial weight"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 200u32, Ok(2_000), "Final weight"),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_piecewise_weights(case.0, case.1, case.2, case.3, &points, case.4),
+			case.5,
+			"{}",
+			case.6
+		);
+	}
+
+	assert_eq!(
+		lbp::calculate_piecewise_weights(100u32, 200u32, 1_000u32, 2_000u32, &[], 170u32),
+		lbp::calculate_linear_weights(100u32, 200u32, 1_000u32, 2_000u32, 170u32),
+		"No points is linear"
+	);
+}
@@ -223,3 +223,46 @@ proptest! {
 		);
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1_000))]
+	#[test]
+	fn calculate_adjusted_accumulated_rpvs(
+		accumulated_rpvs in accumulated_rpvs(),
+		accumulated_rpvs_now in accumulated_rpvs_now(),
+		valued_shares in valued_shares(),
+		added_valued_shares in valued_shares(),
+	) {
+		let accumulated_rpvs = FixedU128::from(accumulated_rpvs);
+		let accumulated_rpvs_now = FixedU128::from(accumulated_rpvs_now);
+
+		let adjusted_rpvs = crate::liquidity_mining::calculate_adjusted_accumulated_rpvs(
+			accumulated_rpvs,
+			valued_shares,
+			added_valued_shares,
+			accumulated_rpvs_now).unwrap();
+
+		assert!(adjusted_rpvs >= accumulated_rpvs, "adjusted_rpvs >= accumulated_rpvs");
+		assert!(adjusted_rpvs <= accumulated_rpvs_now, "adjusted_rpvs <= accumulated_rpvs_now");
+
+		let rewards = crate::liquidity_mining::calculate_reward(
+			accumulated_rpvs,
+			accumulated_rpvs_now,
+			valued_shares).unwrap();
+
+		let adjusted_rewards = crate::liquidity_mining::calculate_reward(
+			adjusted_rpvs,
+			accumulated_rpvs_now,
+			valued_shares.checked_add(added_valued_shares).unwrap()).unwrap();
+
+		assert!(adjusted_rewards <= rewards, "adjusted_rewards <= rewards");
+
+		let tolerance = 1_u128;
+		assert_approx_eq!(
+			adjusted_rewards,
+			rewards,
+			tolerance,
+			"adjusted_rewards ~= rewards"
+		);
+	}
+}
@@ -152,3 +152,54 @@ pub fn calculate_global_farm_rewards<Period: num_traits::CheckedSub + TryInto<u3
 
 	Ok(rewards.min(max_reward_for_periods))
 }
+
+/// This function calculates adjusted accumulated rewards per valued shares of the farm entry
+/// [`FixedU128`] when `added_valued_shares` are added to the entry or error.
+///
+/// Adjusted `accumulated_rpvs` is calculated so rewards of the entry which were not claimed yet
+/// don't change by adding valued shares to the entry.
+///
+/// `accumulated_rpvs_adjusted = accumulated_rpvs_now - valued_shares * (accumulated_rpvs_now - accumulated_rpvs) / (valued_shares + added_valued_shares)`
+pub fn calculate_adjusted_accumulated_rpvs(
+	accumulated_rpvs: FixedU128,
+	valued_shares: Balance,
+	added_valued_shares: Balance,
+	accumulated_rpvs_now: FixedU128,
+) -> Result<FixedU128, MathError> {
+	let delta_rpvs = accumulated_rpvs_now
+		.checked_sub(&accumulated_rpvs)
+		.ok_or(MathError::Overflow)?;
+
+	let total_valued_shares = valued_shares
+		.checked_add(added_valued_shares)
+		.ok_or(MathError::Overflow)?;
+
+	//NOTE: delta is rounded down so adjusted rpvs is rounded up in favor of the farm.
+	let adjusted_delta_rpvs = U128::from(delta_rpvs.into_inner())
+		.full_mul(valued_shares.into())
+		.checked_div(total_valued_shares.into())
+		.ok_or(MathError::DivisionByZero)?;
+
+	let adjusted_delta_rpvs = FixedU128::from_inner(to_balance!(adjusted_delta_rpvs)?);
+
+	accumulated_rpvs_now
+		.checked_sub(&adjusted_delta_rpvs)
+		.ok_or(MathError::Overflow)
+}
+
+/// This function calculates part of the `amount` [`Balance`] belonging to the `shares` from
+/// `total_shares` or error. Result is rounded down.
+///
+/// `amount_part = amount * shares / total_shares`
+pub fn calculate_proportional_amount(
+	amount: Balance,
+	shares: Balance,
+	total_shares: Balance,
+) -> Result<Balance, MathError> {
+	let amount_part = U128::from(amount)
+		.full_mul(shares.into())
+		.checked_div(total_shares.into())
+		.ok_or(MathError::DivisionByZero)?;
+
+	to_balance!(amount_part)
+}
@@ -954,3 +954,87 @@ fn calculate_global_farm_rewards_should_work() {
 		);
 	}
 }
+
+#[test]
+fn calculate_adjusted_accumulated_rpvs_should_work() {
+	//NOTE: nothing accumulated since entry was created.
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(5), 1_000, 1_000, FixedU128::from(5)).unwrap(),
+		FixedU128::from(5)
+	);
+
+	//NOTE: nothing is added.
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(2), 1_000, 0, FixedU128::from(10)).unwrap(),
+		FixedU128::from(2)
+	);
+
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(2), 1_000, 1_000, FixedU128::from(10)).unwrap(),
+		FixedU128::from(6)
+	);
+
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(2), 1_000, 3_000, FixedU128::from(10)).unwrap(),
+		FixedU128::from(8)
+	);
+
+	//NOTE: adjusted rpvs is rounded up.
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(0), 1, 2, FixedU128::from_inner(10)).unwrap(),
+		FixedU128::from_inner(7)
+	);
+
+	//NOTE: rewards of the entry must not change.
+	let accumulated_rpvs = FixedU128::from_inner(1_215_468_000_000_000_000_u128);
+	let accumulated_rpvs_now = FixedU128::from_inner(36_874_651_564_987_000_000_u128);
+	let valued_shares = 16_874_468_874_u128;
+	let added_valued_shares = 654_684_687_465_u128;
+
+	let adjusted_rpvs = calculate_adjusted_accumulated_rpvs(
+		accumulated_rpvs,
+		valued_shares,
+		added_valued_shares,
+		accumulated_rpvs_now,
+	)
+	.unwrap();
+
+	let rewards = calculate_reward(accumulated_rpvs, accumulated_rpvs_now, valued_shares).unwrap();
+	let adjusted_rewards =
+		calculate_reward(adjusted_rpvs, accumulated_rpvs_now, valued_shares + added_valued_shares).unwrap();
+
+	assert!(adjusted_rewards <= rewards);
+	assert_approx_eq!(adjusted_rewards, rewards, 1, "rewards of the entry should not change");
+
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(2), 0, 0, FixedU128::from(10)),
+		Err(crate::MathError::DivisionByZero)
+	);
+
+	assert_eq!(
+		calculate_adjusted_accumulated_rpvs(FixedU128::from(10), 1_000, 1_000, FixedU128::from(2)),
+		Err(crate::MathError::Overflow)
+	);
+}
+
+#[test]
+fn calculate_proportional_amount_should_work() {
+	assert_eq!(calculate_proportional_amount(1_000, 250, 1_000).unwrap(), 250);
+
+	assert_eq!(calculate_proportional_amount(1_000, 0, 1_000).unwrap(), 0);
+
+	assert_eq!(calculate_proportional_amount(1_000, 1_000, 1_000).unwrap(), 1_000);
+
+	//NOTE: result is rounded down.
+	assert_eq!(calculate_proportional_amount(10, 1, 3).unwrap(), 3);
+
+	assert_eq!(
+		calculate_proportional_amount(u128::MAX, u128::MAX / 2, u128::MAX).unwrap(),
+		u128::MAX / 2
+	);
+
+	assert_eq!(
+		calculate_proportional_amount(1_000, 250, 0),
+		Err(crate::MathError::DivisionByZero)
+	);
+}
@@ -613,3 +613,342 @@ proptest! {
 		}
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn remove_liquidity_of_merged_position_should_equal_remove_liquidity_of_positions(asset in asset_state(),
+		position in position(),
+		other_amount in trade_amount(),
+		imbalance in some_imbalance(),
+	) {
+		let positions = [
+			Position {
+				amount: position.amount,
+				shares: position.shares,
+				price: position.price,
+			},
+			Position {
+				amount: other_amount,
+				shares: other_amount,
+				price: position.price,
+			},
+		];
+		let merged = calculate_merged_position(&asset, &positions).unwrap();
+
+		assert_eq!(merged.amount, position.amount + other_amount);
+		assert_eq!(merged.shares, position.shares + other_amount);
+
+		let remove_all_liquidity = |position: &Position<Balance>| {
+			calculate_remove_liquidity_state_changes(&asset,
+				position.shares,
+				position,
+				imbalance,
+				100 * ONE,
+				FixedU128::zero(),
+			).unwrap()
+		};
+
+		let first_changes = remove_all_liquidity(&positions[0]);
+		let second_changes = remove_all_liquidity(&positions[1]);
+		let merged_changes = remove_all_liquidity(&merged);
+
+		// Remove liquidity is linear in shares, merged position differs only by rounding
+		assert_approx_eq!(*merged_changes.asset.delta_reserve,
+			*first_changes.asset.delta_reserve + *second_changes.asset.delta_reserve,
+			TOLERANCE,
+			"Asset received from merged position changed");
+		assert_approx_eq!(*merged_changes.asset.delta_hub_reserve,
+			*first_changes.asset.delta_hub_reserve + *second_changes.asset.delta_hub_reserve,
+			TOLERANCE,
+			"Hub reserve change of merged position changed");
+		assert_approx_eq!(merged_changes.lp_hub_amount,
+			first_changes.lp_hub_amount + second_changes.lp_hub_amount,
+			TOLERANCE,
+			"Hub asset received from merged position changed");
+		assert_approx_eq!(*merged_changes.asset.delta_protocol_shares,
+			*first_changes.asset.delta_protocol_shares + *second_changes.asset.delta_protocol_shares,
+			TOLERANCE,
+			"Protocol shares from merged position changed");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn merged_position_should_preserve_value_when_prices_differ(asset in asset_state(),
+		position in position(),
+		other in position(),
+		imbalance in some_imbalance(),
+	) {
+		let merged = calculate_merged_position(&asset, &[
+			Position {
+				amount: position.amount,
+				shares: position.shares,
+				price: position.price,
+			},
+			Position {
+				amount: other.amount,
+				shares: other.shares,
+				price: other.price,
+			},
+		]).unwrap();
+
+		assert_eq!(merged.amount, position.amount + other.amount);
+		assert_eq!(merged.shares, position.shares + other.shares);
+
+		// Value of removed liquidity in hub asset at current state: dR * Q / R + dq
+		let remove_all_liquidity_value = |position: &Position<Balance>| {
+			let changes = calculate_remove_liquidity_state_changes(&asset,
+				position.shares,
+				position,
+				imbalance,
+				100 * ONE,
+				FixedU128::zero(),
+			).unwrap();
+			U256::from(*changes.asset.delta_reserve) * U256::from(asset.hub_reserve) / U256::from(asset.reserve)
+				+ U256::from(changes.lp_hub_amount)
+		};
+
+		let separate_value = remove_all_liquidity_value(&position) + remove_all_liquidity_value(&other);
+		let merged_value = remove_all_liquidity_value(&merged);
+
+		assert_approx_eq!(merged_value,
+			separate_value,
+			separate_value / U256::from(1_000_000_000u128) + U256::from(TOLERANCE),
+			"Value of merged position changed");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn merged_position_should_keep_price_when_prices_are_same(asset in asset_state(),
+		position in position(),
+		other_amount in trade_amount(),
+	) {
+		let price = position.price;
+		let other = Position {
+			amount: other_amount,
+			shares: other_amount,
+			price,
+		};
+		let merged = calculate_merged_position(&asset, &[position, other]).unwrap();
+
+		assert_eq!(merged.price, price);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn split_position_should_preserve_amount_shares_and_price(asset in asset_state(),
+		position in position(),
+		split_percent in 1u128..100u128,
+	) {
+		let shares = position.shares * split_percent / 100;
+
+		let (remaining, split) = calculate_split_position(&position, shares).unwrap();
+
+		assert_eq!(remaining.amount + split.amount, position.amount);
+		assert_eq!(remaining.shares + split.shares, position.shares);
+		assert_eq!(split.shares, shares);
+		assert_eq!(remaining.price, position.price);
+		assert_eq!(split.price, position.price);
+
+		// Rounding errors should favor remaining position
+		// a_split * S <= A * s_split
+		assert!(U256::from(split.amount) * U256::from(position.shares) <= U256::from(position.amount) * U256::from(split.shares));
+
+		// Merging split positions back results in the original position
+		let merged = calculate_merged_position(&asset, &[remaining, split]).unwrap();
+		assert_eq!(merged, position);
+	}
+}
+
+fn hub_withdrawal_imbalance() -> impl Strategy<Value = I129<Balance>> {
+	(0..100_000 * ONE).prop_map(|value| I129 { value, negative: true })
+}
+
+/// Asserts that imbalance invariant holds when hub asset is withdrawn from the pool
+/// or that the imbalance would become positive - such trade is not allowed.
+fn assert_hub_withdrawal_imbalance_update(
+	old_imbalance: I129<Balance>,
+	delta_imbalance: Balance,
+	old_hub_reserve: Balance,
+	hub_amount_out: Balance,
+	desc: &str,
+) {
+	if delta_imbalance > old_imbalance.value {
+		return;
+	}
+
+	assert_imbalance_update(
+		old_imbalance,
+		I129::<Balance> {
+			value: old_imbalance.value - delta_imbalance,
+			negative: true,
+		},
+		old_hub_reserve,
+		old_hub_reserve - hub_amount_out,
+		desc,
+	);
+}
+
+// Hub asset value of `amount` of an asset at the spot price of given state
+fn hub_asset_value(amount: Balance, state: &AssetReserveState<Balance>) -> U256 {
+	U256::from(amount) * U256::from(state.hub_reserve) / U256::from(state.reserve)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_for_hub_update_invariants_no_fees(asset_in in asset_state(),
+		amount in trade_amount(),
+		imbalance in hub_withdrawal_imbalance(),
+	) {
+		let total_hub_reserve = 100 * ONE + asset_in.hub_reserve;
+
+		let result = calculate_sell_for_hub_asset_state_changes(&asset_in, amount,
+			Permill::from_percent(0),
+			imbalance,
+			total_hub_reserve,
+		);
+
+		assert!(result.is_some());
+
+		let state_changes = result.unwrap();
+
+		let asset_in_state = asset_in.clone();
+		let asset_in_state = asset_in_state.delta_update(&state_changes.asset).unwrap();
+
+		assert_hub_withdrawal_imbalance_update(
+			imbalance,
+			*state_changes.delta_imbalance,
+			total_hub_reserve,
+			*state_changes.asset.delta_hub_reserve,
+			"sell for hub imbalance invariant failed");
+
+		// Hub asset received is never worth more than the sold amount at spot price
+		assert!(U256::from(*state_changes.asset.delta_hub_reserve) <= hub_asset_value(amount, &asset_in));
+
+		assert_asset_invariant(&asset_in, &asset_in_state, Some(FixedU128::from((TOLERANCE, ONE))), "Sell for hub update invariant - token in");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_for_hub_update_invariants_with_fees(asset_in in asset_state(),
+		amount in trade_amount(),
+		protocol_fee in fee(),
+		imbalance in hub_withdrawal_imbalance(),
+	) {
+		let total_hub_reserve = 100 * ONE + asset_in.hub_reserve;
+
+		let result = calculate_sell_for_hub_asset_state_changes(&asset_in, amount,
+			protocol_fee,
+			imbalance,
+			total_hub_reserve,
+		);
+
+		assert!(result.is_some());
+
+		let state_changes = result.unwrap();
+
+		let asset_in_state = asset_in.clone();
+		let asset_in_state = asset_in_state.delta_update(&state_changes.asset).unwrap();
+
+		// protocol fee stays in the pool
+		let hub_amount_out = *state_changes.asset.delta_hub_reserve - state_changes.fee.protocol_fee;
+
+		assert_hub_withdrawal_imbalance_update(
+			imbalance,
+			*state_changes.delta_imbalance,
+			total_hub_reserve,
+			hub_amount_out,
+			"sell for hub imbalance invariant failed");
+
+		// Hub asset received is never worth more than the sold amount at spot price
+		assert!(U256::from(hub_amount_out) <= hub_asset_value(amount, &asset_in));
+
+		assert_asset_invariant(&asset_in, &asset_in_state, None, "Sell for hub update invariant - token in");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn buy_hub_asset_update_invariants_no_fees(asset_in in asset_state(),
+		amount in trade_amount(),
+		imbalance in hub_withdrawal_imbalance(),
+	) {
+		let total_hub_reserve = 100 * ONE + asset_in.hub_reserve;
+
+		let result = calculate_buy_hub_asset_state_changes(&asset_in, amount,
+			Permill::from_percent(0),
+			imbalance,
+			total_hub_reserve,
+		);
+
+		assert!(result.is_some());
+
+		let state_changes = result.unwrap();
+
+		assert_eq!(*state_changes.asset.delta_hub_reserve, amount);
+
+		let asset_in_state = asset_in.clone();
+		let asset_in_state = asset_in_state.delta_update(&state_changes.asset).unwrap();
+
+		assert_hub_withdrawal_imbalance_update(
+			imbalance,
+			*state_changes.delta_imbalance,
+			total_hub_reserve,
+			amount,
+			"buy hub imbalance invariant failed");
+
+		// Asset paid is never worth less than the bought hub asset at spot price
+		assert!(hub_asset_value(*state_changes.asset.delta_reserve, &asset_in) >= U256::from(amount));
+
+		assert_asset_invariant(&asset_in, &asset_in_state, Some(FixedU128::from((TOLERANCE, ONE))), "Buy hub update invariant - token in");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn buy_hub_asset_update_invariants_with_fees(asset_in in asset_state(),
+		amount in trade_amount(),
+		protocol_fee in fee(),
+		imbalance in hub_withdrawal_imbalance(),
+	) {
+		let total_hub_reserve = 100 * ONE + asset_in.hub_reserve;
+
+		let result = calculate_buy_hub_asset_state_changes(&asset_in, amount,
+			protocol_fee,
+			imbalance,
+			total_hub_reserve,
+		);
+
+		assert!(result.is_some());
+
+		let state_changes = result.unwrap();
+
+		assert_eq!(*state_changes.asset.delta_hub_reserve, amount + state_changes.fee.protocol_fee);
+
+		let asset_in_state = asset_in.clone();
+		let asset_in_state = asset_in_state.delta_update(&state_changes.asset).unwrap();
+
+		assert_hub_withdrawal_imbalance_update(
+			imbalance,
+			*state_changes.delta_imbalance,
+			total_hub_reserve,
+			amount,
+			"buy hub imbalance invariant failed");
+
+		// Asset paid is never worth less than the bought hub asset at spot price
+		assert!(hub_asset_value(*state_changes.asset.delta_reserve, &asset_in) >= U256::from(amount));
+
+		assert_asset_invariant(&asset_in, &asset_in_state, None, "Buy hub update invariant - token in");
+	}
+}
@@ -206,6 +206,105 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 	})
 }
 
+fn calculate_imbalance_in_hub_withdrawal(
+	total_hub_reserve: Balance,
+	delta_hub_reserve: Balance,
+	imbalance: I129<Balance>,
+) -> Option<Balance> {
+	let (delta_q, q, l) = to_u256!(delta_hub_reserve, total_hub_reserve, imbalance.value);
+
+	let num = delta_q.checked_mul(q.checked_sub(l)?)?;
+
+	let denom = q.checked_sub(delta_q)?;
+
+	// rounding down - we want to underestimate how much less to burn.
+	to_balance!(num.checked_div(denom)?.checked_add(delta_q)?).ok()
+}
+
+/// Calculate delta changes of a sell where asset_out is Hub Asset
+///
+/// Protocol fee is deducted from the hub asset amount received by the trader. Fee amount is returned in `fee.protocol_fee`.
+pub fn calculate_sell_for_hub_asset_state_changes(
+	asset_in_state: &AssetReserveState<Balance>,
+	asset_in_amount: Balance,
+	protocol_fee: Permill,
+	imbalance: I129<Balance>,
+	total_hub_reserve: Balance,
+) -> Option<HubTradeStateChange<Balance>> {
+	let (reserve_hp, hub_reserve_hp, amount_hp) =
+		to_u256!(asset_in_state.reserve, asset_in_state.hub_reserve, asset_in_amount);
+
+	let delta_hub_reserve_hp = hub_reserve_hp
+		.checked_mul(amount_hp)
+		.and_then(|v| v.checked_div(reserve_hp.checked_add(amount_hp)?))?;
+
+	let delta_hub_reserve = to_balance!(delta_hub_reserve_hp).ok()?;
+
+	let protocol_fee_amount = protocol_fee.mul_floor(delta_hub_reserve);
+	let hub_amount_out = delta_hub_reserve.checked_sub(protocol_fee_amount)?;
+
+	let delta_imbalance = calculate_imbalance_in_hub_withdrawal(total_hub_reserve, hub_amount_out, imbalance)?;
+
+	Some(HubTradeStateChange {
+		asset: AssetStateChange {
+			delta_reserve: Increase(asset_in_amount),
+			delta_hub_reserve: Decrease(delta_hub_reserve),
+			..Default::default()
+		},
+		delta_imbalance: Increase(delta_imbalance),
+		fee: TradeFee {
+			protocol_fee: protocol_fee_amount,
+			..Default::default()
+		},
+	})
+}
+
+/// Calculate delta changes of a buy where asset_out is Hub Asset
+///
+/// Protocol fee is added to the hub asset amount taken from asset's subpool. Fee amount is returned in `fee.protocol_fee`.
+pub fn calculate_buy_hub_asset_state_changes(
+	asset_in_state: &AssetReserveState<Balance>,
+	hub_asset_amount: Balance,
+	protocol_fee: Permill,
+	imbalance: I129<Balance>,
+	total_hub_reserve: Balance,
+) -> Option<HubTradeStateChange<Balance>> {
+	let delta_hub_reserve: Balance = FixedU128::from_inner(hub_asset_amount)
+		.checked_div(&Permill::from_percent(100).checked_sub(&protocol_fee)?.into())?
+		.into_inner();
+
+	if delta_hub_reserve >= asset_in_state.hub_reserve {
+		return None;
+	}
+
+	let (delta_hub_reserve_hp, hub_reserve_hp, reserve_hp) =
+		to_u256!(delta_hub_reserve, asset_in_state.hub_reserve, asset_in_state.reserve);
+
+	let delta_reserve_in = reserve_hp
+		.checked_mul(delta_hub_reserve_hp)
+		.and_then(|v| v.checked_div(hub_reserve_hp.checked_sub(delta_hub_reserve_hp)?))?;
+
+	let delta_reserve_in = to_balance!(delta_reserve_in).ok()?;
+	let delta_reserve_in = delta_reserve_in.checked_add(Balance::one())?;
+
+	let protocol_fee_amount = delta_hub_reserve.checked_sub(hub_asset_amount)?;
+
+	let delta_imbalance = calculate_imbalance_in_hub_withdrawal(total_hub_reserve, hub_asset_amount, imbalance)?;
+
+	Some(HubTradeStateChange {
+		asset: AssetStateChange {
+			delta_reserve: Increase(delta_reserve_in),
+			delta_hub_reserve: Decrease(delta_hub_reserve),
+			..Default::default()
+		},
+		delta_imbalance: Increase(delta_imbalance),
+		fee: TradeFee {
+			protocol_fee: protocol_fee_amount,
+			..Default::default()
+		},
+	})
+}
+
 // only temporary helper function to calculate in amount with no fees
 // will be removed when fee calculation in buy is simplified
 fn calculate_amount_in_no_fee(
@@ -505,6 +604,183 @@ pub fn calculate_tvl(hub_reserve: Balance, stable_asset: (Balance, Balance)) ->
 	to_balance!(tvl).ok()
 }
 
+/// Calculate amount of asset reserve which belongs to given quantity of shares.
+/// Result is rounded down.
+pub fn calculate_shares_reserve(reserve: Balance, shares: Balance, total_shares: Balance) -> Option<Balance> {
+	let (reserve_hp, shares_hp, total_shares_hp) = to_u256!(reserve, shares, total_shares);
+
+	let shares_reserve_hp = reserve_hp
+		.checked_mul(shares_hp)
+		.and_then(|v| v.checked_div(total_shares_hp))?;
+
+	to_balance!(shares_reserve_hp).ok()
+}
+
+/// Calculate position which results from merging given positions of an asset in given asset state.
+///
+/// Amounts and shares of the positions are summed. If all positions have the same price ( compared with
+/// FixedU128 precision ), price of the first position is kept and removing liquidity of the merged position
+/// is equivalent to removing liquidity of the positions separately in any state of the pool.
+///
+/// Otherwise price of the merged position is calculated so value of the positions in the current state is preserved.
+/// Value of a share of position with price p removed at state ( R, Q, S ) is 2 * Q^2 / ( S * ( Q + p * R ) )
+/// in hub asset - both when LP receives hub asset ( p < Q/R ) and when part of the shares becomes protocol owned
+/// ( p > Q/R ). Merged price p' is therefore given by
+///
+/// sum(s) / ( Q + p' * R ) = sum( s_i / ( Q + p_i * R ) )
+///
+/// and returned as rational ( p' * R, R ). Price is rounded up so value of the merged position can be lower only
+/// by rounding.
+///
+/// Returns None if there is no position to merge, total shares is zero or calculation overflows.
+pub fn calculate_merged_position(
+	asset_state: &AssetReserveState<Balance>,
+	positions: &[Position<Balance>],
+) -> Option<Position<Balance>> {
+	let first = positions.first()?;
+	let first_price = first.price()?;
+
+	let mut amount = Balance::zero();
+	let mut shares = Balance::zero();
+	let mut same_price = true;
+
+	for position in positions {
+		amount = amount.checked_add(position.amount)?;
+		shares = shares.checked_add(position.shares)?;
+		same_price = same_price && position.price()? == first_price;
+	}
+
+	if same_price {
+		return Some(Position {
+			amount,
+			shares,
+			price: first.price,
+		});
+	}
+
+	let (reserve_hp, hub_reserve_hp, div_hp) = to_u256!(asset_state.reserve, asset_state.hub_reserve, FixedU128::DIV);
+	let scale_hp = hub_reserve_hp.checked_mul(div_hp)?;
+
+	// sum( s_i * scale / ( Q + p_i * R ) ), rounded down
+	let mut weighted_shares_hp = U256::zero();
+	for position in positions {
+		let (shares_hp, price_n_hp, price_d_hp) = to_u256!(position.shares, position.price.0, position.price.1);
+
+		let p_x_r = price_n_hp.checked_mul(reserve_hp)?.checked_div(price_d_hp)?;
+		let denom = hub_reserve_hp.checked_add(p_x_r)?;
+
+		weighted_shares_hp = weighted_shares_hp.checked_add(shares_hp.checked_mul(scale_hp)?.checked_div(denom)?)?;
+	}
+
+	// p' * R = sum(s) * scale / weighted_shares - Q, rounded up
+	let merged_p_x_r = to_u256!(shares)
+		.checked_mul(scale_hp)?
+		.checked_add(weighted_shares_hp.checked_sub(U256::one())?)?
+		.checked_div(weighted_shares_hp)?
+		.checked_sub(hub_reserve_hp)?;
+
+	Some(Position {
+		amount,
+		shares,
+		price: (to_balance!(merged_p_x_r).ok()?, asset_state.reserve),
+	})
+}
+
+/// Calculate positions which result from splitting given quantity of shares off a position.
+///
+/// Amount is split proportionally to shares. Split amount is rounded down, so sum of amounts is preserved.
+/// Price of both positions is unchanged.
+///
+/// Returns ( remaining position, new position ) or None if shares exceed shares of the position.
+pub fn calculate_split_position(
+	position: &Position<Balance>,
+	shares: Balance,
+) -> Option<(Position<Balance>, Position<Balance>)> {
+	let remaining_shares = position.shares.checked_sub(shares)?;
+
+	let split_amount = calculate_shares_reserve(position.amount, shares, position.shares)?;
+	let remaining_amount = position.amount.checked_sub(split_amount)?;
+
+	Some((
+		Position {
+			amount: remaining_amount,
+			shares: remaining_shares,
+			price: position.price,
+		},
+		Position {
+			amount: split_amount,
+			shares,
+			price: position.price,
+		},
+	))
+}
+
+/// Calculate average of values weighted by given weights. Result is rounded down.
+///
+/// Returns None if there is no value or sum of weights is zero.
+pub fn calculate_weighted_average(values: &[(u128, Balance)]) -> Option<u128> {
+	let mut weighted_sum_hp = U256::zero();
+	let mut total_weight_hp = U256::zero();
+
+	for (value, weight) in values {
+		let (value_hp, weight_hp) = to_u256!(*value, *weight);
+
+		weighted_sum_hp = weighted_sum_hp.checked_add(value_hp.checked_mul(weight_hp)?)?;
+		total_weight_hp = total_weight_hp.checked_add(weight_hp)?;
+	}
+
+	to_balance!(weighted_sum_hp.checked_div(total_weight_hp)?).ok()
+}
+
+/// Calculate fraction of impermanent loss which is compensated to LP who held a position for `held_period` blocks.
+///
+/// No compensation is provided before `min_holding_period` is reached. Afterwards, the fraction vests linearly
+/// so the full loss is compensated once the position is held for `vesting_period` blocks.
+pub fn calculate_il_vesting_fraction(held_period: u128, min_holding_period: u128, vesting_period: u128) -> FixedU128 {
+	if held_period < min_holding_period {
+		return FixedU128::zero();
+	}
+
+	if held_period >= vesting_period {
+		return FixedU128::one();
+	}
+
+	FixedU128::checked_from_rational(held_period, vesting_period).unwrap_or_else(FixedU128::zero)
+}
+
+/// Calculate impermanent loss compensation of LP who removes liquidity with given state changes.
+///
+/// Impermanent loss is the difference between the position amount of removed shares ( what LP would have if the asset
+/// was held ) and the value of asset and hub asset received by LP, both in asset units at the current price.
+/// Withdrawal fee is not a part of the loss, so it is applied to the held amount as well.
+///
+/// Returns `vesting_fraction` of the loss or None if calculation overflows.
+pub fn calculate_il_compensation(
+	asset_state: &AssetReserveState<Balance>,
+	state_changes: &LiquidityStateChange<Balance>,
+	withdrawal_fee: FixedU128,
+	vesting_fraction: FixedU128,
+) -> Option<Balance> {
+	let fee_complement = FixedU128::one().saturating_sub(withdrawal_fee);
+	let held_amount = fee_complement.checked_mul_int(*state_changes.delta_position_reserve)?;
+
+	let (hub_received_hp, reserve_hp, hub_reserve_hp) = to_u256!(
+		state_changes.lp_hub_amount,
+		asset_state.reserve,
+		asset_state.hub_reserve
+	);
+
+	let hub_received_in_asset_hp = hub_received_hp
+		.checked_mul(reserve_hp)
+		.and_then(|v| v.checked_div(hub_reserve_hp))?;
+
+	let received = (*state_changes.asset.delta_reserve).checked_add(to_balance!(hub_received_in_asset_hp).ok()?)?;
+
+	let loss = held_amount.saturating_sub(received);
+
+	vesting_fraction.checked_mul_int(loss)
+}
+
 /// Calculate delta imbalance given delta hub reserve to be added to pool
 pub fn calculate_delta_imbalance(
 	delta_hub_reserve: Balance,
@@ -1,13 +1,15 @@
 use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position, TradeFee, I129};
 use crate::omnipool::{
-	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes, calculate_buy_state_changes,
-	calculate_cap_difference, calculate_delta_imbalance, calculate_remove_liquidity_state_changes,
-	calculate_sell_hub_state_changes, calculate_sell_state_changes, calculate_tvl_cap_difference,
-	calculate_withdrawal_fee, verify_asset_cap,
+	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes,
+	calculate_buy_hub_asset_state_changes, calculate_buy_state_changes, calculate_cap_difference,
+	calculate_delta_imbalance, calculate_il_compensation, calculate_il_vesting_fraction, calculate_merged_position,
+	calculate_remove_liquidity_state_changes, calculate_sell_for_hub_asset_state_changes,
+	calculate_sell_hub_state_changes, calculate_sell_state_changes, calculate_shares_reserve, calculate_split_position,
+	calculate_tvl_cap_difference, calculate_weighted_average, calculate_withdrawal_fee, verify_asset_cap,
 };
 use crate::types::Balance;
 use num_traits::{One, Zero};
-use sp_arithmetic::{FixedU128, Permill};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use std::str::FromStr;
 
 const UNIT: Balance = 1_000_000_000_000;
@@ -291,6 +293,196 @@ fn calculate_sell_hub_asset_with_fee_should_work_when_correct_input_provided() {
 	);
 }
 
+#[test]
+fn calculate_sell_for_hub_asset_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_sell = 4 * UNIT;
+	let protocol_fee = Permill::from_percent(0);
+	let imbalance = I129 {
+		value: 2 * UNIT,
+		negative: true,
+	};
+	let total_hub_reserve = 40 * UNIT;
+
+	let state_changes = calculate_sell_for_hub_asset_state_changes(
+		&asset_state,
+		amount_to_sell,
+		protocol_fee,
+		imbalance,
+		total_hub_reserve,
+	);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(amount_to_sell)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(5714285714285u128)
+	);
+
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(12047619047617));
+	assert_eq!(state_changes.fee, TradeFee::default());
+}
+
+#[test]
+fn calculate_sell_for_hub_asset_with_fee_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_sell = 4 * UNIT;
+	let protocol_fee = Permill::from_percent(1);
+	let imbalance = I129 {
+		value: 2 * UNIT,
+		negative: true,
+	};
+	let total_hub_reserve = 40 * UNIT;
+
+	let state_changes = calculate_sell_for_hub_asset_state_changes(
+		&asset_state,
+		amount_to_sell,
+		protocol_fee,
+		imbalance,
+		total_hub_reserve,
+	);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(amount_to_sell)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(5714285714285u128)
+	);
+
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(11916710244830));
+	assert_eq!(
+		state_changes.fee,
+		TradeFee {
+			asset_fee: 0,
+			protocol_fee: 57142857142,
+		}
+	);
+}
+
+#[test]
+fn calculate_buy_hub_asset_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_buy = 2 * UNIT;
+	let protocol_fee = Permill::from_percent(0);
+	let imbalance = I129 {
+		value: 2 * UNIT,
+		negative: true,
+	};
+	let total_hub_reserve = 40 * UNIT;
+
+	let state_changes =
+		calculate_buy_hub_asset_state_changes(&asset_state, amount_to_buy, protocol_fee, imbalance, total_hub_reserve);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(1111111111112u128)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(amount_to_buy)
+	);
+
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(4 * UNIT));
+	assert_eq!(state_changes.fee, TradeFee::default());
+}
+
+#[test]
+fn calculate_buy_hub_asset_with_fee_should_work_when_correct_input_provided() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let amount_to_buy = 2 * UNIT;
+	let protocol_fee = Permill::from_percent(1);
+	let imbalance = I129 {
+		value: 2 * UNIT,
+		negative: true,
+	};
+	let total_hub_reserve = 40 * UNIT;
+
+	let state_changes =
+		calculate_buy_hub_asset_state_changes(&asset_state, amount_to_buy, protocol_fee, imbalance, total_hub_reserve);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(1123595505618u128)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(2020202020202u128)
+	);
+
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(4 * UNIT));
+	assert_eq!(
+		state_changes.fee,
+		TradeFee {
+			asset_fee: 0,
+			protocol_fee: 20202020202,
+		}
+	);
+}
+
+#[test]
+fn calculate_buy_hub_asset_should_fail_when_hub_reserve_is_insufficient() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let imbalance = I129 {
+		value: 2 * UNIT,
+		negative: true,
+	};
+
+	let state_changes =
+		calculate_buy_hub_asset_state_changes(&asset_state, 20 * UNIT, Permill::from_percent(0), imbalance, 40 * UNIT);
+
+	assert!(state_changes.is_none());
+}
+
 #[test]
 fn calculate_buy_should_work_when_correct_input_provided() {
 	let asset_in_state = AssetReserveState {
@@ -1058,3 +1250,255 @@ fn calculate_withdrawal_fee_should_work_correctly() {
 		expected_fee
 	);
 }
+
+#[test]
+fn calculate_shares_reserve_should_work_when_correct_input_provided() {
+	let result = calculate_shares_reserve(1_000 * UNIT, 250 * UNIT, 500 * UNIT);
+	assert_eq!(result, Some(500 * UNIT));
+}
+
+#[test]
+fn calculate_shares_reserve_should_round_down() {
+	let result = calculate_shares_reserve(10, 1, 3);
+	assert_eq!(result, Some(3));
+}
+
+#[test]
+fn calculate_shares_reserve_should_fail_when_total_shares_is_zero() {
+	let result = calculate_shares_reserve(1_000 * UNIT, 0, 0);
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_merged_position_should_work_when_correct_input_provided() {
+	let positions = [
+		Position {
+			amount: 100 * UNIT,
+			shares: 100 * UNIT,
+			price: (2 * UNIT, UNIT),
+		},
+		Position {
+			amount: 200 * UNIT,
+			shares: 300 * UNIT,
+			price: (4 * UNIT, 2 * UNIT),
+		},
+	];
+
+	let state = AssetReserveState {
+		reserve: 1_000 * UNIT,
+		hub_reserve: 1_000 * UNIT,
+		shares: 1_000 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let result = calculate_merged_position(&state, &positions);
+
+	assert_eq!(
+		result,
+		Some(Position {
+			amount: 300 * UNIT,
+			shares: 400 * UNIT,
+			price: (2 * UNIT, UNIT),
+		})
+	);
+}
+
+#[test]
+fn calculate_merged_position_should_preserve_value_when_prices_differ() {
+	let positions = [
+		Position {
+			amount: 100 * UNIT,
+			shares: 100 * UNIT,
+			price: (UNIT, UNIT),
+		},
+		Position {
+			amount: 200 * UNIT,
+			shares: 300 * UNIT,
+			price: (2 * UNIT, UNIT),
+		},
+	];
+
+	let state = AssetReserveState {
+		reserve: 1_000 * UNIT,
+		hub_reserve: 1_000 * UNIT,
+		shares: 1_000 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let result = calculate_merged_position(&state, &positions);
+
+	// 400 / ( 1000 + p' * 1000 ) = 100 / 2000 + 300 / 3000
+	assert_eq!(
+		result,
+		Some(Position {
+			amount: 300 * UNIT,
+			shares: 400 * UNIT,
+			price: (1_666_666_666_666_667, 1_000 * UNIT),
+		})
+	);
+}
+
+#[test]
+fn calculate_merged_position_should_fail_when_no_position_provided() {
+	let state = AssetReserveState {
+		reserve: 1_000 * UNIT,
+		hub_reserve: 1_000 * UNIT,
+		shares: 1_000 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let result = calculate_merged_position(&state, &[]);
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_split_position_should_work_when_correct_input_provided() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: 300 * UNIT,
+		price: (2 * UNIT, UNIT),
+	};
+
+	let result = calculate_split_position(&position, 100 * UNIT);
+
+	assert_eq!(
+		result,
+		Some((
+			Position {
+				amount: 66_666_666_666_667,
+				shares: 200 * UNIT,
+				price: (2 * UNIT, UNIT),
+			},
+			Position {
+				amount: 33_333_333_333_333,
+				shares: 100 * UNIT,
+				price: (2 * UNIT, UNIT),
+			}
+		))
+	);
+}
+
+#[test]
+fn calculate_split_position_should_fail_when_shares_exceed_position_shares() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: 300 * UNIT,
+		price: (2 * UNIT, UNIT),
+	};
+
+	let result = calculate_split_position(&position, 301 * UNIT);
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_il_vesting_fraction_should_be_zero_when_min_holding_period_not_reached() {
+	assert_eq!(calculate_il_vesting_fraction(9, 10, 100), FixedU128::zero());
+}
+
+#[test]
+fn calculate_il_vesting_fraction_should_vest_linearly_when_min_holding_period_reached() {
+	assert_eq!(
+		calculate_il_vesting_fraction(10, 10, 100),
+		FixedU128::from_rational(1, 10)
+	);
+	assert_eq!(
+		calculate_il_vesting_fraction(50, 10, 100),
+		FixedU128::from_rational(1, 2)
+	);
+}
+
+#[test]
+fn calculate_il_vesting_fraction_should_be_one_when_vesting_period_reached() {
+	assert_eq!(calculate_il_vesting_fraction(100, 10, 100), FixedU128::one());
+	assert_eq!(calculate_il_vesting_fraction(1_000, 10, 100), FixedU128::one());
+	assert_eq!(calculate_il_vesting_fraction(10, 10, 0), FixedU128::one());
+}
+
+#[test]
+fn calculate_il_compensation_should_work_when_price_decreased() {
+	let asset_state = AssetReserveState {
+		reserve: 10_000 * UNIT,
+		hub_reserve: 10_000 * UNIT,
+		shares: 10_000 * UNIT,
+		protocol_shares: Balance::zero(),
+	};
+
+	let position = Position {
+		amount: 1_000 * UNIT,
+		shares: 1_000 * UNIT,
+		price: (2 * UNIT, UNIT),
+	};
+
+	let state_changes = calculate_remove_liquidity_state_changes(
+		&asset_state,
+		1_000 * UNIT,
+		&position,
+		I129 {
+			value: 0,
+			negative: true,
+		},
+		10_000 * UNIT,
+		FixedU128::zero(),
+	)
+	.unwrap();
+
+	assert_eq!(*state_changes.asset.delta_reserve, 666_666_666_666_666);
+
+	let full = calculate_il_compensation(&asset_state, &state_changes, FixedU128::zero(), FixedU128::one());
+	assert_eq!(full, Some(333_333_333_333_334));
+
+	let half = calculate_il_compensation(
+		&asset_state,
+		&state_changes,
+		FixedU128::zero(),
+		FixedU128::from_rational(1, 2),
+	);
+	assert_eq!(half, Some(166_666_666_666_667));
+}
+
+#[test]
+fn calculate_il_compensation_should_be_zero_when_price_increased() {
+	let asset_state = AssetReserveState {
+		reserve: 10_000 * UNIT,
+		hub_reserve: 10_000 * UNIT,
+		shares: 10_000 * UNIT,
+		protocol_shares: Balance::zero(),
+	};
+
+	let position = Position {
+		amount: 1_000 * UNIT,
+		shares: 1_000 * UNIT,
+		price: (UNIT, 2 * UNIT),
+	};
+
+	let state_changes = calculate_remove_liquidity_state_changes(
+		&asset_state,
+		1_000 * UNIT,
+		&position,
+		I129 {
+			value: 0,
+			negative: true,
+		},
+		10_000 * UNIT,
+		FixedU128::zero(),
+	)
+	.unwrap();
+
+	assert!(state_changes.lp_hub_amount > Balance::zero());
+
+	let result = calculate_il_compensation(&asset_state, &state_changes, FixedU128::zero(), FixedU128::one());
+	assert_eq!(result, Some(Balance::zero()));
+}
+
+#[test]
+fn calculate_weighted_average_should_work_when_correct_input_provided() {
+	let result = calculate_weighted_average(&[(10, 100 * UNIT), (20, 300 * UNIT)]);
+	assert_eq!(result, Some(17));
+}
+
+#[test]
+fn calculate_weighted_average_should_fail_when_total_weight_is_zero() {
+	assert!(calculate_weighted_average(&[]).is_none());
+	assert!(calculate_weighted_average(&[(10, 0)]).is_none());
+}
@@ -43,6 +43,18 @@ fn whitelist_storage_maps<T: Config>() {
 		let key = <AllowedRemoveLiquidityAmountPerAsset<T>>::hashed_key_for(k);
 		frame_benchmarking::benchmarking::add_to_whitelist(key.into());
 	}
+	// Whitelist storage map from further DB operations.
+	let iter = <AllowedTradeVolumeLimitPerAccount<T>>::iter();
+	for (k1, k2, _v) in iter {
+		let key = <AllowedTradeVolumeLimitPerAccount<T>>::hashed_key_for(k1, k2);
+		frame_benchmarking::benchmarking::add_to_whitelist(key.into());
+	}
+	// Whitelist storage map from further DB operations.
+	let iter = <AllowedRemoveLiquidityAmountPerAccount<T>>::iter();
+	for (k1, k2, _v) in iter {
+		let key = <AllowedRemoveLiquidityAmountPerAccount<T>>::hashed_key_for(k1, k2);
+		frame_benchmarking::benchmarking::add_to_whitelist(key.into());
+	}
 }
 
 benchmarks! {
@@ -102,6 +114,23 @@ benchmarks! {
 	}: { Pallet::<T>::on_finalize(block_num); }
 	verify {}
 
+	on_finalize_single_account_limit_entry {
+		let block_num: T::BlockNumber = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+
+		Pallet::<T>::on_initialize(block_num);
+
+		let user: T::AccountId = account("user", 0, 1);
+		let amount = T::Balance::from(1_000_000u32);
+		let asset_id = T::AssetId::from(1);
+		let limit = (crate::MAX_LIMIT_VALUE, 1);
+		Pallet::<T>::ensure_and_update_account_trade_volume_limit(&user, limit, asset_id, amount, amount, Zero::zero())?;
+		Pallet::<T>::ensure_and_update_account_remove_liquidity_limit(&user, limit, asset_id, amount, Zero::zero())?;
+
+		whitelist_storage_maps::<T>();
+	}: { Pallet::<T>::on_finalize(block_num); }
+	verify {}
+
 	on_finalize_empty {
 		let block_num: T::BlockNumber = 5u32.into();
 		frame_system::Pallet::<T>::set_block_number(block_num);
@@ -139,6 +168,44 @@ benchmarks! {
 		assert_eq!(LiquidityRemoveLimitPerAsset::<T>::get(asset_id), trade_limit);
 	}
 
+	set_limit_tier {
+		let tier_id: LimitTierId = 1;
+		let tier = AccountLimitTier {
+			trade_volume_limit: Some((crate::MAX_LIMIT_VALUE, 1)),
+			remove_liquidity_limit: Some((crate::MAX_LIMIT_VALUE, 1)),
+			bypass_asset_limits: true,
+		};
+
+	}: _(RawOrigin::Root, tier_id, Some(tier.clone()))
+	verify {
+		assert_eq!(LimitTiers::<T>::get(tier_id), Some(tier));
+	}
+
+	set_account_limit_tier {
+		let user: T::AccountId = account("user", 0, 1);
+		let tier_id: LimitTierId = 1;
+		crate::Pallet::<T>::set_limit_tier(RawOrigin::Root.into(), tier_id, Some(AccountLimitTier::default()))?;
+
+	}: _(RawOrigin::Root, user.clone(), Some(tier_id))
+	verify {
+		assert_eq!(AccountLimitTiers::<T>::get(user), Some(tier_id));
+	}
+
+	set_pool_limits {
+		let pool: T::AccountId = account("pool", 0, 1);
+		let pool_key = (*b"omnipool", pool);
+		let asset_id = T::AssetId::from(2u32);
+		let limits = PoolLimits {
+			trade_volume_limit: (crate::MAX_LIMIT_VALUE, 1),
+			add_liquidity_limit: Some((crate::MAX_LIMIT_VALUE, 1)),
+			remove_liquidity_limit: Some((crate::MAX_LIMIT_VALUE, 1)),
+		};
+
+	}: _(RawOrigin::Root, pool_key.clone(), asset_id, Some(limits.clone()))
+	verify {
+		assert_eq!(PoolLimitsPerAsset::<T>::get(pool_key, asset_id), Some(limits));
+	}
+
 	ensure_add_liquidity_limit {
 		let user: T::AccountId = account("user", 0, 1);
 		let asset_id = T::AssetId::from(2u32);
@@ -146,6 +213,11 @@ benchmarks! {
 		let before = AllowedAddLiquidityAmountPerAsset::<T>::get(asset_id);
 
 		crate::Pallet::<T>::set_add_liquidity_limit(RawOrigin::Root.into(), asset_id, trade_limit)?;
+
+		// worst case: the account has a tier which doesn't bypass the asset limits
+		let tier_id: LimitTierId = 1;
+		crate::Pallet::<T>::set_limit_tier(RawOrigin::Root.into(), tier_id, Some(AccountLimitTier::default()))?;
+		crate::Pallet::<T>::set_account_limit_tier(RawOrigin::Root.into(), user.clone(), Some(tier_id))?;
 	}: {
 		crate::Pallet::<T>::ensure_add_liquidity_limit(RawOrigin::Signed(user).into(), asset_id, 100u128.into(), 10u128.into())?
 	}
@@ -161,6 +233,15 @@ benchmarks! {
 		let before = AllowedAddLiquidityAmountPerAsset::<T>::get(asset_id);
 		let initial_liquidity = 100_000_000_000_000u128;
 		let removed_liquidity = 100_000_000_000u128;	// 0.1% of initial_liquidity
+
+		// worst case: the account is limited individually as well
+		let tier_id: LimitTierId = 1;
+		let tier = AccountLimitTier {
+			remove_liquidity_limit: trade_limit,
+			..Default::default()
+		};
+		crate::Pallet::<T>::set_limit_tier(RawOrigin::Root.into(), tier_id, Some(tier))?;
+		crate::Pallet::<T>::set_account_limit_tier(RawOrigin::Root.into(), user.clone(), Some(tier_id))?;
 	}: {
 		crate::Pallet::<T>::ensure_remove_liquidity_limit(RawOrigin::Signed(user).into(), asset_id, initial_liquidity.into(), removed_liquidity.into())?
 	}
@@ -170,6 +251,7 @@ benchmarks! {
 	}
 
 	ensure_pool_state_change_limit {
+		let user: T::AccountId = account("user", 0, 1);
 		let asset_in_id = T::AssetId::from(2u32);
 		let asset_in_reserve = 100_000_000_000_000u128;
 		let amount_in= 10_000_000_000_000u128;
@@ -178,8 +260,17 @@ benchmarks! {
 		let amount_out = 10_000_000_000_000u128;
 		let before_in = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_in_id);
 		let before_out = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_out_id);
+
+		// worst case: the account is limited individually as well
+		let tier_id: LimitTierId = 1;
+		let tier = AccountLimitTier {
+			trade_volume_limit: Some((crate::MAX_LIMIT_VALUE, 1)),
+			..Default::default()
+		};
+		crate::Pallet::<T>::set_limit_tier(RawOrigin::Root.into(), tier_id, Some(tier))?;
+		crate::Pallet::<T>::set_account_limit_tier(RawOrigin::Root.into(), user.clone(), Some(tier_id))?;
 	}: {
-		crate::Pallet::<T>::ensure_pool_state_change_limit(asset_in_id, asset_in_reserve.into(), amount_in.into(), asset_out_id, asset_out_reserve.into(), amount_out.into())?
+		crate::Pallet::<T>::ensure_pool_state_change_limit(RawOrigin::Signed(user.clone()).into(), asset_in_id, asset_in_reserve.into(), amount_in.into(), asset_out_id, asset_out_reserve.into(), amount_out.into())?
 	}
 	verify {
 		let after_in = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_in_id);
@@ -187,6 +278,8 @@ benchmarks! {
 
 		assert!(before_in != after_in);
 		assert!(before_out != after_out);
+		assert!(AllowedTradeVolumeLimitPerAccount::<T>::get(&user, asset_in_id).is_some());
+		assert!(AllowedTradeVolumeLimitPerAccount::<T>::get(&user, asset_out_id).is_some());
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
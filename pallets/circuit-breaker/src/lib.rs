@@ -21,8 +21,9 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::Weight;
 use frame_support::traits::{Contains, EnsureOrigin};
 use frame_support::{ensure, pallet_prelude::DispatchResult, traits::Get};
-use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::OriginFor;
+use frame_system::{ensure_signed, ensure_signed_or_root};
+use hydradx_traits::{pools::PoolLimitHandler, Source};
 use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
 use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
@@ -39,6 +40,9 @@ mod tests;
 /// Max trade volume limit multiplier of liquidity that can be traded in a block
 pub const MAX_LIMIT_VALUE: u32 = 10_000;
 
+/// Number of storage maps of allowed amounts which are cleared in `on_finalize`
+const CLEARED_STORAGE_MAPS: u64 = 10;
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct TradeVolumeLimit<T: Config> {
@@ -120,6 +124,40 @@ where
 	}
 }
 
+/// Identifier of an account limit tier.
+pub type LimitTierId = u32;
+
+/// Limits applied to every account assigned to the tier. The limits are tracked per account and asset,
+/// in addition to the limits of the asset itself.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq, Default)]
+pub struct AccountLimitTier {
+	/// The maximum percentage of a pool's liquidity that an account can trade (net volume) in a block.
+	/// If set to None, the limit is not enforced.
+	pub trade_volume_limit: Option<(u32, u32)>,
+	/// The maximum percentage of a pool's liquidity that an account can remove in a block.
+	/// If set to None, the limit is not enforced.
+	pub remove_liquidity_limit: Option<(u32, u32)>,
+	/// Accounts of the tier bypass the add and remove liquidity limits of an asset,
+	/// the same way as `WhitelistedAccounts` do.
+	pub bypass_asset_limits: bool,
+}
+
+/// Identifier of a pool tracked by `PoolLimitHandler`: the source of the pool and the pool's account.
+pub type PoolKey<AccountId> = (Source, AccountId);
+
+/// Limits of an asset in a specific pool. Overrides the limits of the asset.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct PoolLimits {
+	/// The maximum percentage of a pool's liquidity that can be traded (net volume) in a block.
+	pub trade_volume_limit: (u32, u32),
+	/// The maximum percentage of a pool's liquidity that can be added in a block.
+	/// If set to None, the limit is not enforced.
+	pub add_liquidity_limit: Option<(u32, u32)>,
+	/// The maximum percentage of a pool's liquidity that can be removed in a block.
+	/// If set to None, the limit is not enforced.
+	pub remove_liquidity_limit: Option<(u32, u32)>,
+}
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -134,13 +172,20 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
-			T::WeightInfo::on_finalize(0, 0)
+			T::WeightInfo::on_finalize(0, 0).saturating_add(T::DbWeight::get().writes(CLEARED_STORAGE_MAPS))
 		}
 
 		fn on_finalize(_n: T::BlockNumber) {
 			let _ = <AllowedTradeVolumeLimitPerAsset<T>>::clear(u32::MAX, None);
 			let _ = <AllowedAddLiquidityAmountPerAsset<T>>::clear(u32::MAX, None);
 			let _ = <AllowedRemoveLiquidityAmountPerAsset<T>>::clear(u32::MAX, None);
+			let _ = <AllowedTradeVolumeLimitPerAccount<T>>::clear(u32::MAX, None);
+			let _ = <AllowedRemoveLiquidityAmountPerAccount<T>>::clear(u32::MAX, None);
+			let _ = <AllowedTradeVolumeLimitPerPool<T>>::clear(u32::MAX, None);
+			let _ = <AllowedAddLiquidityAmountPerPool<T>>::clear(u32::MAX, None);
+			let _ = <AllowedRemoveLiquidityAmountPerPool<T>>::clear(u32::MAX, None);
+			let _ = <AllowedTradeVolumeLimitPerPoolAccount<T>>::clear(u32::MAX, None);
+			let _ = <AllowedRemoveLiquidityAmountPerPoolAccount<T>>::clear(u32::MAX, None);
 		}
 
 		fn integrity_test() {
@@ -285,6 +330,78 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	#[pallet::storage]
+	/// Account limit tiers set by set_limit_tier.
+	#[pallet::getter(fn limit_tier)]
+	pub type LimitTiers<T: Config> = StorageMap<_, Blake2_128Concat, LimitTierId, AccountLimitTier>;
+
+	#[pallet::storage]
+	/// Limit tiers assigned to accounts by set_account_limit_tier.
+	/// Accounts without a tier are not limited individually.
+	#[pallet::getter(fn account_limit_tier_id)]
+	pub type AccountLimitTiers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, LimitTierId>;
+
+	#[pallet::storage]
+	/// Trade volumes per account and asset
+	#[pallet::getter(fn allowed_trade_volume_limit_per_account)]
+	pub type AllowedTradeVolumeLimitPerAccount<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, TradeVolumeLimit<T>>;
+
+	#[pallet::storage]
+	/// Remove liquidity volumes per account and asset
+	#[pallet::getter(fn allowed_remove_liquidity_limit_per_account)]
+	pub type AllowedRemoveLiquidityAmountPerAccount<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
+
+	#[pallet::storage]
+	/// Limits of assets in pools set by set_pool_limits.
+	/// If not set, the limits of the asset are used.
+	#[pallet::getter(fn pool_limits_per_asset)]
+	pub type PoolLimitsPerAsset<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKey<T::AccountId>, Blake2_128Concat, T::AssetId, PoolLimits>;
+
+	#[pallet::storage]
+	/// Trade volumes per pool and asset
+	#[pallet::getter(fn allowed_trade_volume_limit_per_pool)]
+	pub type AllowedTradeVolumeLimitPerPool<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKey<T::AccountId>, Blake2_128Concat, T::AssetId, TradeVolumeLimit<T>>;
+
+	#[pallet::storage]
+	/// Add liquidity volumes per pool and asset
+	#[pallet::getter(fn allowed_add_liquidity_limit_per_pool)]
+	pub type AllowedAddLiquidityAmountPerPool<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKey<T::AccountId>, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
+
+	#[pallet::storage]
+	/// Remove liquidity volumes per pool and asset
+	#[pallet::getter(fn allowed_remove_liquidity_limit_per_pool)]
+	pub type AllowedRemoveLiquidityAmountPerPool<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKey<T::AccountId>, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
+
+	#[pallet::storage]
+	/// Trade volumes per pool, account and asset
+	#[pallet::getter(fn allowed_trade_volume_limit_per_pool_account)]
+	pub type AllowedTradeVolumeLimitPerPoolAccount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(PoolKey<T::AccountId>, T::AccountId),
+		Blake2_128Concat,
+		T::AssetId,
+		TradeVolumeLimit<T>,
+	>;
+
+	#[pallet::storage]
+	/// Remove liquidity volumes per pool, account and asset
+	#[pallet::getter(fn allowed_remove_liquidity_limit_per_pool_account)]
+	pub type AllowedRemoveLiquidityAmountPerPoolAccount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(PoolKey<T::AccountId>, T::AccountId),
+		Blake2_128Concat,
+		T::AssetId,
+		LiquidityLimit<T>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -303,6 +420,22 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Account limit tier was set or removed.
+		LimitTierChanged {
+			tier_id: LimitTierId,
+			tier: Option<AccountLimitTier>,
+		},
+		/// Limit tier of an account was set or removed.
+		AccountLimitTierChanged {
+			who: T::AccountId,
+			tier_id: Option<LimitTierId>,
+		},
+		/// Limits of an asset in a pool were set or removed.
+		PoolLimitsChanged {
+			pool: PoolKey<T::AccountId>,
+			asset_id: T::AssetId,
+			limits: Option<PoolLimits>,
+		},
 	}
 
 	#[pallet::error]
@@ -320,6 +453,12 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Account limit tier does not exist
+		LimitTierNotFound,
+		/// Account's trade volume per block has been reached
+		AccountTradeVolumeLimitReached,
+		/// Account's remove liquidity limit per block has been reached
+		AccountRemoveLiquidityLimitReached,
 	}
 
 	#[pallet::call]
@@ -423,6 +562,114 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or remove an account limit tier.
+		///
+		/// Accounts assigned to a removed tier are no longer limited individually.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `tier_id`: The identifier of a tier
+		/// - `tier`: Limits of the tier. If set to None, the tier is removed.
+		///
+		/// Emits `LimitTierChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_limit_tier())]
+		pub fn set_limit_tier(
+			origin: OriginFor<T>,
+			tier_id: LimitTierId,
+			tier: Option<AccountLimitTier>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			match &tier {
+				Some(limits) => {
+					if let Some(limit) = limits.trade_volume_limit {
+						Self::validate_limit(limit)?;
+					}
+					if let Some(limit) = limits.remove_liquidity_limit {
+						Self::validate_limit(limit)?;
+					}
+					<LimitTiers<T>>::insert(tier_id, limits);
+				}
+				None => <LimitTiers<T>>::remove(tier_id),
+			}
+
+			Self::deposit_event(Event::LimitTierChanged { tier_id, tier });
+
+			Ok(())
+		}
+
+		/// Assign a limit tier to an account or remove the account from its tier.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `who`: The account
+		/// - `tier_id`: The identifier of an existing tier. If set to None, the account is removed from its tier.
+		///
+		/// Emits `AccountLimitTierChanged` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_account_limit_tier())]
+		pub fn set_account_limit_tier(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			tier_id: Option<LimitTierId>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			match tier_id {
+				Some(id) => {
+					ensure!(<LimitTiers<T>>::contains_key(id), Error::<T>::LimitTierNotFound);
+					<AccountLimitTiers<T>>::insert(&who, id);
+				}
+				None => <AccountLimitTiers<T>>::remove(&who),
+			}
+
+			Self::deposit_event(Event::AccountLimitTierChanged { who, tier_id });
+
+			Ok(())
+		}
+
+		/// Set or remove limits of an asset in a pool tracked through `PoolLimitHandler`.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `pool`: The source and the account of the pool
+		/// - `asset_id`: The identifier of an asset
+		/// - `limits`: Limits of the asset in the pool. If set to None, the limits of the asset are used.
+		///
+		/// Emits `PoolLimitsChanged` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_limits())]
+		pub fn set_pool_limits(
+			origin: OriginFor<T>,
+			pool: PoolKey<T::AccountId>,
+			asset_id: T::AssetId,
+			limits: Option<PoolLimits>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			match &limits {
+				Some(pool_limits) => {
+					Self::validate_limit(pool_limits.trade_volume_limit)?;
+					if let Some(limit) = pool_limits.add_liquidity_limit {
+						Self::validate_limit(limit)?;
+					}
+					if let Some(limit) = pool_limits.remove_liquidity_limit {
+						Self::validate_limit(limit)?;
+					}
+					<PoolLimitsPerAsset<T>>::insert(&pool, asset_id, pool_limits);
+				}
+				None => <PoolLimitsPerAsset<T>>::remove(&pool, asset_id),
+			}
+
+			Self::deposit_event(Event::PoolLimitsChanged { pool, asset_id, limits });
+
+			Ok(())
+		}
 	}
 }
 
@@ -544,6 +791,220 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_and_update_account_trade_volume_limit(
+		who: &T::AccountId,
+		limit: (u32, u32),
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		// ignore Omnipool's hub asset
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		let mut allowed_volume = match Pallet::<T>::allowed_trade_volume_limit_per_account(who, asset_id) {
+			Some(volume) => volume,
+			None => TradeVolumeLimit::<T> {
+				limit: Self::calculate_limit(asset_reserve, limit)?,
+				volume_in: Zero::zero(),
+				volume_out: Zero::zero(),
+			},
+		};
+
+		allowed_volume.update_amounts(amount_in, amount_out)?;
+		ensure!(
+			allowed_volume.check_limits().is_ok(),
+			Error::<T>::AccountTradeVolumeLimitReached
+		);
+
+		<AllowedTradeVolumeLimitPerAccount<T>>::insert(who, asset_id, allowed_volume);
+
+		Ok(())
+	}
+
+	fn ensure_and_update_account_remove_liquidity_limit(
+		who: &T::AccountId,
+		limit: (u32, u32),
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		removed_liquidity: T::Balance,
+	) -> DispatchResult {
+		// ignore Omnipool's hub asset
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		let mut allowed_liquidity_limit = match Pallet::<T>::allowed_remove_liquidity_limit_per_account(who, asset_id) {
+			Some(liquidity_limit) => liquidity_limit,
+			None => LiquidityLimit::<T> {
+				limit: Self::calculate_limit(initial_liquidity, limit)?,
+				liquidity: Zero::zero(),
+			},
+		};
+
+		allowed_liquidity_limit.update_amount(removed_liquidity)?;
+		ensure!(
+			allowed_liquidity_limit.check_limit().is_ok(),
+			Error::<T>::AccountRemoveLiquidityLimitReached
+		);
+
+		<AllowedRemoveLiquidityAmountPerAccount<T>>::insert(who, asset_id, allowed_liquidity_limit);
+
+		Ok(())
+	}
+
+	/// Returns limits of an asset in a pool. Falls back to the limits of the asset if not set for the pool.
+	pub fn pool_limits(pool: &PoolKey<T::AccountId>, asset_id: T::AssetId) -> PoolLimits {
+		Pallet::<T>::pool_limits_per_asset(pool, asset_id).unwrap_or_else(|| PoolLimits {
+			trade_volume_limit: Pallet::<T>::trade_volume_limit_per_asset(asset_id),
+			add_liquidity_limit: Pallet::<T>::add_liquidity_limit_per_asset(asset_id),
+			remove_liquidity_limit: Pallet::<T>::remove_liquidity_limit_per_asset(asset_id),
+		})
+	}
+
+	fn ensure_and_update_pool_trade_volume_limit(
+		pool: &PoolKey<T::AccountId>,
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		let mut allowed_volume = match Pallet::<T>::allowed_trade_volume_limit_per_pool(pool, asset_id) {
+			Some(volume) => volume,
+			None => TradeVolumeLimit::<T> {
+				limit: Self::calculate_limit(asset_reserve, Self::pool_limits(pool, asset_id).trade_volume_limit)?,
+				volume_in: Zero::zero(),
+				volume_out: Zero::zero(),
+			},
+		};
+
+		allowed_volume.update_amounts(amount_in, amount_out)?;
+		allowed_volume.check_limits()?;
+
+		<AllowedTradeVolumeLimitPerPool<T>>::insert(pool, asset_id, allowed_volume);
+
+		Ok(())
+	}
+
+	fn ensure_and_update_pool_add_liquidity_limit(
+		pool: &PoolKey<T::AccountId>,
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		added_liquidity: T::Balance,
+	) -> DispatchResult {
+		if let Some(limit) = Self::pool_limits(pool, asset_id).add_liquidity_limit {
+			let mut allowed_liquidity_limit = match Pallet::<T>::allowed_add_liquidity_limit_per_pool(pool, asset_id) {
+				Some(liquidity_limit) => liquidity_limit,
+				None => LiquidityLimit::<T> {
+					limit: Self::calculate_limit(initial_liquidity, limit)?,
+					liquidity: Zero::zero(),
+				},
+			};
+
+			allowed_liquidity_limit.update_amount(added_liquidity)?;
+			allowed_liquidity_limit.check_limit()?;
+
+			<AllowedAddLiquidityAmountPerPool<T>>::insert(pool, asset_id, allowed_liquidity_limit);
+		}
+
+		Ok(())
+	}
+
+	fn ensure_and_update_pool_remove_liquidity_limit(
+		pool: &PoolKey<T::AccountId>,
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		removed_liquidity: T::Balance,
+	) -> DispatchResult {
+		if let Some(limit) = Self::pool_limits(pool, asset_id).remove_liquidity_limit {
+			let mut allowed_liquidity_limit = match Pallet::<T>::allowed_remove_liquidity_limit_per_pool(pool, asset_id)
+			{
+				Some(liquidity_limit) => liquidity_limit,
+				None => LiquidityLimit::<T> {
+					limit: Self::calculate_limit(initial_liquidity, limit)?,
+					liquidity: Zero::zero(),
+				},
+			};
+
+			allowed_liquidity_limit.update_amount(removed_liquidity)?;
+			allowed_liquidity_limit.check_limit()?;
+
+			<AllowedRemoveLiquidityAmountPerPool<T>>::insert(pool, asset_id, allowed_liquidity_limit);
+		}
+
+		Ok(())
+	}
+
+	fn ensure_and_update_pool_account_trade_volume_limit(
+		pool: &PoolKey<T::AccountId>,
+		who: &T::AccountId,
+		limit: (u32, u32),
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		let key = (pool.clone(), who.clone());
+		let mut allowed_volume = match Pallet::<T>::allowed_trade_volume_limit_per_pool_account(&key, asset_id) {
+			Some(volume) => volume,
+			None => TradeVolumeLimit::<T> {
+				limit: Self::calculate_limit(asset_reserve, limit)?,
+				volume_in: Zero::zero(),
+				volume_out: Zero::zero(),
+			},
+		};
+
+		allowed_volume.update_amounts(amount_in, amount_out)?;
+		ensure!(
+			allowed_volume.check_limits().is_ok(),
+			Error::<T>::AccountTradeVolumeLimitReached
+		);
+
+		<AllowedTradeVolumeLimitPerPoolAccount<T>>::insert(&key, asset_id, allowed_volume);
+
+		Ok(())
+	}
+
+	fn ensure_and_update_pool_account_remove_liquidity_limit(
+		pool: &PoolKey<T::AccountId>,
+		who: &T::AccountId,
+		limit: (u32, u32),
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		removed_liquidity: T::Balance,
+	) -> DispatchResult {
+		let key = (pool.clone(), who.clone());
+		let mut allowed_liquidity_limit =
+			match Pallet::<T>::allowed_remove_liquidity_limit_per_pool_account(&key, asset_id) {
+				Some(liquidity_limit) => liquidity_limit,
+				None => LiquidityLimit::<T> {
+					limit: Self::calculate_limit(initial_liquidity, limit)?,
+					liquidity: Zero::zero(),
+				},
+			};
+
+		allowed_liquidity_limit.update_amount(removed_liquidity)?;
+		ensure!(
+			allowed_liquidity_limit.check_limit().is_ok(),
+			Error::<T>::AccountRemoveLiquidityLimitReached
+		);
+
+		<AllowedRemoveLiquidityAmountPerPoolAccount<T>>::insert(&key, asset_id, allowed_liquidity_limit);
+
+		Ok(())
+	}
+
+	/// Returns true if the origin bypasses add and remove liquidity limits of assets and pools.
+	fn is_liquidity_limit_bypassed(origin: OriginFor<T>) -> Result<bool, DispatchError> {
+		let bypass_asset_limits = Self::account_limit_tier(origin.clone())
+			.map(|(_, tier)| tier.bypass_asset_limits)
+			.unwrap_or(false);
+
+		Ok(bypass_asset_limits || Self::is_origin_whitelisted_or_root(origin)?)
+	}
+
 	pub fn validate_limit(limit: (u32, u32)) -> DispatchResult {
 		let (numerator, denominator) = (limit.0, limit.1);
 		ensure!(
@@ -570,6 +1031,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn ensure_pool_state_change_limit(
+		origin: OriginFor<T>,
 		asset_in: T::AssetId,
 		asset_in_reserve: T::Balance,
 		amount_in: T::Balance,
@@ -581,6 +1043,27 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::initialize_trade_limit(asset_out, asset_out_reserve)?;
 		Pallet::<T>::ensure_and_update_trade_volume_limit(asset_in, amount_in, asset_out, amount_out)?;
 
+		if let Some((who, tier)) = Self::account_limit_tier(origin) {
+			if let Some(limit) = tier.trade_volume_limit {
+				Pallet::<T>::ensure_and_update_account_trade_volume_limit(
+					&who,
+					limit,
+					asset_in,
+					asset_in_reserve,
+					amount_in,
+					Zero::zero(),
+				)?;
+				Pallet::<T>::ensure_and_update_account_trade_volume_limit(
+					&who,
+					limit,
+					asset_out,
+					asset_out_reserve,
+					Zero::zero(),
+					amount_out,
+				)?;
+			}
+		}
+
 		Ok(T::WeightInfo::ensure_pool_state_change_limit())
 	}
 
@@ -590,8 +1073,7 @@ impl<T: Config> Pallet<T> {
 		initial_liquidity: T::Balance,
 		added_liquidity: T::Balance,
 	) -> Result<Weight, DispatchError> {
-		let is_whitelisted = Self::is_origin_whitelisted_or_root(origin)?;
-		if is_whitelisted {
+		if Self::is_liquidity_limit_bypassed(origin)? {
 			return Ok(Weight::zero());
 		}
 
@@ -607,9 +1089,24 @@ impl<T: Config> Pallet<T> {
 		initial_liquidity: T::Balance,
 		removed_liquidity: T::Balance,
 	) -> Result<Weight, DispatchError> {
+		// account limits apply to whitelisted accounts too
+		let maybe_tier = Self::account_limit_tier(origin.clone());
+		if let Some((who, tier)) = &maybe_tier {
+			if let Some(limit) = tier.remove_liquidity_limit {
+				Pallet::<T>::ensure_and_update_account_remove_liquidity_limit(
+					who,
+					limit,
+					asset_id,
+					initial_liquidity,
+					removed_liquidity,
+				)?;
+			}
+		}
+		let bypass_asset_limits = maybe_tier.map(|(_, tier)| tier.bypass_asset_limits).unwrap_or(false);
+
 		let is_whitelisted = Self::is_origin_whitelisted_or_root(origin)?;
-		if is_whitelisted {
-			return Ok(Weight::zero());
+		if is_whitelisted || bypass_asset_limits {
+			return Ok(T::WeightInfo::ensure_remove_liquidity_limit());
 		}
 
 		Pallet::<T>::calculate_and_store_liquidity_limits(asset_id, initial_liquidity)?;
@@ -618,6 +1115,13 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
 	}
 
+	/// Returns the account of a signed origin together with its limit tier, if it has one.
+	pub(crate) fn account_limit_tier(origin: OriginFor<T>) -> Option<(T::AccountId, AccountLimitTier)> {
+		let who = ensure_signed(origin).ok()?;
+		let tier = Pallet::<T>::limit_tier(Pallet::<T>::account_limit_tier_id(&who)?)?;
+		Some((who, tier))
+	}
+
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
 		let who = ensure_signed_or_root(origin)?;
 		match who {
@@ -636,3 +1140,135 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+impl<T: Config> PoolLimitHandler<OriginFor<T>, T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+	fn ensure_trade_limit(
+		origin: OriginFor<T>,
+		source: Source,
+		pool: T::AccountId,
+		asset_in: T::AssetId,
+		asset_in_reserve: T::Balance,
+		amount_in: T::Balance,
+		asset_out: T::AssetId,
+		asset_out_reserve: T::Balance,
+		amount_out: T::Balance,
+	) -> Result<Weight, DispatchError> {
+		let pool = (source, pool);
+
+		Pallet::<T>::ensure_and_update_pool_trade_volume_limit(
+			&pool,
+			asset_in,
+			asset_in_reserve,
+			amount_in,
+			Zero::zero(),
+		)?;
+		Pallet::<T>::ensure_and_update_pool_trade_volume_limit(
+			&pool,
+			asset_out,
+			asset_out_reserve,
+			Zero::zero(),
+			amount_out,
+		)?;
+
+		if let Some((who, tier)) = Self::account_limit_tier(origin) {
+			if let Some(limit) = tier.trade_volume_limit {
+				Pallet::<T>::ensure_and_update_pool_account_trade_volume_limit(
+					&pool,
+					&who,
+					limit,
+					asset_in,
+					asset_in_reserve,
+					amount_in,
+					Zero::zero(),
+				)?;
+				Pallet::<T>::ensure_and_update_pool_account_trade_volume_limit(
+					&pool,
+					&who,
+					limit,
+					asset_out,
+					asset_out_reserve,
+					Zero::zero(),
+					amount_out,
+				)?;
+			}
+		}
+
+		Ok(T::WeightInfo::ensure_pool_state_change_limit())
+	}
+
+	fn ensure_add_liquidity_limit(
+		origin: OriginFor<T>,
+		source: Source,
+		pool: T::AccountId,
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		added_liquidity: T::Balance,
+	) -> Result<Weight, DispatchError> {
+		// there is nothing to protect in an empty pool
+		if initial_liquidity.is_zero() || Self::is_liquidity_limit_bypassed(origin)? {
+			return Ok(Weight::zero());
+		}
+
+		Pallet::<T>::ensure_and_update_pool_add_liquidity_limit(
+			&(source, pool),
+			asset_id,
+			initial_liquidity,
+			added_liquidity,
+		)?;
+
+		Ok(T::WeightInfo::ensure_add_liquidity_limit())
+	}
+
+	fn ensure_remove_liquidity_limit(
+		origin: OriginFor<T>,
+		source: Source,
+		pool: T::AccountId,
+		asset_id: T::AssetId,
+		initial_liquidity: T::Balance,
+		removed_liquidity: T::Balance,
+	) -> Result<Weight, DispatchError> {
+		let pool = (source, pool);
+
+		// account limits apply to whitelisted accounts too
+		let maybe_tier = Self::account_limit_tier(origin.clone());
+		if let Some((who, tier)) = &maybe_tier {
+			if let Some(limit) = tier.remove_liquidity_limit {
+				Pallet::<T>::ensure_and_update_pool_account_remove_liquidity_limit(
+					&pool,
+					who,
+					limit,
+					asset_id,
+					initial_liquidity,
+					removed_liquidity,
+				)?;
+			}
+		}
+		let bypass_asset_limits = maybe_tier.map(|(_, tier)| tier.bypass_asset_limits).unwrap_or(false);
+
+		if bypass_asset_limits || Self::is_origin_whitelisted_or_root(origin)? {
+			return Ok(T::WeightInfo::ensure_remove_liquidity_limit());
+		}
+
+		Pallet::<T>::ensure_and_update_pool_remove_liquidity_limit(
+			&pool,
+			asset_id,
+			initial_liquidity,
+			removed_liquidity,
+		)?;
+
+		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
+	}
+
+	fn ensure_trade_limit_weight() -> Weight {
+		T::WeightInfo::ensure_pool_state_change_limit()
+			.saturating_add(T::WeightInfo::on_finalize_single_trade_limit_entry())
+			.saturating_add(T::WeightInfo::on_finalize_single_account_limit_entry())
+	}
+
+	fn ensure_liquidity_limit_weight() -> Weight {
+		T::WeightInfo::ensure_add_liquidity_limit()
+			.max(T::WeightInfo::ensure_remove_liquidity_limit())
+			.saturating_add(T::WeightInfo::on_finalize_single_liquidity_limit_entry())
+			.saturating_add(T::WeightInfo::on_finalize_single_account_limit_entry())
+	}
+}
@@ -0,0 +1,450 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_err, assert_noop};
+pub use pretty_assertions::{assert_eq, assert_ne};
+
+const TIER: LimitTierId = 1;
+
+fn market_maker_tier() -> AccountLimitTier {
+	AccountLimitTier {
+		trade_volume_limit: Some(FIVE_PERCENT),
+		remove_liquidity_limit: Some(FIVE_PERCENT),
+		bypass_asset_limits: true,
+	}
+}
+
+fn assign_tier(who: AccountId, tier: AccountLimitTier) {
+	assert_ok!(CircuitBreaker::set_limit_tier(RuntimeOrigin::root(), TIER, Some(tier)));
+	assert_ok!(CircuitBreaker::set_account_limit_tier(
+		RuntimeOrigin::root(),
+		who,
+		Some(TIER)
+	));
+}
+
+#[test]
+fn set_limit_tier_should_store_tier_when_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_limit_tier(
+			RuntimeOrigin::root(),
+			TIER,
+			Some(market_maker_tier())
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_tier(TIER), Some(market_maker_tier()));
+
+		expect_events(vec![crate::Event::LimitTierChanged {
+			tier_id: TIER,
+			tier: Some(market_maker_tier()),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_limit_tier_should_remove_tier_when_none_is_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_limit_tier(
+			RuntimeOrigin::root(),
+			TIER,
+			Some(market_maker_tier())
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_limit_tier(RuntimeOrigin::root(), TIER, None));
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_tier(TIER), None);
+
+		expect_events(vec![crate::Event::LimitTierChanged {
+			tier_id: TIER,
+			tier: None,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_limit_tier_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_limit_tier(RuntimeOrigin::signed(ALICE), TIER, Some(market_maker_tier())),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_limit_tier_should_fail_if_limit_is_not_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_limit_tier(
+				RuntimeOrigin::root(),
+				TIER,
+				Some(AccountLimitTier {
+					trade_volume_limit: Some((0, 100)),
+					..Default::default()
+				})
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+
+		assert_noop!(
+			CircuitBreaker::set_limit_tier(
+				RuntimeOrigin::root(),
+				TIER,
+				Some(AccountLimitTier {
+					remove_liquidity_limit: Some((MAX_LIMIT_VALUE + 1, 100)),
+					..Default::default()
+				})
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn set_account_limit_tier_should_assign_tier_to_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_limit_tier(
+			RuntimeOrigin::root(),
+			TIER,
+			Some(market_maker_tier())
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_account_limit_tier(
+			RuntimeOrigin::root(),
+			ALICE,
+			Some(TIER)
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::account_limit_tier_id(ALICE), Some(TIER));
+
+		expect_events(vec![crate::Event::AccountLimitTierChanged {
+			who: ALICE,
+			tier_id: Some(TIER),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_account_limit_tier_should_remove_account_from_tier_when_none_is_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		// Act
+		assert_ok!(CircuitBreaker::set_account_limit_tier(
+			RuntimeOrigin::root(),
+			ALICE,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::account_limit_tier_id(ALICE), None);
+	});
+}
+
+#[test]
+fn set_account_limit_tier_should_fail_when_tier_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_limit_tier(RuntimeOrigin::root(), ALICE, Some(TIER)),
+			Error::<Test>::LimitTierNotFound
+		);
+	});
+}
+
+#[test]
+fn set_account_limit_tier_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_limit_tier(
+			RuntimeOrigin::root(),
+			TIER,
+			Some(market_maker_tier())
+		));
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::set_account_limit_tier(RuntimeOrigin::signed(ALICE), ALICE, Some(TIER)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn ensure_pool_state_change_limit_should_track_account_volume_when_account_has_tier() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		// Act
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			10_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			20_000,
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, HDX).unwrap(),
+			TradeVolumeLimit {
+				volume_in: 10_000,
+				volume_out: 0,
+				limit: 50_000,
+			}
+		);
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, DOT).unwrap(),
+			TradeVolumeLimit {
+				volume_in: 0,
+				volume_out: 20_000,
+				limit: 50_000,
+			}
+		);
+	});
+}
+
+#[test]
+fn ensure_pool_state_change_limit_should_not_track_account_volume_when_account_has_no_tier() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		// Act
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(TRADER),
+			HDX,
+			INITIAL_LIQUIDITY,
+			100_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			100_000,
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_account(TRADER, HDX),
+			None
+		);
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_account(TRADER, DOT),
+			None
+		);
+	});
+}
+
+#[test]
+fn ensure_pool_state_change_limit_should_fail_when_account_limit_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			30_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			30_000,
+		));
+
+		// Act & Assert
+		// asset limit is 20%, account limit is 5%
+		assert_err!(
+			CircuitBreaker::ensure_pool_state_change_limit(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				INITIAL_LIQUIDITY,
+				30_000,
+				DOT,
+				INITIAL_LIQUIDITY,
+				30_000,
+			),
+			Error::<Test>::AccountTradeVolumeLimitReached
+		);
+
+		// other accounts are limited only by the asset limit
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(TRADER),
+			HDX,
+			INITIAL_LIQUIDITY,
+			30_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			30_000,
+		));
+	});
+}
+
+#[test]
+fn ensure_pool_state_change_limit_should_ignore_omnipool_hub_asset_for_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		// Act
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(ALICE),
+			LRNA,
+			INITIAL_LIQUIDITY,
+			10_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			10_000,
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, LRNA),
+			None
+		);
+	});
+}
+
+#[test]
+fn ensure_remove_liquidity_limit_should_fail_for_whitelisted_account_when_account_limit_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(
+			WHITELISTED_ACCCOUNT,
+			AccountLimitTier {
+				remove_liquidity_limit: Some(FIVE_PERCENT),
+				..Default::default()
+			},
+		);
+
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+			HDX,
+			INITIAL_LIQUIDITY,
+			50_000
+		));
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::ensure_remove_liquidity_limit(
+				RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+				HDX,
+				INITIAL_LIQUIDITY,
+				1
+			),
+			Error::<Test>::AccountRemoveLiquidityLimitReached
+		);
+	});
+}
+
+#[test]
+fn ensure_remove_liquidity_limit_should_bypass_asset_limit_when_tier_allows_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(
+			ALICE,
+			AccountLimitTier {
+				remove_liquidity_limit: Some((5_000, 10_000)),
+				bypass_asset_limits: true,
+				..Default::default()
+			},
+		);
+
+		// Act & Assert
+		// asset limit is 20%, account limit is 50%
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			400_000
+		));
+		assert_eq!(CircuitBreaker::allowed_remove_liquidity_limit_per_asset(HDX), None);
+		assert_eq!(
+			CircuitBreaker::allowed_remove_liquidity_limit_per_account(ALICE, HDX).unwrap(),
+			LiquidityLimit {
+				liquidity: 400_000,
+				limit: 500_000,
+			}
+		);
+	});
+}
+
+#[test]
+fn ensure_add_liquidity_limit_should_bypass_asset_limit_when_tier_allows_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		// Act & Assert
+		// asset limit is 40%
+		assert_ok!(CircuitBreaker::ensure_add_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			500_000
+		));
+	});
+}
+
+#[test]
+fn account_limits_should_be_cleared_at_the_end_of_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, market_maker_tier());
+
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			10_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			10_000,
+		));
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			10_000
+		));
+
+		// Act
+		CircuitBreaker::on_finalize(2);
+
+		// Assert
+		assert_eq!(CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, HDX), None);
+		assert_eq!(CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, DOT), None);
+		assert_eq!(
+			CircuitBreaker::allowed_remove_liquidity_limit_per_account(ALICE, HDX),
+			None
+		);
+		assert_eq!(CircuitBreaker::limit_tier(TIER), Some(market_maker_tier()));
+	});
+}
@@ -205,6 +205,7 @@ parameter_types! {
 
 impl pallet_omnipool::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type PositionItemId = u32;
 	type Currency = Tokens;
@@ -268,7 +269,7 @@ where
 	}
 
 	fn on_trade(
-		_: RuntimeOrigin,
+		origin: RuntimeOrigin,
 		asset_in: AssetInfo<AssetId, Balance>,
 		asset_out: AssetInfo<AssetId, Balance>,
 	) -> Result<Weight, Self::Error> {
@@ -283,6 +284,7 @@ where
 		};
 
 		pallet_circuit_breaker::Pallet::<T>::ensure_pool_state_change_limit(
+			origin.into(),
 			asset_in.asset_id.into(),
 			asset_in.before.reserve.into(),
 			amount_in.into(),
@@ -311,7 +313,7 @@ where
 	}
 }
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate};
 use frame_support::weights::Weight;
 use hydra_dx_math::ema::EmaPrice;
 
@@ -333,6 +335,37 @@ impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
 	}
 }
 
+impl<AccountId: From<u64> + Into<u64> + Copy> InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = u32>>;
+	type ItemsIterator = Box<dyn Iterator<Item = u32>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (u32, u32)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = u32>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_class: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(_class: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let who: u64 = (*who).into();
+		let owned: Vec<u32> = POSITIONS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| **owner == who)
+				.map(|(instance, _)| *instance)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
 impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
 	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
 		Ok(())
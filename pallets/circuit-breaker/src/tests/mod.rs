@@ -1,7 +1,9 @@
+mod account_limits;
 mod add_liquidity_limit;
 mod ensure_add_liquidity_limit;
 mod ensure_remove_liquidity_limit;
 pub(crate) mod mock;
 mod omnipool;
+mod pool_limits;
 mod remove_liquidity_limit;
 mod trade_volume;
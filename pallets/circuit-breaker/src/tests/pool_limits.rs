@@ -0,0 +1,383 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_err, assert_noop};
+use hydradx_traits::pools::PoolLimitHandler;
+pub use pretty_assertions::{assert_eq, assert_ne};
+
+const POOL: AccountId = 1_000;
+const OTHER_POOL: AccountId = 1_001;
+const SOURCE: Source = *b"testpool";
+
+fn pool_key(pool: AccountId) -> PoolKey<AccountId> {
+	(SOURCE, pool)
+}
+
+fn limits() -> PoolLimits {
+	PoolLimits {
+		trade_volume_limit: FIVE_PERCENT,
+		add_liquidity_limit: Some(FIVE_PERCENT),
+		remove_liquidity_limit: Some(FIVE_PERCENT),
+	}
+}
+
+fn trade(pool: AccountId, amount_in: Balance, amount_out: Balance) -> Result<Weight, DispatchError> {
+	<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_trade_limit(
+		RuntimeOrigin::signed(ALICE),
+		SOURCE,
+		pool,
+		HDX,
+		INITIAL_LIQUIDITY,
+		amount_in,
+		DOT,
+		INITIAL_LIQUIDITY,
+		amount_out,
+	)
+}
+
+#[test]
+fn set_pool_limits_should_store_limits_when_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			Some(limits())
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::pool_limits_per_asset(pool_key(POOL), HDX),
+			Some(limits())
+		);
+		assert_eq!(CircuitBreaker::pool_limits(&pool_key(POOL), HDX), limits());
+
+		expect_events(vec![crate::Event::PoolLimitsChanged {
+			pool: pool_key(POOL),
+			asset_id: HDX,
+			limits: Some(limits()),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_limits_should_remove_limits_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			Some(limits())
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::pool_limits_per_asset(pool_key(POOL), HDX), None);
+	});
+}
+
+#[test]
+fn set_pool_limits_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_pool_limits(RuntimeOrigin::signed(ALICE), pool_key(POOL), HDX, Some(limits())),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_pool_limits_should_fail_when_limit_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let invalid_limits = PoolLimits {
+			trade_volume_limit: (0, 10_000),
+			..limits()
+		};
+
+		assert_noop!(
+			CircuitBreaker::set_pool_limits(RuntimeOrigin::root(), pool_key(POOL), HDX, Some(invalid_limits)),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn pool_limits_should_fall_back_to_asset_limits_when_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			CircuitBreaker::pool_limits(&pool_key(POOL), HDX),
+			PoolLimits {
+				trade_volume_limit: CircuitBreaker::trade_volume_limit_per_asset(HDX),
+				add_liquidity_limit: CircuitBreaker::add_liquidity_limit_per_asset(HDX),
+				remove_liquidity_limit: CircuitBreaker::remove_liquidity_limit_per_asset(HDX),
+			}
+		);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_fail_when_pool_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			DOT,
+			Some(limits())
+		));
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+
+		// Act & Assert
+		assert_err!(trade(POOL, 1, 1), Error::<Test>::TokenOutflowLimitReached);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_track_pools_separately() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let limit =
+			CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, CircuitBreaker::trade_volume_limit_per_asset(DOT))
+				.unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+
+		// Act & Assert
+		assert_ok!(trade(OTHER_POOL, 1, limit));
+		assert_err!(trade(POOL, 1, 1), Error::<Test>::TokenOutflowLimitReached);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_reset_pool_volumes_on_finalize() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let limit =
+			CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, CircuitBreaker::trade_volume_limit_per_asset(DOT))
+				.unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+		assert!(CircuitBreaker::allowed_trade_volume_limit_per_pool(pool_key(POOL), DOT).is_some());
+
+		// Act
+		CircuitBreaker::on_finalize(1);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_pool(pool_key(POOL), DOT),
+			None
+		);
+		assert_ok!(trade(POOL, 1, limit));
+	});
+}
+
+#[test]
+fn ensure_add_liquidity_limit_should_fail_when_pool_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			Some(limits())
+		));
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+
+		// Act & Assert
+		assert_noop!(
+			<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_add_liquidity_limit(
+				RuntimeOrigin::signed(ALICE),
+				SOURCE,
+				POOL,
+				HDX,
+				INITIAL_LIQUIDITY,
+				limit + 1,
+			),
+			Error::<Test>::MaxLiquidityLimitPerBlockReached
+		);
+	});
+}
+
+#[test]
+fn ensure_add_liquidity_limit_should_pass_when_pool_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(
+			<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_add_liquidity_limit(
+				RuntimeOrigin::signed(ALICE),
+				SOURCE,
+				POOL,
+				HDX,
+				0,
+				INITIAL_LIQUIDITY,
+			)
+		);
+	});
+}
+
+#[test]
+fn ensure_remove_liquidity_limit_should_fail_when_pool_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			Some(limits())
+		));
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+
+		// Act & Assert
+		assert_noop!(
+			<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_remove_liquidity_limit(
+				RuntimeOrigin::signed(ALICE),
+				SOURCE,
+				POOL,
+				HDX,
+				INITIAL_LIQUIDITY,
+				limit + 1,
+			),
+			Error::<Test>::MaxLiquidityLimitPerBlockReached
+		);
+	});
+}
+
+#[test]
+fn ensure_remove_liquidity_limit_should_pass_when_origin_is_whitelisted() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_limits(
+			RuntimeOrigin::root(),
+			pool_key(POOL),
+			HDX,
+			Some(limits())
+		));
+
+		// Act & Assert
+		assert_ok!(
+			<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_remove_liquidity_limit(
+				RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+				SOURCE,
+				POOL,
+				HDX,
+				INITIAL_LIQUIDITY,
+				INITIAL_LIQUIDITY,
+			)
+		);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_fail_when_account_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, Some(FIVE_PERCENT), None);
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+
+		// Act & Assert
+		assert_err!(trade(POOL, 1, 1), Error::<Test>::AccountTradeVolumeLimitReached);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_track_account_volumes_per_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, Some(FIVE_PERCENT), None);
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+
+		// Act & Assert
+		assert_ok!(trade(OTHER_POOL, 1, limit));
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_pool_account((pool_key(POOL), ALICE), DOT)
+				.map(|volume| volume.volume_out),
+			Some(limit)
+		);
+		assert_eq!(CircuitBreaker::allowed_trade_volume_limit_per_account(ALICE, DOT), None);
+	});
+}
+
+#[test]
+fn ensure_remove_liquidity_limit_should_fail_when_account_limit_is_exceeded_by_whitelisted_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(WHITELISTED_ACCCOUNT, None, Some(FIVE_PERCENT));
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+
+		// Act & Assert
+		assert_noop!(
+			<CircuitBreaker as PoolLimitHandler<_, _, _, _>>::ensure_remove_liquidity_limit(
+				RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+				SOURCE,
+				POOL,
+				HDX,
+				INITIAL_LIQUIDITY,
+				limit + 1,
+			),
+			Error::<Test>::AccountRemoveLiquidityLimitReached
+		);
+	});
+}
+
+#[test]
+fn ensure_trade_limit_should_reset_account_volumes_on_finalize() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assign_tier(ALICE, Some(FIVE_PERCENT), None);
+		let limit = CircuitBreaker::calculate_limit(INITIAL_LIQUIDITY, FIVE_PERCENT).unwrap();
+		assert_ok!(trade(POOL, 1, limit));
+
+		// Act
+		CircuitBreaker::on_finalize(1);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_pool_account((pool_key(POOL), ALICE), DOT),
+			None
+		);
+		assert_ok!(trade(POOL, 1, limit));
+	});
+}
+
+fn assign_tier(who: AccountId, trade_volume_limit: Option<(u32, u32)>, remove_liquidity_limit: Option<(u32, u32)>) {
+	assert_ok!(CircuitBreaker::set_limit_tier(
+		RuntimeOrigin::root(),
+		1,
+		Some(AccountLimitTier {
+			trade_volume_limit,
+			remove_liquidity_limit,
+			bypass_asset_limits: false,
+		})
+	));
+	assert_ok!(CircuitBreaker::set_account_limit_tier(
+		RuntimeOrigin::root(),
+		who,
+		Some(1)
+	));
+}
@@ -52,6 +52,7 @@ pub trait WeightInfo {
 	fn on_finalize(m: u32, n: u32) -> Weight;
 	fn on_finalize_single_liquidity_limit_entry() -> Weight;
 	fn on_finalize_single_trade_limit_entry() -> Weight;
+	fn on_finalize_single_account_limit_entry() -> Weight;
 	fn on_finalize_empty() -> Weight;
 	fn set_trade_volume_limit() -> Weight;
 	fn set_add_liquidity_limit() -> Weight;
@@ -59,6 +60,9 @@ pub trait WeightInfo {
 	fn ensure_pool_state_change_limit() -> Weight;
 	fn ensure_add_liquidity_limit() -> Weight;
 	fn ensure_remove_liquidity_limit() -> Weight;
+	fn set_limit_tier() -> Weight;
+	fn set_account_limit_tier() -> Weight;
+	fn set_pool_limits() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -72,6 +76,9 @@ impl WeightInfo for () {
 	fn on_finalize_single_trade_limit_entry() -> Weight {
 		Weight::zero()
 	}
+	fn on_finalize_single_account_limit_entry() -> Weight {
+		Weight::zero()
+	}
 	fn on_finalize_empty() -> Weight {
 		Weight::zero()
 	}
@@ -93,4 +100,13 @@ impl WeightInfo for () {
 	fn ensure_remove_liquidity_limit() -> Weight {
 		Weight::zero()
 	}
+	fn set_limit_tier() -> Weight {
+		Weight::zero()
+	}
+	fn set_account_limit_tier() -> Weight {
+		Weight::zero()
+	}
+	fn set_pool_limits() -> Weight {
+		Weight::zero()
+	}
 }
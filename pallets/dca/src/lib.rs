@@ -74,7 +74,7 @@ use frame_support::{
 use frame_system::{ensure_signed, pallet_prelude::OriginFor, Origin};
 use hydradx_adapters::RelayChainBlockHashProvider;
 use hydradx_traits::pools::SpotPriceProvider;
-use hydradx_traits::{OraclePeriod, PriceOracle};
+use hydradx_traits::{OnScheduledTradeHandler, OraclePeriod, PriceOracle};
 use orml_traits::arithmetic::CheckedAdd;
 use orml_traits::MultiCurrency;
 use orml_traits::NamedMultiReservableCurrency;
@@ -171,6 +171,14 @@ pub mod pallet {
 
 				match Self::execute_trade(schedule_id, &schedule) {
 					Ok(amounts) => {
+						T::OnScheduledTrade::on_scheduled_trade(
+							&schedule.owner,
+							schedule.order.get_asset_in(),
+							schedule.order.get_asset_out(),
+							amounts.amount_in,
+							amounts.amount_out,
+						);
+
 						if let Err(err) = Self::replan_or_complete(
 							schedule_id,
 							&schedule,
@@ -268,6 +276,9 @@ pub mod pallet {
 		/// Convert a weight value into a deductible fee
 		type WeightToFee: WeightToFee<Balance = Balance>;
 
+		/// Handler called when a trade of a schedule is executed.
+		type OnScheduledTrade: OnScheduledTradeHandler<Self::AccountId, Self::AssetId, Balance>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -1034,10 +1045,12 @@ where
 	}
 
 	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
-		match order {
+		let trade_weight = match order {
 			Order::Sell { .. } => <T as Config>::WeightInfo::on_initialize_with_sell_trade(),
 			Order::Buy { .. } => <T as Config>::WeightInfo::on_initialize_with_buy_trade(),
-		}
+		};
+
+		trade_weight.saturating_add(T::OnScheduledTrade::on_scheduled_trade_weight())
 	}
 
 	fn convert_native_amount_to_currency(
@@ -246,6 +246,7 @@ parameter_types! {
 
 impl pallet_omnipool::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type PositionItemId = u32;
 	type Currency = Currencies;
@@ -646,6 +647,7 @@ impl Config for Test {
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type RelayChainBlockHashProvider = ParentHashGetterMock;
 	type MinimumTradingLimit = MinTradeAmount;
+	type OnScheduledTrade = ();
 }
 
 pub struct ParentHashGetterMock {}
@@ -656,7 +658,7 @@ impl RelayChainBlockHashProvider for ParentHashGetterMock {
 	}
 }
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate};
 use frame_support::weights::{WeightToFeeCoefficients, WeightToFeePolynomial};
 use frame_system::pallet_prelude::OriginFor;
 use hydra_dx_math::ema::EmaPrice;
@@ -687,6 +689,37 @@ impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
 	}
 }
 
+impl<AccountId: From<u64> + Into<u64> + Copy> InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = u32>>;
+	type ItemsIterator = Box<dyn Iterator<Item = u32>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (u32, u32)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = u32>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_class: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(_class: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let who: u64 = (*who).into();
+		let owned: Vec<u32> = POSITIONS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| **owner == who)
+				.map(|(instance, _)| *instance)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
 impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
 	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
 		Ok(())
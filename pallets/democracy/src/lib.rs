@@ -1221,9 +1221,11 @@ impl<T: Config> Pallet<T> {
 				Err(Error::<T>::AlreadyDelegating.into())
 			}
 		})?;
-		// Extend the lock to `balance` (rather than setting it) since we don't know what other
-		// votes are in place.
-		T::Currency::extend_lock(DEMOCRACY_ID, who, vote.balance(), WithdrawReasons::TRANSFER);
+		// Extend the lock (rather than setting it) since prior locks might not be rejigged yet.
+		let lock_needed = Self::lock_needed(who, VotingOf::<T>::get(who).locked_balance());
+		if !lock_needed.is_zero() {
+			T::Currency::extend_lock(DEMOCRACY_ID, who, lock_needed, WithdrawReasons::TRANSFER);
+		}
 		ReferendumInfoOf::<T>::insert(ref_index, ReferendumInfo::Ongoing(status));
 		T::DemocracyHooks::on_vote(who, ref_index, vote)?;
 		Ok(())
@@ -1377,9 +1379,11 @@ impl<T: Config> Pallet<T> {
 				}
 			}
 			let votes = Self::increase_upstream_delegation(&target, conviction.votes(balance));
-			// Extend the lock to `balance` (rather than setting it) since we don't know what other
-			// votes are in place.
-			T::Currency::extend_lock(DEMOCRACY_ID, &who, balance, WithdrawReasons::TRANSFER);
+			// Extend the lock (rather than setting it) since prior locks might not be rejigged yet.
+			let lock_needed = Self::lock_needed(&who, voting.locked_balance());
+			if !lock_needed.is_zero() {
+				T::Currency::extend_lock(DEMOCRACY_ID, &who, lock_needed, WithdrawReasons::TRANSFER);
+			}
 			Ok(votes)
 		})?;
 		Self::deposit_event(Event::<T>::Delegated { who, target });
@@ -1420,11 +1424,12 @@ impl<T: Config> Pallet<T> {
 
 	/// Rejig the lock on an account. It will never get more stringent (since that would indicate
 	/// a security hole) but may be reduced from what they are currently.
-	fn update_lock(who: &T::AccountId) {
+	pub fn update_lock(who: &T::AccountId) {
 		let lock_needed = VotingOf::<T>::mutate(who, |voting| {
 			voting.rejig(frame_system::Pallet::<T>::block_number());
 			voting.locked_balance()
 		});
+		let lock_needed = Self::lock_needed(who, lock_needed);
 		if lock_needed.is_zero() {
 			T::Currency::remove_lock(DEMOCRACY_ID, who);
 		} else {
@@ -1432,6 +1437,27 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Amount of `who`'s balance democracy has to lock to back `voting_balance`.
+	///
+	/// Nothing is locked if `voting_balance` is fully backed by the balance locked by
+	/// `DemocracyHooks`, e.g. staked tokens. Otherwise the whole `voting_balance` is locked because
+	/// locks of the same balance overlap.
+	fn lock_needed(who: &T::AccountId, voting_balance: BalanceOf<T>) -> BalanceOf<T> {
+		if voting_balance <= T::DemocracyHooks::locked_balance(who) {
+			Zero::zero()
+		} else {
+			voting_balance
+		}
+	}
+
+	/// Amount of `who`'s balance which has to stay locked because of its votes and delegations.
+	/// Expired prior locks are not included even if `unlock` was not called yet.
+	pub fn voting_locked_balance(who: &T::AccountId) -> BalanceOf<T> {
+		let mut voting = VotingOf::<T>::get(who);
+		voting.rejig(frame_system::Pallet::<T>::block_number());
+		voting.locked_balance()
+	}
+
 	/// Start a referendum
 	fn inject_referendum(
 		end: T::BlockNumber,
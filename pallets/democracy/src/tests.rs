@@ -39,6 +39,7 @@ mod decoders;
 mod delegation;
 mod external_proposing;
 mod fast_tracking;
+mod hooks_lock;
 mod lock_voting;
 mod public_proposals;
 mod scheduling;
@@ -158,6 +159,7 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub static PreimageByteDeposit: u64 = 0;
 	pub static InstantAllowed: bool = false;
+	pub static HooksLockedBalance: Vec<(u64, u64)> = vec![];
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -205,7 +207,33 @@ impl Config for Test {
 	type WeightInfo = ();
 	type MaxProposals = ConstU32<100>;
 	type Preimages = Preimage;
-	type DemocracyHooks = ();
+	type DemocracyHooks = HooksMock;
+}
+
+pub struct HooksMock;
+
+impl DemocracyHooks<u64, u64> for HooksMock {
+	fn on_vote(_who: &u64, _ref_index: ReferendumIndex, _vote: AccountVote<u64>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_remove_vote(_who: &u64, _ref_index: ReferendumIndex) -> DispatchResult {
+		Ok(())
+	}
+
+	fn locked_balance(who: &u64) -> u64 {
+		HooksLockedBalance::get()
+			.iter()
+			.find(|(account, _)| account == who)
+			.map(|(_, amount)| *amount)
+			.unwrap_or_default()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn on_vote_worst_case(_who: &u64) {}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn on_remove_vote_worst_case(_who: &u64) {}
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
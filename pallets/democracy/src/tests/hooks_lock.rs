@@ -0,0 +1,109 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The tests for votes backed by the balance locked by `DemocracyHooks`.
+
+use super::*;
+
+fn big_aye_with(balance: u64) -> AccountVote<u64> {
+	AccountVote::Standard { vote: BIG_AYE, balance }
+}
+
+fn the_lock(amount: u64) -> BalanceLock<u64> {
+	BalanceLock {
+		id: DEMOCRACY_ID,
+		amount,
+		reasons: pallet_balances::Reasons::Misc,
+	}
+}
+
+#[test]
+fn vote_should_not_lock_balance_when_it_is_locked_by_hooks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		HooksLockedBalance::set(vec![(2, 15)]);
+		let r = Democracy::inject_referendum(2, set_balance_proposal(2), VoteThreshold::SuperMajorityApprove, 0);
+
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, big_aye_with(15)));
+
+		assert_eq!(Balances::locks(2), vec![]);
+		assert_eq!(Democracy::voting_locked_balance(&2), 15);
+	});
+}
+
+#[test]
+fn vote_should_lock_whole_balance_when_it_is_not_fully_locked_by_hooks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		HooksLockedBalance::set(vec![(2, 15)]);
+		let r = Democracy::inject_referendum(2, set_balance_proposal(2), VoteThreshold::SuperMajorityApprove, 0);
+
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, big_aye_with(20)));
+
+		assert_eq!(Balances::locks(2), vec![the_lock(20)]);
+	});
+}
+
+#[test]
+fn delegate_should_not_lock_balance_when_it_is_locked_by_hooks() {
+	new_test_ext().execute_with(|| {
+		HooksLockedBalance::set(vec![(2, 20)]);
+
+		assert_ok!(Democracy::delegate(
+			RuntimeOrigin::signed(2),
+			1,
+			Conviction::Locked1x,
+			20
+		));
+
+		assert_eq!(Balances::locks(2), vec![]);
+		assert_eq!(Democracy::voting_locked_balance(&2), 20);
+	});
+}
+
+#[test]
+fn unlock_should_remove_lock_when_balance_becomes_locked_by_hooks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		let r = Democracy::inject_referendum(2, set_balance_proposal(2), VoteThreshold::SuperMajorityApprove, 0);
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, big_aye_with(20)));
+		assert_eq!(Balances::locks(2), vec![the_lock(20)]);
+
+		HooksLockedBalance::set(vec![(2, 20)]);
+		assert_ok!(Democracy::unlock(RuntimeOrigin::signed(2), 2));
+
+		assert_eq!(Balances::locks(2), vec![]);
+	});
+}
+
+#[test]
+fn voting_locked_balance_should_not_include_expired_prior_lock() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		HooksLockedBalance::set(vec![(2, 20)]);
+		let r = Democracy::inject_referendum(2, set_balance_proposal(2), VoteThreshold::SuperMajorityApprove, 0);
+		assert_ok!(Democracy::vote(RuntimeOrigin::signed(2), r, big_aye_with(20)));
+
+		fast_forward_to(3);
+		assert_ok!(Democracy::remove_vote(RuntimeOrigin::signed(2), r));
+		// Locked by conviction until end + `VoteLockingPeriod`.
+		assert_eq!(Democracy::voting_locked_balance(&2), 20);
+
+		fast_forward_to(5);
+		assert_eq!(Democracy::voting_locked_balance(&2), 0);
+	});
+}
@@ -1,10 +1,15 @@
 use crate::{AccountVote, ReferendumIndex};
 use frame_support::dispatch::DispatchResult;
+use sp_runtime::traits::Zero;
 
 pub trait DemocracyHooks<AccountId, Balance> {
 	fn on_vote(who: &AccountId, ref_index: ReferendumIndex, vote: AccountVote<Balance>) -> DispatchResult;
 	fn on_remove_vote(who: &AccountId, ref_index: ReferendumIndex) -> DispatchResult;
 
+	/// Returns amount of `who`'s balance which is already locked by the hooks' implementor.
+	/// Votes which are fully backed by this balance are not locked by democracy.
+	fn locked_balance(who: &AccountId) -> Balance;
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn on_vote_worst_case(_who: &AccountId);
 
@@ -12,7 +17,7 @@ pub trait DemocracyHooks<AccountId, Balance> {
 	fn on_remove_vote_worst_case(_who: &AccountId);
 }
 
-impl<AccountId, Balance> DemocracyHooks<AccountId, Balance> for () {
+impl<AccountId, Balance: Zero> DemocracyHooks<AccountId, Balance> for () {
 	fn on_vote(_who: &AccountId, _ref_index: ReferendumIndex, _vote: AccountVote<Balance>) -> DispatchResult {
 		Ok(())
 	}
@@ -21,6 +26,10 @@ impl<AccountId, Balance> DemocracyHooks<AccountId, Balance> for () {
 		Ok(())
 	}
 
+	fn locked_balance(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn on_vote_worst_case(_who: &AccountId) {}
 
@@ -0,0 +1,64 @@
+// This file is part of pallet-dynamic-fees.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+use sp_runtime::FixedU128;
+
+fn fee_params<T: Config>() -> FeeParams<T::Fee> {
+	FeeParams {
+		min_fee: T::Fee::from_percent(1),
+		max_fee: T::Fee::from_percent(10),
+		decay: FixedU128::from_rational(5, 1_000_000),
+		amplification: FixedU128::one(),
+	}
+}
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+	}
+
+	set_asset_fee_params {
+		let asset_id: T::AssetId = 1u32.into();
+		let params = fee_params::<T>();
+	}: _(RawOrigin::Root, asset_id, params, params)
+	verify {
+		assert!(Pallet::<T>::asset_fee_config(asset_id).is_some());
+	}
+
+	remove_asset_fee_params {
+		let asset_id: T::AssetId = 1u32.into();
+		let params = fee_params::<T>();
+		assert_ok!(Pallet::<T>::set_asset_fee_params(RawOrigin::Root.into(), asset_id, params, params));
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(Pallet::<T>::asset_fee_config(asset_id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -40,6 +40,9 @@
 //! The module stores last calculated fees as tuple of `(Fee, Fee, Block number)` where the first item is asset fee,
 //! the second one is protocol fee and the third one is block number indicating when the two fees were updated.
 //!
+//! The fee parameters set in the pallet config are used for all assets by default. They can be overridden for an asset
+//! by `AuthorityOrigin` with `set_asset_fee_params` and reverted back to the defaults with `remove_asset_fee_params`.
+//!
 //! ## Interface
 //!
 //! ### Update and retrieve fee
@@ -60,15 +63,18 @@ use orml_traits::GetByKey;
 use sp_runtime::traits::{BlockNumberProvider, Saturating};
 use sp_runtime::{FixedPointOperand, PerThing, SaturatedConversion};
 
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
 #[cfg(test)]
 mod tests;
 pub mod traits;
 pub mod types;
+pub mod weights;
 
 pub use pallet::*;
 
 use crate::traits::{Volume, VolumeProvider};
-use crate::types::{FeeEntry, FeeParams};
+use crate::types::{AssetFeeConfig, FeeEntry, FeeParams};
 use hydra_dx_math::dynamic_fees::types::OracleEntry;
 use hydra_dx_math::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
 
@@ -79,8 +85,9 @@ pub mod pallet {
 	use super::*;
 	use crate::traits::VolumeProvider;
 	use crate::types::FeeEntry;
+	use crate::weights::WeightInfo;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::BlockNumberFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use sp_runtime::traits::{BlockNumberProvider, Zero};
 
 	#[pallet::pallet]
@@ -94,6 +101,12 @@ pub mod pallet {
 	pub type AssetFee<T: Config> =
 		StorageMap<_, Twox64Concat, T::AssetId, FeeEntry<T::Fee, T::BlockNumber>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn asset_fee_config)]
+	/// Asset specific fee parameters. If not set, `AssetFeeParameters` and `ProtocolFeeParameters` are used.
+	pub type AssetFeeConfiguration<T: Config> =
+		StorageMap<_, Twox64Concat, T::AssetId, AssetFeeConfig<T::Fee>, OptionQuery>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -115,16 +128,103 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type ProtocolFeeParameters: Get<FeeParams<Self::Fee>>;
+
+		/// Origin that can set and remove asset specific fee parameters.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee parameters of an asset were updated. `None` means that the default parameters are used.
+		FeeParametersUpdated {
+			asset_id: T::AssetId,
+			fee_config: Option<AssetFeeConfig<T::Fee>>,
+		},
+		/// Asset fee and protocol fee of an asset were recalculated.
+		FeeUpdated {
+			asset_id: T::AssetId,
+			asset_fee: T::Fee,
+			protocol_fee: T::Fee,
+		},
+	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Min fee is greater than max fee or amplification is 0.
+		InvalidFeeParameters,
+		/// Asset has no specific fee parameters set.
+		FeeParametersNotFound,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set asset specific fee parameters.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: Asset id
+		/// - `asset_fee_params`: Parameters used to calculate asset fee
+		/// - `protocol_fee_params`: Parameters used to calculate protocol fee
+		///
+		/// Emits `FeeParametersUpdated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_fee_params())]
+		pub fn set_asset_fee_params(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			asset_fee_params: FeeParams<T::Fee>,
+			protocol_fee_params: FeeParams<T::Fee>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let fee_config = AssetFeeConfig {
+				asset_fee_params,
+				protocol_fee_params,
+			};
+			ensure!(fee_config.is_valid(), Error::<T>::InvalidFeeParameters);
+
+			AssetFeeConfiguration::<T>::insert(asset_id, fee_config);
+
+			Self::deposit_event(Event::FeeParametersUpdated {
+				asset_id,
+				fee_config: Some(fee_config),
+			});
+
+			Ok(())
+		}
+
+		/// Remove asset specific fee parameters. Default parameters are used for the asset afterwards.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: Asset id
+		///
+		/// Emits `FeeParametersUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_asset_fee_params())]
+		pub fn remove_asset_fee_params(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AssetFeeConfiguration::<T>::contains_key(asset_id),
+				Error::<T>::FeeParametersNotFound
+			);
+
+			AssetFeeConfiguration::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::FeeParametersUpdated {
+				asset_id,
+				fee_config: None,
+			});
+
+			Ok(())
+		}
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
@@ -158,8 +258,7 @@ where
 	fn update_fee(asset_id: T::AssetId) -> (T::Fee, T::Fee) {
 		let block_number = T::BlockNumberProvider::current_block_number();
 
-		let asset_fee_params = T::AssetFeeParameters::get();
-		let protocol_fee_params = T::ProtocolFeeParameters::get();
+		let (asset_fee_params, protocol_fee_params) = Self::fee_params(asset_id);
 
 		let current_fee_entry = Self::current_fees(asset_id).unwrap_or(FeeEntry {
 			asset_fee: asset_fee_params.min_fee,
@@ -212,10 +311,27 @@ where
 				timestamp: block_number,
 			},
 		);
+
+		Self::deposit_event(Event::FeeUpdated {
+			asset_id,
+			asset_fee,
+			protocol_fee,
+		});
+
 		(asset_fee, protocol_fee)
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Returns asset and protocol fee parameters of an asset. Falls back to the defaults set in the pallet config.
+	pub fn fee_params(asset_id: T::AssetId) -> (FeeParams<T::Fee>, FeeParams<T::Fee>) {
+		Self::asset_fee_config(asset_id).map_or_else(
+			|| (T::AssetFeeParameters::get(), T::ProtocolFeeParameters::get()),
+			|config| (config.asset_fee_params, config.protocol_fee_params),
+		)
+	}
+}
+
 pub struct UpdateAndRetrieveFees<T: Config>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> GetByKey<T::AssetId, (T::Fee, T::Fee)> for UpdateAndRetrieveFees<T>
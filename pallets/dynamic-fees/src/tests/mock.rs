@@ -26,6 +26,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
+use frame_system::EnsureRoot;
 use orml_traits::GetByKey;
 pub use orml_traits::MultiCurrency;
 use sp_core::H256;
@@ -118,6 +119,8 @@ impl Config for Test {
 	type Oracle = OracleProvider;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
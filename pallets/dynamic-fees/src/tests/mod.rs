@@ -4,4 +4,5 @@ mod limits;
 mod mock;
 mod oracle;
 mod oracle_fees;
+mod params;
 mod property;
@@ -0,0 +1,235 @@
+use crate::tests::mock::*;
+use crate::tests::oracle::SingleValueOracle;
+use crate::types::{AssetFeeConfig, FeeParams};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::{DispatchError, FixedU128};
+
+fn fee_params(min_fee: Fee, max_fee: Fee) -> FeeParams<Fee> {
+	FeeParams {
+		min_fee,
+		max_fee,
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+#[test]
+fn set_asset_fee_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_fee_params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+		let protocol_fee_params = fee_params(Fee::from_percent(2), Fee::from_percent(4));
+
+		assert_ok!(DynamicFees::set_asset_fee_params(
+			RuntimeOrigin::root(),
+			HDX,
+			asset_fee_params,
+			protocol_fee_params
+		));
+
+		let fee_config = AssetFeeConfig {
+			asset_fee_params,
+			protocol_fee_params,
+		};
+		assert_eq!(DynamicFees::asset_fee_config(HDX), Some(fee_config));
+		System::assert_last_event(
+			Event::<Test>::FeeParametersUpdated {
+				asset_id: HDX,
+				fee_config: Some(fee_config),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::signed(1), HDX, params, params),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let valid_params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+		let invalid_params = fee_params(Fee::from_percent(4), Fee::from_percent(3));
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, invalid_params, valid_params),
+			Error::<Test>::InvalidFeeParameters
+		);
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, valid_params, invalid_params),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_amplification_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let valid_params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+		let invalid_params = FeeParams {
+			amplification: FixedU128::zero(),
+			..valid_params
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, invalid_params, valid_params),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn remove_asset_fee_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+		assert_ok!(DynamicFees::set_asset_fee_params(
+			RuntimeOrigin::root(),
+			HDX,
+			params,
+			params
+		));
+
+		assert_ok!(DynamicFees::remove_asset_fee_params(RuntimeOrigin::root(), HDX));
+
+		assert_eq!(DynamicFees::asset_fee_config(HDX), None);
+		System::assert_last_event(
+			Event::<Test>::FeeParametersUpdated {
+				asset_id: HDX,
+				fee_config: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_asset_fee_params_should_fail_when_params_are_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::remove_asset_fee_params(RuntimeOrigin::root(), HDX),
+			Error::<Test>::FeeParametersNotFound
+		);
+	});
+}
+
+#[test]
+fn fees_should_be_calculated_with_asset_fee_params_when_set() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, initial_fee, 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_protocol_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_ok!(DynamicFees::set_asset_fee_params(
+				RuntimeOrigin::root(),
+				HDX,
+				fee_params(Fee::from_percent(1), Fee::from_percent(3)),
+				fee_params(Fee::from_percent(5), Fee::from_percent(40)),
+			));
+
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(3));
+			assert_eq!(fee.1, Fee::from_percent(5));
+		});
+}
+
+#[test]
+fn fees_should_be_calculated_with_default_params_when_asset_fee_params_are_removed() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			let params = fee_params(Fee::from_percent(1), Fee::from_percent(3));
+			assert_ok!(DynamicFees::set_asset_fee_params(
+				RuntimeOrigin::root(),
+				HDX,
+				params,
+				params
+			));
+			assert_ok!(DynamicFees::remove_asset_fee_params(RuntimeOrigin::root(), HDX));
+
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(4));
+		});
+}
+
+#[test]
+fn fee_updated_event_should_be_emitted_when_fees_are_recalculated() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, initial_fee, 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_protocol_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let (asset_fee, protocol_fee) = retrieve_fee_entry(HDX);
+
+			System::assert_last_event(
+				Event::<Test>::FeeUpdated {
+					asset_id: HDX,
+					asset_fee,
+					protocol_fee,
+				}
+				.into(),
+			);
+
+			// fees are not recalculated again within the same block
+			let events_count = System::events().len();
+			retrieve_fee_entry(HDX);
+			assert_eq!(System::events().len(), events_count);
+		});
+}
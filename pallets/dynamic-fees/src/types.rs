@@ -1,13 +1,14 @@
 use frame_support::pallet_prelude::*;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Zero;
 use sp_runtime::FixedU128;
 
 use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
 
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeParams<Fee> {
 	pub min_fee: Fee,
@@ -16,6 +17,26 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+impl<Fee: PartialOrd> FeeParams<Fee> {
+	pub fn is_valid(&self) -> bool {
+		self.min_fee <= self.max_fee && !self.amplification.is_zero()
+	}
+}
+
+/// Asset specific fee parameters which override the default ones set in the pallet config.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetFeeConfig<Fee> {
+	pub asset_fee_params: FeeParams<Fee>,
+	pub protocol_fee_params: FeeParams<Fee>,
+}
+
+impl<Fee: PartialOrd> AssetFeeConfig<Fee> {
+	pub fn is_valid(&self) -> bool {
+		self.asset_fee_params.is_valid() && self.protocol_fee_params.is_valid()
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeEntry<Fee, Block> {
@@ -0,0 +1,78 @@
+// This file is part of pallet-dynamic-fees.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_dynamic_fees
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-07-04, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=5
+// --repeat=20
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=.maintain/pallet-weight-template.hbs
+// --pallet=pallet-dynamic-fees
+// --output=weights.rs
+// --extrinsic=*
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_dynamic_fees.
+pub trait WeightInfo {
+	fn set_asset_fee_params() -> Weight;
+	fn remove_asset_fee_params() -> Weight;
+}
+
+/// Weights for pallet_dynamic_fees using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	fn set_asset_fee_params() -> Weight {
+		Weight::from_ref_time(18_613_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn remove_asset_fee_params() -> Weight {
+		Weight::from_ref_time(20_872_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_asset_fee_params() -> Weight {
+		Weight::from_ref_time(18_613_000 as u64).saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn remove_asset_fee_params() -> Weight {
+		Weight::from_ref_time(20_872_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
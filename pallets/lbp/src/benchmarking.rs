@@ -46,6 +46,47 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+fn piecewise_weight_curve() -> WeightCurveType {
+	let points: Vec<(Permill, LBPWeight)> = (1..=MAX_WEIGHT_CURVE_POINTS)
+		.map(|i| (Permill::from_percent(i * 5), INITIAL_WEIGHT + i * 1_000_000))
+		.collect();
+
+	WeightCurveType::Piecewise(points.try_into().unwrap())
+}
+
+fn launch_parameters<T: Config>() -> LaunchParameters<T::CollectionId> {
+	LaunchParameters {
+		access: Some(ParticipantAccess::Allowlist),
+		max_purchase_per_account: Some(ASSET_B_AMOUNT),
+		max_purchase_per_block: Some(ASSET_B_AMOUNT),
+	}
+}
+
+fn allowlist<T: Config>(accounts: Vec<T::AccountId>) -> BoundedVec<T::AccountId, T::MaxAllowlistSize> {
+	accounts.try_into().unwrap()
+}
+
+// Fills allowlist and purchase records of a pool which have to be removed when the pool is destroyed
+fn fill_launch_data<T: Config>(pool_id: &PoolId<T>, a: u32, p: u32) {
+	for i in 0..a {
+		let who: T::AccountId = account("participant", i, SEED);
+		Allowlist::<T>::insert(pool_id, who, ());
+	}
+	AllowlistSize::<T>::insert(pool_id, a);
+
+	for i in 0..p {
+		let who: T::AccountId = account("buyer", i, SEED);
+		Purchases::<T>::insert(pool_id, who, 1_000_000);
+	}
+}
+
+fn graduation_settings() -> GraduationSettings {
+	GraduationSettings {
+		weight_cap: Permill::from_percent(10),
+		beneficiary: GraduationBeneficiary::Protocol,
+	}
+}
+
 benchmarks! {
 	create_pool {
 		let caller = funded_account::<T>("caller", 0);
@@ -69,13 +110,14 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, fee, caller.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
-	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(DEFAULT_FEE), Some(fee_collector), Some(1))
+	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(piecewise_weight_curve()), Some(DEFAULT_FEE), Some(fee_collector), Some(1))
 	verify {
 		let pool_data = LBP::<T>::pool_data(pool_id).unwrap();
 		assert_eq!(pool_data.start, new_start);
 		assert_eq!(pool_data.end, new_end);
 		assert_eq!(pool_data.initial_weight, new_initial_weight);
 		assert_eq!(pool_data.final_weight, new_final_weight);
+		assert_eq!(pool_data.weight_curve, piecewise_weight_curve());
 	}
 
 	add_liquidity {
@@ -92,15 +134,22 @@ benchmarks! {
 	}
 
 	remove_liquidity {
+		let a in 0 .. T::MaxAllowlistSize::get();
+		let p in 0 .. T::PurchasesRemovalLimit::get();
+
 		let caller = funded_account::<T>("caller", 0);
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		fill_launch_data::<T>(&pool_id, a, p);
+
 	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone())
 	verify {
 		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert_eq!(Allowlist::<T>::iter_prefix(&pool_id).count(), 0);
+		assert_eq!(Purchases::<T>::iter_prefix(&pool_id).count(), 0);
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_A_ID, &caller), 1000000000000000);
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &caller), 1000000000000000);
 	}
@@ -115,13 +164,15 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector.clone(), 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Exponential { rate: MAX_EXPONENTIAL_RATE }, DEFAULT_FEE, fee_collector.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = T::BlockNumber::from(1u32);
 		let end = T::BlockNumber::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None)?;
+		LBP::<T>::set_launch_parameters(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(launch_parameters::<T>()))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, allowlist::<T>(vec![caller.clone()]))?;
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_in, asset_out, amount, max_limit)
 	verify{
@@ -139,13 +190,15 @@ benchmarks! {
 		let max_limit: Balance = 1_000_000_000;
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector.clone(), 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Exponential { rate: MAX_EXPONENTIAL_RATE }, DEFAULT_FEE, fee_collector.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = T::BlockNumber::from(1u32);
 		let end = T::BlockNumber::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None)?;
+		LBP::<T>::set_launch_parameters(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(launch_parameters::<T>()))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, allowlist::<T>(vec![caller.clone()]))?;
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_out, asset_in, amount, max_limit)
 	verify{
@@ -153,6 +206,127 @@ benchmarks! {
 		assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998772262325);
 		assert_eq!(T::MultiCurrency::free_balance(asset_in, &fee_collector), 1000000000455474);
 	}
+
+	set_launch_parameters {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(launch_parameters::<T>()))
+	verify {
+		assert_eq!(LBP::<T>::launch_parameters(pool_id), Some(launch_parameters::<T>()));
+	}
+
+	add_to_allowlist {
+		let n in 1 .. T::MaxAllowlistSize::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let accounts = allowlist::<T>((0..n).map(|i| account("participant", i, SEED)).collect());
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), accounts.clone())
+	verify {
+		for account in accounts {
+			assert!(Allowlist::<T>::contains_key(&pool_id, account));
+		}
+	}
+
+	remove_from_allowlist {
+		let n in 1 .. T::MaxAllowlistSize::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let accounts = allowlist::<T>((0..n).map(|i| account("participant", i, SEED)).collect());
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), accounts.clone())?;
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), accounts.clone())
+	verify {
+		for account in accounts {
+			assert!(!Allowlist::<T>::contains_key(&pool_id, account));
+		}
+	}
+
+	set_graduation {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(graduation_settings()))
+	verify {
+		assert_eq!(LBP::<T>::graduation(pool_id), Some(graduation_settings()));
+	}
+
+	approve_graduation {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::set_graduation(RawOrigin::Signed(caller).into(), pool_id.clone(), Some(graduation_settings()))?;
+
+	}: _(RawOrigin::Root, pool_id.clone(), true)
+	verify {
+		assert!(LBP::<T>::is_graduation_approved(pool_id));
+	}
+
+	graduate {
+		let a in 0 .. T::MaxAllowlistSize::get();
+		let p in 0 .. T::PurchasesRemovalLimit::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		let start = T::BlockNumber::from(10u32);
+		let end = T::BlockNumber::from(20u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None)?;
+		LBP::<T>::set_launch_parameters(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(launch_parameters::<T>()))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), allowlist::<T>(vec![caller.clone()]))?;
+		LBP::<T>::set_graduation(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(graduation_settings()))?;
+		LBP::<T>::approve_graduation(RawOrigin::Root.into(), pool_id.clone(), true)?;
+
+		// collected fees have to be unlocked on graduation
+		frame_system::Pallet::<T>::set_block_number(T::BlockNumber::from(15u32));
+		LBP::<T>::sell(RawOrigin::Signed(caller.clone()).into(), ASSET_A_ID, ASSET_B_ID, 100_000_000, 10_000_000)?;
+
+		frame_system::Pallet::<T>::set_block_number(T::BlockNumber::from(21u32));
+
+		fill_launch_data::<T>(&pool_id, a, p);
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone())
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &pool_id), 0);
+	}
+
+	clear_purchases {
+		let p in 1 .. T::PurchasesRemovalLimit::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		fill_launch_data::<T>(&pool_id, 0, p);
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone())
+	verify {
+		assert_eq!(Purchases::<T>::iter_prefix(&pool_id).count(), 0);
+	}
 }
 
 #[cfg(test)]
@@ -170,6 +344,13 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_launch_parameters());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_to_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_from_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_graduation());
+			assert_ok!(Pallet::<Test>::test_benchmark_approve_graduation());
+			assert_ok!(Pallet::<Test>::test_benchmark_graduate());
+			assert_ok!(Pallet::<Test>::test_benchmark_clear_purchases());
 		});
 	}
 }
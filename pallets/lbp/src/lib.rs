@@ -24,17 +24,19 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
 	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	DispatchError, FixedPointNumber, FixedU128, Permill, RuntimeDebug,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{EnsureOrigin, Get, LockIdentifier},
-	transactional,
+	pallet_prelude::ConstU32,
+	traits::{tokens::nonfungibles::InspectEnumerable, EnsureOrigin, Get, LockIdentifier},
+	transactional, BoundedVec,
 };
 use frame_system::ensure_signed;
 use hydra_dx_math::types::LBPWeight;
-use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
+use hydradx_traits::pools::{GraduationHandler, PoolLimitHandler};
+use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, OnTradeHandler, Source, AMM};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 use primitives::{asset::AssetPair, Amount, AssetId, Balance};
 
@@ -52,6 +54,8 @@ mod tests;
 
 mod benchmarking;
 
+pub mod migration;
+
 #[allow(clippy::all)]
 pub mod weights;
 
@@ -64,10 +68,27 @@ pub use pallet::*;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 type PoolId<T> = <T as frame_system::Config>::AccountId;
 
+/// Max number of intermediate points of a piecewise weight curve
+pub const MAX_WEIGHT_CURVE_POINTS: u32 = 10;
+
+/// Max rate of an exponential weight curve, corresponds to the steepness of 20
+pub const MAX_EXPONENTIAL_RATE: u32 = 20_000;
+
+/// Intermediate points of a piecewise weight curve. Each point is the progress of the sale and the weight at that progress.
+pub type WeightCurvePoints = BoundedVec<(Permill, LBPWeight), ConstU32<MAX_WEIGHT_CURVE_POINTS>>;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum WeightCurveType {
+	/// Weight changes at a constant rate.
 	Linear,
+	/// Weight changes exponentially, most of the change happens at the beginning of the sale.
+	/// `rate` is the steepness of the curve multiplied by 1_000.
+	Exponential { rate: u32 },
+	/// Weight changes in `steps` equal steps.
+	Stepped { steps: u32 },
+	/// Weight is linearly interpolated between the points sorted by progress of the sale.
+	Piecewise(WeightCurvePoints),
 }
 
 impl Default for WeightCurveType {
@@ -76,6 +97,47 @@ impl Default for WeightCurveType {
 	}
 }
 
+/// Defines who can participate in a sale.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ParticipantAccess<CollectionId> {
+	/// Only accounts in the allowlist of the pool can trade.
+	Allowlist,
+	/// Only owners of an NFT of the collection can trade.
+	NftHolders(CollectionId),
+}
+
+/// Optional parameters of a sale used for fair launches.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LaunchParameters<CollectionId> {
+	/// Restriction of accounts which can trade in the pool. Anyone can trade if not set.
+	pub access: Option<ParticipantAccess<CollectionId>>,
+
+	/// Max cumulative amount of the sold asset which can be bought by an account
+	pub max_purchase_per_account: Option<Balance>,
+
+	/// Max amount of the sold asset which can be bought from the pool in a single block
+	pub max_purchase_per_block: Option<Balance>,
+}
+
+/// Owner of the liquidity position created when a sale graduates.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum GraduationBeneficiary {
+	/// The position is sacrificed to the protocol.
+	Protocol,
+	/// The position is assigned to the pool owner.
+	Owner,
+}
+
+/// Settings used to list the sold asset in another pool once the sale ends.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct GraduationSettings {
+	/// Weight cap of the sold asset in the pool it is listed in
+	pub weight_cap: Permill,
+
+	/// Owner of the created liquidity position
+	pub beneficiary: GraduationBeneficiary,
+}
+
 /// Max weight corresponds to 100%
 pub const MAX_WEIGHT: LBPWeight = 100_000_000;
 
@@ -85,6 +147,9 @@ pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Source of LBP pools used to identify them in `PoolLimitHandler` and in the oracle
+pub const LBP_SOURCE: Source = primitives::constants::chain::LBP_SOURCE;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -148,7 +213,7 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
-		weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
@@ -160,14 +225,28 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Exponential { rate } => {
+				hydra_dx_math::lbp::calculate_exponential_weights(start, end, initial_weight, final_weight, *rate, at)
+			}
+			WeightCurveType::Stepped { steps } => {
+				hydra_dx_math::lbp::calculate_stepped_weights(start, end, initial_weight, final_weight, *steps, at)
+			}
+			WeightCurveType::Piecewise(points) => {
+				hydra_dx_math::lbp::calculate_piecewise_weights(start, end, initial_weight, final_weight, points, at)
+			}
+		}
+		.ok()
 	}
 }
 
@@ -177,7 +256,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::OriginFor;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -221,6 +304,29 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		/// Handler which enforces per block trade limits of pools (e.g. circuit breaker)
+		type PoolLimitHandler: PoolLimitHandler<Self::RuntimeOrigin, Self::AccountId, AssetId, Balance>;
+
+		/// Identifier of NFT collections which can be used to restrict access to a sale
+		type CollectionId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Non fungible handler used to check ownership of NFTs
+		type NFTHandler: InspectEnumerable<Self::AccountId, CollectionId = Self::CollectionId>;
+
+		/// Max number of accounts in the allowlist of a pool
+		#[pallet::constant]
+		type MaxAllowlistSize: Get<u32>;
+
+		/// Max number of purchase records of a pool removed from the storage in a single call
+		#[pallet::constant]
+		type PurchasesRemovalLimit: Get<u32>;
+
+		/// Handler which lists the sold asset in another pool when a sale graduates
+		type GraduationHandler: GraduationHandler<Self::AccountId, AssetId, Balance>;
+
+		/// Handler which is notified about the executed trades (e.g. oracle)
+		type OnTradeHandler: OnTradeHandler<AssetId, Balance>;
 	}
 
 	#[pallet::hooks]
@@ -296,6 +402,42 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Weight curve parameters are invalid
+		InvalidWeightCurve,
+
+		/// Launch parameters are invalid
+		InvalidLaunchParameters,
+
+		/// Account is not allowed to trade in the pool
+		NotAllowed,
+
+		/// Purchase exceeds max cumulative purchase of the account
+		MaxPurchasePerAccountExceeded,
+
+		/// Purchase exceeds max purchase of the pool in the current block
+		MaxPurchasePerBlockExceeded,
+
+		/// Allowlist of the pool would exceed the max allowed size
+		MaxAllowlistSizeExceeded,
+
+		/// Purchase records of a destroyed pool with the same assets have not been removed yet
+		PurchasesNotCleared,
+
+		/// There are no purchase records to remove
+		NothingToClear,
+
+		/// Graduation settings are invalid
+		InvalidGraduationSettings,
+
+		/// Graduation of the pool is not set
+		GraduationNotSet,
+
+		/// Graduation of the pool is not approved
+		GraduationNotApproved,
+
+		/// Liquidity of a pool with an approved graduation can be moved only by the graduation
+		GraduationApproved,
 	}
 
 	#[pallet::event]
@@ -352,6 +494,45 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Launch parameters of a pool were updated.
+		LaunchParametersUpdated {
+			pool: PoolId<T>,
+			parameters: Option<LaunchParameters<T::CollectionId>>,
+		},
+
+		/// Accounts were added to the allowlist of a pool.
+		AddedToAllowlist {
+			pool: PoolId<T>,
+			accounts: Vec<T::AccountId>,
+		},
+
+		/// Accounts were removed from the allowlist of a pool.
+		RemovedFromAllowlist {
+			pool: PoolId<T>,
+			accounts: Vec<T::AccountId>,
+		},
+
+		/// Graduation settings of a pool were updated.
+		GraduationUpdated {
+			pool: PoolId<T>,
+			settings: Option<GraduationSettings>,
+		},
+
+		/// Graduation of a pool was approved or the approval was revoked.
+		GraduationApprovalUpdated { pool: PoolId<T>, approved: bool },
+
+		/// Sale ended and the sold asset was listed in another pool.
+		PoolGraduated {
+			pool: PoolId<T>,
+			asset: AssetId,
+			amount: BalanceOf<T>,
+			price_asset: AssetId,
+			price: FixedU128,
+		},
+
+		/// Purchase records of a destroyed pool were removed.
+		PurchasesCleared { pool: PoolId<T>, remaining: bool },
 	}
 
 	/// Details of a pool.
@@ -366,6 +547,43 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Launch parameters of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn launch_parameters)]
+	pub type PoolLaunchParameters<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, LaunchParameters<T::CollectionId>, OptionQuery>;
+
+	/// Accounts allowed to trade in a pool restricted by an allowlist.
+	#[pallet::storage]
+	pub type Allowlist<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Number of accounts in the allowlist of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist_size)]
+	pub type AllowlistSize<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, u32, ValueQuery>;
+
+	/// Cumulative amount of the sold asset bought by an account from a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn purchased)]
+	pub type Purchases<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Amount of the sold asset bought from a pool in the last block with a purchase.
+	#[pallet::storage]
+	pub type BlockPurchases<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, (T::BlockNumber, Balance), OptionQuery>;
+
+	/// Graduation settings of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn graduation)]
+	pub type PoolGraduation<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, GraduationSettings, OptionQuery>;
+
+	/// Pools whose graduation was approved by `CreatePoolOrigin`.
+	#[pallet::storage]
+	#[pallet::getter(fn is_graduation_approved)]
+	pub type ApprovedGraduations<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, bool, ValueQuery>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -395,8 +613,8 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. Linear, exponential,
+		/// stepped and piecewise linear functions are supported.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
@@ -471,6 +689,11 @@ pub mod pallet {
 
 			let pool_id = Self::get_pair_id(asset_pair);
 
+			ensure!(
+				<Purchases<T>>::iter_key_prefix(&pool_id).next().is_none(),
+				Error::<T>::PurchasesNotCleared
+			);
+
 			<PoolData<T>>::insert(&pool_id, &pool_data);
 			<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
 
@@ -507,6 +730,7 @@ pub mod pallet {
 		/// - `end`: The new ending time of the sale. This parameter is optional.
 		/// - `initial_weight`: The new initial weight. This parameter is optional.
 		/// - `final_weight`: The new final weight. This parameter is optional.
+		/// - `weight_curve`: The new weight function. This parameter is optional.
 		/// - `fee`: The new trading fee charged on every trade. This parameter is optional.
 		/// - `fee_collector`: The new receiver of trading fees. This parameter is optional.
 		///
@@ -521,6 +745,7 @@ pub mod pallet {
 			end: Option<T::BlockNumber>,
 			initial_weight: Option<LBPWeight>,
 			final_weight: Option<LBPWeight>,
+			weight_curve: Option<WeightCurveType>,
 			fee: Option<(u32, u32)>,
 			fee_collector: Option<T::AccountId>,
 			repay_target: Option<Balance>,
@@ -535,6 +760,7 @@ pub mod pallet {
 					start.is_some()
 						|| end.is_some() || initial_weight.is_some()
 						|| final_weight.is_some()
+						|| weight_curve.is_some()
 						|| fee.is_some() || fee_collector.is_some()
 						|| repay_target.is_some(),
 					Error::<T>::NothingToUpdate
@@ -553,6 +779,10 @@ pub mod pallet {
 
 				pool.final_weight = final_weight.unwrap_or(pool.final_weight);
 
+				if let Some(weight_curve) = weight_curve {
+					pool.weight_curve = weight_curve;
+				}
+
 				pool.fee = fee.unwrap_or(pool.fee);
 
 				// Handle update of fee collector - validate and replace old fee collector
@@ -659,7 +889,10 @@ pub mod pallet {
 		///
 		/// Emits 'LiquidityRemoved' when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity(
+			T::MaxAllowlistSize::get(),
+			T::PurchasesRemovalLimit::get(),
+		))]
 		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -669,6 +902,8 @@ pub mod pallet {
 
 			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
 
+			ensure!(!Self::is_graduation_approved(&pool_id), Error::<T>::GraduationApproved);
+
 			let (asset_a, asset_b) = pool_data.assets;
 
 			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
@@ -677,12 +912,7 @@ pub mod pallet {
 			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a)?;
 			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b)?;
 
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
-
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
+			Self::destroy_pool(&pool_id, pool_data)?;
 
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: pool_id,
@@ -710,7 +940,9 @@ pub mod pallet {
 		///
 		/// Emits `SellExecuted` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight())
+			.saturating_add(T::OnTradeHandler::on_trade_weight()))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -740,7 +972,9 @@ pub mod pallet {
 		///
 		/// Emits `BuyExecuted` when successful.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight())
+			.saturating_add(T::OnTradeHandler::on_trade_weight()))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -754,6 +988,321 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set launch parameters of a pool.
+		///
+		/// Launch parameters restrict who can trade in the pool and how much of the sold asset
+		/// can be bought by an account and in a single block.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The parameters can be updated only if the sale has not already started.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool to be updated.
+		/// - `parameters`: The new launch parameters. `None` removes the launch parameters.
+		///
+		/// Emits `LaunchParametersUpdated` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_launch_parameters())]
+		pub fn set_launch_parameters(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			parameters: Option<LaunchParameters<T::CollectionId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let Some(parameters) = parameters {
+				ensure!(
+					parameters.max_purchase_per_account != Some(Balance::zero())
+						&& parameters.max_purchase_per_block != Some(Balance::zero()),
+					Error::<T>::InvalidLaunchParameters
+				);
+			}
+
+			<PoolLaunchParameters<T>>::set(&pool_id, parameters);
+
+			Self::deposit_event(Event::LaunchParametersUpdated {
+				pool: pool_id,
+				parameters,
+			});
+
+			Ok(())
+		}
+
+		/// Add accounts to the allowlist of a pool.
+		///
+		/// The allowlist is used only if the access of the pool is restricted by the allowlist.
+		/// The allowlist can contain at most `MaxAllowlistSize` accounts.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `accounts`: Accounts allowed to trade in the pool.
+		///
+		/// Emits `AddedToAllowlist` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_allowlist(accounts.len() as u32))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxAllowlistSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			let mut size = <AllowlistSize<T>>::get(&pool_id);
+
+			for account in accounts.iter() {
+				if !<Allowlist<T>>::contains_key(&pool_id, account) {
+					ensure!(size < T::MaxAllowlistSize::get(), Error::<T>::MaxAllowlistSizeExceeded);
+					<Allowlist<T>>::insert(&pool_id, account, ());
+					size = size.saturating_add(1);
+				}
+			}
+
+			<AllowlistSize<T>>::insert(&pool_id, size);
+
+			Self::deposit_event(Event::AddedToAllowlist {
+				pool: pool_id,
+				accounts: accounts.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Remove accounts from the allowlist of a pool.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `accounts`: Accounts which are not allowed to trade in the pool anymore.
+		///
+		/// Emits `RemovedFromAllowlist` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_from_allowlist(accounts.len() as u32))]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxAllowlistSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			let mut size = <AllowlistSize<T>>::get(&pool_id);
+
+			for account in accounts.iter() {
+				if <Allowlist<T>>::take(&pool_id, account).is_some() {
+					size = size.saturating_sub(1);
+				}
+			}
+
+			<AllowlistSize<T>>::insert(&pool_id, size);
+
+			Self::deposit_event(Event::RemovedFromAllowlist {
+				pool: pool_id,
+				accounts: accounts.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Set graduation settings of a pool.
+		///
+		/// Once the sale ends, the sold asset is listed in another pool at the final spot price of the sale
+		/// if the graduation was approved by `CreatePoolOrigin`.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The settings can be updated only if the sale has not already started. Any update revokes
+		/// the approval of the graduation.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool to be updated.
+		/// - `settings`: The new graduation settings. `None` removes the graduation settings.
+		///
+		/// Emits `GraduationUpdated` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_graduation())]
+		pub fn set_graduation(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			settings: Option<GraduationSettings>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let Some(settings) = settings {
+				ensure!(!settings.weight_cap.is_zero(), Error::<T>::InvalidGraduationSettings);
+			}
+
+			<PoolGraduation<T>>::set(&pool_id, settings);
+			<ApprovedGraduations<T>>::remove(&pool_id);
+
+			Self::deposit_event(Event::GraduationUpdated {
+				pool: pool_id,
+				settings,
+			});
+
+			Ok(())
+		}
+
+		/// Approve graduation of a pool or revoke the approval.
+		///
+		/// Liquidity of a pool with an approved graduation can't be removed by the pool owner.
+		///
+		/// The dispatch origin for this call must be `T::CreatePoolOrigin`.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `approved`: `true` to approve the graduation, `false` to revoke the approval.
+		///
+		/// Emits `GraduationApprovalUpdated` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_graduation())]
+		pub fn approve_graduation(origin: OriginFor<T>, pool_id: PoolId<T>, approved: bool) -> DispatchResult {
+			T::CreatePoolOrigin::ensure_origin(origin)?;
+
+			ensure!(<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+			ensure!(
+				!approved || <PoolGraduation<T>>::contains_key(&pool_id),
+				Error::<T>::GraduationNotSet
+			);
+
+			if approved {
+				<ApprovedGraduations<T>>::insert(&pool_id, true);
+			} else {
+				<ApprovedGraduations<T>>::remove(&pool_id);
+			}
+
+			Self::deposit_event(Event::GraduationApprovalUpdated {
+				pool: pool_id,
+				approved,
+			});
+
+			Ok(())
+		}
+
+		/// Graduate a pool after the sale ended.
+		///
+		/// The accumulated asset is transferred to the pool owner and the sold asset is listed
+		/// in another pool by `T::GraduationHandler` at the final spot price of the sale.
+		/// The pool is destroyed afterwards.
+		///
+		/// The dispatch origin for this call must be signed, anyone can graduate the pool.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		///
+		/// Emits `PoolGraduated` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::graduate(T::MaxAllowlistSize::get(), T::PurchasesRemovalLimit::get())
+			.saturating_add(T::GraduationHandler::graduate_weight()))]
+		pub fn graduate(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			let settings = <PoolGraduation<T>>::get(&pool_id).ok_or(Error::<T>::GraduationNotSet)?;
+			ensure!(
+				Self::is_graduation_approved(&pool_id),
+				Error::<T>::GraduationNotApproved
+			);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let end = match pool_data.end {
+				Some(end) if end < now => end,
+				_ => return Err(Error::<T>::SaleNotEnded.into()),
+			};
+
+			let (asset_a, asset_b) = pool_data.assets;
+
+			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
+			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
+
+			// final spot price of the sold asset denominated in the accumulated asset
+			let (weight_b, weight_a) = Self::get_sorted_weight(asset_b, end, &pool_data)?;
+			let price =
+				hydra_dx_math::lbp::calculate_spot_price(amount_b, amount_a, weight_b, weight_a, FixedU128::DIV)
+					.map(FixedU128::from_inner)
+					.map_err(|_| Error::<T>::Overflow)?;
+
+			T::MultiCurrency::transfer(asset_a, &pool_id, &pool_data.owner, amount_a)?;
+
+			let position_owner = match settings.beneficiary {
+				GraduationBeneficiary::Protocol => None,
+				GraduationBeneficiary::Owner => Some(pool_data.owner.clone()),
+			};
+
+			T::GraduationHandler::graduate(
+				pool_id.clone(),
+				asset_b,
+				amount_b,
+				asset_a,
+				price,
+				settings.weight_cap,
+				position_owner,
+			)?;
+
+			Self::destroy_pool(&pool_id, pool_data)?;
+
+			Self::deposit_event(Event::PoolGraduated {
+				pool: pool_id,
+				asset: asset_b,
+				amount: amount_b,
+				price_asset: asset_a,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Remove purchase records of a destroyed pool.
+		///
+		/// Only up to `PurchasesRemovalLimit` purchase records are removed when a pool is destroyed.
+		/// Remaining records have to be removed before a pool of the same assets can be created again.
+		///
+		/// The dispatch origin for this call must be signed, anyone can remove the records.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the destroyed pool.
+		///
+		/// Emits `PurchasesCleared` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_purchases(T::PurchasesRemovalLimit::get()))]
+		pub fn clear_purchases(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolAlreadyExists);
+
+			let result = <Purchases<T>>::clear_prefix(&pool_id, T::PurchasesRemovalLimit::get(), None);
+
+			ensure!(result.unique > 0, Error::<T>::NothingToClear);
+
+			Self::deposit_event(Event::PurchasesCleared {
+				pool: pool_id,
+				remaining: result.maybe_cursor.is_some(),
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -763,7 +1312,7 @@ impl<T: Config> Pallet<T> {
 		at: T::BlockNumber,
 	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
 		let weight_a = T::LBPWeightFunction::calculate_weight(
-			pool_data.weight_curve,
+			&pool_data.weight_curve,
 			pool_data.start.unwrap_or_else(Zero::zero),
 			pool_data.end.unwrap_or_else(Zero::zero),
 			pool_data.initial_weight,
@@ -812,6 +1361,40 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
+		Self::validate_weight_curve(&pool_data.weight_curve)?;
+
+		Ok(())
+	}
+
+	fn validate_weight_curve(weight_curve: &WeightCurveType) -> DispatchResult {
+		match weight_curve {
+			WeightCurveType::Linear => {}
+			WeightCurveType::Exponential { rate } => {
+				ensure!(
+					!rate.is_zero() && *rate <= MAX_EXPONENTIAL_RATE,
+					Error::<T>::InvalidWeightCurve
+				);
+			}
+			WeightCurveType::Stepped { steps } => {
+				ensure!(*steps > 1, Error::<T>::InvalidWeightCurve);
+			}
+			WeightCurveType::Piecewise(points) => {
+				ensure!(!points.is_empty(), Error::<T>::InvalidWeightCurve);
+
+				// progress of the points has to be strictly increasing and inside of the sale
+				let mut previous_progress = Permill::zero();
+				for (progress, weight) in points.iter() {
+					ensure!(
+						*progress > previous_progress && *progress < Permill::one(),
+						Error::<T>::InvalidWeightCurve
+					);
+					ensure!(!weight.is_zero() && *weight < MAX_WEIGHT, Error::<T>::InvalidWeight);
+
+					previous_progress = *progress;
+				}
+			}
+		}
+
 		Ok(())
 	}
 
@@ -862,11 +1445,125 @@ impl<T: Config> Pallet<T> {
 		Self::collected_fees(pool) < pool.repay_target
 	}
 
+	/// Remove the fee collector lock and all the data of the pool from the storage
+	fn destroy_pool(pool_id: &PoolId<T>, pool_data: Pool<T::AccountId, T::BlockNumber>) -> DispatchResult {
+		if Self::collected_fees(&pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, pool_data.assets.0, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
+		<PoolData<T>>::remove(pool_id);
+
+		<PoolLaunchParameters<T>>::remove(pool_id);
+		<BlockPurchases<T>>::remove(pool_id);
+		let _ = <Allowlist<T>>::clear_prefix(pool_id, T::MaxAllowlistSize::get(), None);
+		<AllowlistSize<T>>::remove(pool_id);
+
+		// remaining purchase records have to be removed by `clear_purchases`
+		let _ = <Purchases<T>>::clear_prefix(pool_id, T::PurchasesRemovalLimit::get(), None);
+
+		<PoolGraduation<T>>::remove(pool_id);
+		<ApprovedGraduations<T>>::remove(pool_id);
+
+		Ok(())
+	}
+
+	/// Amount of the sold asset bought from the pool in the current block
+	fn purchased_in_block(pool_id: &PoolId<T>) -> Balance {
+		let now = T::BlockNumberProvider::current_block_number();
+		match <BlockPurchases<T>>::get(pool_id) {
+			Some((block, amount)) if block == now => amount,
+			_ => Balance::zero(),
+		}
+	}
+
+	/// Ensures that the trade complies with the launch parameters of the pool
+	fn ensure_launch_parameters(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, T::BlockNumber>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let parameters = match <PoolLaunchParameters<T>>::get(pool_id) {
+			Some(parameters) => parameters,
+			None => return Ok(()),
+		};
+
+		match parameters.access {
+			Some(ParticipantAccess::Allowlist) => {
+				ensure!(
+					<Allowlist<T>>::contains_key(pool_id, &transfer.origin),
+					Error::<T>::NotAllowed
+				);
+			}
+			Some(ParticipantAccess::NftHolders(collection)) => {
+				ensure!(
+					T::NFTHandler::owned_in_collection(&collection, &transfer.origin)
+						.next()
+						.is_some(),
+					Error::<T>::NotAllowed
+				);
+			}
+			None => {}
+		}
+
+		// only purchases of the sold asset are limited
+		if transfer.assets.asset_out != pool_data.assets.1 {
+			return Ok(());
+		}
+
+		if let Some(max_purchase) = parameters.max_purchase_per_account {
+			let purchased = Self::purchased(pool_id, &transfer.origin)
+				.checked_add(transfer.amount_b)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(purchased <= max_purchase, Error::<T>::MaxPurchasePerAccountExceeded);
+		}
+
+		if let Some(max_purchase) = parameters.max_purchase_per_block {
+			let purchased = Self::purchased_in_block(pool_id)
+				.checked_add(transfer.amount_b)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(purchased <= max_purchase, Error::<T>::MaxPurchasePerBlockExceeded);
+		}
+
+		Ok(())
+	}
+
+	/// Tracks purchases of the sold asset if the pool has launch parameters
+	fn record_purchase(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, T::BlockNumber>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) {
+		if transfer.assets.asset_out != pool_data.assets.1 || !<PoolLaunchParameters<T>>::contains_key(pool_id) {
+			return;
+		}
+
+		<Purchases<T>>::mutate(pool_id, &transfer.origin, |purchased| {
+			*purchased = purchased.saturating_add(transfer.amount_b)
+		});
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let purchased_in_block = Self::purchased_in_block(pool_id).saturating_add(transfer.amount_b);
+		<BlockPurchases<T>>::insert(pool_id, (now, purchased_in_block));
+	}
+
 	#[transactional]
 	fn execute_trade(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
 		let pool_account = Self::get_pair_id(transfer.assets);
 		let pool = <PoolData<T>>::try_get(&pool_account).map_err(|_| Error::<T>::PoolNotFound)?;
 
+		T::PoolLimitHandler::ensure_trade_limit(
+			frame_system::RawOrigin::Signed(transfer.origin.clone()).into(),
+			LBP_SOURCE,
+			pool_account.clone(),
+			transfer.assets.asset_in,
+			T::MultiCurrency::free_balance(transfer.assets.asset_in, &pool_account),
+			transfer.amount,
+			transfer.assets.asset_out,
+			T::MultiCurrency::free_balance(transfer.assets.asset_out, &pool_account),
+			transfer.amount_b,
+		)?;
+
 		// Transfer assets between pool and user
 		T::MultiCurrency::transfer(
 			transfer.assets.asset_in,
@@ -895,6 +1592,46 @@ impl<T: Config> Pallet<T> {
 		let collected_fee_total = Self::collected_fees(&pool) + fee_amount;
 		T::MultiCurrency::set_lock(COLLECTOR_LOCK_ID, fee_asset, &pool.fee_collector, collected_fee_total)?;
 
+		Self::record_purchase(&pool_account, &pool, transfer);
+
+		Self::notify_trade(&pool_account, &pool, transfer)
+	}
+
+	/// Notify `OnTradeHandler` about the trade.
+	///
+	/// The spot price of the pool depends on the weights, so the liquidity of asset out is reported as
+	/// the value of the asset in reserve. The ratio of the reported liquidity is the spot price of the pool.
+	fn notify_trade(
+		pool_account: &PoolId<T>,
+		pool: &Pool<T::AccountId, T::BlockNumber>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let AssetPair { asset_in, asset_out } = transfer.assets;
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let (weight_in, weight_out) = Self::get_sorted_weight(asset_in, now, pool)?;
+
+		let liquidity_in = T::MultiCurrency::free_balance(asset_in, pool_account);
+		let liquidity_out = hydra_dx_math::lbp::calculate_spot_price(
+			liquidity_in,
+			T::MultiCurrency::free_balance(asset_out, pool_account),
+			weight_in,
+			weight_out,
+			liquidity_in,
+		)
+		.map_err(|_| Error::<T>::Overflow)?;
+
+		T::OnTradeHandler::on_trade(
+			LBP_SOURCE,
+			asset_in,
+			asset_out,
+			transfer.amount,
+			transfer.amount_b,
+			liquidity_in,
+			liquidity_out,
+		)
+		.map_err(|(_, e)| e)?;
+
 		Ok(())
 	}
 
@@ -1011,7 +1748,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		// Take distributed asset (out) and send to seller
 		// Take fee from the seller and send to fee collector
 		// Pool bears repay fee
-		if fee_asset == assets.asset_in {
+		let transfer = if fee_asset == assets.asset_in {
 			let fee = Self::calculate_fees(&pool_data, amount)?;
 
 			let amount_out = hydra_dx_math::lbp::calculate_out_given_in(
@@ -1035,7 +1772,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			let amount_without_fee = amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
-			Ok(AMMTransfer {
+			AMMTransfer {
 				origin: who.clone(),
 				assets,
 				amount: amount_without_fee,
@@ -1043,7 +1780,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				discount: false,
 				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
-			})
+			}
 
 		// Distributed asset is sold (in) to the pool for accumulated asset (out)
 		// Take accumulated asset (out) from the pool sans fee and send to the seller
@@ -1073,7 +1810,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(min_bought <= amount_out_without_fee, Error::<T>::TradingLimitReached);
 
-			Ok(AMMTransfer {
+			AMMTransfer {
 				origin: who.clone(),
 				assets,
 				amount,
@@ -1081,8 +1818,12 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				discount: false,
 				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
-			})
-		}
+			}
+		};
+
+		Self::ensure_launch_parameters(&pool_id, &pool_data, &transfer)?;
+
+		Ok(transfer)
 	}
 
 	fn execute_sell(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
@@ -1136,7 +1877,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		// Take distributed asset (in) from the seller and add to pool
 		// Take fee from the pool and send to fee collector
 		// Buyer bears repay fee
-		if fee_asset == assets.asset_out {
+		let transfer = if fee_asset == assets.asset_out {
 			let fee = Self::calculate_fees(&pool_data, amount)?;
 			let amount_out_plus_fee = amount.checked_add(fee).ok_or(Error::<T>::Overflow)?;
 
@@ -1164,7 +1905,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
-			Ok(AMMTransfer {
+			AMMTransfer {
 				origin: who.clone(),
 				assets,
 				amount: calculated_in,
@@ -1172,7 +1913,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				discount: false,
 				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
-			})
+			}
 
 		// Distributed asset is bought (out) of the pool for accumulated asset (in)
 		// Take accumulated asset (in) sans fee from the buyer and send to pool
@@ -1207,7 +1948,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
-			Ok(AMMTransfer {
+			AMMTransfer {
 				origin: who.clone(),
 				assets,
 				amount: calculated_in_without_fee,
@@ -1215,8 +1956,12 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				discount: false,
 				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
-			})
-		}
+			}
+		};
+
+		Self::ensure_launch_parameters(&pool_id, &pool_data, &transfer)?;
+
+		Ok(transfer)
 	}
 
 	fn execute_buy(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, BalanceOf<T>>) -> DispatchResult {
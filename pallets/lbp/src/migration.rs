@@ -0,0 +1,181 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v0 {
+	use super::*;
+
+	/// Weight curve before the introduction of non-linear curves.
+	#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub enum WeightCurveType {
+		Linear,
+	}
+
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
+		pub owner: AccountId,
+		pub start: Option<BlockNumber>,
+		pub end: Option<BlockNumber>,
+		pub assets: (AssetId, AssetId),
+		pub initial_weight: LBPWeight,
+		pub final_weight: LBPWeight,
+		pub weight_curve: WeightCurveType,
+		pub fee: (u32, u32),
+		pub fee_collector: AccountId,
+		pub repay_target: Balance,
+	}
+}
+
+/// Migrate the pallet storage to v1. Existing pools use the linear weight curve which keeps working
+/// with the extended `WeightCurveType`, all the pools are re-encoded to the new format.
+pub fn migrate_to_v1<T: Config, P: GetStorageVersion + PalletInfoAccess>() -> Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::lbp",
+		"Running migration storage v1 for lbp with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let mut translated: u64 = 0;
+
+		PoolData::<T>::translate::<v0::Pool<T::AccountId, T::BlockNumber>, _>(|_, old| {
+			translated.saturating_inc();
+
+			let weight_curve = match old.weight_curve {
+				v0::WeightCurveType::Linear => WeightCurveType::Linear,
+			};
+
+			Some(Pool {
+				owner: old.owner,
+				start: old.start,
+				end: old.end,
+				assets: old.assets,
+				initial_weight: old.initial_weight,
+				final_weight: old.final_weight,
+				weight_curve,
+				fee: old.fee,
+				fee_collector: old.fee_collector,
+				repay_target: old.repay_target,
+			})
+		});
+
+		StorageVersion::new(1).put::<P>();
+
+		weight = weight.saturating_add(T::DbWeight::get().reads_writes(translated, translated.saturating_add(1)));
+
+		log::info!(
+			target: "runtime::lbp",
+			"Migration storage v1 for lbp was complete, {:?} pools were migrated",
+			translated,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::lbp",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn pre_migrate<T: Config, P: GetStorageVersion>() -> Result<Vec<u8>, &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 0,
+		"Storage version too high."
+	);
+
+	let pools = PoolData::<T>::iter_keys().count() as u32;
+
+	Ok(pools.encode())
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate<T: Config, P: GetStorageVersion>(state: Vec<u8>) -> Result<(), &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 1,
+		"Unexpected storage version."
+	);
+
+	let pools = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode number of pools")?;
+
+	// every pool has to be decodable in the new format
+	ensure!(
+		PoolData::<T>::iter_values().count() as u32 == pools,
+		"Number of pools does not match."
+	);
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, LBPPallet, Test, ALICE, BSX, CHARLIE, KUSD, KUSD_BSX_POOL_ID};
+	use frame_support::storage::unhashed;
+
+	#[test]
+	fn migrate_to_v1_should_keep_linear_pools_working() {
+		ExtBuilder::default().build().execute_with(|| {
+			let old_pool = v0::Pool {
+				owner: ALICE,
+				start: Some(10u64),
+				end: Some(20u64),
+				assets: (KUSD, BSX),
+				initial_weight: 20_000_000,
+				final_weight: 80_000_000,
+				weight_curve: v0::WeightCurveType::Linear,
+				fee: (1, 100),
+				fee_collector: CHARLIE,
+				repay_target: 0,
+			};
+
+			StorageVersion::new(0).put::<LBPPallet>();
+			unhashed::put(&PoolData::<Test>::hashed_key_for(KUSD_BSX_POOL_ID), &old_pool);
+
+			migrate_to_v1::<Test, LBPPallet>();
+
+			assert_eq!(StorageVersion::get::<LBPPallet>(), 1);
+			assert_eq!(
+				LBPPallet::pool_data(KUSD_BSX_POOL_ID),
+				Some(Pool {
+					owner: ALICE,
+					start: Some(10u64),
+					end: Some(20u64),
+					assets: (KUSD, BSX),
+					initial_weight: 20_000_000,
+					final_weight: 80_000_000,
+					weight_curve: WeightCurveType::Linear,
+					fee: (1, 100),
+					fee_collector: CHARLIE,
+					repay_target: 0,
+				})
+			);
+		});
+	}
+}
@@ -4,7 +4,9 @@ use super::*;
 use crate as lbp;
 use crate::{AssetPairAccountIdFor, Config};
 use frame_support::parameter_types;
+use frame_support::traits::tokens::nonfungibles::{Inspect, InspectEnumerable};
 use frame_support::traits::{Everything, GenesisBuild, LockIdentifier, Nothing};
+use frame_support::weights::Weight;
 use hydradx_traits::LockedBalance;
 use orml_traits::parameter_type_with_key;
 use primitives::constants::chain::{AssetId, Balance, CORE_ASSET_ID};
@@ -13,11 +15,14 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
 pub type AccountId = u64;
 pub type BlockNumber = u64;
+pub type CollectionId = u128;
+pub type ItemId = u32;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -41,6 +46,10 @@ pub const KUSD_BSX_POOL_ID: AccountId = 2_003_000;
 
 pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
+pub const LAUNCH_COLLECTION_ID: CollectionId = 1_000;
+
+pub const GRADUATION_POOL_ACCOUNT: AccountId = 1_000;
+
 pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	owner: ALICE,
 	start: SALE_START,
@@ -155,6 +164,8 @@ parameter_types! {
 	pub const MinPoolLiquidity: Balance = 1_000;
 	pub const MaxInRatio: u128 = 3;
 	pub const MaxOutRatio: u128 = 3;
+	pub const MaxAllowlistSize: u32 = 5;
+	pub const PurchasesRemovalLimit: u32 = 2;
 }
 
 pub struct MultiLockedBalance();
@@ -171,6 +182,149 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+thread_local! {
+	pub static NFTS: RefCell<Vec<(CollectionId, ItemId, AccountId)>> = RefCell::new(vec![]);
+}
+
+pub fn mint_nft(collection: CollectionId, item: ItemId, owner: AccountId) {
+	NFTS.with(|v| v.borrow_mut().push((collection, item, owner)));
+}
+
+pub struct DummyNFT;
+
+impl Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.find(|(c, i, _)| c == collection && i == item)
+				.map(|(_, _, owner)| *owner)
+		})
+	}
+}
+
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		let collections: Vec<CollectionId> = NFTS.with(|v| v.borrow().iter().map(|(c, _, _)| *c).collect());
+		Box::new(collections.into_iter())
+	}
+
+	fn items(collection: &Self::CollectionId) -> Self::ItemsIterator {
+		let items: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(c, _, _)| c == collection)
+				.map(|(_, i, _)| *i)
+				.collect()
+		});
+		Box::new(items.into_iter())
+	}
+
+	fn owned(who: &AccountId) -> Self::OwnedIterator {
+		let owned: Vec<(CollectionId, ItemId)> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, _, owner)| owner == who)
+				.map(|(c, i, _)| (*c, *i))
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(c, _, owner)| c == collection && owner == who)
+				.map(|(_, i, _)| *i)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
+/// Asset, amount, price asset, price, weight cap and position owner of graduated sales
+pub type GraduationRecord = (AssetId, Balance, AssetId, FixedU128, Permill, Option<AccountId>);
+
+thread_local! {
+	pub static GRADUATIONS: RefCell<Vec<GraduationRecord>> = RefCell::new(vec![]);
+}
+
+pub fn graduations() -> Vec<GraduationRecord> {
+	GRADUATIONS.with(|v| v.borrow().clone())
+}
+
+pub struct DummyGraduation;
+
+impl GraduationHandler<AccountId, AssetId, Balance> for DummyGraduation {
+	fn graduate(
+		from: AccountId,
+		asset: AssetId,
+		amount: Balance,
+		price_asset: AssetId,
+		price: FixedU128,
+		weight_cap: Permill,
+		position_owner: Option<AccountId>,
+	) -> DispatchResult {
+		<Currency as MultiCurrency<AccountId>>::transfer(asset, &from, &GRADUATION_POOL_ACCOUNT, amount)?;
+
+		GRADUATIONS.with(|v| {
+			v.borrow_mut()
+				.push((asset, amount, price_asset, price, weight_cap, position_owner))
+		});
+
+		Ok(())
+	}
+
+	fn graduate_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Source, asset in, asset out, amount in, amount out, liquidity in and liquidity out of trades
+pub type TradeRecord = (Source, AssetId, AssetId, Balance, Balance, Balance, Balance);
+
+thread_local! {
+	pub static TRADES: RefCell<Vec<TradeRecord>> = RefCell::new(vec![]);
+}
+
+pub fn trades() -> Vec<TradeRecord> {
+	TRADES.with(|v| v.borrow().clone())
+}
+
+pub struct DummyOracle;
+
+impl OnTradeHandler<AssetId, Balance> for DummyOracle {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADES.with(|v| {
+			v.borrow_mut()
+				.push((source, asset_a, asset_b, amount_a, amount_b, liquidity_a, liquidity_b))
+		});
+
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currency;
@@ -184,6 +338,13 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type PoolLimitHandler = ();
+	type CollectionId = CollectionId;
+	type NFTHandler = DummyNFT;
+	type GraduationHandler = DummyGraduation;
+	type OnTradeHandler = DummyOracle;
+	type MaxAllowlistSize = MaxAllowlistSize;
+	type PurchasesRemovalLimit = PurchasesRemovalLimit;
 }
 
 pub struct ExtBuilder {
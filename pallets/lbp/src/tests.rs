@@ -18,9 +18,10 @@
 #![allow(clippy::bool_assert_comparison)]
 use super::*;
 use crate::mock::{
-	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, RuntimeCall as Call, DEFAULT_FEE,
-	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START, SAMPLE_AMM_TRANSFER,
-	SAMPLE_POOL_DATA,
+	expect_events, generate_trades, graduations, mint_nft, run_to_sale_end, run_to_sale_start, trades, AccountId,
+	CollectionId, MaxAllowlistSize, RuntimeCall as Call, DEFAULT_FEE, EXISTENTIAL_DEPOSIT, GRADUATION_POOL_ACCOUNT,
+	HDX_BSX_POOL_ID, INITIAL_BALANCE, KUSD_BSX_POOL_ID, LAUNCH_COLLECTION_ID, SALE_END, SALE_START,
+	SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
 };
 pub use crate::mock::{
 	set_block_number, Currency, ExtBuilder, LBPPallet, RuntimeEvent as TestEvent, RuntimeOrigin as Origin, Test, ALICE,
@@ -69,6 +70,7 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data2 = Pool {
@@ -136,6 +138,7 @@ pub fn predefined_test_ext_with_repay_target() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		assert_ok!(LBPPallet::add_liquidity(
@@ -317,6 +320,71 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_work_with_non_linear_weight_curves() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 20_000_000,
+			final_weight: 80_000_000,
+			weight_curve: WeightCurveType::Exponential { rate: 2_000 },
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+		};
+		// most of the change happens at the beginning of the sale
+		let (weight_a, weight_b) = LBPPallet::calculate_weights(&pool_data, 150).unwrap();
+		assert_eq!(weight_a, 63_863_514);
+		assert_eq!(weight_a + weight_b, MAX_WEIGHT);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((20_000_000, 80_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((80_000_000, 20_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Stepped { steps: 3 };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 120),
+			Ok((20_000_000, 80_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 180),
+			Ok((80_000_000, 20_000_000))
+		);
+
+		pool_data.weight_curve =
+			WeightCurveType::Piecewise(vec![(Permill::from_percent(50), 30_000_000)].try_into().unwrap());
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 125),
+			Ok((25_000_000, 75_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((30_000_000, 70_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 175),
+			Ok((55_000_000, 45_000_000))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -631,6 +699,7 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((5, 100)),
 			Some(BOB),
 			None,
@@ -662,6 +731,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -685,6 +755,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -706,6 +777,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(ALICE),
 			None,
 		));
@@ -728,6 +800,7 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((6, 1_000)),
 			None,
 			None,
@@ -755,6 +828,7 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(repayment),
 		));
 		let updated_pool_data_6 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -807,6 +881,7 @@ fn update_non_existing_pool_data_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -828,6 +903,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10),
 				Some(10_000_000),
+				None,
 				Some(80_000_000),
 				Some((5, 100)),
 				None,
@@ -847,6 +923,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -863,6 +940,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -879,6 +957,7 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(0),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
@@ -903,6 +982,7 @@ fn update_pool_data_without_changes_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::NothingToUpdate
 		);
@@ -924,6 +1004,7 @@ fn update_pool_data_by_non_owner_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::NotOwner
 		);
@@ -944,6 +1025,7 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data1 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -959,6 +1041,7 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool_data2 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -992,6 +1075,7 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		set_block_number::<Test>(16);
@@ -1006,6 +1090,7 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 				Some(30),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
@@ -1050,6 +1135,7 @@ fn update_pool_with_existing_fee_collector_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
@@ -1091,6 +1177,7 @@ fn update_pool_interval_should_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -1106,6 +1193,7 @@ fn update_pool_interval_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		// verify changes
@@ -1884,6 +1972,7 @@ fn zero_weight_should_not_work() {
 			end: Some(18),
 			initial_weight: Some(0),
 			final_weight: Some(80),
+			weight_curve: None,
 			fee: Some((5, 100)),
 			fee_collector: Some(BOB),
 			repay_target: Some(0),
@@ -1893,6 +1982,115 @@ fn zero_weight_should_not_work() {
 	});
 }
 
+#[test]
+fn invalid_weight_curve_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		let invalid_curves = vec![
+			WeightCurveType::Exponential { rate: 0 },
+			WeightCurveType::Exponential {
+				rate: MAX_EXPONENTIAL_RATE + 1,
+			},
+			WeightCurveType::Stepped { steps: 1 },
+			WeightCurveType::Piecewise(vec![].try_into().unwrap()),
+			WeightCurveType::Piecewise(
+				vec![
+					(Permill::from_percent(50), 30_000_000),
+					(Permill::from_percent(20), 40_000_000),
+				]
+				.try_into()
+				.unwrap(),
+			),
+			WeightCurveType::Piecewise(vec![(Permill::one(), 30_000_000)].try_into().unwrap()),
+		];
+
+		for weight_curve in invalid_curves {
+			assert_noop!(
+				LBPPallet::create_pool(
+					Origin::root(),
+					ALICE,
+					ETH,
+					1_000_000_000,
+					KUSD,
+					2_000_000_000,
+					20_000_000,
+					80_000_000,
+					weight_curve.clone(),
+					DEFAULT_FEE,
+					CHARLIE,
+					0,
+				),
+				Error::<Test>::InvalidWeightCurve
+			);
+
+			assert_noop!(
+				LBPPallet::update_pool_data(
+					Origin::signed(ALICE),
+					KUSD_BSX_POOL_ID,
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(weight_curve),
+					None,
+					None,
+					None
+				),
+				Error::<Test>::InvalidWeightCurve
+			);
+		}
+
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(WeightCurveType::Piecewise(
+					vec![(Permill::from_percent(50), 0)].try_into().unwrap()
+				)),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InvalidWeight
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_update_weight_curve() {
+	predefined_test_ext().execute_with(|| {
+		let weight_curve = WeightCurveType::Stepped { steps: 5 };
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(weight_curve.clone()),
+			None,
+			None,
+			None
+		));
+
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(pool_data.weight_curve, weight_curve);
+
+		expect_events(vec![Event::PoolUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			data: pool_data,
+		}
+		.into()]);
+	});
+}
+
 #[test]
 fn execute_buy_should_work() {
 	predefined_test_ext().execute_with(|| {
@@ -2326,6 +2524,7 @@ fn buy_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2466,6 +2665,7 @@ fn update_pool_data_after_sale_should_not_work() {
 				None,
 				None,
 				None,
+				None,
 			),
 			Error::<Test>::SaleStarted
 		);
@@ -2575,6 +2775,7 @@ fn sell_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2641,6 +2842,7 @@ fn zero_fee_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2809,6 +3011,7 @@ fn get_spot_price_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -2963,6 +3166,7 @@ fn simulate_lbp_event_should_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3252,6 +3456,7 @@ fn validate_trade_should_not_work() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 	});
@@ -3325,6 +3530,7 @@ fn calculate_fees_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 
 		let pool = LBPPallet::pool_data(HDX_BSX_POOL_ID).unwrap();
@@ -3380,6 +3586,7 @@ fn can_create_should_work() {
 			None,
 			None,
 			None,
+			None,
 		));
 		// pool is initialized but is not running
 		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(
@@ -3591,6 +3798,7 @@ fn simulate_lbp_event_with_repayment() {
 			None,
 			None,
 			None,
+			None,
 			None
 		));
 
@@ -3659,3 +3867,801 @@ fn simulate_lbp_event_with_repayment() {
 		assert_eq!(Currency::free_balance(sold_asset, &fee_collector), 0);
 	});
 }
+
+fn allowlist(accounts: Vec<AccountId>) -> BoundedVec<AccountId, MaxAllowlistSize> {
+	accounts.try_into().unwrap()
+}
+
+fn launch_parameters(
+	access: Option<ParticipantAccess<CollectionId>>,
+	max_purchase_per_account: Option<Balance>,
+	max_purchase_per_block: Option<Balance>,
+) -> LaunchParameters<CollectionId> {
+	LaunchParameters {
+		access,
+		max_purchase_per_account,
+		max_purchase_per_block,
+	}
+}
+
+#[test]
+fn set_launch_parameters_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let parameters = launch_parameters(Some(ParticipantAccess::Allowlist), Some(100_000_000), None);
+
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(parameters)
+		));
+
+		assert_eq!(LBPPallet::launch_parameters(KUSD_BSX_POOL_ID), Some(parameters));
+
+		expect_events(vec![Event::LaunchParametersUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			parameters: Some(parameters),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None
+		));
+
+		assert_eq!(LBPPallet::launch_parameters(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn set_launch_parameters_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		let parameters = launch_parameters(None, Some(100_000_000), None);
+
+		assert_noop!(
+			LBPPallet::set_launch_parameters(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(parameters)),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_launch_parameters(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(parameters)),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_launch_parameters(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(launch_parameters(None, Some(0), None))
+			),
+			Error::<Test>::InvalidLaunchParameters
+		);
+
+		assert_noop!(
+			LBPPallet::set_launch_parameters(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(launch_parameters(None, None, Some(0)))
+			),
+			Error::<Test>::InvalidLaunchParameters
+		);
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_noop!(
+			LBPPallet::set_launch_parameters(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(parameters)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn allowlist_should_restrict_trading() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(launch_parameters(Some(ParticipantAccess::Allowlist), None, None))
+		));
+
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, allowlist(vec![BOB])),
+			Error::<Test>::NotOwner
+		);
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000_u128, 0),
+			Error::<Test>::NotAllowed
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![BOB])
+		));
+
+		expect_events(vec![Event::AddedToAllowlist {
+			pool: KUSD_BSX_POOL_ID,
+			accounts: vec![BOB],
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 1_000_000_u128, 0));
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![BOB])
+		));
+
+		expect_events(vec![Event::RemovedFromAllowlist {
+			pool: KUSD_BSX_POOL_ID,
+			accounts: vec![BOB],
+		}
+		.into()]);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn nft_holders_access_should_restrict_trading() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(launch_parameters(
+				Some(ParticipantAccess::NftHolders(LAUNCH_COLLECTION_ID)),
+				None,
+				None
+			))
+		));
+
+		// NFT of another collection does not give access to the sale
+		mint_nft(LAUNCH_COLLECTION_ID + 1, 1, BOB);
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::NotAllowed
+		);
+
+		mint_nft(LAUNCH_COLLECTION_ID, 1, BOB);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+	});
+}
+
+#[test]
+fn max_purchase_per_account_should_limit_cumulative_purchases() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(launch_parameters(None, Some(25_000_000), None))
+		));
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		set_block_number::<Test>(12);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		assert_eq!(LBPPallet::purchased(KUSD_BSX_POOL_ID, BOB), 20_000_000);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::MaxPurchasePerAccountExceeded
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 20_000_000_u128, 0),
+			Error::<Test>::MaxPurchasePerAccountExceeded
+		);
+
+		// selling of the sold asset is not limited
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 1_000_000_u128, 0));
+		assert_eq!(LBPPallet::purchased(KUSD_BSX_POOL_ID, BOB), 20_000_000);
+
+		// other accounts are limited separately
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+	});
+}
+
+#[test]
+fn max_purchase_per_block_should_limit_purchases_in_block() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(launch_parameters(None, None, Some(15_000_000)))
+		));
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(ALICE), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::MaxPurchasePerBlockExceeded
+		);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			5_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		set_block_number::<Test>(12);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_clear_launch_data() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_launch_parameters(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(launch_parameters(
+				Some(ParticipantAccess::Allowlist),
+				Some(100_000_000),
+				Some(100_000_000)
+			))
+		));
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![BOB])
+		));
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(LBPPallet::launch_parameters(KUSD_BSX_POOL_ID), None);
+		assert!(!Allowlist::<Test>::contains_key(KUSD_BSX_POOL_ID, BOB));
+		assert_eq!(LBPPallet::allowlist_size(KUSD_BSX_POOL_ID), 0);
+		assert_eq!(LBPPallet::purchased(KUSD_BSX_POOL_ID, BOB), 0);
+		assert_eq!(BlockPurchases::<Test>::get(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn add_to_allowlist_should_not_work_when_max_allowlist_size_is_exceeded() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![10, 11, 12])
+		));
+
+		// accounts already in the allowlist are not counted twice
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![11, 12, 13, 14])
+		));
+		assert_eq!(LBPPallet::allowlist_size(KUSD_BSX_POOL_ID), 5);
+
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, allowlist(vec![15])),
+			Error::<Test>::MaxAllowlistSizeExceeded
+		);
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![10, 15])
+		));
+		assert_eq!(LBPPallet::allowlist_size(KUSD_BSX_POOL_ID), 4);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			allowlist(vec![15])
+		));
+		assert_eq!(LBPPallet::allowlist_size(KUSD_BSX_POOL_ID), 5);
+	});
+}
+
+#[test]
+fn clear_purchases_should_remove_purchases_remaining_after_pool_was_destroyed() {
+	predefined_test_ext().execute_with(|| {
+		// more purchase records than `PurchasesRemovalLimit`
+		for who in 10..13 {
+			Purchases::<Test>::insert(KUSD_BSX_POOL_ID, who, 1_000_000);
+		}
+
+		assert_noop!(
+			LBPPallet::clear_purchases(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::PoolAlreadyExists
+		);
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+		assert_eq!(Purchases::<Test>::iter_prefix(KUSD_BSX_POOL_ID).count(), 1);
+
+		assert_noop!(
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::PurchasesNotCleared
+		);
+
+		assert_ok!(LBPPallet::clear_purchases(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		expect_events(vec![Event::PurchasesCleared {
+			pool: KUSD_BSX_POOL_ID,
+			remaining: false,
+		}
+		.into()]);
+
+		assert_eq!(Purchases::<Test>::iter_prefix(KUSD_BSX_POOL_ID).count(), 0);
+
+		assert_noop!(
+			LBPPallet::clear_purchases(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::NothingToClear
+		);
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+	});
+}
+
+fn graduation_settings(beneficiary: GraduationBeneficiary) -> GraduationSettings {
+	GraduationSettings {
+		weight_cap: Permill::from_percent(20),
+		beneficiary,
+	}
+}
+
+#[test]
+fn set_graduation_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+
+		assert_eq!(
+			LBPPallet::graduation(KUSD_BSX_POOL_ID),
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		);
+
+		expect_events(vec![Event::GraduationUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			settings: Some(graduation_settings(GraduationBeneficiary::Owner)),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+
+		// update of the settings revokes the approval
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Protocol))
+		));
+		assert!(!LBPPallet::is_graduation_approved(KUSD_BSX_POOL_ID));
+
+		assert_ok!(LBPPallet::set_graduation(Origin::signed(ALICE), KUSD_BSX_POOL_ID, None));
+		assert_eq!(LBPPallet::graduation(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn set_graduation_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_graduation(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				Some(graduation_settings(GraduationBeneficiary::Owner))
+			),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_graduation(
+				Origin::signed(ALICE),
+				HDX_BSX_POOL_ID,
+				Some(graduation_settings(GraduationBeneficiary::Owner))
+			),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_graduation(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(GraduationSettings {
+					weight_cap: Permill::zero(),
+					beneficiary: GraduationBeneficiary::Owner,
+				})
+			),
+			Error::<Test>::InvalidGraduationSettings
+		);
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_noop!(
+			LBPPallet::set_graduation(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(graduation_settings(GraduationBeneficiary::Owner))
+			),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn approve_graduation_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+		assert!(LBPPallet::is_graduation_approved(KUSD_BSX_POOL_ID));
+
+		expect_events(vec![Event::GraduationApprovalUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			approved: true,
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, false));
+		assert!(!LBPPallet::is_graduation_approved(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn approve_graduation_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true),
+			Error::<Test>::GraduationNotSet
+		);
+
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+
+		assert_noop!(
+			LBPPallet::approve_graduation(Origin::signed(ALICE), KUSD_BSX_POOL_ID, true),
+			BadOrigin
+		);
+
+		assert_noop!(
+			LBPPallet::approve_graduation(Origin::root(), HDX_BSX_POOL_ID, true),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn graduate_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+
+		run_to_sale_end();
+
+		let kusd_balance_before = Currency::free_balance(KUSD, &ALICE);
+		let bsx_balance_before = Currency::free_balance(BSX, &ALICE);
+
+		// anyone can graduate the pool
+		assert_ok!(LBPPallet::graduate(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// final weights are 80% KUSD and 20% BSX
+		let price = FixedU128::from_rational(1, 8);
+
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			kusd_balance_before + 1_000_000_000
+		);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), bsx_balance_before);
+		assert_eq!(Currency::free_balance(BSX, &GRADUATION_POOL_ACCOUNT), 2_000_000_000);
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 0);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 0);
+
+		assert_eq!(
+			graduations(),
+			vec![(BSX, 2_000_000_000, KUSD, price, Permill::from_percent(20), Some(ALICE))]
+		);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<FeeCollectorWithAsset<Test>>::contains_key(CHARLIE, KUSD));
+		assert_eq!(LBPPallet::graduation(KUSD_BSX_POOL_ID), None);
+		assert!(!LBPPallet::is_graduation_approved(KUSD_BSX_POOL_ID));
+
+		expect_events(vec![Event::PoolGraduated {
+			pool: KUSD_BSX_POOL_ID,
+			asset: BSX,
+			amount: 2_000_000_000,
+			price_asset: KUSD,
+			price,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn graduate_should_assign_position_to_protocol_and_unlock_collected_fees() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Protocol))
+		));
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+
+		//start sale
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		assert!(<Test as pallet::Config>::LockedBalance::get_by_lock(COLLECTOR_LOCK_ID, KUSD, CHARLIE) > 0);
+
+		run_to_sale_end();
+
+		let kusd_reserve = Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID);
+		let bsx_reserve = Currency::free_balance(BSX, &KUSD_BSX_POOL_ID);
+
+		assert_ok!(LBPPallet::graduate(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		let price = FixedU128::from_inner(
+			hydra_dx_math::lbp::calculate_spot_price(bsx_reserve, kusd_reserve, 20_000_000, 80_000_000, FixedU128::DIV)
+				.unwrap(),
+		);
+
+		assert_eq!(
+			graduations(),
+			vec![(BSX, bsx_reserve, KUSD, price, Permill::from_percent(20), None)]
+		);
+		assert_eq!(
+			<Test as pallet::Config>::LockedBalance::get_by_lock(COLLECTOR_LOCK_ID, KUSD, CHARLIE),
+			0
+		);
+	});
+}
+
+#[test]
+fn graduate_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		assert_noop!(
+			LBPPallet::graduate(Origin::signed(BOB), HDX_BSX_POOL_ID),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::graduate(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::GraduationNotSet
+		);
+	});
+
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+
+		run_to_sale_end();
+
+		assert_noop!(
+			LBPPallet::graduate(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::GraduationNotApproved
+		);
+
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+
+		// sale is still running in the last block
+		set_block_number::<Test>(SALE_END.unwrap());
+
+		assert_noop!(
+			LBPPallet::graduate(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleNotEnded
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_work_when_graduation_is_approved() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_graduation(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(graduation_settings(GraduationBeneficiary::Owner))
+		));
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, true));
+
+		run_to_sale_end();
+
+		assert_noop!(
+			LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID),
+			Error::<Test>::GraduationApproved
+		);
+
+		assert_ok!(LBPPallet::approve_graduation(Origin::root(), KUSD_BSX_POOL_ID, false));
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(LBPPallet::graduation(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn sell_should_notify_on_trade_handler_with_weighted_liquidity() {
+	predefined_test_ext().execute_with(|| {
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		// weights at block 11 are 22% KUSD and 78% BSX
+		assert_eq!(
+			trades(),
+			vec![(
+				LBP_SOURCE,
+				KUSD,
+				BSX,
+				9_980_000,
+				5_605_128,
+				1_009_980_000,
+				1_994_394_872 * 22 / 78
+			)]
+		);
+	});
+}
+
+#[test]
+fn buy_should_notify_on_trade_handler_with_weighted_liquidity() {
+	predefined_test_ext().execute_with(|| {
+		set_block_number::<Test>(11);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		// weights at block 11 are 22% KUSD and 78% BSX
+		assert_eq!(
+			trades(),
+			vec![(
+				LBP_SOURCE,
+				KUSD,
+				BSX,
+				17_894_744,
+				10_000_000,
+				1_017_894_744,
+				1_990_000_000 * 22 / 78
+			)]
+		);
+	});
+}
@@ -49,9 +49,16 @@ pub trait WeightInfo {
 	fn create_pool() -> Weight;
 	fn update_pool_data() -> Weight;
 	fn add_liquidity() -> Weight;
-	fn remove_liquidity() -> Weight;
+	fn remove_liquidity(a: u32, p: u32) -> Weight;
 	fn sell() -> Weight;
 	fn buy() -> Weight;
+	fn set_launch_parameters() -> Weight;
+	fn add_to_allowlist(n: u32) -> Weight;
+	fn remove_from_allowlist(n: u32) -> Weight;
+	fn set_graduation() -> Weight;
+	fn approve_graduation() -> Weight;
+	fn graduate(a: u32, p: u32) -> Weight;
+	fn clear_purchases(p: u32) -> Weight;
 }
 
 /// Weights for lbp using the hack.hydraDX node and recommended hardware.
@@ -59,7 +66,7 @@ pub struct HydraWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn create_pool() -> Weight {
 		Weight::from_ref_time(121_358_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	fn update_pool_data() -> Weight {
@@ -72,20 +79,70 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
-	fn remove_liquidity() -> Weight {
+	fn remove_liquidity(a: u32, p: u32) -> Weight {
 		Weight::from_ref_time(122_961_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(Weight::from_ref_time(1_452_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(160_655_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(161_152_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	fn set_launch_parameters() -> Weight {
+		Weight::from_ref_time(24_185_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn add_to_allowlist(n: u32) -> Weight {
+		Weight::from_ref_time(21_472_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_318_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn remove_from_allowlist(n: u32) -> Weight {
+		Weight::from_ref_time(21_250_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_295_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn set_graduation() -> Weight {
+		Weight::from_ref_time(25_312_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn approve_graduation() -> Weight {
+		Weight::from_ref_time(19_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn graduate(a: u32, p: u32) -> Weight {
+		Weight::from_ref_time(131_508_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_452_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	fn clear_purchases(p: u32) -> Weight {
+		Weight::from_ref_time(18_240_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 }
 
@@ -93,7 +150,7 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 impl WeightInfo for () {
 	fn create_pool() -> Weight {
 		Weight::from_ref_time(121_358_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	fn update_pool_data() -> Weight {
@@ -106,19 +163,65 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
-	fn remove_liquidity() -> Weight {
+	fn remove_liquidity(a: u32, p: u32) -> Weight {
 		Weight::from_ref_time(122_961_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(Weight::from_ref_time(1_452_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(160_655_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(161_152_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	fn set_launch_parameters() -> Weight {
+		Weight::from_ref_time(24_185_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn add_to_allowlist(n: u32) -> Weight {
+		Weight::from_ref_time(21_472_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_318_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn remove_from_allowlist(n: u32) -> Weight {
+		Weight::from_ref_time(21_250_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_295_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn set_graduation() -> Weight {
+		Weight::from_ref_time(25_312_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn approve_graduation() -> Weight {
+		Weight::from_ref_time(19_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn graduate(a: u32, p: u32) -> Weight {
+		Weight::from_ref_time(131_508_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_452_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	fn clear_purchases(p: u32) -> Weight {
+		Weight::from_ref_time(18_240_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_471_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 }
@@ -1135,25 +1135,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Self::sync_global_farm(global_farm, current_period)?;
 						Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
 
-						//NOTE: this should never fail yield-farm's stopped must be >= entry's
-						//stopped
-						let delta_stopped =
-							yield_farm
-								.total_stopped
-								.checked_sub(&farm_entry.stopped_at_creation)
-								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						//NOTE: yield-farm's `updated_at` is updated to current period if it's
-						//possible so this should be ok.
-						let periods = yield_farm
-							.updated_at
-							.checked_sub(&farm_entry.entered_at)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
-							.checked_sub(&delta_stopped)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						let loyalty_multiplier =
-							Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())?;
+						let loyalty_multiplier = Self::get_farm_entry_loyalty_multiplier(yield_farm, farm_entry)?;
 
 						let (rewards, unclaimable_rewards) = math::calculate_user_reward(
 							farm_entry.accumulated_rpvs,
@@ -1332,6 +1314,299 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Add LP shares to existing deposit.
+	///
+	/// This function values added LP shares and adds them to all yield farm entries of the
+	/// deposit. Accumulated rpvs of the entries are adjusted so rewards of the entries which were
+	/// not claimed yet don't change. Entries are not recreated so loyalty of the entries is kept.
+	///
+	/// Parameters:
+	/// - `deposit_id`: id representing deposit in the yield farms.
+	/// - `shares_amount`: amount of LP shares to add to the deposit.
+	/// - `get_token_value_of_lp_shares`: callback function returning amount of
+	/// `incentivized_asset` behind `lp_shares`.
+	#[require_transactional]
+	fn add_lp_shares(
+		deposit_id: DepositId,
+		shares_amount: Balance,
+		get_token_value_of_lp_shares: impl Fn(T::AssetId, T::AmmPoolId, Balance) -> Result<Balance, DispatchError>,
+	) -> Result<(), DispatchError> {
+		ensure!(!shares_amount.is_zero(), Error::<T, I>::InvalidDepositAmount);
+
+		<Deposit<T, I>>::try_mutate(deposit_id, |maybe_deposit| {
+			//NOTE: At this point deposit existence and owner must be checked by pallet calling this
+			//function so this should never happen.
+			let deposit = maybe_deposit
+				.as_mut()
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::DepositNotFound.into())?;
+
+			let amm_pool_id = deposit.amm_pool_id.clone();
+			for farm_entry in deposit.yield_farm_entries.iter_mut() {
+				<YieldFarm<T, I>>::try_mutate(
+					(amm_pool_id.clone(), farm_entry.global_farm_id, farm_entry.yield_farm_id),
+					|maybe_yield_farm| -> Result<(), DispatchError> {
+						//NOTE: yield-farm must exist if yield-farm-entry exists.
+						let yield_farm = maybe_yield_farm
+							.as_mut()
+							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::YieldFarmNotFound.into())?;
+
+						ensure!(yield_farm.state.is_active(), Error::<T, I>::LiquidityMiningCanceled);
+
+						<GlobalFarm<T, I>>::try_mutate(farm_entry.global_farm_id, |maybe_global_farm| {
+							//NOTE: global-farm must exist if yield-farm exists.
+							let global_farm = maybe_global_farm
+								.as_mut()
+								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+							let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+
+							Self::sync_global_farm(global_farm, current_period)?;
+							Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
+
+							let valued_shares = get_token_value_of_lp_shares(
+								global_farm.incentivized_asset,
+								amm_pool_id.clone(),
+								shares_amount,
+							)?;
+
+							farm_entry.accumulated_rpvs = math::calculate_adjusted_accumulated_rpvs(
+								farm_entry.accumulated_rpvs,
+								farm_entry.valued_shares,
+								valued_shares,
+								yield_farm.accumulated_rpvs,
+							)
+							.map_err(|_| ArithmeticError::Overflow)?;
+
+							farm_entry.valued_shares = farm_entry
+								.valued_shares
+								.checked_add(valued_shares)
+								.ok_or(ArithmeticError::Overflow)?;
+
+							let deposit_stake_in_global_farm =
+								math::calculate_global_farm_shares(valued_shares, yield_farm.multiplier)
+									.map_err(|_| ArithmeticError::Overflow)?;
+
+							yield_farm.total_shares = yield_farm
+								.total_shares
+								.checked_add(shares_amount)
+								.ok_or(ArithmeticError::Overflow)?;
+
+							yield_farm.total_valued_shares = yield_farm
+								.total_valued_shares
+								.checked_add(valued_shares)
+								.ok_or(ArithmeticError::Overflow)?;
+
+							global_farm.add_stake(deposit_stake_in_global_farm)?;
+
+							Ok(())
+						})
+					},
+				)?;
+			}
+
+			deposit.shares = deposit
+				.shares
+				.checked_add(shares_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(())
+		})
+	}
+
+	/// Remove part of LP shares from existing deposit.
+	///
+	/// This function claims rewards for `who` from all claimable yield farms of the deposit and
+	/// removes LP shares from all yield farm entries of the deposit. Rewards of removed LP shares
+	/// which can't be claimed because of loyalty are transferred back to global farms. Loyalty of
+	/// the entries is kept.
+	///
+	/// Deposit can't be destroyed by this function, `withdraw_lp_shares()` have to be used to
+	/// remove all LP shares from the deposit.
+	///
+	/// Returns: `Vec<(GlobalFarmId, YieldFarmId, reward currency, claimed amount)>`
+	///
+	/// Parameters:
+	/// - `who`: destination account to receive rewards.
+	/// - `deposit_id`: id representing deposit in the yield farms.
+	/// - `shares_amount`: amount of LP shares to remove from the deposit.
+	#[require_transactional]
+	fn remove_lp_shares(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		shares_amount: Balance,
+	) -> Result<Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Balance)>, DispatchError> {
+		//NOTE: At this point deposit existence and owner must be checked by pallet calling this
+		//function so this should never happen.
+		let deposit = <Deposit<T, I>>::get(deposit_id)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::DepositNotFound.into())?;
+
+		ensure!(
+			!shares_amount.is_zero() && shares_amount < deposit.shares,
+			Error::<T, I>::InvalidDepositAmount
+		);
+
+		let mut claims = Vec::with_capacity(deposit.yield_farm_entries.len());
+		for farm_entry in deposit.yield_farm_entries.iter() {
+			if Self::is_yield_farm_claimable(
+				farm_entry.global_farm_id,
+				farm_entry.yield_farm_id,
+				deposit.amm_pool_id.clone(),
+			) {
+				let fail_on_doubleclaim = false;
+				let (global_farm_id, reward_currency, claimed, _) =
+					Self::claim_rewards(who.clone(), deposit_id, farm_entry.yield_farm_id, fail_on_doubleclaim)?;
+
+				claims.push((global_farm_id, farm_entry.yield_farm_id, reward_currency, claimed));
+			}
+		}
+
+		<Deposit<T, I>>::try_mutate(deposit_id, |maybe_deposit| {
+			let deposit = maybe_deposit
+				.as_mut()
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::DepositNotFound.into())?;
+
+			let amm_pool_id = deposit.amm_pool_id.clone();
+			let remaining_shares = deposit
+				.shares
+				.checked_sub(shares_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			for farm_entry in deposit.yield_farm_entries.iter_mut() {
+				<YieldFarm<T, I>>::try_mutate(
+					(amm_pool_id.clone(), farm_entry.global_farm_id, farm_entry.yield_farm_id),
+					|maybe_yield_farm| -> Result<(), DispatchError> {
+						//NOTE: yield-farm must exist if yield-farm-entry exists.
+						let yield_farm = maybe_yield_farm
+							.as_mut()
+							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::YieldFarmNotFound.into())?;
+
+						<GlobalFarm<T, I>>::try_mutate(farm_entry.global_farm_id, |maybe_global_farm| {
+							//NOTE: global-farm must exist if yield-farm exists.
+							let global_farm = maybe_global_farm
+								.as_mut()
+								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+							let removed_valued_shares = math::calculate_proportional_amount(
+								farm_entry.valued_shares,
+								shares_amount,
+								deposit.shares,
+							)
+							.map_err(|_| ArithmeticError::Overflow)?;
+
+							let remaining_valued_shares = farm_entry
+								.valued_shares
+								.checked_sub(removed_valued_shares)
+								.ok_or(ArithmeticError::Overflow)?;
+
+							// rewards can't be claimed from terminated yield farm so there is
+							// nothing to adjust.
+							if !yield_farm.state.is_terminated() {
+								ensure!(
+									remaining_shares.ge(&global_farm.min_deposit),
+									Error::<T, I>::InvalidDepositAmount,
+								);
+
+								let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+
+								Self::sync_global_farm(global_farm, current_period)?;
+								Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
+
+								let loyalty_multiplier =
+									Self::get_farm_entry_loyalty_multiplier(yield_farm, farm_entry)?;
+
+								let (rewards, unclaimable_rewards) = math::calculate_user_reward(
+									farm_entry.accumulated_rpvs,
+									farm_entry.valued_shares,
+									farm_entry.accumulated_claimed_rewards,
+									yield_farm.accumulated_rpvs,
+									loyalty_multiplier,
+								)
+								.map_err(|_| ArithmeticError::Overflow)?;
+
+								let (remaining_claimable_rewards, remaining_unclaimable_rewards) =
+									math::calculate_user_reward(
+										farm_entry.accumulated_rpvs,
+										remaining_valued_shares,
+										Zero::zero(),
+										yield_farm.accumulated_rpvs,
+										loyalty_multiplier,
+									)
+									.map_err(|_| ArithmeticError::Overflow)?;
+
+								//NOTE: rewards which were not claimed yet are kept in the entry.
+								farm_entry.accumulated_claimed_rewards = remaining_claimable_rewards
+									.checked_sub(rewards)
+									.ok_or(ArithmeticError::Overflow)?;
+
+								//NOTE: this should never fail, unclaimable rewards are proportional to
+								//valued shares.
+								let removed_unclaimable_rewards = unclaimable_rewards
+									.checked_sub(remaining_unclaimable_rewards)
+									.defensive_ok_or::<Error<T, I>>(
+										InconsistentStateError::InvalidValuedShares.into(),
+									)?;
+
+								if !removed_unclaimable_rewards.is_zero() {
+									yield_farm.left_to_distribute = yield_farm
+										.left_to_distribute
+										.checked_sub(removed_unclaimable_rewards)
+										.defensive_ok_or::<Error<T, I>>(
+											InconsistentStateError::NotEnoughRewardsInYieldFarm.into(),
+										)?;
+
+									global_farm.accumulated_paid_rewards = global_farm
+										.accumulated_paid_rewards
+										.checked_sub(removed_unclaimable_rewards)
+										.defensive_ok_or::<Error<T, I>>(
+											InconsistentStateError::InvalidPaidAccumulatedRewards.into(),
+										)?;
+
+									let global_farm_account = Self::farm_account_id(global_farm.id)?;
+									let pot = Self::pot_account_id().ok_or(Error::<T, I>::ErrorGetAccountId)?;
+
+									T::MultiCurrency::transfer(
+										global_farm.reward_currency,
+										&pot,
+										&global_farm_account,
+										removed_unclaimable_rewards,
+									)?;
+								}
+							}
+
+							farm_entry.valued_shares = remaining_valued_shares;
+
+							yield_farm.total_shares = yield_farm
+								.total_shares
+								.checked_sub(shares_amount)
+								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidTotalShares.into())?;
+
+							yield_farm.total_valued_shares = yield_farm
+								.total_valued_shares
+								.checked_sub(removed_valued_shares)
+								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidValuedShares.into())?;
+
+							// yield farm's stake in global farm is set to `0` when farm is stopped
+							// so this update is only required for active farms.
+							if yield_farm.state.is_active() {
+								let removed_stake_in_global_farm =
+									math::calculate_global_farm_shares(removed_valued_shares, yield_farm.multiplier)
+										.map_err(|_| ArithmeticError::Overflow)?;
+
+								global_farm.remove_stake(removed_stake_in_global_farm)?;
+							}
+
+							Ok(())
+						})
+					},
+				)?;
+			}
+
+			deposit.shares = remaining_shares;
+
+			Ok(claims)
+		})
+	}
+
 	/// Helper function to create yield farm entry.
 	#[require_transactional]
 	fn do_deposit_lp_shares(
@@ -1494,6 +1769,31 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(m)
 	}
 
+	/// This function returns loyalty multiplier of the farm entry in the yield farm or error.
+	/// Yield farm must be synced to current period before calling this function.
+	fn get_farm_entry_loyalty_multiplier(
+		yield_farm: &YieldFarmData<T, I>,
+		farm_entry: &YieldFarmEntry<T, I>,
+	) -> Result<FixedU128, DispatchError> {
+		//NOTE: this should never fail yield-farm's stopped must be >= entry's
+		//stopped
+		let delta_stopped = yield_farm
+			.total_stopped
+			.checked_sub(&farm_entry.stopped_at_creation)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		//NOTE: yield-farm's `updated_at` is updated to current period if it's
+		//possible so this should be ok.
+		let periods = yield_farm
+			.updated_at
+			.checked_sub(&farm_entry.entered_at)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
+			.checked_sub(&delta_stopped)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())
+	}
+
 	/// This function calculates and updates `accumulated_rpz` and all associated properties of
 	/// `global_farm` if conditions are met.
 	/// Returns the reward transferred to the pot.
@@ -1864,6 +2164,22 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 		Self::redeposit_lp_shares(global_farm_id, yield_farm_id, deposit_id, get_token_value_of_lp_shares)
 	}
 
+	fn add_lp_shares<F: Fn(T::AssetId, Self::AmmPoolId, Self::Balance) -> Result<Self::Balance, Self::Error>>(
+		deposit_id: DepositId,
+		shares_amount: Self::Balance,
+		get_token_value_of_lp_shares: F,
+	) -> Result<(), Self::Error> {
+		Self::add_lp_shares(deposit_id, shares_amount, get_token_value_of_lp_shares)
+	}
+
+	fn remove_lp_shares(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		shares_amount: Self::Balance,
+	) -> Result<Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Self::Balance)>, Self::Error> {
+		Self::remove_lp_shares(who, deposit_id, shares_amount)
+	}
+
 	fn claim_rewards(
 		who: T::AccountId,
 		deposit_id: DepositId,
@@ -0,0 +1,106 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+#[test]
+fn add_lp_shares_should_work() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			const FAIL_ON_DOUBLECLAIM: bool = false;
+			let deposit_id = PREDEFINED_DEPOSIT_IDS[0];
+			let yield_farm_key = (BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID);
+
+			let claimable_rewards = with_transaction(|| {
+				let (_, _, claimed, _) =
+					LiquidityMining::claim_rewards(ALICE, deposit_id, GC_BSX_TKN1_YIELD_FARM_ID, FAIL_ON_DOUBLECLAIM)
+						.unwrap();
+
+				TransactionOutcome::Rollback(Ok::<Balance, DispatchError>(claimed))
+			})
+			.unwrap();
+
+			let yield_farm_0 = LiquidityMining::yield_farm(yield_farm_key).unwrap();
+
+			//Act
+			assert_ok!(LiquidityMining::add_lp_shares(deposit_id, 50 * ONE, |_, _, _| {
+				Ok(2_500 * ONE)
+			}));
+
+			//Assert
+			let deposit = LiquidityMining::deposit(deposit_id).unwrap();
+			assert_eq!(deposit.shares, 100 * ONE);
+			assert_eq!(deposit.yield_farm_entries.len(), 1);
+
+			let farm_entry = &deposit.yield_farm_entries[0];
+			assert_eq!(farm_entry.valued_shares, 5_000 * ONE);
+			//NOTE: entry is not recreated so loyalty is kept.
+			assert_eq!(farm_entry.entered_at, 18);
+
+			let yield_farm = LiquidityMining::yield_farm(yield_farm_key).unwrap();
+			assert_eq!(yield_farm.total_shares, yield_farm_0.total_shares + 50 * ONE);
+			assert_eq!(
+				yield_farm.total_valued_shares,
+				yield_farm_0.total_valued_shares + 2_500 * ONE
+			);
+			assert_eq!(yield_farm.entries_count, yield_farm_0.entries_count);
+
+			//NOTE: rewards which were not claimed yet should not change.
+			let (_, _, claimed, _) =
+				LiquidityMining::claim_rewards(ALICE, deposit_id, GC_BSX_TKN1_YIELD_FARM_ID, FAIL_ON_DOUBLECLAIM)
+					.unwrap();
+
+			assert!(!claimable_rewards.is_zero());
+			assert!(claimed <= claimable_rewards);
+			assert!(claimable_rewards - claimed <= 1);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn add_lp_shares_should_not_work_when_shares_amount_is_zero() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::add_lp_shares(PREDEFINED_DEPOSIT_IDS[0], 0, |_, _, _| { Ok(2_500 * ONE) }),
+				Error::<Test, Instance1>::InvalidDepositAmount
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn add_lp_shares_should_not_work_when_yield_farm_is_not_active() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_ok!(LiquidityMining::stop_yield_farm(GC, GC_FARM, BSX_TKN1_AMM));
+
+			assert_noop!(
+				LiquidityMining::add_lp_shares(PREDEFINED_DEPOSIT_IDS[0], 50 * ONE, |_, _, _| { Ok(2_500 * ONE) }),
+				Error::<Test, Instance1>::LiquidityMiningCanceled
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
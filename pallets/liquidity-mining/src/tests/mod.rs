@@ -247,6 +247,7 @@ macro_rules! assert_eq_approx {
 	}};
 }
 
+pub mod add_lp_shares;
 pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
@@ -255,6 +256,7 @@ pub mod full_run;
 pub mod invariants;
 pub mod mock;
 pub mod redeposit_lp_shares;
+pub mod remove_lp_shares;
 pub mod resume_yield_farm;
 pub mod stop_yield_farm;
 pub mod terminate_global_farm;
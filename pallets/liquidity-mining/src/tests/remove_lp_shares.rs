@@ -0,0 +1,126 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+#[test]
+fn remove_lp_shares_should_work() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			const FAIL_ON_DOUBLECLAIM: bool = false;
+			const REWARD_CURRENCY: AssetId = BSX;
+			let deposit_id = PREDEFINED_DEPOSIT_IDS[0];
+			let yield_farm_key = (BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID);
+			let global_farm_account = LiquidityMining::farm_account_id(GC_FARM).unwrap();
+
+			let claimable_rewards = with_transaction(|| {
+				let (_, _, claimed, _) =
+					LiquidityMining::claim_rewards(ALICE, deposit_id, GC_BSX_TKN1_YIELD_FARM_ID, FAIL_ON_DOUBLECLAIM)
+						.unwrap();
+
+				TransactionOutcome::Rollback(Ok::<Balance, DispatchError>(claimed))
+			})
+			.unwrap();
+
+			//_0 - value before act.
+			let alice_bsx_balance_0 = Tokens::free_balance(REWARD_CURRENCY, &ALICE);
+			let global_farm_account_balance_0 = Tokens::free_balance(REWARD_CURRENCY, &global_farm_account);
+			let yield_farm_0 = LiquidityMining::yield_farm(yield_farm_key).unwrap();
+
+			//Act
+			assert_eq!(
+				LiquidityMining::remove_lp_shares(ALICE, deposit_id, 20 * ONE).unwrap(),
+				vec![(GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID, REWARD_CURRENCY, claimable_rewards)]
+			);
+
+			//Assert
+			assert_eq!(
+				Tokens::free_balance(REWARD_CURRENCY, &ALICE),
+				alice_bsx_balance_0 + claimable_rewards
+			);
+
+			//NOTE: unclaimable rewards of removed shares are returned to the global farm.
+			assert!(Tokens::free_balance(REWARD_CURRENCY, &global_farm_account) > global_farm_account_balance_0);
+
+			let deposit = LiquidityMining::deposit(deposit_id).unwrap();
+			assert_eq!(deposit.shares, 30 * ONE);
+			assert_eq!(deposit.yield_farm_entries.len(), 1);
+
+			let farm_entry = &deposit.yield_farm_entries[0];
+			assert_eq!(farm_entry.valued_shares, 1_500 * ONE);
+			//NOTE: entry is not recreated so loyalty is kept.
+			assert_eq!(farm_entry.entered_at, 18);
+
+			let yield_farm = LiquidityMining::yield_farm(yield_farm_key).unwrap();
+			assert_eq!(yield_farm.total_shares, yield_farm_0.total_shares - 20 * ONE);
+			assert_eq!(
+				yield_farm.total_valued_shares,
+				yield_farm_0.total_valued_shares - 1_000 * ONE
+			);
+			assert_eq!(yield_farm.entries_count, yield_farm_0.entries_count);
+
+			//NOTE: all rewards were claimed so there is nothing to claim in the same period.
+			let (_, _, claimed, _) =
+				LiquidityMining::claim_rewards(ALICE, deposit_id, GC_BSX_TKN1_YIELD_FARM_ID, FAIL_ON_DOUBLECLAIM)
+					.unwrap();
+			assert_eq!(claimed, 0);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn remove_lp_shares_should_not_work_when_shares_amount_is_not_valid() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			let deposit_id = PREDEFINED_DEPOSIT_IDS[0];
+
+			assert_noop!(
+				LiquidityMining::remove_lp_shares(ALICE, deposit_id, 0),
+				Error::<Test, Instance1>::InvalidDepositAmount
+			);
+
+			//NOTE: deposit can't be destroyed by removing shares.
+			assert_noop!(
+				LiquidityMining::remove_lp_shares(ALICE, deposit_id, 50 * ONE),
+				Error::<Test, Instance1>::InvalidDepositAmount
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn remove_lp_shares_should_not_work_when_remaining_shares_are_below_min_deposit() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			let deposit_id = PREDEFINED_DEPOSIT_IDS[0];
+			let min_deposit = LiquidityMining::global_farm(GC_FARM).unwrap().min_deposit;
+
+			frame_support::assert_err!(
+				LiquidityMining::remove_lp_shares(ALICE, deposit_id, 50 * ONE - min_deposit + 1),
+				Error::<Test, Instance1>::InvalidDepositAmount
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
@@ -17,13 +17,17 @@
 
 use crate::*;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::{
+	traits::{OnFinalize, OnInitialize},
+	BoundedVec,
+};
 use frame_system::{Pallet as System, RawOrigin};
 use hydradx_traits::Registry;
 use orml_traits::MultiCurrencyExtended;
 use pallet_liquidity_mining::Instance1;
 use primitives::AssetId;
 use sp_runtime::{traits::One, FixedU128, Permill};
+use sp_std::vec::Vec;
 
 const TVL_CAP: Balance = 222_222_000_000_000_000_000_000;
 const ONE: Balance = 1_000_000_000_000;
@@ -510,5 +514,69 @@ benchmarks! {
 		set_period::<T>(400);
 	}: _(RawOrigin::Signed(lp1), deposit_id, yield_farm_id)
 
+	add_liquidity_and_join_farms {
+		let c in 1 .. T::MaxFarmEntriesPerDeposit::get();
+
+		initialize_omnipool::<T>()?;
+
+		let mut farm_entries = Vec::new();
+		for i in 0..c {
+			let owner = create_funded_account::<T>("owner", i, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+			//gId: 2i + 1, yId: 2i + 2
+			let global_farm_id = 2 * i + 1;
+			initialize_global_farm::<T>(owner.clone())?;
+			initialize_yield_farm::<T>(owner, global_farm_id, BTC.into())?;
+
+			farm_entries.push((global_farm_id, global_farm_id + 1));
+		}
+		let farm_entries: BoundedVec<_, T::MaxFarmEntriesPerDeposit> = farm_entries.try_into().unwrap();
+
+		let lp = create_funded_account::<T>("lp_1", c, 10 * BTC_ONE, BTC.into());
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(lp), BTC.into(), 10 * BTC_ONE, farm_entries)
+	verify {
+		assert!(OmniPositionId::<T>::contains_key(1));
+	}
+
+	exit_farms_and_remove_liquidity {
+		let c in 1 .. T::MaxFarmEntriesPerDeposit::get();
+
+		let deposit_id = 1;
+
+		initialize_omnipool::<T>()?;
+
+		let mut yield_farm_ids = Vec::new();
+		for i in 0..c {
+			let owner = create_funded_account::<T>("owner", i, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+			//gId: 2i + 1, yId: 2i + 2
+			let global_farm_id = 2 * i + 1;
+			initialize_global_farm::<T>(owner.clone())?;
+			initialize_yield_farm::<T>(owner, global_farm_id, BTC.into())?;
+
+			yield_farm_ids.push(global_farm_id + 1);
+		}
+
+		let lp1 = create_funded_account::<T>("lp_1", c, 10 * BTC_ONE, BTC.into());
+		let lp1_position_id = omnipool_add_liquidity::<T>(lp1.clone(), BTC.into(), 10 * BTC_ONE)?;
+
+		//NOTE: This is necessary because paid rewards are lower than ED.
+		fund::<T>(lp1.clone(), REWARD_CURRENCY.into(), 100 * ONE)?;
+
+		set_period::<T>(200);
+
+		lm_deposit_shares::<T>(lp1.clone(), 1, 2, lp1_position_id)?;
+		for y_id in yield_farm_ids.iter().skip(1) {
+			crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), y_id - 1, *y_id, deposit_id)?;
+		}
+
+		let yield_farm_ids: BoundedVec<_, T::MaxFarmEntriesPerDeposit> = yield_farm_ids.try_into().unwrap();
+
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(lp1), deposit_id, yield_farm_ids)
+	verify {
+		assert!(!OmniPositionId::<T>::contains_key(deposit_id));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -52,7 +52,7 @@ use frame_support::{
 		tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
 		Get,
 	},
-	PalletId,
+	transactional, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ema::EmaPrice as Price;
@@ -63,11 +63,11 @@ use hydradx_traits::{
 use orml_traits::MultiCurrency;
 use pallet_ema_oracle::OracleError;
 use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
-use pallet_omnipool::{types::Position as OmniPosition, NFTCollectionIdOf};
+use pallet_omnipool::{traits::OmnipoolHooks, types::Position as OmniPosition, NFTCollectionIdOf};
 use primitive_types::U256;
 use primitives::{Balance, ItemId as DepositId};
 use sp_runtime::{ArithmeticError, FixedU128, Perquintill};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -151,6 +151,11 @@ pub mod pallet {
 		/// Oracle providing price of LRNA/{Asset} used to calculate `valued_shares`.
 		type PriceOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, Price, Error = OracleError>;
 
+		/// Maximum number of yield farms same LP shares can be deposited into. This should be same
+		/// as `MaxFarmEntriesPerDeposit` of the liquidity mining handler.
+		#[pallet::constant]
+		type MaxFarmEntriesPerDeposit: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -271,6 +276,22 @@ pub mod pallet {
 
 		/// All LP shares were unlocked and NFT representing deposit was destroyed.
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// LP shares of omnipool position were added to the deposit.
+		DepositSharesAdded {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			position_id: T::PositionItemId,
+			shares_amount: Balance,
+		},
+
+		/// LP shares were removed from the deposit and transferred to the new omnipool position.
+		DepositSharesRemoved {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			position_id: T::PositionItemId,
+			shares_amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -294,6 +315,16 @@ pub mod pallet {
 
 		/// Oracle providing `price_adjustment` could not be found for requested assets.
 		PriceAdjustmentNotAvailable,
+
+		/// No farm entries were specified.
+		NoFarmEntriesSpecified,
+
+		/// Deposit was not destroyed after withdrawing from provided yield farms. All the yield
+		/// farms of the deposit must be provided.
+		NotAllFarmEntriesExited,
+
+		/// Asset of the omnipool position doesn't match asset of the deposit.
+		AssetMismatch,
 	}
 
 	//NOTE: these errors should never happen.
@@ -667,38 +698,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let lp_position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
-
-			ensure!(
-				OmnipoolPallet::<T>::exists(lp_position.asset_id),
-				Error::<T>::AssetNotFound
-			);
-
-			let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
-				global_farm_id,
-				yield_farm_id,
-				lp_position.asset_id,
-				lp_position.shares,
-				|_, _, _| -> Result<Balance, DispatchError> { Self::get_position_value_in_hub_asset(&lp_position) },
-			)?;
-
-			Self::lock_lp_position(position_id, deposit_id)?;
-
-			<T as pallet::Config>::NFTHandler::mint_into(
-				&<T as pallet::Config>::NFTCollectionId::get(),
-				&deposit_id,
-				&who,
-			)?;
-
-			Self::deposit_event(Event::SharesDeposited {
-				global_farm_id,
-				yield_farm_id,
-				deposit_id,
-				asset_id: lp_position.asset_id,
-				who,
-				shares_amount: lp_position.shares,
-				position_id,
-			});
+			Self::do_deposit_shares(who, global_farm_id, yield_farm_id, position_id)?;
 
 			Ok(())
 		}
@@ -728,32 +728,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
 
-			//NOTE: not tested this should never fail.
-			let position_id = OmniPositionId::<T>::get(deposit_id)
-				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
-
-			//NOTE: pallet should be owner of the omnipool position at this point.
-			let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
-			ensure!(
-				OmnipoolPallet::<T>::exists(lp_position.asset_id),
-				Error::<T>::AssetNotFound
-			);
-
-			T::LiquidityMiningHandler::redeposit_lp_shares(global_farm_id, yield_farm_id, deposit_id, |_, _, _| {
-				Self::get_position_value_in_hub_asset(&lp_position)
-			})?;
-
-			Self::deposit_event(Event::SharesRedeposited {
-				global_farm_id,
-				yield_farm_id,
-				deposit_id,
-				asset_id: lp_position.asset_id,
-				who: owner,
-				shares_amount: lp_position.shares,
-				position_id,
-			});
-
-			Ok(())
+			Self::do_redeposit_shares(owner, global_farm_id, yield_farm_id, deposit_id)
 		}
 
 		/// Claim rewards from liquidity mining program for deposit represented by the `deposit_id`.
@@ -777,20 +752,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
 
-			let (global_farm_id, reward_currency, claimed, _) =
-				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
+			let (_, claimed) = Self::do_claim_rewards(owner, deposit_id, yield_farm_id)?;
 
 			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
 
-			Self::deposit_event(Event::RewardClaimed {
-				global_farm_id,
-				yield_farm_id,
-				who: owner,
-				claimed,
-				reward_currency,
-				deposit_id,
-			});
-
 			Ok(())
 		}
 
@@ -823,57 +788,102 @@ pub mod pallet {
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
 
-			//NOTE: not tested - this should never fail.
-			let position_id = OmniPositionId::<T>::get(deposit_id)
-				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
-			let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
+			Self::do_withdraw_shares(owner, deposit_id, yield_farm_id)?;
 
-			//NOTE: not tested - this should never fail.
-			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
-				.defensive_ok_or::<Error<T>>(InconsistentStateError::DepositDataNotFound.into())?;
+			Ok(())
+		}
 
-			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
-				owner.clone(),
-				deposit_id,
-				global_farm_id,
-				yield_farm_id,
-				lp_position.asset_id,
-			)?;
+		/// Add liquidity of an asset to the omnipool and deposit created position(LP shares) to
+		/// the liquidity mining in one transaction.
+		///
+		/// This function creates omnipool position, deposits it into first yield farm and
+		/// redeposits it into other provided yield farms. Minted NFT represents deposit in the
+		/// liquidity mining.
+		///
+		/// Parameters:
+		/// - `origin`: account providing liquidity.
+		/// - `asset`: id of the asset to add to the omnipool.
+		/// - `amount`: amount of the asset to add to the omnipool.
+		/// - `farm_entries`: list of `(global_farm_id, yield_farm_id)` to deposit LP shares to. At least
+		/// one and at most `MaxFarmEntriesPerDeposit` farm entries.
+		///
+		/// Emits omnipool's events, `SharesDeposited` event and `SharesRedeposited` event for each
+		/// redeposit when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_and_join_farms(farm_entries.len() as u32)
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::PriceOracle::get_price_weight().saturating_mul(farm_entries.len() as u64)))]
+		#[transactional]
+		pub fn add_liquidity_and_join_farms(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			amount: Balance,
+			farm_entries: BoundedVec<(GlobalFarmId, YieldFarmId), T::MaxFarmEntriesPerDeposit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
 
-			if let Some((reward_currency, claimed, _)) = claim_data {
-				if !claimed.is_zero() {
-					Self::deposit_event(Event::RewardClaimed {
-						global_farm_id,
-						yield_farm_id,
-						who: owner.clone(),
-						claimed,
-						reward_currency,
-						deposit_id,
-					});
-				}
-			}
+			let ((global_farm_id, yield_farm_id), other_farm_entries) =
+				farm_entries.split_first().ok_or(Error::<T>::NoFarmEntriesSpecified)?;
 
-			Self::deposit_event(Event::SharesWithdrawn {
-				global_farm_id,
-				yield_farm_id,
-				who: owner.clone(),
-				amount: withdrawn_amount,
-				deposit_id,
-			});
+			let position_id = OmnipoolPallet::<T>::next_position_id();
+			OmnipoolPallet::<T>::add_liquidity(origin, asset, amount)?;
 
-			if is_destroyed {
-				Self::unlock_lp_postion(deposit_id, &owner)?;
-				<T as pallet::Config>::NFTHandler::burn(
-					&<T as pallet::Config>::NFTCollectionId::get(),
-					&deposit_id,
-					Some(&owner),
-				)?;
+			let deposit_id = Self::do_deposit_shares(who.clone(), *global_farm_id, *yield_farm_id, position_id)?;
 
-				Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+			for (global_farm_id, yield_farm_id) in other_farm_entries {
+				Self::do_redeposit_shares(who.clone(), *global_farm_id, *yield_farm_id, deposit_id)?;
 			}
 
 			Ok(())
 		}
+
+		/// Withdraw LP shares from all yield farms of the deposit and remove liquidity of the
+		/// omnipool position in one transaction.
+		///
+		/// This function claims rewards and withdraws LP shares from all provided yield farms. Deposit
+		/// must be destroyed after the last withdraw, i.e. all yield farms of the deposit must be
+		/// provided. Whole liquidity of the unlocked omnipool position is removed from the omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: owner of deposit.
+		/// - `deposit_id`: id of the deposit to exit farms with.
+		/// - `yield_farm_ids`: ids of all the yield farms of the deposit.
+		///
+		/// Emits `RewardClaimed` and `SharesWithdrawn` events for each yield farm, `DepositDestroyed`
+		/// event and omnipool's events when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::exit_farms_and_remove_liquidity(yield_farm_ids.len() as u32)
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn exit_farms_and_remove_liquidity(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_ids: BoundedVec<YieldFarmId, T::MaxFarmEntriesPerDeposit>,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin.clone(), deposit_id)?;
+
+			ensure!(!yield_farm_ids.is_empty(), Error::<T>::NoFarmEntriesSpecified);
+
+			//NOTE: not tested - this should never fail.
+			let position_id = OmniPositionId::<T>::get(deposit_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+
+			for yield_farm_id in yield_farm_ids.iter() {
+				Self::do_withdraw_shares(owner.clone(), deposit_id, *yield_farm_id)?;
+			}
+
+			//NOTE: deposit is destroyed and position is unlocked only after last withdraw.
+			ensure!(
+				!OmniPositionId::<T>::contains_key(deposit_id),
+				Error::<T>::NotAllFarmEntriesExited
+			);
+
+			let lp_position = OmnipoolPallet::<T>::load_position(position_id, owner)?;
+
+			OmnipoolPallet::<T>::remove_liquidity(origin, position_id, lp_position.shares)
+		}
 	}
 }
 
@@ -884,6 +894,287 @@ impl<T: Config> Pallet<T> {
 		<T as pallet::Config>::PalletId::get().into_account_truncating()
 	}
 
+	/// Deposit omnipool position to the yield farm. Position must be owned by `who`.
+	pub fn do_deposit_shares(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		position_id: T::PositionItemId,
+	) -> Result<DepositId, DispatchError> {
+		OmnipoolPallet::<T>::ensure_no_flash_swap()?;
+
+		let lp_position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+
+		ensure!(
+			OmnipoolPallet::<T>::exists(lp_position.asset_id),
+			Error::<T>::AssetNotFound
+		);
+
+		let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
+			global_farm_id,
+			yield_farm_id,
+			lp_position.asset_id,
+			lp_position.shares,
+			|_, _, _| -> Result<Balance, DispatchError> { Self::get_position_value_in_hub_asset(&lp_position) },
+		)?;
+
+		Self::lock_lp_position(position_id, deposit_id)?;
+
+		<T as pallet::Config>::NFTHandler::mint_into(
+			&<T as pallet::Config>::NFTCollectionId::get(),
+			&deposit_id,
+			&who,
+		)?;
+
+		Self::deposit_event(Event::SharesDeposited {
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			asset_id: lp_position.asset_id,
+			who,
+			shares_amount: lp_position.shares,
+			position_id,
+		});
+
+		Ok(deposit_id)
+	}
+
+	/// Redeposit LP shares of the deposit to another yield farm. Deposit must be owned by `owner`.
+	fn do_redeposit_shares(
+		owner: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		deposit_id: DepositId,
+	) -> DispatchResult {
+		OmnipoolPallet::<T>::ensure_no_flash_swap()?;
+
+		//NOTE: not tested this should never fail.
+		let position_id = OmniPositionId::<T>::get(deposit_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+
+		//NOTE: pallet should be owner of the omnipool position at this point.
+		let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
+		ensure!(
+			OmnipoolPallet::<T>::exists(lp_position.asset_id),
+			Error::<T>::AssetNotFound
+		);
+
+		T::LiquidityMiningHandler::redeposit_lp_shares(global_farm_id, yield_farm_id, deposit_id, |_, _, _| {
+			Self::get_position_value_in_hub_asset(&lp_position)
+		})?;
+
+		Self::deposit_event(Event::SharesRedeposited {
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			asset_id: lp_position.asset_id,
+			who: owner,
+			shares_amount: lp_position.shares,
+			position_id,
+		});
+
+		Ok(())
+	}
+
+	/// Claim rewards and withdraw LP shares of the deposit from the yield farm. Omnipool position
+	/// is transferred to `owner` and deposit is destroyed if this was last withdraw from the
+	/// deposit. Deposit must be owned by `owner`.
+	///
+	/// Returns: claimed rewards as `(reward currency, claimed amount)` if any rewards were claimed.
+	pub fn do_withdraw_shares(
+		owner: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<Option<(T::AssetId, Balance)>, DispatchError> {
+		//NOTE: not tested - this should never fail.
+		let position_id = OmniPositionId::<T>::get(deposit_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+		let lp_position = OmnipoolPallet::<T>::load_position(position_id, Self::account_id())?;
+
+		//NOTE: not tested - this should never fail.
+		let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::DepositDataNotFound.into())?;
+
+		let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
+			owner.clone(),
+			deposit_id,
+			global_farm_id,
+			yield_farm_id,
+			lp_position.asset_id,
+		)?;
+
+		let mut claimed_rewards = None;
+		if let Some((reward_currency, claimed, _)) = claim_data {
+			if !claimed.is_zero() {
+				Self::deposit_event(Event::RewardClaimed {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					claimed,
+					reward_currency,
+					deposit_id,
+				});
+
+				claimed_rewards = Some((reward_currency, claimed));
+			}
+		}
+
+		Self::deposit_event(Event::SharesWithdrawn {
+			global_farm_id,
+			yield_farm_id,
+			who: owner.clone(),
+			amount: withdrawn_amount,
+			deposit_id,
+		});
+
+		if is_destroyed {
+			Self::unlock_lp_postion(deposit_id, &owner)?;
+			<T as pallet::Config>::NFTHandler::burn(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&deposit_id,
+				Some(&owner),
+			)?;
+
+			Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+		}
+
+		Ok(claimed_rewards)
+	}
+
+	/// Claim rewards of the deposit from the yield farm. Deposit must be owned by `owner`.
+	///
+	/// Claimed amount can be zero, `RewardClaimed` event is emitted only if rewards were claimed.
+	///
+	/// Returns: `(reward currency, claimed amount)`
+	pub fn do_claim_rewards(
+		owner: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<(T::AssetId, Balance), DispatchError> {
+		let (global_farm_id, reward_currency, claimed, _) =
+			T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
+
+		if !claimed.is_zero() {
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: owner,
+				claimed,
+				reward_currency,
+				deposit_id,
+			});
+		}
+
+		Ok((reward_currency, claimed))
+	}
+
+	/// Add LP shares of omnipool position to the existing deposit. Position is merged into the
+	/// deposit's position so yield farm entries of the deposit are not recreated and their loyalty
+	/// is kept.
+	///
+	/// Position and deposit must be owned by `who`. All the yield farms of the deposit must be active.
+	#[transactional]
+	pub fn increase_deposit_shares(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		position_id: T::PositionItemId,
+	) -> DispatchResult {
+		Self::ensure_deposit_owner(&who, deposit_id)?;
+
+		let locked_position_id = OmniPositionId::<T>::get(deposit_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+		let locked_position = OmnipoolPallet::<T>::load_position(locked_position_id, Self::account_id())?;
+
+		let lp_position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+		ensure!(
+			lp_position.asset_id == locked_position.asset_id,
+			Error::<T>::AssetMismatch
+		);
+
+		T::LiquidityMiningHandler::add_lp_shares(deposit_id, lp_position.shares, |_, _, _| {
+			Self::get_position_value_in_hub_asset(&lp_position)
+		})?;
+
+		<T as pallet::Config>::NFTHandler::transfer(
+			&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
+			&position_id,
+			&Self::account_id(),
+		)?;
+
+		OmnipoolPallet::<T>::merge_positions(
+			frame_system::RawOrigin::Signed(Self::account_id()).into(),
+			lp_position.asset_id,
+			vec![locked_position_id, position_id],
+		)?;
+
+		Self::deposit_event(Event::DepositSharesAdded {
+			who,
+			deposit_id,
+			position_id,
+			shares_amount: lp_position.shares,
+		});
+
+		Ok(())
+	}
+
+	/// Remove `shares_amount` of LP shares from the deposit. Rewards are claimed from all the yield
+	/// farms of the deposit first. Removed shares are split from the deposit's position into new
+	/// omnipool position which is transferred to `who`.
+	///
+	/// Deposit must be owned by `who` and can't be fully withdrawn by this function.
+	///
+	/// Returns: `(id of the omnipool position created from removed shares, claimed rewards as (reward currency, claimed amount))`
+	#[transactional]
+	pub fn decrease_deposit_shares(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		shares_amount: Balance,
+	) -> Result<(T::PositionItemId, Vec<(T::AssetId, Balance)>), DispatchError> {
+		Self::ensure_deposit_owner(&who, deposit_id)?;
+
+		let locked_position_id = OmniPositionId::<T>::get(deposit_id)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingLpPosition.into())?;
+
+		let claims = T::LiquidityMiningHandler::remove_lp_shares(who.clone(), deposit_id, shares_amount)?;
+		let mut claimed_rewards = Vec::with_capacity(claims.len());
+		for (global_farm_id, yield_farm_id, reward_currency, claimed) in claims {
+			if !claimed.is_zero() {
+				Self::deposit_event(Event::RewardClaimed {
+					global_farm_id,
+					yield_farm_id,
+					who: who.clone(),
+					claimed,
+					reward_currency,
+					deposit_id,
+				});
+
+				claimed_rewards.push((reward_currency, claimed));
+			}
+		}
+
+		let position_id = OmnipoolPallet::<T>::next_position_id();
+		OmnipoolPallet::<T>::split_position(
+			frame_system::RawOrigin::Signed(Self::account_id()).into(),
+			locked_position_id,
+			shares_amount,
+		)?;
+
+		<T as pallet::Config>::NFTHandler::transfer(
+			&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
+			&position_id,
+			&who,
+		)?;
+
+		Self::deposit_event(Event::DepositSharesRemoved {
+			who,
+			deposit_id,
+			position_id,
+			shares_amount,
+		});
+
+		Ok((position_id, claimed_rewards))
+	}
+
 	/// This function transfers omnipool's position NFT to liquidity mining's account. This
 	/// function also saves mapping of the deposit's id to omnipool position's id.
 	fn lock_lp_position(position_id: T::PositionItemId, deposit_id: DepositId) -> Result<(), DispatchError> {
@@ -952,12 +1243,19 @@ impl<T: Config> Pallet<T> {
 	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
 		let who = ensure_signed(origin)?;
 
+		Self::ensure_deposit_owner(&who, deposit_id)?;
+
+		Ok(who)
+	}
+
+	/// This function returns error if `who` is not owner of the deposit.
+	fn ensure_deposit_owner(who: &T::AccountId, deposit_id: DepositId) -> DispatchResult {
 		let nft_owner =
 			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &deposit_id)
 				.ok_or(Error::<T>::Forbidden)?;
 
-		ensure!(nft_owner == who, Error::<T>::Forbidden);
+		ensure!(nft_owner == *who, Error::<T>::Forbidden);
 
-		Ok(who)
+		Ok(())
 	}
 }
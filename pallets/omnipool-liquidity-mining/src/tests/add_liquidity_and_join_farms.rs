@@ -0,0 +1,232 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_and_join_farms_should_work_with_multiple_farm_entries() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let gc_g_farm_id = 1;
+			let gc_y_farm_id = 3;
+			let charlie_g_farm_id = 2;
+			let charlie_y_farm_id = 4;
+			let omnipool_position_id = 1;
+			let deposit_id = 1;
+
+			//Act
+			assert_ok!(OmnipoolMining::add_liquidity_and_join_farms(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				1_000 * ONE,
+				vec![(gc_g_farm_id, gc_y_farm_id), (charlie_g_farm_id, charlie_y_farm_id)]
+					.try_into()
+					.unwrap(),
+			));
+
+			//Assert
+			let lp_position = Omnipool::positions(omnipool_position_id).unwrap();
+			assert_eq!(lp_position.amount, 1_000 * ONE);
+
+			assert!(has_event(
+				crate::Event::SharesDeposited {
+					global_farm_id: gc_g_farm_id,
+					yield_farm_id: gc_y_farm_id,
+					deposit_id,
+					asset_id: KSM,
+					who: ALICE,
+					shares_amount: lp_position.shares,
+					position_id: omnipool_position_id,
+				}
+				.into()
+			));
+
+			assert_last_event!(crate::Event::SharesRedeposited {
+				global_farm_id: charlie_g_farm_id,
+				yield_farm_id: charlie_y_farm_id,
+				deposit_id,
+				asset_id: KSM,
+				who: ALICE,
+				shares_amount: lp_position.shares,
+				position_id: omnipool_position_id,
+			}
+			.into());
+
+			//Storage check
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(deposit_id).unwrap(),
+				omnipool_position_id
+			);
+
+			//Omnipool's NFT should be locked in the liquidity mining pallet.
+			let lm_account = OmnipoolMining::account_id();
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id).unwrap();
+			assert_eq!(owner, lm_account);
+
+			//Deposit's NFT should be minted to the LP.
+			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
+			assert_eq!(owner, ALICE);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_no_farm_entries_are_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolMining::add_liquidity_and_join_farms(
+					RuntimeOrigin::signed(ALICE),
+					KSM,
+					1_000 * ONE,
+					vec![].try_into().unwrap(),
+				),
+				crate::Error::<Test>::NoFarmEntriesSpecified
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_origin_is_none() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolMining::add_liquidity_and_join_farms(
+					RuntimeOrigin::none(),
+					KSM,
+					1_000 * ONE,
+					vec![(1, 3)].try_into().unwrap(),
+				),
+				BadOrigin
+			);
+		});
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn decrease_deposit_shares_should_split_deposit_position_and_transfer_it_to_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 2
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+			let locked_position_id = 1;
+			let expected_position_id = 2;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 1_000 * ONE));
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				global_farm_id,
+				yield_farm_id,
+				locked_position_id
+			));
+			let locked_position = Omnipool::positions(locked_position_id).unwrap();
+			let removed_shares = locked_position.shares / 4;
+
+			set_block_number(1_000);
+			let alice_hdx_balance = Tokens::free_balance(HDX, &ALICE);
+
+			//Act
+			let (position_id, claimed_rewards) =
+				OmnipoolMining::decrease_deposit_shares(ALICE, deposit_id, removed_shares).unwrap();
+
+			//Assert
+			assert_eq!(position_id, expected_position_id);
+
+			assert_eq!(claimed_rewards.len(), 1);
+			let (reward_currency, claimed) = claimed_rewards[0];
+			assert_eq!(reward_currency, HDX);
+			assert!(!claimed.is_zero());
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance + claimed);
+
+			assert_last_event!(crate::Event::DepositSharesRemoved {
+				who: ALICE,
+				deposit_id,
+				position_id,
+				shares_amount: removed_shares,
+			}
+			.into());
+
+			assert_eq!(
+				Omnipool::positions(locked_position_id).unwrap().shares,
+				locked_position.shares - removed_shares
+			);
+			assert_eq!(Omnipool::positions(position_id).unwrap().shares, removed_shares);
+
+			//Storage check
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(deposit_id).unwrap(),
+				locked_position_id
+			);
+
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &locked_position_id).unwrap();
+			assert_eq!(owner, OmnipoolMining::account_id());
+
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id).unwrap();
+			assert_eq!(owner, ALICE);
+
+			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
+			assert_eq!(owner, ALICE);
+		});
+}
+
+#[test]
+fn decrease_deposit_shares_should_fail_when_who_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 2
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 1_000 * ONE));
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				global_farm_id,
+				yield_farm_id,
+				1
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::decrease_deposit_shares(BOB, deposit_id, ONE),
+				crate::Error::<Test>::Forbidden
+			);
+		});
+}
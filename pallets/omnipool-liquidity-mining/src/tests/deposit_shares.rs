@@ -340,3 +340,54 @@ fn deposit_shares_should_fail_with_asset_not_found_when_omnipool_deosnt_exists()
 			);
 		});
 }
+
+#[test]
+fn deposit_shares_should_fail_when_called_in_flash_swap() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let omnipool_position_id = 0;
+
+			//Act & assert
+			assert_noop!(
+				Omnipool::flash_swap(
+					RuntimeOrigin::signed(LP1),
+					DAI,
+					10 * ONE,
+					KSM,
+					vec![RuntimeCall::OmnipoolMining(crate::Call::deposit_shares {
+						global_farm_id,
+						yield_farm_id,
+						position_id: omnipool_position_id,
+					})]
+				),
+				pallet_omnipool::Error::<Test>::FlashSwapInProgress
+			);
+		});
+}
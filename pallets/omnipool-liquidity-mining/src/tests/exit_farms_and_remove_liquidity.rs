@@ -0,0 +1,296 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_work_when_all_farm_entries_are_exited() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let omnipool_position_id = 1;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::add_liquidity_and_join_farms(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				1_000 * ONE,
+				vec![(1, 3), (2, 4)].try_into().unwrap(),
+			));
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(OmnipoolMining::exit_farms_and_remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				deposit_id,
+				vec![3, 4].try_into().unwrap(),
+			));
+
+			//Assert
+			assert!(has_event(
+				crate::Event::DepositDestroyed { who: ALICE, deposit_id }.into()
+			));
+
+			//Storage check
+			assert_eq!(crate::OmniPositionId::<Test>::get(deposit_id), None);
+
+			//Omnipool's position should be removed and its NFT burned.
+			assert!(Omnipool::positions(omnipool_position_id).is_none());
+			let owner: Option<AccountId> = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &omnipool_position_id);
+			assert_eq!(owner, None);
+
+			//Deposit's NFT should be burned.
+			let owner: Option<AccountId> = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id);
+			assert_eq!(owner, None);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_when_not_all_farm_entries_are_exited() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::add_liquidity_and_join_farms(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				1_000 * ONE,
+				vec![(1, 3), (2, 4)].try_into().unwrap(),
+			));
+
+			set_block_number(1_000);
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::exit_farms_and_remove_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					deposit_id,
+					vec![3].try_into().unwrap(),
+				),
+				crate::Error::<Test>::NotAllFarmEntriesExited
+			);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_when_no_farm_entries_are_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::add_liquidity_and_join_farms(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				1_000 * ONE,
+				vec![(1, 3)].try_into().unwrap(),
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::exit_farms_and_remove_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					deposit_id,
+					vec![].try_into().unwrap(),
+				),
+				crate::Error::<Test>::NoFarmEntriesSpecified
+			);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_with_forbidden_when_account_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, 2, KSM, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::add_liquidity_and_join_farms(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				1_000 * ONE,
+				vec![(1, 3)].try_into().unwrap(),
+			));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::exit_farms_and_remove_liquidity(
+					RuntimeOrigin::signed(BOB),
+					deposit_id,
+					vec![3].try_into().unwrap(),
+				),
+				crate::Error::<Test>::Forbidden
+			);
+		});
+}
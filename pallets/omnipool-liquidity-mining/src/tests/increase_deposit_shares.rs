@@ -0,0 +1,151 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn increase_deposit_shares_should_merge_position_into_deposit_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 2
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+			let locked_position_id = 1;
+			let added_position_id = 2;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 1_000 * ONE));
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				global_farm_id,
+				yield_farm_id,
+				locked_position_id
+			));
+			let locked_position = Omnipool::positions(locked_position_id).unwrap();
+
+			set_block_number(1_000);
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 500 * ONE));
+			let added_position = Omnipool::positions(added_position_id).unwrap();
+
+			//Act
+			assert_ok!(OmnipoolMining::increase_deposit_shares(
+				ALICE,
+				deposit_id,
+				added_position_id
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::DepositSharesAdded {
+				who: ALICE,
+				deposit_id,
+				position_id: added_position_id,
+				shares_amount: added_position.shares,
+			}
+			.into());
+
+			let merged_position = Omnipool::positions(locked_position_id).unwrap();
+			assert_eq!(merged_position.shares, locked_position.shares + added_position.shares);
+			assert_eq!(merged_position.amount, locked_position.amount + added_position.amount);
+			assert_eq!(Omnipool::positions(added_position_id), None);
+
+			//Storage check
+			assert_eq!(
+				crate::OmniPositionId::<Test>::get(deposit_id).unwrap(),
+				locked_position_id
+			);
+
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &locked_position_id).unwrap();
+			assert_eq!(owner, OmnipoolMining::account_id());
+
+			let owner: Option<AccountId> = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &added_position_id);
+			assert_eq!(owner, None);
+		});
+}
+
+#[test]
+fn increase_deposit_shares_should_fail_when_who_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, KSM, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 2
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+
+			//Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(ALICE), KSM, 1_000 * ONE));
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				global_farm_id,
+				yield_farm_id,
+				1
+			));
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(BOB), KSM, 500 * ONE));
+
+			//Act & assert
+			assert_noop!(
+				OmnipoolMining::increase_deposit_shares(BOB, deposit_id, 2),
+				crate::Error::<Test>::Forbidden
+			);
+		});
+}
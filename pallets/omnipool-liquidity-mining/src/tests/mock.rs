@@ -180,6 +180,7 @@ impl Config for Test {
 	type OracleSource = OracleSource;
 	type OraclePeriod = PeriodOracle;
 	type PriceOracle = DummyOracle;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
 	type WeightInfo = ();
 }
 
@@ -272,6 +273,7 @@ parameter_types! {
 
 impl pallet_omnipool::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type PositionItemId = u128;
 	type Currency = Tokens;
@@ -570,7 +572,7 @@ impl ExtBuilder {
 	}
 }
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
 use hydra_dx_math::ema::EmaPrice;
 
 pub struct DummyNFT;
@@ -591,6 +593,36 @@ impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
 	}
 }
 
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_collection: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((c, _), owner)| c == collection && *owner == who)
+				.map(|((_, i), _)| *i)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
 impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
 	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
 		Ok(())
@@ -29,9 +29,13 @@ pub fn has_event(event: mock::RuntimeEvent) -> bool {
 	System::events().iter().any(|record| record.event == event)
 }
 
+pub mod add_liquidity_and_join_farms;
 pub mod create_global_farm;
 pub mod create_yield_farm;
+pub mod decrease_deposit_shares;
 pub mod deposit_shares;
+pub mod exit_farms_and_remove_liquidity;
+pub mod increase_deposit_shares;
 pub mod mock;
 pub mod redeposit_shares;
 pub mod resume_yield_farm;
@@ -57,6 +57,8 @@ pub trait WeightInfo {
 	fn redeposit_shares() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn withdraw_shares() -> Weight;
+	fn add_liquidity_and_join_farms(c: u32) -> Weight;
+	fn exit_farms_and_remove_liquidity(c: u32) -> Weight;
 }
 
 /// Weights for pallet_omnipool_liquidity_mining using the hydraDX node and recommended hardware.
@@ -118,6 +120,22 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
+	fn add_liquidity_and_join_farms(c: u32) -> Weight {
+		Weight::from_ref_time(262_187_000 as u64)
+			.saturating_add(Weight::from_ref_time(158_315_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(27 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(24 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
+	fn exit_farms_and_remove_liquidity(c: u32) -> Weight {
+		Weight::from_ref_time(318_546_000 as u64)
+			.saturating_add(Weight::from_ref_time(201_437_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(22 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(c as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -177,4 +195,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(13 as u64))
 			.saturating_add(RocksDbWeight::get().writes(15 as u64))
 	}
+	fn add_liquidity_and_join_farms(c: u32) -> Weight {
+		Weight::from_ref_time(262_187_000 as u64)
+			.saturating_add(Weight::from_ref_time(158_315_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(27 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(24 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
+	fn exit_farms_and_remove_liquidity(c: u32) -> Weight {
+		Weight::from_ref_time(318_546_000 as u64)
+			.saturating_add(Weight::from_ref_time(201_437_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(24 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(22 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(c as u64)))
+	}
 }
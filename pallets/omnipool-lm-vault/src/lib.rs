@@ -0,0 +1,681 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool liquidity mining vault pallet
+//!
+//! ## Overview
+//!
+//! Vault provides liquidity to the omnipool on behalf of its users and keeps all of it in one
+//! omnipool position deposited in one yield farm of the omnipool liquidity mining.
+//! Users receive fungible vault shares representing their part of the vault's liquidity.
+//!
+//! Rewards of the vault's deposit can be compounded by anyone. Claimed rewards are swapped via
+//! route executor to the vault's asset and added to the vault's position. Omnipool position and
+//! liquidity mining deposit of the vault are never recreated so loyalty of the deposit is kept.
+//! Rewards claimed during withdrawals are recorded in the vault and compounded with the next
+//! claim. Only recorded rewards are compounded, assets sent to the vault's account are ignored.
+//!
+//! Vault is identified by its share asset. Share asset must be registered in the asset registry
+//! before vault is created and its issuance is controlled by the vault.
+//!
+//! ### Terminology
+//!
+//! * **Vault shares:** fungible asset representing user's part of the vault's liquidity
+//! * **LP shares:** shares of the vault's omnipool position
+//!
+//! ## Dispatchable functions
+//! * `create_vault` - creates new vault. Only `AuthorityOrigin` can create vaults.
+//! * `set_compounding_route` - sets route used to swap rewards to the vault's asset.
+//! * `deposit` - adds liquidity to the vault and mints vault shares to the user.
+//! * `withdraw` - burns vault shares and removes user's part of the vault's liquidity.
+//! * `compound` - claims rewards and adds them to the vault's liquidity.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+use frame_support::{
+	ensure,
+	pallet_prelude::{DispatchError, DispatchResult},
+	sp_runtime::traits::{AccountIdConversion, Zero},
+	storage::with_storage_layer,
+	traits::Get,
+	transactional, BoundedVec, PalletId,
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::{
+	liquidity_mining::{GlobalFarmId, YieldFarmId},
+	router::PoolType,
+	OraclePeriod, PriceOracle, Registry,
+};
+use orml_traits::MultiCurrency;
+use pallet_route_executor::{weights::WeightInfo as RouteExecutorWeightInfo, Trade};
+use primitive_types::U256;
+use primitives::Balance;
+use sp_runtime::{ArithmeticError, Permill};
+use sp_std::{vec, vec::Vec};
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type OmnipoolLiquidityMiningPallet<T> = pallet_omnipool_liquidity_mining::Pallet<T>;
+type RouteExecutorPallet<T> = pallet_route_executor::Pallet<T>;
+
+/// Maximum number of trades in the compounding route.
+pub const MAX_ROUTE_LENGTH: u32 = 5;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool_liquidity_mining::Config
+		+ pallet_route_executor::Config<AssetId = AssetIdOf<Self>, Balance = Balance>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for transfers and vault shares management.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetIdOf<Self>, Balance = Balance>;
+
+		/// Asset registry used to check existence of the vault's share asset.
+		type AssetRegistry: Registry<AssetIdOf<Self>, Vec<u8>, Balance, DispatchError>;
+
+		/// Origin allowed to create vaults and set compounding routes.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet id. Accounts of the vaults are derived from it.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Oracle price provider used to calculate minimum amount received from the rewards swap.
+		type OraclePriceProvider: PriceOracle<AssetIdOf<Self>, Price = EmaPrice>;
+
+		/// Maximum allowed slippage of the rewards swap compared to the oracle price.
+		#[pallet::constant]
+		type MaxCompoundingSlippage: Get<Permill>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn vaults)]
+	/// Vaults identified by their share asset.
+	pub type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, VaultInfo<AssetIdOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn compounding_routes)]
+	/// Routes used to swap rewards to the vault's asset. Direct omnipool trade is used if route is
+	/// not set.
+	pub type CompoundingRoutes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		BoundedVec<Trade<AssetIdOf<T>>, ConstU32<MAX_ROUTE_LENGTH>>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New vault was created.
+		VaultCreated {
+			vault_id: AssetIdOf<T>,
+			asset_id: AssetIdOf<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+		},
+
+		/// Compounding route of the vault was set.
+		CompoundingRouteSet {
+			vault_id: AssetIdOf<T>,
+			route: Vec<Trade<AssetIdOf<T>>>,
+		},
+
+		/// Liquidity was deposited to the vault.
+		Deposited {
+			vault_id: AssetIdOf<T>,
+			who: T::AccountId,
+			amount: Balance,
+			lp_shares: Balance,
+			shares: Balance,
+		},
+
+		/// Liquidity was withdrawn from the vault.
+		Withdrawn {
+			vault_id: AssetIdOf<T>,
+			who: T::AccountId,
+			shares: Balance,
+			lp_shares: Balance,
+			amount: Balance,
+			hub_amount: Balance,
+		},
+
+		/// Rewards of the vault were compounded.
+		Compounded {
+			vault_id: AssetIdOf<T>,
+			who: T::AccountId,
+			reward_currency: AssetIdOf<T>,
+			reward_amount: Balance,
+			amount: Balance,
+			lp_shares: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Vault with given share asset already exists.
+		VaultAlreadyExists,
+
+		/// Vault doesn't exist.
+		VaultNotFound,
+
+		/// Share asset is not registered in the asset registry.
+		ShareAssetNotRegistered,
+
+		/// Share asset has non-zero issuance or is the vault's asset.
+		InvalidShareAsset,
+
+		/// Asset is not in the omnipool.
+		AssetNotFound,
+
+		/// Amount is zero or too small to mint any vault shares.
+		InvalidAmount,
+
+		/// Account doesn't have enough vault shares.
+		InsufficientShares,
+
+		/// Vault holds no liquidity.
+		EmptyVault,
+
+		/// Route is empty or doesn't end with the vault's asset.
+		InvalidRoute,
+
+		/// Oracle price of the rewards swap is not available.
+		PriceNotAvailable,
+
+		/// Vault has no rewards to compound.
+		NothingToCompound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new vault.
+		///
+		/// Vault provides liquidity of `asset_id` to the omnipool and deposits it to the yield farm.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `share_asset`: registered asset with zero issuance used as vault shares and as vault's id.
+		/// - `asset_id`: omnipool asset provided as liquidity by the vault.
+		/// - `global_farm_id`: id of the global farm the vault's liquidity is deposited to.
+		/// - `yield_farm_id`: id of the `asset_id`'s yield farm the vault's liquidity is deposited to.
+		///
+		/// Emits `VaultCreated` event when successful.
+		///
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_vault())]
+		pub fn create_vault(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			asset_id: AssetIdOf<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!Vaults::<T>::contains_key(share_asset), Error::<T>::VaultAlreadyExists);
+			ensure!(
+				<T as Config>::AssetRegistry::exists(share_asset),
+				Error::<T>::ShareAssetNotRegistered
+			);
+			ensure!(
+				share_asset != asset_id && <T as Config>::Currency::total_issuance(share_asset).is_zero(),
+				Error::<T>::InvalidShareAsset
+			);
+			ensure!(OmnipoolPallet::<T>::exists(asset_id), Error::<T>::AssetNotFound);
+
+			Vaults::<T>::insert(
+				share_asset,
+				VaultInfo {
+					asset_id,
+					global_farm_id,
+					yield_farm_id,
+					lp_shares: Balance::zero(),
+					deposit: None,
+					pending_rewards: None,
+				},
+			);
+
+			Self::deposit_event(Event::VaultCreated {
+				vault_id: share_asset,
+				asset_id,
+				global_farm_id,
+				yield_farm_id,
+			});
+
+			Ok(())
+		}
+
+		/// Set route used to swap rewards of the vault to the vault's asset.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `vault_id`: id of the vault.
+		/// - `route`: route of the rewards swap. Last trade of the route must end with the vault's asset.
+		///
+		/// Emits `CompoundingRouteSet` event when successful.
+		///
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_compounding_route())]
+		pub fn set_compounding_route(
+			origin: OriginFor<T>,
+			vault_id: AssetIdOf<T>,
+			route: BoundedVec<Trade<AssetIdOf<T>>, ConstU32<MAX_ROUTE_LENGTH>>,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			let vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+			let last_trade = route.last().ok_or(Error::<T>::InvalidRoute)?;
+			ensure!(last_trade.asset_out == vault.asset_id, Error::<T>::InvalidRoute);
+			ensure!(
+				route.windows(2).all(|w| w[0].asset_out == w[1].asset_in),
+				Error::<T>::InvalidRoute
+			);
+
+			CompoundingRoutes::<T>::insert(vault_id, &route);
+
+			Self::deposit_event(Event::CompoundingRouteSet {
+				vault_id,
+				route: route.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		/// Deposit liquidity to the vault.
+		///
+		/// `amount` of the vault's asset is added to the omnipool as liquidity of the vault and
+		/// vault shares proportional to the added LP shares are minted to origin.
+		///
+		/// Parameters:
+		/// - `origin`: account providing liquidity.
+		/// - `vault_id`: id of the vault.
+		/// - `amount`: amount of the vault's asset to deposit.
+		///
+		/// Emits `Deposited` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit(origin: OriginFor<T>, vault_id: AssetIdOf<T>, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			OmnipoolPallet::<T>::ensure_no_flash_swap()?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+			let vault_account = Self::vault_account(vault_id);
+
+			<T as Config>::Currency::transfer(vault.asset_id, &who, &vault_account, amount)?;
+
+			let lp_shares = Self::add_vault_liquidity(&mut vault, &vault_account, amount)?;
+
+			let shares_issuance = <T as Config>::Currency::total_issuance(vault_id);
+			let shares = if shares_issuance.is_zero() {
+				lp_shares
+			} else {
+				hydra_dx_math::liquidity_mining::calculate_proportional_amount(
+					shares_issuance,
+					lp_shares,
+					vault.lp_shares,
+				)
+				.map_err(|_| ArithmeticError::Overflow)?
+			};
+			ensure!(!shares.is_zero(), Error::<T>::InvalidAmount);
+
+			vault.lp_shares = vault
+				.lp_shares
+				.checked_add(lp_shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			<T as Config>::Currency::deposit(vault_id, &who, shares)?;
+
+			Vaults::<T>::insert(vault_id, vault);
+
+			Self::deposit_event(Event::Deposited {
+				vault_id,
+				who,
+				amount,
+				lp_shares,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw liquidity from the vault.
+		///
+		/// `shares` of the vault are burned and proportional part of the vault's liquidity is
+		/// removed from the omnipool. Removed asset and hub asset, if any, are transferred to origin.
+		/// Rewards claimed during the withdrawal stay in the vault as pending rewards and are
+		/// compounded later.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the vault shares.
+		/// - `vault_id`: id of the vault.
+		/// - `shares`: amount of vault shares to burn.
+		///
+		/// Emits `Withdrawn` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, vault_id: AssetIdOf<T>, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!shares.is_zero(), Error::<T>::InvalidAmount);
+			ensure!(
+				<T as Config>::Currency::free_balance(vault_id, &who) >= shares,
+				Error::<T>::InsufficientShares
+			);
+
+			let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+			let vault_deposit = vault.deposit.ok_or(Error::<T>::EmptyVault)?;
+			let vault_account = Self::vault_account(vault_id);
+
+			let shares_issuance = <T as Config>::Currency::total_issuance(vault_id);
+			let lp_shares = if shares == shares_issuance {
+				vault.lp_shares
+			} else {
+				hydra_dx_math::liquidity_mining::calculate_proportional_amount(vault.lp_shares, shares, shares_issuance)
+					.map_err(|_| ArithmeticError::Overflow)?
+			};
+			ensure!(!lp_shares.is_zero(), Error::<T>::InvalidAmount);
+
+			<T as Config>::Currency::withdraw(vault_id, &who, shares)?;
+
+			let (position_id, claimed_rewards) = if lp_shares == vault.lp_shares {
+				let claimed_rewards = OmnipoolLiquidityMiningPallet::<T>::do_withdraw_shares(
+					vault_account.clone(),
+					vault_deposit.deposit_id,
+					vault.yield_farm_id,
+				)?;
+				vault.deposit = None;
+
+				(vault_deposit.position_id, claimed_rewards.into_iter().collect())
+			} else {
+				OmnipoolLiquidityMiningPallet::<T>::decrease_deposit_shares(
+					vault_account.clone(),
+					vault_deposit.deposit_id,
+					lp_shares,
+				)?
+			};
+
+			for (reward_currency, claimed) in claimed_rewards {
+				Self::add_pending_rewards(&mut vault, reward_currency, claimed)?;
+			}
+
+			//NOTE: balances are read after claim so claimed rewards are not transferred to `who`.
+			let hub_asset = <T as pallet_omnipool::Config>::HubAssetId::get();
+			let asset_balance_before = <T as Config>::Currency::free_balance(vault.asset_id, &vault_account);
+			let hub_balance_before = <T as Config>::Currency::free_balance(hub_asset, &vault_account);
+
+			OmnipoolPallet::<T>::remove_liquidity(
+				RawOrigin::Signed(vault_account.clone()).into(),
+				position_id,
+				lp_shares,
+			)?;
+
+			let amount = <T as Config>::Currency::free_balance(vault.asset_id, &vault_account)
+				.checked_sub(asset_balance_before)
+				.ok_or(ArithmeticError::Underflow)?;
+			let hub_amount = <T as Config>::Currency::free_balance(hub_asset, &vault_account)
+				.checked_sub(hub_balance_before)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			<T as Config>::Currency::transfer(vault.asset_id, &vault_account, &who, amount)?;
+			if !hub_amount.is_zero() {
+				<T as Config>::Currency::transfer(hub_asset, &vault_account, &who, hub_amount)?;
+			}
+
+			vault.lp_shares = vault
+				.lp_shares
+				.checked_sub(lp_shares)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			Vaults::<T>::insert(vault_id, vault);
+
+			Self::deposit_event(Event::Withdrawn {
+				vault_id,
+				who,
+				shares,
+				lp_shares,
+				amount,
+				hub_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Compound rewards of the vault.
+		///
+		/// Rewards of the vault's deposit are claimed and swapped together with the vault's pending
+		/// rewards to the vault's asset using the vault's compounding route or direct omnipool trade.
+		/// Minimum amount received from the swap is derived from the oracle price and
+		/// `MaxCompoundingSlippage`. Received asset is added to the vault's position without minting
+		/// new vault shares.
+		///
+		/// Only claimed and pending rewards are compounded, other balances of the vault's account
+		/// are not used.
+		///
+		/// Parameters:
+		/// - `origin`: any signed account.
+		/// - `vault_id`: id of the vault.
+		///
+		/// Emits `Compounded` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::compound()
+			.saturating_add(<T as pallet_route_executor::Config>::WeightInfo::sell(MAX_ROUTE_LENGTH)))]
+		#[transactional]
+		pub fn compound(origin: OriginFor<T>, vault_id: AssetIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			OmnipoolPallet::<T>::ensure_no_flash_swap()?;
+
+			let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+			let vault_deposit = vault.deposit.ok_or(Error::<T>::EmptyVault)?;
+			let vault_account = Self::vault_account(vault_id);
+
+			let claim_result = with_storage_layer(|| {
+				OmnipoolLiquidityMiningPallet::<T>::do_claim_rewards(
+					vault_account.clone(),
+					vault_deposit.deposit_id,
+					vault.yield_farm_id,
+				)
+			});
+
+			//NOTE: claim fails if rewards were already claimed in current period e.g. by withdrawal.
+			//Rewards claimed by withdrawals are pending so they can be compounded without new claim.
+			let (reward_currency, reward_amount) = match (claim_result, vault.pending_rewards.take()) {
+				(Ok((reward_currency, claimed)), None) => (reward_currency, claimed),
+				(Ok((reward_currency, claimed)), Some((_, pending))) => (
+					reward_currency,
+					claimed.checked_add(pending).ok_or(ArithmeticError::Overflow)?,
+				),
+				(Err(_), Some(pending_rewards)) => pending_rewards,
+				(Err(e), None) => return Err(e),
+			};
+			ensure!(!reward_amount.is_zero(), Error::<T>::NothingToCompound);
+
+			let amount = if reward_currency == vault.asset_id {
+				reward_amount
+			} else {
+				let route = CompoundingRoutes::<T>::get(vault_id)
+					.map(|r| r.into_inner())
+					.unwrap_or_else(|| {
+						vec![Trade {
+							pool: PoolType::Omnipool,
+							asset_in: reward_currency,
+							asset_out: vault.asset_id,
+						}]
+					});
+
+				let min_amount_out = Self::calculate_min_amount_out(reward_currency, vault.asset_id, reward_amount)?;
+
+				let balance_before = <T as Config>::Currency::free_balance(vault.asset_id, &vault_account);
+
+				RouteExecutorPallet::<T>::sell(
+					RawOrigin::Signed(vault_account.clone()).into(),
+					reward_currency,
+					vault.asset_id,
+					reward_amount,
+					min_amount_out,
+					route,
+				)?;
+
+				<T as Config>::Currency::free_balance(vault.asset_id, &vault_account)
+					.checked_sub(balance_before)
+					.ok_or(ArithmeticError::Underflow)?
+			};
+			ensure!(!amount.is_zero(), Error::<T>::NothingToCompound);
+
+			let lp_shares = Self::add_vault_liquidity(&mut vault, &vault_account, amount)?;
+
+			vault.lp_shares = vault
+				.lp_shares
+				.checked_add(lp_shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Vaults::<T>::insert(vault_id, vault);
+
+			Self::deposit_event(Event::Compounded {
+				vault_id,
+				who,
+				reward_currency,
+				reward_amount,
+				amount,
+				lp_shares,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding the vault's liquidity and rewards.
+	pub fn vault_account(vault_id: AssetIdOf<T>) -> T::AccountId {
+		<T as Config>::PalletId::get().into_sub_account_truncating(vault_id)
+	}
+
+	/// Add `amount` of the vault's asset held by the vault's account to the omnipool and add
+	/// created position to the vault's deposit. Deposit is created if vault doesn't have one.
+	///
+	/// Returns: amount of LP shares added to the vault's deposit.
+	fn add_vault_liquidity(
+		vault: &mut VaultInfo<AssetIdOf<T>>,
+		vault_account: &T::AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let position_id = OmnipoolPallet::<T>::next_position_id();
+		OmnipoolPallet::<T>::add_liquidity(RawOrigin::Signed(vault_account.clone()).into(), vault.asset_id, amount)?;
+
+		let lp_shares = OmnipoolPallet::<T>::load_position(position_id, vault_account.clone())?.shares;
+
+		match vault.deposit {
+			Some(vault_deposit) => {
+				OmnipoolLiquidityMiningPallet::<T>::increase_deposit_shares(
+					vault_account.clone(),
+					vault_deposit.deposit_id,
+					position_id,
+				)?;
+			}
+			None => {
+				let deposit_id = OmnipoolLiquidityMiningPallet::<T>::do_deposit_shares(
+					vault_account.clone(),
+					vault.global_farm_id,
+					vault.yield_farm_id,
+					position_id,
+				)?;
+
+				vault.deposit = Some(VaultDeposit {
+					deposit_id,
+					position_id,
+				});
+			}
+		}
+
+		Ok(lp_shares)
+	}
+
+	/// Add rewards claimed from the vault's deposit outside of compounding to the vault's pending
+	/// rewards.
+	fn add_pending_rewards(
+		vault: &mut VaultInfo<AssetIdOf<T>>,
+		reward_currency: AssetIdOf<T>,
+		claimed: Balance,
+	) -> DispatchResult {
+		let pending = match vault.pending_rewards {
+			Some((_, pending)) => pending.checked_add(claimed).ok_or(ArithmeticError::Overflow)?,
+			None => claimed,
+		};
+
+		vault.pending_rewards = Some((reward_currency, pending));
+
+		Ok(())
+	}
+
+	/// Calculate minimum amount of `asset_out` received for `amount_in` of `asset_in` from the
+	/// last block oracle price reduced by `MaxCompoundingSlippage`.
+	fn calculate_min_amount_out(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_in: Balance,
+	) -> Result<Balance, DispatchError> {
+		let price = T::OraclePriceProvider::price(asset_out, asset_in, OraclePeriod::LastBlock)
+			.ok_or(Error::<T>::PriceNotAvailable)?;
+
+		let amount_out: Balance = U256::from(amount_in)
+			.checked_mul(price.n.into())
+			.ok_or(ArithmeticError::Overflow)?
+			.checked_div(price.d.into())
+			.ok_or(ArithmeticError::DivisionByZero)?
+			.try_into()
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+		Ok(amount_out.saturating_sub(T::MaxCompoundingSlippage::get().mul_floor(amount_out)))
+	}
+}
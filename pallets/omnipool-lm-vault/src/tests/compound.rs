@@ -0,0 +1,178 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn compound_should_add_swapped_rewards_to_vault_position() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let position_id = 1;
+			let deposit_id = 1;
+			let vault_account = Vault::vault_account(VAULT_SHARES);
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+			let vault_lp_shares = Vault::vaults(VAULT_SHARES).unwrap().lp_shares;
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(Vault::compound(RuntimeOrigin::signed(BOB), VAULT_SHARES));
+
+			//Assert
+			let vault = Vault::vaults(VAULT_SHARES).unwrap();
+			assert!(vault.lp_shares > vault_lp_shares);
+			assert_eq!(Omnipool::positions(position_id).unwrap().shares, vault.lp_shares);
+
+			//Vault's deposit and position are not recreated.
+			assert_eq!(
+				vault.deposit,
+				Some(VaultDeposit {
+					deposit_id,
+					position_id
+				})
+			);
+
+			//No new shares are minted.
+			assert_eq!(Tokens::total_issuance(VAULT_SHARES), alice_shares);
+
+			//All the rewards are compounded.
+			assert_eq!(Tokens::free_balance(HDX, &vault_account), 0);
+			assert_eq!(Tokens::free_balance(KSM, &vault_account), 0);
+
+			assert!(matches!(
+				System::events().last().expect("events expected").event,
+				RuntimeEvent::Vault(crate::Event::Compounded {
+					vault_id: VAULT_SHARES,
+					who: BOB,
+					reward_currency: HDX,
+					..
+				})
+			));
+		});
+}
+
+#[test]
+fn withdraw_should_include_compounded_rewards() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+
+			set_block_number(1_000);
+			assert_ok!(Vault::compound(RuntimeOrigin::signed(BOB), VAULT_SHARES));
+
+			//Act
+			assert_ok!(Vault::withdraw(
+				RuntimeOrigin::signed(ALICE),
+				VAULT_SHARES,
+				alice_shares
+			));
+
+			//Assert
+			assert!(Tokens::free_balance(KSM, &ALICE) > 10_000 * ONE);
+		});
+}
+
+#[test]
+fn compound_should_ignore_assets_sent_to_vault_account() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let vault_account = Vault::vault_account(VAULT_SHARES);
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+
+			assert_ok!(Tokens::deposit(KSM, &vault_account, 100 * ONE));
+			assert_ok!(Tokens::deposit(HDX, &vault_account, 1_000 * ONE));
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(Vault::compound(RuntimeOrigin::signed(BOB), VAULT_SHARES));
+
+			//Assert
+			//Only claimed rewards are compounded.
+			assert_eq!(Tokens::free_balance(KSM, &vault_account), 100 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &vault_account), 1_000 * ONE);
+		});
+}
+
+#[test]
+fn compound_should_add_pending_rewards_when_rewards_were_claimed_by_withdrawal_in_same_period() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let vault_account = Vault::vault_account(VAULT_SHARES);
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+
+			set_block_number(1_000);
+
+			assert_ok!(Vault::withdraw(
+				RuntimeOrigin::signed(ALICE),
+				VAULT_SHARES,
+				alice_shares / 2
+			));
+
+			let (reward_currency, pending) = Vault::vaults(VAULT_SHARES).unwrap().pending_rewards.unwrap();
+			assert_eq!(reward_currency, HDX);
+			assert!(pending > 0);
+			assert_eq!(Tokens::free_balance(HDX, &vault_account), pending);
+			let vault_lp_shares = Vault::vaults(VAULT_SHARES).unwrap().lp_shares;
+
+			//Act
+			assert_ok!(Vault::compound(RuntimeOrigin::signed(BOB), VAULT_SHARES));
+
+			//Assert
+			let vault = Vault::vaults(VAULT_SHARES).unwrap();
+			assert_eq!(vault.pending_rewards, None);
+			assert!(vault.lp_shares > vault_lp_shares);
+			assert_eq!(Tokens::free_balance(HDX, &vault_account), 0);
+
+			assert!(matches!(
+				System::events().last().expect("events expected").event,
+				RuntimeEvent::Vault(crate::Event::Compounded {
+					vault_id: VAULT_SHARES,
+					who: BOB,
+					reward_currency: HDX,
+					reward_amount,
+					..
+				}) if reward_amount == pending
+			));
+		});
+}
+
+#[test]
+fn compound_should_fail_when_vault_is_empty() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Vault::compound(RuntimeOrigin::signed(BOB), VAULT_SHARES),
+				Error::<Test>::EmptyVault
+			);
+		});
+}
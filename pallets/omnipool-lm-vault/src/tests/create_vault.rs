@@ -0,0 +1,123 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn create_vault_should_work() {
+	default_ext().build().execute_with(|| {
+		//Act
+		assert_ok!(Vault::create_vault(
+			RuntimeOrigin::root(),
+			VAULT_SHARES,
+			KSM,
+			GLOBAL_FARM_ID,
+			YIELD_FARM_ID
+		));
+
+		//Assert
+		assert_eq!(
+			Vault::vaults(VAULT_SHARES).unwrap(),
+			VaultInfo {
+				asset_id: KSM,
+				global_farm_id: GLOBAL_FARM_ID,
+				yield_farm_id: YIELD_FARM_ID,
+				lp_shares: 0,
+				deposit: None,
+				pending_rewards: None,
+			}
+		);
+
+		assert_last_event!(crate::Event::VaultCreated {
+			vault_id: VAULT_SHARES,
+			asset_id: KSM,
+			global_farm_id: GLOBAL_FARM_ID,
+			yield_farm_id: YIELD_FARM_ID,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn create_vault_should_fail_when_origin_is_not_authority() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Vault::create_vault(
+				RuntimeOrigin::signed(ALICE),
+				VAULT_SHARES,
+				KSM,
+				GLOBAL_FARM_ID,
+				YIELD_FARM_ID
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_vault_should_fail_when_vault_already_exists() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Vault::create_vault(RuntimeOrigin::root(), VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID),
+				Error::<Test>::VaultAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn create_vault_should_fail_when_share_asset_is_not_registered() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Vault::create_vault(
+				RuntimeOrigin::root(),
+				VAULT_SHARES + 1,
+				KSM,
+				GLOBAL_FARM_ID,
+				YIELD_FARM_ID
+			),
+			Error::<Test>::ShareAssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_vault_should_fail_when_share_asset_has_issuance() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Vault::create_vault(RuntimeOrigin::root(), DAI, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID),
+			Error::<Test>::InvalidShareAsset
+		);
+	});
+}
+
+#[test]
+fn create_vault_should_fail_when_asset_is_not_in_omnipool() {
+	default_ext().with_registered_asset(KSM + 1).build().execute_with(|| {
+		assert_noop!(
+			Vault::create_vault(
+				RuntimeOrigin::root(),
+				VAULT_SHARES,
+				KSM + 1,
+				GLOBAL_FARM_ID,
+				YIELD_FARM_ID
+			),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
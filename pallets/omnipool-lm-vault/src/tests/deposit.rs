@@ -0,0 +1,130 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::tokens::nonfungibles::Inspect;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn deposit_should_create_vault_deposit_when_vault_is_empty() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let position_id = 1;
+			let deposit_id = 1;
+			let vault_account = Vault::vault_account(VAULT_SHARES);
+
+			//Act
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+
+			//Assert
+			let lp_shares = Omnipool::positions(position_id).unwrap().shares;
+			assert_eq!(
+				Vault::vaults(VAULT_SHARES).unwrap(),
+				VaultInfo {
+					asset_id: KSM,
+					global_farm_id: GLOBAL_FARM_ID,
+					yield_farm_id: YIELD_FARM_ID,
+					lp_shares,
+					deposit: Some(VaultDeposit {
+						deposit_id,
+						position_id
+					}),
+					pending_rewards: None,
+				}
+			);
+
+			assert_eq!(Tokens::free_balance(VAULT_SHARES, &ALICE), lp_shares);
+			assert_eq!(Tokens::free_balance(KSM, &ALICE), 9_000 * ONE);
+			assert_eq!(Tokens::free_balance(KSM, &vault_account), 0);
+
+			//Omnipool's NFT should be locked in the liquidity mining and deposit owned by vault.
+			let owner: AccountId = DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id).unwrap();
+			assert_eq!(owner, OmnipoolMining::account_id());
+			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
+			assert_eq!(owner, vault_account);
+
+			assert_last_event!(crate::Event::Deposited {
+				vault_id: VAULT_SHARES,
+				who: ALICE,
+				amount: 1_000 * ONE,
+				lp_shares,
+				shares: lp_shares,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn deposit_should_add_liquidity_to_existing_vault_deposit() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let position_id = 1;
+			let deposit_id = 1;
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+
+			set_block_number(100);
+
+			//Act
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(BOB), VAULT_SHARES, 500 * ONE));
+
+			//Assert
+			let vault = Vault::vaults(VAULT_SHARES).unwrap();
+			let position = Omnipool::positions(position_id).unwrap();
+			assert_eq!(
+				vault.deposit,
+				Some(VaultDeposit {
+					deposit_id,
+					position_id
+				})
+			);
+			assert_eq!(vault.lp_shares, position.shares);
+
+			//Added position should be merged into vault's position.
+			assert_eq!(Omnipool::positions(2), None);
+
+			let bob_shares = Tokens::free_balance(VAULT_SHARES, &BOB);
+			assert_eq!(bob_shares, alice_shares / 2);
+			assert_eq!(Tokens::total_issuance(VAULT_SHARES), alice_shares + bob_shares);
+		});
+}
+
+#[test]
+fn deposit_should_fail_when_amount_is_zero() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 0),
+				Error::<Test>::InvalidAmount
+			);
+		});
+}
+
+#[test]
+fn deposit_should_fail_when_vault_does_not_exist() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE),
+			Error::<Test>::VaultNotFound
+		);
+	});
+}
@@ -0,0 +1,689 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::type_complexity)]
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as omnipool_lm_vault;
+
+use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild};
+use frame_support::weights::Weight;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::{
+	liquidity_mining::PriceAdjustment,
+	oracle::{AggregatedPriceOracle, OraclePeriod, Source},
+	pools::DustRemovalAccountWhitelist,
+	AssetKind,
+};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_ema_oracle::OracleError;
+use pallet_liquidity_mining::{FarmMultiplier, GlobalFarmData, Instance1, LoyaltyCurve};
+use pallet_omnipool::traits::ExternalPriceProvider;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup, One},
+	DispatchError, FixedU128, Perquintill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type AssetId = u32;
+//NTF types
+pub type CollectionId = u128;
+pub type ItemId = u128;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const KSM: AssetId = 1_001;
+pub const VAULT_SHARES: AssetId = 2_000;
+
+pub const LP1: AccountId = 1;
+pub const ALICE: AccountId = 4;
+pub const BOB: AccountId = 5;
+pub const GC: AccountId = 7;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
+
+pub const OMNIPOOL_COLLECTION_ID: u128 = 1_000;
+pub const LM_COLLECTION_ID: u128 = 1;
+
+pub const GLOBAL_FARM_ID: GlobalFarmId = 1;
+pub const YIELD_FARM_ID: YieldFarmId = 2;
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<(CollectionId, ItemId), AccountId>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Omnipool: pallet_omnipool,
+		Tokens: orml_tokens,
+		WarehouseLM: pallet_liquidity_mining::<Instance1>,
+		OmnipoolMining: pallet_omnipool_liquidity_mining,
+		RouteExecutor: pallet_route_executor,
+		Vault: omnipool_lm_vault,
+	}
+);
+
+parameter_types! {
+	pub static MockBlockNumberProvider: u64 = 0;
+}
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		System::block_number()
+	}
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const VaultPalletId: PalletId = PalletId(*b"TEST_vlt");
+	pub const MaxCompoundingSlippage: Permill = Permill::from_percent(5);
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type AssetRegistry = DummyRegistry<Test>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PalletId = VaultPalletId;
+	type OraclePriceProvider = SpotPriceOracle;
+	type MaxCompoundingSlippage = MaxCompoundingSlippage;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaxNumberOfTrades: u8 = 5;
+}
+
+impl pallet_route_executor::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MaxNumberOfTrades = MaxNumberOfTrades;
+	type Currency = Tokens;
+	type AMM = Omnipool;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const LMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const LMCollectionId: CollectionId = LM_COLLECTION_ID;
+	pub const PeriodOracle: OraclePeriod= OraclePeriod::Day;
+	pub const OracleSource: Source = *b"omnipool";
+	pub const MinTotalFarmRewards: Balance = 1_000_000 * ONE;
+	pub const MinPlannedYieldingPeriods: BlockNumber  = 100;
+	#[derive(PartialEq, Eq)]
+	pub const MaxEntriesPerDeposit: u32 = 5;
+	pub const MaxYieldFarmsPerGlobalFarm: u32 = 10;
+}
+
+impl pallet_omnipool_liquidity_mining::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type PalletId = LMPalletId;
+	type NFTCollectionId = LMCollectionId;
+	type NFTHandler = DummyNFT;
+	type LiquidityMiningHandler = WarehouseLM;
+	type OracleSource = OracleSource;
+	type OraclePeriod = PeriodOracle;
+	type PriceOracle = DummyOracle;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type WeightInfo = ();
+}
+
+impl pallet_liquidity_mining::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Tokens;
+	type PalletId = LMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = MockBlockNumberProvider;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = DummyRegistry<Test>;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PriceAdjustment = DummyOracle;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const DAIAssetId: AssetId = DAI;
+	pub const PositionCollectionId: CollectionId = OMNIPOOL_COLLECTION_ID;
+	pub const MinAddedLiquidity: Balance = 1_000;
+	pub const MinTradeAmount: Balance = 1_000;
+	pub const MaxInRatio: Balance = 1;
+	pub const MaxOutRatio: Balance = 1;
+	pub const MinWithdrawFee: Permill = Permill::from_percent(0);
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AssetId = AssetId;
+	type PositionItemId = u128;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type StableCoinAssetId = DAIAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry<Test>;
+	type MinimumTradingLimit = MinTradeAmount;
+	type MinimumPoolLiquidity = MinAddedLiquidity;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CollectionId = u128;
+	type OmnipoolHooks = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type Fee = FeeProvider;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+	lm_global_farms: Vec<(Balance, AssetId, AccountId)>,
+	lm_yield_farms: Vec<(AccountId, GlobalFarmId, AssetId, Option<LoyaltyCurve>)>,
+	vaults: Vec<(AssetId, AssetId, GlobalFarmId, YieldFarmId)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			],
+			registered_assets: vec![],
+			pool_tokens: vec![],
+			lm_global_farms: vec![],
+			lm_yield_farms: vec![],
+			vaults: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId) -> Self {
+		self.registered_assets.push(asset);
+		self
+	}
+
+	pub fn with_token(
+		mut self,
+		asset_id: AssetId,
+		price: FixedU128,
+		position_owner: AccountId,
+		amount: Balance,
+	) -> Self {
+		self.pool_tokens.push((asset_id, price, position_owner, amount));
+		self
+	}
+
+	pub fn with_global_farm(mut self, total_rewards: Balance, reward_currency: AssetId, owner: AccountId) -> Self {
+		self.lm_global_farms.push((total_rewards, reward_currency, owner));
+		self
+	}
+
+	pub fn with_yield_farm(
+		mut self,
+		owner: AccountId,
+		global_farm_id: GlobalFarmId,
+		asset: AssetId,
+		loyalty_curve: Option<LoyaltyCurve>,
+	) -> Self {
+		self.lm_yield_farms.push((owner, global_farm_id, asset, loyalty_curve));
+		self
+	}
+
+	pub fn with_vault(
+		mut self,
+		share_asset: AssetId,
+		asset: AssetId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+	) -> Self {
+		self.vaults.push((share_asset, asset, global_farm_id, yield_farm_id));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(DAI, DAI);
+			v.borrow_mut().insert(HDX, HDX);
+			v.borrow_mut().insert(LRNA, LRNA);
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, *asset);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			set_block_number(1);
+
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), u128::MAX));
+			assert_ok!(Omnipool::initialize_pool(
+				RuntimeOrigin::root(),
+				FixedU128::from_float(0.5),
+				FixedU128::from(1),
+				Permill::from_percent(100),
+				Permill::from_percent(100)
+			));
+
+			for (asset_id, price, owner, amount) in self.pool_tokens {
+				assert_ok!(Tokens::transfer(
+					RuntimeOrigin::signed(owner),
+					Omnipool::protocol_account(),
+					asset_id,
+					amount
+				));
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					asset_id,
+					price,
+					Permill::from_percent(100),
+					owner
+				));
+			}
+
+			for (total_rewards, reward_currency, owner) in self.lm_global_farms {
+				assert_ok!(OmnipoolMining::create_global_farm(
+					RuntimeOrigin::root(),
+					total_rewards,
+					2_628_000,
+					1,
+					reward_currency,
+					owner,
+					Perquintill::from_float(0.000_000_15_f64),
+					1_000,
+					FixedU128::one(),
+				));
+			}
+
+			for (owner, global_farm_id, asset, loyalty_curve) in self.lm_yield_farms {
+				assert_ok!(OmnipoolMining::create_yield_farm(
+					RuntimeOrigin::signed(owner),
+					global_farm_id,
+					asset,
+					FarmMultiplier::one(),
+					loyalty_curve
+				));
+			}
+
+			for (share_asset, asset, global_farm_id, yield_farm_id) in self.vaults {
+				assert_ok!(Vault::create_vault(
+					RuntimeOrigin::root(),
+					share_asset,
+					asset,
+					global_farm_id,
+					yield_farm_id
+				));
+			}
+		});
+
+		r
+	}
+}
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		NFTS.with(|v| {
+			if let Some(o) = v.borrow().get(&(*collection, *item)) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_collection: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((c, _), owner)| c == collection && *owner == who)
+				.map(|((_, i), _)| *i)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
+impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
+	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u128> + Into<u128> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert((*collection, *item), (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(&(*collection, *item));
+		});
+		Ok(())
+	}
+}
+
+impl Transfer<AccountId> for DummyNFT {
+	fn transfer(collection: &Self::CollectionId, item: &Self::ItemId, destination: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			let key = (*collection, *item);
+
+			if !m.contains_key(&key) {
+				return Err(sp_runtime::DispatchError::Other("NFT not found"));
+			}
+
+			m.insert(key, *destination);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: pallet_omnipool::Config> Registry<T::AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry<T>
+where
+	T::AssetId: Into<AssetId> + From<u32>,
+{
+	fn exists(asset_id: T::AssetId) -> bool {
+		let asset = REGISTERED_ASSETS.with(|v| v.borrow().get(&(asset_id.into())).copied());
+		matches!(asset, Some(_))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<T::AssetId, DispatchError> {
+		Ok(T::AssetId::default())
+	}
+
+	fn retrieve_asset_type(_asset_id: T::AssetId) -> Result<AssetKind, DispatchError> {
+		unimplemented!()
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<T::AssetId, DispatchError> {
+		unimplemented!()
+	}
+}
+
+impl<T: pallet_omnipool::Config> GetByKey<T::AssetId, Balance> for DummyRegistry<T> {
+	fn get(_key: &T::AssetId) -> Balance {
+		1_000_u128
+	}
+}
+
+pub struct DummyOracle;
+
+impl AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice> for DummyOracle {
+	type Error = OracleError;
+
+	fn get_price(
+		_asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumber), Self::Error> {
+		match asset_b {
+			KSM => Ok((
+				EmaPrice {
+					n: 650_000_000_000_000_000,
+					d: 1_000_000_000_000_000_000,
+				},
+				0,
+			)),
+			_ => Err(OracleError::NotPresent),
+		}
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl PriceAdjustment<GlobalFarmData<Test, Instance1>> for DummyOracle {
+	type Error = DispatchError;
+
+	type PriceAdjustment = FixedU128;
+
+	fn get(_global_farm: &GlobalFarmData<Test, Instance1>) -> Result<Self::PriceAdjustment, Self::Error> {
+		Ok(FixedU128::from_inner(500_000_000_000_000_000)) //0.5
+	}
+}
+
+/// Price of `asset_a` denominated in `asset_b` derived from the current state of the omnipool.
+pub struct SpotPriceOracle;
+
+impl PriceOracle<AssetId> for SpotPriceOracle {
+	type Price = EmaPrice;
+
+	fn price(asset_a: AssetId, asset_b: AssetId, _period: OraclePeriod) -> Option<Self::Price> {
+		let state_a = Omnipool::load_asset_state(asset_a).ok()?;
+		let state_b = Omnipool::load_asset_state(asset_b).ok()?;
+
+		Some(EmaPrice::new(
+			state_b.hub_reserve * state_a.reserve,
+			state_b.reserve * state_a.hub_reserve,
+		))
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|x| *x != *account));
+
+		Ok(())
+	}
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
+
+pub struct WithdrawFeePriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		let price = EmaPrice::new(asset_state.hub_reserve, asset_state.reserve);
+		Ok(price)
+	}
+
+	fn get_price_weight() -> Weight {
+		todo!()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(Permill::zero(), Permill::zero())
+	}
+}
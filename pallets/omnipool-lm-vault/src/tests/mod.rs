@@ -0,0 +1,48 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use mock::*;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, FixedU128};
+
+macro_rules! assert_last_event {
+	( $x:expr ) => {{
+		pretty_assertions::assert_eq!(System::events().last().expect("events expected").event, $x);
+	}};
+}
+
+pub mod compound;
+pub mod create_vault;
+pub mod deposit;
+pub mod mock;
+pub mod set_compounding_route;
+pub mod withdraw;
+
+pub fn default_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, KSM, 5_000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, KSM, 10_000 * ONE),
+			(BOB, KSM, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(VAULT_SHARES)
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2_000 * ONE)
+		.with_global_farm(80_000_000 * ONE, HDX, GC)
+		.with_yield_farm(GC, GLOBAL_FARM_ID, KSM, None)
+}
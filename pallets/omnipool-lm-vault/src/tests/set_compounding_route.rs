@@ -0,0 +1,116 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_compounding_route_should_work() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let route = vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: KSM,
+				},
+			];
+
+			//Act
+			assert_ok!(Vault::set_compounding_route(
+				RuntimeOrigin::root(),
+				VAULT_SHARES,
+				route.clone().try_into().unwrap()
+			));
+
+			//Assert
+			assert_eq!(Vault::compounding_routes(VAULT_SHARES).unwrap().into_inner(), route);
+
+			assert_last_event!(crate::Event::CompoundingRouteSet {
+				vault_id: VAULT_SHARES,
+				route,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn set_compounding_route_should_fail_when_route_does_not_end_with_vault_asset() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let route = vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}];
+
+			assert_noop!(
+				Vault::set_compounding_route(RuntimeOrigin::root(), VAULT_SHARES, route.try_into().unwrap()),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn set_compounding_route_should_fail_when_trades_are_not_connected() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let route = vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: LRNA,
+					asset_out: KSM,
+				},
+			];
+
+			assert_noop!(
+				Vault::set_compounding_route(RuntimeOrigin::root(), VAULT_SHARES, route.try_into().unwrap()),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn set_compounding_route_should_fail_when_vault_does_not_exist() {
+	default_ext().build().execute_with(|| {
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: KSM,
+		}];
+
+		assert_noop!(
+			Vault::set_compounding_route(RuntimeOrigin::root(), VAULT_SHARES, route.try_into().unwrap()),
+			Error::<Test>::VaultNotFound
+		);
+	});
+}
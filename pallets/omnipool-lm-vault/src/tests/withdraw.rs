@@ -0,0 +1,128 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::tokens::nonfungibles::Inspect;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn withdraw_should_remove_part_of_vault_liquidity() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let position_id = 1;
+			let deposit_id = 1;
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(BOB), VAULT_SHARES, 500 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+			let vault_lp_shares = Vault::vaults(VAULT_SHARES).unwrap().lp_shares;
+
+			//Act
+			assert_ok!(Vault::withdraw(
+				RuntimeOrigin::signed(ALICE),
+				VAULT_SHARES,
+				alice_shares / 2
+			));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(VAULT_SHARES, &ALICE), alice_shares / 2);
+			assert_eq!(Tokens::free_balance(KSM, &ALICE), 9_500 * ONE);
+
+			let vault = Vault::vaults(VAULT_SHARES).unwrap();
+			assert_eq!(vault.lp_shares, vault_lp_shares - alice_shares / 2);
+			assert_eq!(
+				vault.deposit,
+				Some(VaultDeposit {
+					deposit_id,
+					position_id
+				})
+			);
+			assert_eq!(Omnipool::positions(position_id).unwrap().shares, vault.lp_shares);
+
+			assert_last_event!(crate::Event::Withdrawn {
+				vault_id: VAULT_SHARES,
+				who: ALICE,
+				shares: alice_shares / 2,
+				lp_shares: alice_shares / 2,
+				amount: 500 * ONE,
+				hub_amount: 0,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn withdraw_should_destroy_vault_deposit_when_all_shares_are_withdrawn() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			let position_id = 1;
+			let deposit_id = 1;
+
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+			let alice_shares = Tokens::free_balance(VAULT_SHARES, &ALICE);
+
+			//Act
+			assert_ok!(Vault::withdraw(
+				RuntimeOrigin::signed(ALICE),
+				VAULT_SHARES,
+				alice_shares
+			));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(VAULT_SHARES, &ALICE), 0);
+			assert_eq!(Tokens::total_issuance(VAULT_SHARES), 0);
+			assert_eq!(Tokens::free_balance(KSM, &ALICE), 10_000 * ONE);
+
+			let vault = Vault::vaults(VAULT_SHARES).unwrap();
+			assert_eq!(vault.lp_shares, 0);
+			assert_eq!(vault.deposit, None);
+
+			assert_eq!(Omnipool::positions(position_id), None);
+			let owner: Option<AccountId> = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id);
+			assert_eq!(owner, None);
+		});
+}
+
+#[test]
+fn withdraw_should_fail_when_account_has_not_enough_shares() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 1_000 * ONE));
+
+			assert_noop!(
+				Vault::withdraw(RuntimeOrigin::signed(BOB), VAULT_SHARES, ONE),
+				Error::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn withdraw_should_fail_when_shares_amount_is_zero() {
+	default_ext()
+		.with_vault(VAULT_SHARES, KSM, GLOBAL_FARM_ID, YIELD_FARM_ID)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Vault::withdraw(RuntimeOrigin::signed(ALICE), VAULT_SHARES, 0),
+				Error::<Test>::InvalidAmount
+			);
+		});
+}
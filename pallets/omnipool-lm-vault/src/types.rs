@@ -0,0 +1,45 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use hydradx_traits::liquidity_mining::{GlobalFarmId, YieldFarmId};
+use primitives::{Balance, ItemId as DepositId};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Liquidity mining deposit of the vault.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VaultDeposit {
+	/// Id of the liquidity mining deposit owned by the vault.
+	pub deposit_id: DepositId,
+	/// Id of the omnipool position locked in the deposit.
+	pub position_id: DepositId,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VaultInfo<AssetId> {
+	/// Omnipool asset provided as liquidity by the vault.
+	pub asset_id: AssetId,
+	/// Global farm the vault's liquidity is deposited to.
+	pub global_farm_id: GlobalFarmId,
+	/// Yield farm the vault's liquidity is deposited to.
+	pub yield_farm_id: YieldFarmId,
+	/// Omnipool LP shares held by the vault.
+	pub lp_shares: Balance,
+	/// Liquidity mining deposit of the vault. `None` if vault holds no liquidity.
+	pub deposit: Option<VaultDeposit>,
+	/// Rewards claimed during withdrawals which were not compounded yet as `(reward currency, amount)`.
+	pub pending_rewards: Option<(AssetId, Balance)>,
+}
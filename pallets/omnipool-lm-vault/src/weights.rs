@@ -0,0 +1,115 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2021  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_omnipool_lm_vault
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-06-12, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/hydradx
+// benchmark
+// pallet
+// --pallet=pallet_omnipool_lm_vault
+// --chain=dev
+// --steps=5
+// --repeat=20
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=.maintain/pallet-weight-template.hbs
+// --output=pallets/omnipool-lm-vault/src/weights.rs
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_omnipool_lm_vault.
+pub trait WeightInfo {
+	fn create_vault() -> Weight;
+	fn set_compounding_route() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn compound() -> Weight;
+}
+
+/// Weights for pallet_omnipool_lm_vault using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	fn create_vault() -> Weight {
+		Weight::from_ref_time(36_118_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_compounding_route() -> Weight {
+		Weight::from_ref_time(27_541_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn deposit() -> Weight {
+		Weight::from_ref_time(412_683_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(44 as u64))
+			.saturating_add(T::DbWeight::get().writes(28 as u64))
+	}
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(468_905_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(46 as u64))
+			.saturating_add(T::DbWeight::get().writes(31 as u64))
+	}
+	fn compound() -> Weight {
+		Weight::from_ref_time(527_330_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(50 as u64))
+			.saturating_add(T::DbWeight::get().writes(30 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_vault() -> Weight {
+		Weight::from_ref_time(36_118_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_compounding_route() -> Weight {
+		Weight::from_ref_time(27_541_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn deposit() -> Weight {
+		Weight::from_ref_time(412_683_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(44 as u64))
+			.saturating_add(RocksDbWeight::get().writes(28 as u64))
+	}
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(468_905_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(46 as u64))
+			.saturating_add(RocksDbWeight::get().writes(31 as u64))
+	}
+	fn compound() -> Weight {
+		Weight::from_ref_time(527_330_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(50 as u64))
+			.saturating_add(RocksDbWeight::get().writes(30 as u64))
+	}
+}
@@ -65,20 +65,91 @@
 //! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
 //! * `set_asset_tradable_state` - Updates asset's tradable asset with new flags. This allows/forbids asset operation such SELL,BUY,ADD or  REMOVE liquidtityy.
 //! * `refund_refused_asset` - Refunds the initial liquidity amount sent to pool account prior to add_token if the token has been refused to be added.
+//! * `remove_token` - Removes frozen token from the pool. Liquidity of remaining LP positions can be claimed later.
+//! * `claim_delisted_position` - Claims liquidity of a position of an asset which has been removed from the pool.
+//! * `merge_positions` - Merges LP positions of an asset into one position.
+//! * `split_position` - Splits given quantity of shares of LP position into new position. Mints corresponding position NFT.
+//! * `set_il_protection` - Sets impermanent loss protection parameters of an asset.
+//! * `flash_swap` - Buys asset out, dispatches given calls and collects asset in afterwards.
+//! * `withdraw_protocol_liquidity` - Withdraws protocol owned liquidity of an asset to a beneficiary (eg. treasury).
+//! * `redeploy_protocol_liquidity` - Moves protocol owned liquidity of an asset to another asset.
+//! * `convert_protocol_liquidity_to_position` - Converts protocol owned liquidity of an asset into a position.
+//! * `set_asset_fee_override` - Sets fixed asset fee and protocol fee of an asset.
+//! * `set_asset_fee_bounds` - Sets minimum and maximum asset fee and protocol fee of an asset.
+//!
+//! ### Delisting
+//!
+//! An asset is removed from the pool in following steps:
+//! 1. The asset is frozen by `set_asset_tradable_state` with FROZEN state.
+//! 2. The asset is removed by `remove_token`. Its hub asset reserve is burned and the part of its reserve owned by the protocol
+//!    is transferred to the beneficiary (eg. treasury).
+//! 3. LPs of remaining positions claim their liquidity by `claim_delisted_position`. Liquidity is removed at the frozen price
+//!    as in `remove_liquidity` but without withdrawal fee. Reserve which becomes protocol owned in claims is transferred
+//!    to the beneficiary once all positions are claimed.
+//!
+//! ### Impermanent loss protection
+//!
+//! Impermanent loss protection can be enabled for an asset by `set_il_protection`. A share of trade fees of the asset
+//! is then diverted into the impermanent loss fund account instead of being processed by `OmnipoolHooks::on_trade_fee`.
+//!
+//! LP who removes liquidity of a position held for at least minimum holding period is compensated
+//! for a vesting fraction of measured impermanent loss. Compensation is paid in the asset and is capped by the fund balance.
+//!
+//! ### Flash swaps
+//!
+//! `flash_swap` transfers bought amount of asset out to the origin first and dispatches given calls with the origin.
+//! Amount of asset in is calculated as for `buy`, including fees, and it is collected after the calls.
+//! The whole flash swap is reverted if the origin cannot pay it.
+//!
+//! Price barrier and trade hooks (eg. circuit breaker) apply as for regular trades. Omnipool trades and liquidity
+//! operations cannot be executed within the calls.
+//!
+//! ### Hub asset trades
+//!
+//! Hub asset can be traded in both directions by `sell` and `buy`. Hub asset's tradable state set by `set_asset_tradable_state`
+//! controls each direction separately - SELL allows hub asset to be sold to the pool and BUY allows hub asset to be bought from the pool.
+//!
+//! Protocol fee is applied to hub asset bought from the pool and it is added to hub reserve of HDX subpool.
+//! Hub asset bought from the pool decreases negative `HubAssetImbalance`. The trade fails if the imbalance would become positive.
+//!
+//! ### Protocol owned liquidity
+//!
+//! Shares of sacrificed positions are owned by the protocol. `AuthorityOrigin` can manage this liquidity:
+//! * `withdraw_protocol_liquidity` removes it from the pool and transfers the asset to a beneficiary,
+//! * `redeploy_protocol_liquidity` removes it, sells withdrawn amount for another asset and adds it back as protocol owned
+//!   liquidity of that asset,
+//! * `convert_protocol_liquidity_to_position` moves the shares to a new position NFT.
+//!
+//! Liquidity is removed with the same state changes as `remove_liquidity` of a position created at current spot price,
+//! without withdrawal fee.
+//!
+//! ### Fees
+//!
+//! Asset fee and protocol fee of an asset are provided by `Config::Fee` (eg. dynamic fees).
+//! `AuthorityOrigin` can pin fees of an asset by `set_asset_fee_override` - fixed fees are then used instead of provided fees.
+//! Provided fees can be also clamped to minimum and maximum fees of an asset set by `set_asset_fee_bounds`.
+//!
+//! ### Runtime API
+//!
+//! `OmnipoolApi` returns value of a position or of all positions of an account if all liquidity was removed at current
+//! state of the pool - amounts of asset and hub asset received, withdrawal fee, impermanent loss compared to holding
+//! the asset and impermanent loss compensation.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::require_transactional;
+use frame_support::traits::DefensiveOption;
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
-use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One};
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One, SaturatedConversion};
+use sp_runtime::traits::{CheckedAdd, CheckedSub, Saturating, Zero};
 use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, I129};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate};
+use hydra_dx_math::ema::EmaPrice;
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, LiquidityStateChange, I129};
 use hydradx_traits::Registry;
 use orml_traits::{GetByKey, MultiCurrency};
 use scale_info::TypeInfo;
@@ -89,15 +160,25 @@ mod tests;
 
 pub mod provider;
 pub mod router_execution;
+pub mod runtime_api;
 pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks, ShouldAllow};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, DelistedAssetState, FeeBounds, ILProtectionParams, Position, PositionValue,
+	SimpleImbalance, Tradability,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Maximum number of positions which can be merged in `merge_positions`.
+pub const MAX_MERGED_POSITIONS: u32 = 20;
+
+/// Maximum number of calls which can be dispatched in `flash_swap`.
+pub const MAX_FLASH_SWAP_CALLS: u32 = 10;
+
 /// NFT class id type of provided nft implementation
 pub type NFTCollectionIdOf<T> =
 	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
@@ -108,12 +189,13 @@ pub mod pallet {
 	use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks, ShouldAllow};
 	use crate::types::{Position, Price, Tradability};
 	use codec::HasCompact;
+	use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::DefensiveOption;
 	use frame_system::pallet_prelude::*;
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
 	use orml_traits::GetByKey;
+	use sp_runtime::traits::Dispatchable;
 	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
@@ -125,6 +207,11 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching call type - calls dispatched in flash swap.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+
 		/// Identifier for the class of asset.
 		type AssetId: Member
 			+ Parameter
@@ -195,7 +282,8 @@ pub mod pallet {
 		/// Non fungible handling - mint,burn, check owner
 		type NFTHandler: Mutate<Self::AccountId>
 			+ Create<Self::AccountId>
-			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>
+			+ InspectEnumerable<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -243,6 +331,38 @@ pub mod pallet {
 	/// TVL cap
 	pub(super) type TvlCap<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	#[pallet::storage]
+	/// Assets removed from Omnipool with liquidity of remaining LP positions which has not been claimed yet.
+	#[pallet::getter(fn delisted_assets)]
+	pub(super) type DelistedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, DelistedAssetState<T::AccountId, Balance>>;
+
+	#[pallet::storage]
+	/// Impermanent loss protection parameters of assets.
+	#[pallet::getter(fn il_protection)]
+	pub(super) type ILProtection<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, ILProtectionParams<T::BlockNumber>>;
+
+	#[pallet::storage]
+	/// Fixed asset fee and protocol fee of assets. Used instead of fees provided by `Config::Fee`.
+	#[pallet::getter(fn asset_fee_override)]
+	pub(super) type AssetFeeOverrides<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, (Permill, Permill)>;
+
+	#[pallet::storage]
+	/// Bounds of fees of assets. Fees provided by `Config::Fee` are clamped to these bounds.
+	#[pallet::getter(fn asset_fee_bounds)]
+	pub(super) type AssetFeeBounds<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FeeBounds>;
+
+	#[pallet::storage]
+	/// Block number at which liquidity of LP position was provided.
+	/// Share-weighted block number of merged positions is stored for merged position.
+	#[pallet::getter(fn position_created_at)]
+	pub(super) type PositionCreatedAt<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, T::BlockNumber>;
+
+	#[pallet::storage]
+	/// Set while calls of a flash swap are dispatched. Trading and liquidity operations are not allowed meanwhile.
+	pub(super) type FlashSwapInProgress<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -325,6 +445,102 @@ pub mod pallet {
 
 		/// TVL cap has been updated.
 		TVLCapUpdated { cap: Balance },
+
+		/// An asset was removed from Omnipool.
+		TokenRemoved {
+			asset_id: T::AssetId,
+			beneficiary: T::AccountId,
+			amount: Balance,
+			hub_withdrawn: Balance,
+			unclaimed_amount: Balance,
+		},
+
+		/// Liquidity of a position of removed asset was claimed.
+		DelistedPositionClaimed {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			hub_amount: Balance,
+		},
+
+		/// LP positions were merged into one position.
+		PositionsMerged {
+			owner: T::AccountId,
+			asset_id: T::AssetId,
+			position_id: T::PositionItemId,
+			merged_positions: Vec<T::PositionItemId>,
+		},
+
+		/// Shares of LP position were split into new position.
+		PositionSplit {
+			owner: T::AccountId,
+			position_id: T::PositionItemId,
+			new_position_id: T::PositionItemId,
+			shares: Balance,
+		},
+
+		/// Impermanent loss protection parameters of an asset have been updated.
+		ILProtectionUpdated {
+			asset_id: T::AssetId,
+			params: Option<ILProtectionParams<T::BlockNumber>>,
+		},
+
+		/// LP was compensated for impermanent loss from impermanent loss fund.
+		ILCompensationPaid {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+
+		/// Flash swap executed.
+		FlashSwapExecuted {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			asset_fee_amount: Balance,
+			protocol_fee_amount: Balance,
+		},
+
+		/// Protocol owned liquidity has been withdrawn from the pool.
+		ProtocolLiquidityWithdrawn {
+			asset_id: T::AssetId,
+			shares: Balance,
+			amount: Balance,
+			beneficiary: T::AccountId,
+		},
+
+		/// Protocol owned liquidity has been moved from one asset to another.
+		ProtocolLiquidityRedeployed {
+			asset_from: T::AssetId,
+			shares_removed: Balance,
+			asset_to: T::AssetId,
+			amount_added: Balance,
+			shares_added: Balance,
+		},
+
+		/// Protocol owned liquidity has been converted into a position.
+		ProtocolLiquidityConverted {
+			asset_id: T::AssetId,
+			shares: Balance,
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+		},
+
+		/// Fixed fees of an asset have been updated.
+		AssetFeeOverrideUpdated {
+			asset_id: T::AssetId,
+			fees: Option<(Permill, Permill)>,
+		},
+
+		/// Fee bounds of an asset have been updated.
+		AssetFeeBoundsUpdated {
+			asset_id: T::AssetId,
+			bounds: Option<FeeBounds>,
+		},
 	}
 
 	#[pallet::error]
@@ -390,6 +606,32 @@ pub mod pallet {
 		InvalidWithdrawalFee,
 		/// More than allowed amount of fee has been transferred.
 		FeeOverdraft,
+		/// Asset must be frozen to be removed from the pool.
+		AssetNotFrozen,
+		/// Native asset and stable asset cannot be removed from the pool.
+		AssetRemovalNotAllowed,
+		/// Asset has not been removed from the pool.
+		AssetNotDelisted,
+		/// Asset has been removed from the pool and liquidity of some its positions has not been claimed yet.
+		UnclaimedDelistedLiquidity,
+		/// At least two positions are required to be merged.
+		InsufficientPositions,
+		/// More than `MAX_MERGED_POSITIONS` positions provided to be merged.
+		TooManyPositions,
+		/// Same position provided more than once.
+		DuplicatePosition,
+		/// Position is not a position of given asset.
+		PositionAssetMismatch,
+		/// More than `MAX_FLASH_SWAP_CALLS` calls provided to flash swap.
+		TooManyCalls,
+		/// Operation is not allowed while calls of a flash swap are dispatched.
+		FlashSwapInProgress,
+		/// Asset in of flash swap has not been paid after dispatching the calls.
+		FlashSwapNotRepaid,
+		/// Protocol does not own enough shares of the asset.
+		InsufficientProtocolShares,
+		/// Minimum fee is greater than maximum fee.
+		InvalidFeeBounds,
 	}
 
 	#[pallet::call]
@@ -542,6 +784,11 @@ pub mod pallet {
 
 			ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
 
+			ensure!(
+				!DelistedAssets::<T>::contains_key(asset),
+				Error::<T>::UnclaimedDelistedLiquidity
+			);
+
 			ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
 
 			ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
@@ -574,6 +821,7 @@ pub mod pallet {
 			let instance_id = Self::create_and_mint_position_instance(&position_owner)?;
 
 			<Positions<T>>::insert(instance_id, lp_position);
+			<PositionCreatedAt<T>>::insert(instance_id, frame_system::Pallet::<T>::block_number());
 
 			Self::deposit_event(Event::PositionCreated {
 				position_id: instance_id,
@@ -660,6 +908,8 @@ pub mod pallet {
 			//
 			let who = ensure_signed(origin.clone())?;
 
+			Self::ensure_no_flash_swap()?;
+
 			ensure!(
 				amount >= T::MinimumPoolLiquidity::get(),
 				Error::<T>::InsufficientLiquidity
@@ -737,6 +987,7 @@ pub mod pallet {
 			let instance_id = Self::create_and_mint_position_instance(&who)?;
 
 			<Positions<T>>::insert(instance_id, lp_position);
+			<PositionCreatedAt<T>>::insert(instance_id, frame_system::Pallet::<T>::block_number());
 
 			Self::deposit_event(Event::PositionCreated {
 				position_id: instance_id,
@@ -807,6 +1058,8 @@ pub mod pallet {
 			//
 			let who = ensure_signed(origin.clone())?;
 
+			Self::ensure_no_flash_swap()?;
+
 			ensure!(amount > Balance::zero(), Error::<T>::InvalidSharesAmount);
 
 			ensure!(
@@ -827,6 +1080,10 @@ pub mod pallet {
 				Error::<T>::NotAllowed
 			);
 
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+			let current_hub_asset_liquidity =
+				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
 			T::PriceBarrier::ensure_price(
 				&who,
 				T::HubAssetId::get(),
@@ -835,22 +1092,7 @@ pub mod pallet {
 			)
 			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
-
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
+			let withdrawal_fee = Self::calculate_withdrawal_fee(asset_id, &asset_state)?;
 
 			//
 			// calculate state changes of remove liquidity
@@ -913,10 +1155,26 @@ pub mod pallet {
 				)?;
 			}
 
+			// LP is compensated for impermanent loss if protection of the asset is enabled
+			let il_compensation =
+				Self::calculate_il_compensation(position_id, asset_id, &asset_state, &state_changes, withdrawal_fee)?;
+
+			if il_compensation > Balance::zero() {
+				T::Currency::transfer(asset_id, &Self::il_fund_account(), &who, il_compensation)?;
+
+				Self::deposit_event(Event::ILCompensationPaid {
+					who: who.clone(),
+					position_id,
+					asset_id,
+					amount: il_compensation,
+				});
+			}
+
 			if updated_position.shares == Balance::zero() {
 				// All liquidity removed, remove position and burn NFT instance
 
 				<Positions<T>>::remove(position_id);
+				<PositionCreatedAt<T>>::remove(position_id);
 				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
 
 				Self::deposit_event(Event::PositionDestroyed {
@@ -970,6 +1228,8 @@ pub mod pallet {
 		pub fn sacrifice_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			Self::ensure_no_flash_swap()?;
+
 			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
 
 			ensure!(
@@ -990,6 +1250,7 @@ pub mod pallet {
 
 			// Desotry position and burn NFT
 			<Positions<T>>::remove(position_id);
+			<PositionCreatedAt<T>>::remove(position_id);
 			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
 
 			Self::deposit_event(Event::PositionDestroyed {
@@ -1004,7 +1265,8 @@ pub mod pallet {
 		///
 		/// Price is determined by the Omnipool.
 		///
-		/// Hub asset is traded separately.
+		/// Hub asset is traded separately. Hub asset's tradable state must contain SELL flag to sell hub asset to the pool
+		/// and BUY flag to buy hub asset from the pool.
 		///
 		/// Asset's tradable states must contain SELL flag for asset_in and BUY flag for asset_out, otherwise `NotAllowed` error is returned.
 		///
@@ -1031,6 +1293,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
+			Self::ensure_no_flash_swap()?;
+
 			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
 
 			ensure!(
@@ -1050,7 +1314,7 @@ pub mod pallet {
 			}
 
 			if asset_out == T::HubAssetId::get() {
-				return Self::sell_asset_for_hub_asset(&who, asset_in, amount, min_buy_amount);
+				return Self::sell_asset_for_hub_asset(origin, &who, asset_in, amount, min_buy_amount);
 			}
 
 			let asset_in_state = Self::load_asset_state(asset_in)?;
@@ -1072,7 +1336,7 @@ pub mod pallet {
 
 			let current_imbalance = <HubAssetImbalance<T>>::get();
 
-			let (asset_fee, protocol_fee) = T::Fee::get(&asset_out);
+			let (asset_fee, protocol_fee) = Self::get_fee(&asset_out);
 
 			let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
 				&(&asset_in_state).into(),
@@ -1190,7 +1454,8 @@ pub mod pallet {
 		///
 		/// Price is determined by the Omnipool.
 		///
-		/// Hub asset is traded separately.
+		/// Hub asset is traded separately. Hub asset's tradable state must contain SELL flag to sell hub asset to the pool
+		/// and BUY flag to buy hub asset from the pool.
 		///
 		/// Asset's tradable states must contain SELL flag for asset_in and BUY flag for asset_out, otherwise `NotAllowed` error is returned.
 		///
@@ -1217,6 +1482,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
+			Self::ensure_no_flash_swap()?;
+
 			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
 
 			ensure!(
@@ -1226,7 +1493,7 @@ pub mod pallet {
 
 			// Special handling when one of the asset is Hub Asset
 			if asset_out == T::HubAssetId::get() {
-				return Self::buy_hub_asset(&who, asset_in, amount, max_sell_amount);
+				return Self::buy_hub_asset(origin, &who, asset_in, amount, max_sell_amount);
 			}
 
 			if asset_in == T::HubAssetId::get() {
@@ -1254,7 +1521,7 @@ pub mod pallet {
 
 			let current_imbalance = <HubAssetImbalance<T>>::get();
 
-			let (asset_fee, protocol_fee) = T::Fee::get(&asset_in);
+			let (asset_fee, protocol_fee) = Self::get_fee(&asset_in);
 			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
 				&(&asset_in_state).into(),
 				&(&asset_out_state).into(),
@@ -1392,7 +1659,7 @@ pub mod pallet {
 
 			if asset_id == T::HubAssetId::get() {
 				// current omnipool does not allow liquidity add or remove of hub asset.
-				// Although BUY is not supported yet, we can allow the new state to be set to SELL/BUY.
+				// SELL allows hub asset to be sold to the pool, BUY allows hub asset to be bought from the pool.
 				ensure!(
 					!state.contains(Tradability::ADD_LIQUIDITY) && !state.contains(Tradability::REMOVE_LIQUIDITY),
 					Error::<T>::InvalidHubAssetTradableState
@@ -1441,6 +1708,12 @@ pub mod pallet {
 			// Make sure that asset is not in the pool
 			ensure!(!Assets::<T>::contains_key(asset_id), Error::<T>::AssetAlreadyAdded);
 
+			// Unclaimed liquidity of removed asset cannot be refunded
+			ensure!(
+				!DelistedAssets::<T>::contains_key(asset_id),
+				Error::<T>::UnclaimedDelistedLiquidity
+			);
+
 			ensure!(
 				T::Currency::ensure_can_withdraw(asset_id, &Self::protocol_account(), amount).is_ok(),
 				Error::<T>::InsufficientBalance
@@ -1496,128 +1769,1351 @@ pub mod pallet {
 			Self::deposit_event(Event::TVLCapUpdated { cap });
 			Ok(())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn integrity_test() {
-			assert_ne!(
-				T::MinimumPoolLiquidity::get(),
-				Balance::zero(),
-				"Minimum pool liquidity is 0."
-			);
-			assert_ne!(
-				T::MinimumTradingLimit::get(),
-				Balance::zero(),
-				"Minimum trading limit is 0."
-			);
-			assert_ne!(
-				T::HdxAssetId::get(),
-				T::StableCoinAssetId::get(),
-				"Same Hdx asset id and stable asset id."
+		/// Remove asset from Omnipool.
+		///
+		/// Asset's tradable state must be FROZEN, otherwise `AssetNotFrozen` error is returned.
+		///
+		/// Hub asset reserve of the asset is burned and the part of the asset reserve owned by the protocol
+		/// is transferred to the beneficiary.
+		///
+		/// The part of the asset reserve owned by remaining LP positions stays in the pool account
+		/// and can be claimed by `claim_delisted_position`.
+		///
+		/// Native asset and preferred stable asset cannot be removed.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `beneficiary`: account which receives the part of the asset reserve owned by the protocol
+		///
+		/// Emits `TokenRemoved` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token())]
+		#[transactional]
+		pub fn remove_token(origin: OriginFor<T>, asset_id: T::AssetId, beneficiary: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				asset_id != T::HdxAssetId::get() && asset_id != T::StableCoinAssetId::get(),
+				Error::<T>::AssetRemovalNotAllowed
 			);
-			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
-			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
-		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Protocol account address
-	pub fn protocol_account() -> T::AccountId {
-		PalletId(*b"omnipool").into_account_truncating()
-	}
+			let asset_state = Self::load_asset_state(asset_id)?;
 
-	/// Retrieve stable asset detail from the pool.
-	/// Return NoStableCoinInPool if stable asset is not yet in the pool.
-	fn stable_asset() -> Result<(Balance, Balance), DispatchError> {
-		let stable_asset = <Assets<T>>::get(T::StableCoinAssetId::get()).ok_or(Error::<T>::NoStableAssetInPool)?;
-		let stable_reserve = T::Currency::free_balance(T::StableCoinAssetId::get(), &Self::protocol_account());
-		Ok((stable_reserve, stable_asset.hub_reserve))
-	}
+			ensure!(asset_state.tradable == Tradability::FROZEN, Error::<T>::AssetNotFrozen);
 
-	/// Retrieve state of asset from the pool and its pool balance
-	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
-		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
-		let reserve = T::Currency::free_balance(asset_id, &Self::protocol_account());
-		Ok((state, reserve).into())
-	}
+			let lp_shares = asset_state
+				.shares
+				.checked_sub(asset_state.protocol_shares)
+				.ok_or(ArithmeticError::Underflow)?;
 
-	/// Set new state of asset.
-	/// This converts the new state into correct state type ( by removing the reserve)
-	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
-		<Assets<T>>::insert(asset_id, Into::<AssetState<Balance>>::into(new_state));
-	}
+			let (lp_reserve, lp_hub_reserve) = if lp_shares.is_zero() {
+				(Balance::zero(), Balance::zero())
+			} else {
+				(
+					hydra_dx_math::omnipool::calculate_shares_reserve(
+						asset_state.reserve,
+						lp_shares,
+						asset_state.shares,
+					)
+					.ok_or(ArithmeticError::Overflow)?,
+					hydra_dx_math::omnipool::calculate_shares_reserve(
+						asset_state.hub_reserve,
+						lp_shares,
+						asset_state.shares,
+					)
+					.ok_or(ArithmeticError::Overflow)?,
+				)
+			};
 
-	/// Generate an nft instance id and mint NFT into the class and instance.
-	#[require_transactional]
-	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
-		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
-			let next_position_id = *current_value;
+			let protocol_reserve = asset_state
+				.reserve
+				.checked_sub(lp_reserve)
+				.ok_or(ArithmeticError::Underflow)?;
 
-			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+			let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+				asset_state.hub_reserve,
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				Self::get_hub_asset_balance_of_protocol_account(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
 
-			*current_value = current_value
-				.checked_add(&T::PositionItemId::one())
-				.ok_or(ArithmeticError::Overflow)?;
+			Self::update_imbalance(BalanceUpdate::Increase(delta_imbalance))?;
 
-			Ok(next_position_id)
-		})
-	}
+			Self::update_hub_asset_liquidity(&BalanceUpdate::Decrease(asset_state.hub_reserve))?;
 
-	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
-	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
-		if hub_asset_amount > Balance::zero() {
-			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+			if protocol_reserve > Balance::zero() {
+				T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, protocol_reserve)?;
+			}
 
-			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
-			native_subpool.hub_reserve = native_subpool
-				.hub_reserve
-				.checked_add(hub_asset_amount)
-				.ok_or(ArithmeticError::Overflow)?;
-			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+			<Assets<T>>::remove(asset_id);
+
+			if lp_shares > Balance::zero() {
+				<DelistedAssets<T>>::insert(
+					asset_id,
+					DelistedAssetState {
+						reserve: lp_reserve,
+						hub_reserve: lp_hub_reserve,
+						shares: lp_shares,
+						protocol_shares: Balance::zero(),
+						beneficiary: beneficiary.clone(),
+					},
+				);
+			}
 
-			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+			Self::deposit_event(Event::TokenRemoved {
+				asset_id,
+				beneficiary,
+				amount: protocol_reserve,
+				hub_withdrawn: asset_state.hub_reserve,
+				unclaimed_amount: lp_reserve,
+			});
 
-			let delta_changes = AssetStateChange {
-				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
-				..Default::default()
-			};
+			Ok(())
+		}
 
-			let info: AssetInfo<T::AssetId, Balance> =
-				AssetInfo::new(T::HdxAssetId::get(), &hdx_state, &updated_hdx_state, &delta_changes);
+		/// Claim liquidity of a position of an asset which has been removed from Omnipool.
+		///
+		/// Liquidity is removed at the frozen price of the asset as in `remove_liquidity`, no withdrawal fee is applied.
+		/// LP receives its part of the asset reserve and hub asset if the frozen price is higher than the position price.
+		/// Hub asset is minted because hub reserve of the asset was burned when the asset was removed,
+		/// minted amount is added to the hub asset imbalance.
+		/// If the frozen price is lower than the position price, part of the position's shares becomes protocol owned.
+		///
+		/// Once all positions are claimed, the remaining protocol owned reserve is transferred to the beneficiary
+		/// of the asset removal.
+		///
+		/// Position is destroyed and NFT is burned.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity is claimed.
+		///
+		/// Emits `DelistedPositionClaimed` and `PositionDestroyed` events when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_delisted_position())]
+		#[transactional]
+		pub fn claim_delisted_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
-		}
-		Ok(())
-	}
+			Self::ensure_no_flash_swap()?;
 
-	/// Update total hub asset liquidity and write new value to storage.
-	/// Update total issueance if AdjustSupply is specified.
-	#[require_transactional]
-	fn update_hub_asset_liquidity(delta_amount: &BalanceUpdate<Balance>) -> DispatchResult {
-		match delta_amount {
-			BalanceUpdate::Increase(amount) => {
-				T::Currency::deposit(T::HubAssetId::get(), &Self::protocol_account(), *amount)
-			}
-			BalanceUpdate::Decrease(amount) => {
-				T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), *amount)
-			}
-		}
+			let position = Self::load_position(position_id, who.clone())?;
+			let asset_id = position.asset_id;
+
+			let delisted_state = DelistedAssets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotDelisted)?;
+			let frozen_state: AssetReserveState<Balance> = (&delisted_state).into();
+
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+
+			// Price of delisted asset cannot change, liquidity is removed at the frozen price without withdrawal fee.
+			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+				&(&frozen_state).into(),
+				position.shares,
+				&(&position).into(),
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				Self::get_hub_asset_balance_of_protocol_account(),
+				FixedU128::zero(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let new_state = frozen_state
+				.delta_update(&state_changes.asset)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let amount = *state_changes.asset.delta_reserve;
+			let hub_amount = state_changes.lp_hub_amount;
+
+			T::Currency::transfer(asset_id, &Self::protocol_account(), &who, amount)?;
+
+			if hub_amount > Balance::zero() {
+				// Hub asset is minted outside of the pool, it is accounted as imbalance as any other unbacked hub asset.
+				Self::update_imbalance(BalanceUpdate::Decrease(hub_amount))?;
+
+				T::Currency::deposit(T::HubAssetId::get(), &who, hub_amount)?;
+			}
+
+			if new_state.shares == new_state.protocol_shares {
+				// All positions are claimed, the rest of the reserve is owned by protocol.
+				if new_state.reserve > Balance::zero() {
+					T::Currency::transfer(
+						asset_id,
+						&Self::protocol_account(),
+						&delisted_state.beneficiary,
+						new_state.reserve,
+					)?;
+				}
+				<DelistedAssets<T>>::remove(asset_id);
+			} else {
+				<DelistedAssets<T>>::insert(
+					asset_id,
+					DelistedAssetState {
+						reserve: new_state.reserve,
+						hub_reserve: new_state.hub_reserve,
+						shares: new_state.shares,
+						protocol_shares: new_state.protocol_shares,
+						beneficiary: delisted_state.beneficiary,
+					},
+				);
+			}
+
+			<Positions<T>>::remove(position_id);
+			<PositionCreatedAt<T>>::remove(position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+			Self::deposit_event(Event::DelistedPositionClaimed {
+				who: who.clone(),
+				position_id,
+				asset_id,
+				amount,
+				hub_amount,
+			});
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id,
+				owner: who,
+			});
+
+			Ok(())
+		}
+
+		/// Merge LP positions of an asset into one position.
+		///
+		/// If all positions have the same price, merged position keeps the price and removing its liquidity
+		/// is equivalent to removing liquidity of the positions separately.
+		/// Otherwise price of merged position is calculated so the value of the positions in current asset state is preserved.
+		/// Current price of the asset must be within the price barrier, so the merge cannot be done in manipulated state.
+		///
+		/// Amounts and shares of the positions are summed. Merged position is stored under the first position id,
+		/// NFTs of other positions are burned.
+		///
+		/// Only owner of all positions can perform this action.
+		///
+		/// Parameters:
+		/// - `asset_id`: Asset of the positions
+		/// - `position_ids`: Identifiers of positions to merge. At least two and at most `MAX_MERGED_POSITIONS` positions.
+		///
+		/// Emits `PositionUpdated`, `PositionDestroyed` for each burned position and `PositionsMerged` events when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions(position_ids.len() as u32))]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			position_ids: Vec<T::PositionItemId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_no_flash_swap()?;
+
+			ensure!(position_ids.len() >= 2, Error::<T>::InsufficientPositions);
+			ensure!(
+				position_ids.len() <= MAX_MERGED_POSITIONS as usize,
+				Error::<T>::TooManyPositions
+			);
+
+			let mut positions = Vec::with_capacity(position_ids.len());
+			let mut created_at = Vec::with_capacity(position_ids.len());
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			for (idx, position_id) in position_ids.iter().enumerate() {
+				ensure!(
+					!position_ids[..idx].contains(position_id),
+					Error::<T>::DuplicatePosition
+				);
+
+				let position = Self::load_position(*position_id, who.clone())?;
+
+				ensure!(position.asset_id == asset_id, Error::<T>::PositionAssetMismatch);
+
+				// Position without known creation block is considered as created in current block.
+				created_at.push((
+					<PositionCreatedAt<T>>::get(position_id)
+						.unwrap_or(current_block)
+						.saturated_into::<u128>(),
+					position.shares,
+				));
+
+				positions.push(hydra_dx_math::omnipool::types::Position::from(&position));
+			}
+
+			let asset_state = Self::load_asset_state(asset_id)?;
+
+			T::PriceBarrier::ensure_price(
+				&who,
+				T::HubAssetId::get(),
+				asset_id,
+				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+			)
+			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+
+			let merged = hydra_dx_math::omnipool::calculate_merged_position(&(&asset_state).into(), &positions)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let position_id = position_ids[0];
+			let merged_position = Position {
+				asset_id,
+				amount: merged.amount,
+				shares: merged.shares,
+				price: merged.price,
+			};
+
+			<Positions<T>>::insert(position_id, &merged_position);
+
+			let merged_created_at =
+				hydra_dx_math::omnipool::calculate_weighted_average(&created_at).ok_or(ArithmeticError::Overflow)?;
+			<PositionCreatedAt<T>>::insert(position_id, T::BlockNumber::saturated_from(merged_created_at));
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price: merged_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			for merged_position_id in position_ids.iter().skip(1) {
+				<Positions<T>>::remove(merged_position_id);
+				<PositionCreatedAt<T>>::remove(merged_position_id);
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), merged_position_id, Some(&who))?;
+
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id: *merged_position_id,
+					owner: who.clone(),
+				});
+			}
+
+			Self::deposit_event(Event::PositionsMerged {
+				owner: who,
+				asset_id,
+				position_id,
+				merged_positions: position_ids[1..].to_vec(),
+			});
+
+			Ok(())
+		}
+
+		/// Split given quantity of shares of LP position into new position.
+		///
+		/// Amount of the position is split proportionally to shares. Both positions keep the price of the original position.
+		/// New position NFT is minted to the owner.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position to split.
+		/// - `shares`: Quantity of shares moved to the new position. Must be less than shares of the position.
+		///
+		/// Emits `PositionUpdated`, `PositionCreated` and `PositionSplit` events when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_no_flash_swap()?;
+
+			let position = Self::load_position(position_id, who.clone())?;
+
+			ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+			ensure!(shares < position.shares, Error::<T>::InsufficientShares);
+
+			let (remaining, split) = hydra_dx_math::omnipool::calculate_split_position(&(&position).into(), shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				split.amount > Balance::zero() && remaining.amount > Balance::zero(),
+				Error::<T>::InvalidSharesAmount
+			);
+
+			let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+
+			let updated_position = Position {
+				asset_id: position.asset_id,
+				amount: remaining.amount,
+				shares: remaining.shares,
+				price: remaining.price,
+			};
+			let new_position = Position {
+				asset_id: position.asset_id,
+				amount: split.amount,
+				shares: split.shares,
+				price: split.price,
+			};
+
+			<Positions<T>>::insert(position_id, &updated_position);
+
+			let new_position_id = Self::create_and_mint_position_instance(&who)?;
+
+			<Positions<T>>::insert(new_position_id, &new_position);
+
+			if let Some(created_at) = <PositionCreatedAt<T>>::get(position_id) {
+				<PositionCreatedAt<T>>::insert(new_position_id, created_at);
+			}
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: position.asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: new_position_id,
+				owner: who.clone(),
+				asset: position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionSplit {
+				owner: who,
+				position_id,
+				new_position_id,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Set impermanent loss protection parameters of an asset.
+		///
+		/// When enabled, `fee_share` of trade fees of the asset is diverted into impermanent loss fund account.
+		/// LPs removing liquidity of positions held for at least `min_holding_period` are compensated for a vesting
+		/// fraction of their impermanent loss from the fund.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id. Asset must be in the pool.
+		/// - `params`: impermanent loss protection parameters. `None` disables the protection.
+		///
+		/// Emits `ILProtectionUpdated` event when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_il_protection())]
+		#[transactional]
+		pub fn set_il_protection(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			params: Option<ILProtectionParams<T::BlockNumber>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			match params.clone() {
+				Some(p) => ILProtection::<T>::insert(asset_id, p),
+				None => ILProtection::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::ILProtectionUpdated { asset_id, params });
+
+			Ok(())
+		}
+
+		/// Execute a flash swap - buy `amount_out` of `asset_out` and pay for it after dispatching given calls.
+		///
+		/// `amount_out` of `asset_out` is transferred to the origin first. Then the calls are dispatched
+		/// with the origin in the same transactional layer. Finally, amount of `asset_in` calculated as for `buy`,
+		/// including fees, is collected from the origin. If the origin cannot pay it, the whole flash swap is reverted.
+		///
+		/// Price barrier is applied to both assets and trade hooks (eg. circuit breaker) are executed as for `buy`.
+		///
+		/// Hub asset cannot be swapped. Trading and liquidity operations of Omnipool are not allowed within the calls.
+		///
+		/// Parameters:
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_out`: Amount of asset bought
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `calls`: Calls dispatched with the origin before `asset_in` is collected. At most `MAX_FLASH_SWAP_CALLS` calls.
+		///
+		/// Emits `FlashSwapExecuted` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight({
+			let calls_weight = calls
+				.iter()
+				.map(|call| call.get_dispatch_info().weight)
+				.fold(Weight::zero(), |total, weight| total.saturating_add(weight));
+
+			<T as Config>::WeightInfo::flash_swap()
+				.saturating_add(T::OmnipoolHooks::on_trade_weight())
+				.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+				.saturating_add(calls_weight)
+		})]
+		#[transactional]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			asset_out: T::AssetId,
+			amount_out: Balance,
+			asset_in: T::AssetId,
+			calls: Vec<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			Self::ensure_no_flash_swap()?;
+
+			ensure!(calls.len() <= MAX_FLASH_SWAP_CALLS as usize, Error::<T>::TooManyCalls);
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+
+			ensure!(
+				asset_in != T::HubAssetId::get() && asset_out != T::HubAssetId::get(),
+				Error::<T>::NotAllowed
+			);
+
+			ensure!(
+				amount_out >= T::MinimumTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let asset_in_state = Self::load_asset_state(asset_in)?;
+			let asset_out_state = Self::load_asset_state(asset_out)?;
+
+			ensure!(
+				Self::allow_assets(&asset_in_state, &asset_out_state),
+				Error::<T>::NotAllowed
+			);
+
+			for (asset_id, asset_state) in [(asset_in, &asset_in_state), (asset_out, &asset_out_state)] {
+				T::PriceBarrier::ensure_price(
+					&who,
+					T::HubAssetId::get(),
+					asset_id,
+					EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+				)
+				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+			}
+
+			ensure!(asset_out_state.reserve >= amount_out, Error::<T>::InsufficientLiquidity);
+
+			ensure!(
+				amount_out
+					<= asset_out_state
+						.reserve
+						.checked_div(T::MaxOutRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+				Error::<T>::MaxOutRatioExceeded
+			);
+
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+
+			let (asset_fee, protocol_fee) = Self::get_fee(&asset_in);
+			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+				&(&asset_in_state).into(),
+				&(&asset_out_state).into(),
+				amount_out,
+				asset_fee,
+				protocol_fee,
+				current_imbalance.value,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let amount_in = *state_changes.asset_in.delta_reserve;
+
+			ensure!(
+				amount_in
+					<= asset_in_state
+						.reserve
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			let new_asset_in_state = asset_in_state
+				.clone()
+				.delta_update(&state_changes.asset_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			let new_asset_out_state = asset_out_state
+				.clone()
+				.delta_update(&state_changes.asset_out)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			T::Currency::transfer(
+				asset_out,
+				&Self::protocol_account(),
+				&who,
+				*state_changes.asset_out.delta_reserve,
+			)?;
+
+			// Hub liquidity update - work out difference between in and amount so only one update needed.
+			let delta_hub_asset = state_changes
+				.asset_in
+				.delta_hub_reserve
+				.merge(
+					state_changes
+						.asset_out
+						.delta_hub_reserve
+						.merge(BalanceUpdate::Increase(state_changes.hdx_hub_amount))
+						.ok_or(ArithmeticError::Overflow)?,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			match delta_hub_asset {
+				BalanceUpdate::Increase(val) if val == Balance::zero() => {
+					// nothing to do if zero.
+				}
+				BalanceUpdate::Increase(_) => {
+					// trade can only burn some.
+					return Err(Error::<T>::HubAssetUpdateError.into());
+				}
+				BalanceUpdate::Decrease(amount) => {
+					T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), amount)?;
+				}
+			};
+
+			Self::update_imbalance(state_changes.delta_imbalance)?;
+
+			Self::set_asset_state(asset_in, new_asset_in_state.clone());
+			Self::set_asset_state(asset_out, new_asset_out_state.clone());
+
+			// Dispatch calls of the origin. Omnipool reserve of asset in is not paid yet,
+			// so trading and liquidity operations are not allowed meanwhile.
+			FlashSwapInProgress::<T>::put(true);
+
+			for call in calls.into_iter() {
+				call.dispatch(origin.clone()).map_err(|e| e.error)?;
+			}
+
+			FlashSwapInProgress::<T>::kill();
+
+			ensure!(
+				T::Currency::ensure_can_withdraw(asset_in, &who, amount_in).is_ok(),
+				Error::<T>::FlashSwapNotRepaid
+			);
+
+			T::Currency::transfer(asset_in, &who, &Self::protocol_account(), amount_in)?;
+
+			// Callback hook info
+			let info_in: AssetInfo<T::AssetId, Balance> =
+				AssetInfo::new(asset_in, &asset_in_state, &new_asset_in_state, &state_changes.asset_in);
+
+			let info_out: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
+				asset_out,
+				&asset_out_state,
+				&new_asset_out_state,
+				&state_changes.asset_out,
+			);
+
+			T::OmnipoolHooks::on_trade(origin.clone(), info_in, info_out)?;
+
+			Self::update_hdx_subpool_hub_asset(origin, state_changes.hdx_hub_amount)?;
+
+			Self::process_trade_fee(asset_in, state_changes.fee.asset_fee)?;
+
+			Self::deposit_event(Event::FlashSwapExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: *state_changes.asset_out.delta_reserve,
+				asset_fee_amount: state_changes.fee.asset_fee,
+				protocol_fee_amount: state_changes.fee.protocol_fee,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw liquidity owned by the protocol from the pool.
+		///
+		/// Given amount of protocol shares is removed from the pool using the same state changes as `remove_liquidity`
+		/// at current spot price and without withdrawal fee. Withdrawn asset is transferred to the beneficiary.
+		///
+		/// Asset's tradable state must contain REMOVE_LIQUIDITY flag, otherwise `NotAllowed` error is returned.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `shares`: amount of protocol shares to withdraw
+		/// - `beneficiary`: account which receives withdrawn asset
+		///
+		/// Emits `ProtocolLiquidityWithdrawn` event when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_protocol_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn withdraw_protocol_liquidity(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			shares: Balance,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin.clone())?;
+
+			Self::ensure_no_flash_swap()?;
+
+			let amount = Self::remove_protocol_liquidity(origin, asset_id, shares, &beneficiary)?;
+
+			Self::deposit_event(Event::ProtocolLiquidityWithdrawn {
+				asset_id,
+				shares,
+				amount,
+				beneficiary,
+			});
+
+			Ok(())
+		}
+
+		/// Move liquidity owned by the protocol from one asset to another.
+		///
+		/// Given amount of protocol shares of `asset_id` is withdrawn as in `withdraw_protocol_liquidity`, withdrawn
+		/// amount is sold for `target_asset` in the pool by the protocol owned liquidity account and received amount
+		/// is added back to the pool as protocol owned liquidity of `target_asset`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id of which protocol liquidity is withdrawn
+		/// - `shares`: amount of protocol shares to withdraw
+		/// - `target_asset`: asset id to which protocol liquidity is added
+		/// - `min_amount_out`: minimum amount of `target_asset` required to receive from the trade
+		///
+		/// Emits `ProtocolLiquidityRedeployed` event when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeploy_protocol_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_trade_weight())
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight().saturating_mul(3)))]
+		#[transactional]
+		pub fn redeploy_protocol_liquidity(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			shares: Balance,
+			target_asset: T::AssetId,
+			min_amount_out: Balance,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin.clone())?;
+
+			Self::ensure_no_flash_swap()?;
+
+			ensure!(asset_id != target_asset, Error::<T>::SameAssetTradeNotAllowed);
+
+			let pol_account = Self::pol_account();
+
+			let amount = Self::remove_protocol_liquidity(origin.clone(), asset_id, shares, &pol_account)?;
+
+			let initial_balance = T::Currency::free_balance(target_asset, &pol_account);
+
+			Self::sell(
+				frame_system::RawOrigin::Signed(pol_account.clone()).into(),
+				asset_id,
+				target_asset,
+				amount,
+				min_amount_out,
+			)?;
+
+			let amount_added = T::Currency::free_balance(target_asset, &pol_account)
+				.checked_sub(initial_balance)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			let shares_added = Self::add_protocol_liquidity(origin, target_asset, amount_added, &pol_account)?;
+
+			Self::deposit_event(Event::ProtocolLiquidityRedeployed {
+				asset_from: asset_id,
+				shares_removed: shares,
+				asset_to: target_asset,
+				amount_added,
+				shares_added,
+			});
+
+			Ok(())
+		}
+
+		/// Convert liquidity owned by the protocol into a position.
+		///
+		/// Given amount of protocol shares is moved to a new position owned by `owner`. Position is created at current
+		/// spot price and its amount is the amount which would be withdrawn by `withdraw_protocol_liquidity`.
+		///
+		/// Asset reserves are not changed.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `shares`: amount of protocol shares to convert
+		/// - `owner`: account which receives the position NFT
+		///
+		/// Emits `PositionCreated` and `ProtocolLiquidityConverted` events when successful.
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::convert_protocol_liquidity_to_position())]
+		#[transactional]
+		pub fn convert_protocol_liquidity_to_position(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			shares: Balance,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Self::ensure_no_flash_swap()?;
+
+			let asset_state = Self::load_asset_state(asset_id)?;
+
+			// Price cannot be manipulated when trading of the asset is frozen.
+			if !Self::is_trading_frozen(&asset_state) {
+				T::PriceBarrier::ensure_price(
+					&Self::pol_account(),
+					T::HubAssetId::get(),
+					asset_id,
+					EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+				)
+				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+			}
+
+			let state_changes = Self::calculate_protocol_liquidity_removal(&asset_state, shares)?;
+
+			let position = Position::<Balance, T::AssetId> {
+				asset_id,
+				amount: *state_changes.asset.delta_reserve,
+				shares,
+				price: (asset_state.hub_reserve, asset_state.reserve),
+			};
+
+			let position_id = Self::create_and_mint_position_instance(&owner)?;
+
+			<Positions<T>>::insert(position_id, position.clone());
+			<PositionCreatedAt<T>>::insert(position_id, frame_system::Pallet::<T>::block_number());
+
+			Assets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				asset_state.protocol_shares = asset_state
+					.protocol_shares
+					.checked_sub(shares)
+					.ok_or(Error::<T>::InsufficientProtocolShares)?;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id,
+				owner: owner.clone(),
+				asset: asset_id,
+				amount: position.amount,
+				shares,
+				price: asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			Self::deposit_event(Event::ProtocolLiquidityConverted {
+				asset_id,
+				shares,
+				position_id,
+				owner,
+			});
+
+			Ok(())
+		}
+
+		/// Set fixed asset fee and protocol fee of an asset.
+		///
+		/// Fixed fees are used instead of fees provided by `Config::Fee` and fee bounds of the asset are not applied.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `fees`: `(asset fee, protocol fee)`. `None` removes fixed fees of the asset.
+		///
+		/// Emits `AssetFeeOverrideUpdated` event when successful.
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_fee_override())]
+		#[transactional]
+		pub fn set_asset_fee_override(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			fees: Option<(Permill, Permill)>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			match fees {
+				Some(f) => AssetFeeOverrides::<T>::insert(asset_id, f),
+				None => AssetFeeOverrides::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::AssetFeeOverrideUpdated { asset_id, fees });
+
+			Ok(())
+		}
+
+		/// Set bounds of fees of an asset.
+		///
+		/// Asset fee and protocol fee provided by `Config::Fee` are clamped to the bounds.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `bounds`: minimum and maximum fees. `None` removes fee bounds of the asset.
+		///
+		/// Emits `AssetFeeBoundsUpdated` event when successful.
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_fee_bounds())]
+		#[transactional]
+		pub fn set_asset_fee_bounds(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			bounds: Option<FeeBounds>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			match bounds.clone() {
+				Some(b) => {
+					ensure!(b.is_valid(), Error::<T>::InvalidFeeBounds);
+					AssetFeeBounds::<T>::insert(asset_id, b)
+				}
+				None => AssetFeeBounds::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::AssetFeeBoundsUpdated { asset_id, bounds });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert_ne!(
+				T::MinimumPoolLiquidity::get(),
+				Balance::zero(),
+				"Minimum pool liquidity is 0."
+			);
+			assert_ne!(
+				T::MinimumTradingLimit::get(),
+				Balance::zero(),
+				"Minimum trading limit is 0."
+			);
+			assert_ne!(
+				T::HdxAssetId::get(),
+				T::StableCoinAssetId::get(),
+				"Same Hdx asset id and stable asset id."
+			);
+			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
+			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Protocol account address
+	pub fn protocol_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_account_truncating()
+	}
+
+	/// Asset fee and protocol fee of an asset.
+	///
+	/// Fixed fees of the asset are returned if set. Otherwise fees provided by `Config::Fee` are clamped to fee bounds of the asset.
+	pub fn get_fee(asset_id: &T::AssetId) -> (Permill, Permill) {
+		if let Some(fees) = AssetFeeOverrides::<T>::get(asset_id) {
+			return fees;
+		}
+
+		let fees = T::Fee::get(asset_id);
+
+		match AssetFeeBounds::<T>::get(asset_id) {
+			Some(bounds) => bounds.clamp(fees),
+			None => fees,
+		}
+	}
+
+	/// Returns `FlashSwapInProgress` error if calls of a flash swap are being dispatched.
+	pub fn ensure_no_flash_swap() -> DispatchResult {
+		ensure!(!FlashSwapInProgress::<T>::get(), Error::<T>::FlashSwapInProgress);
+		Ok(())
+	}
+
+	/// Impermanent loss fund account address
+	pub fn il_fund_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_sub_account_truncating(*b"ilfund")
+	}
+
+	/// Protocol owned liquidity account address
+	///
+	/// Used to hold protocol owned liquidity while it is redeployed.
+	pub fn pol_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_sub_account_truncating(*b"pol")
+	}
+
+	/// Retrieve stable asset detail from the pool.
+	/// Return NoStableCoinInPool if stable asset is not yet in the pool.
+	fn stable_asset() -> Result<(Balance, Balance), DispatchError> {
+		let stable_asset = <Assets<T>>::get(T::StableCoinAssetId::get()).ok_or(Error::<T>::NoStableAssetInPool)?;
+		let stable_reserve = T::Currency::free_balance(T::StableCoinAssetId::get(), &Self::protocol_account());
+		Ok((stable_reserve, stable_asset.hub_reserve))
+	}
+
+	/// Retrieve state of asset from the pool and its pool balance
+	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
+		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+		let reserve = T::Currency::free_balance(asset_id, &Self::protocol_account());
+		Ok((state, reserve).into())
+	}
+
+	/// Set new state of asset.
+	/// This converts the new state into correct state type ( by removing the reserve)
+	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
+		<Assets<T>>::insert(asset_id, Into::<AssetState<Balance>>::into(new_state));
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
+	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
+		if hub_asset_amount > Balance::zero() {
+			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
+			native_subpool.hub_reserve = native_subpool
+				.hub_reserve
+				.checked_add(hub_asset_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+
+			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let delta_changes = AssetStateChange {
+				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
+				..Default::default()
+			};
+
+			let info: AssetInfo<T::AssetId, Balance> =
+				AssetInfo::new(T::HdxAssetId::get(), &hdx_state, &updated_hdx_state, &delta_changes);
+
+			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+		}
+		Ok(())
+	}
+
+	/// Update total hub asset liquidity and write new value to storage.
+	/// Update total issueance if AdjustSupply is specified.
+	#[require_transactional]
+	fn update_hub_asset_liquidity(delta_amount: &BalanceUpdate<Balance>) -> DispatchResult {
+		match delta_amount {
+			BalanceUpdate::Increase(amount) => {
+				T::Currency::deposit(T::HubAssetId::get(), &Self::protocol_account(), *amount)
+			}
+			BalanceUpdate::Decrease(amount) => {
+				T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), *amount)
+			}
+		}
+	}
+
+	/// Update imbalance with given delta_imbalance - increase or decrease
+	fn update_imbalance(delta_imbalance: BalanceUpdate<Balance>) -> DispatchResult {
+		<HubAssetImbalance<T>>::try_mutate(|current_imbalance| -> DispatchResult {
+			*current_imbalance = match delta_imbalance {
+				BalanceUpdate::Decrease(amount) => (*current_imbalance).sub(amount).ok_or(ArithmeticError::Overflow)?,
+				BalanceUpdate::Increase(amount) => (*current_imbalance).add(amount).ok_or(ArithmeticError::Overflow)?,
+			};
+
+			ensure!(current_imbalance.negative, Error::<T>::PositiveImbalance);
+
+			Ok(())
+		})
+	}
+
+	/// Calculate withdrawal fee of liquidity removed from the pool based on difference between spot price
+	/// and external oracle price of the asset.
+	fn calculate_withdrawal_fee(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+	) -> Result<FixedU128, DispatchError> {
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+
+		Ok(hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		))
+	}
+
+	/// Value of a position if all its liquidity was removed at current state of the pool.
+	///
+	/// Withdrawal fee and impermanent loss compensation are calculated as in `remove_liquidity`.
+	/// Impermanent loss is the difference between the position amount and the value of asset and hub asset
+	/// received, both in asset units at the current price.
+	pub fn position_value(position_id: T::PositionItemId) -> Result<PositionValue<T::AssetId, Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		let asset_id = position.asset_id;
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		let withdrawal_fee = Self::calculate_withdrawal_fee(asset_id, &asset_state)?;
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			position.shares,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let impermanent_loss = hydra_dx_math::omnipool::calculate_il_compensation(
+			&(&asset_state).into(),
+			&state_changes,
+			withdrawal_fee,
+			FixedU128::one(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let il_compensation =
+			Self::calculate_il_compensation(position_id, asset_id, &asset_state, &state_changes, withdrawal_fee)?;
+
+		Ok(PositionValue {
+			asset_id,
+			amount: position.amount,
+			shares: position.shares,
+			asset_amount: *state_changes.asset.delta_reserve,
+			hub_amount: state_changes.lp_hub_amount,
+			withdrawal_fee,
+			impermanent_loss,
+			il_compensation,
+		})
 	}
 
-	/// Update imbalance with given delta_imbalance - increase or decrease
-	fn update_imbalance(delta_imbalance: BalanceUpdate<Balance>) -> DispatchResult {
-		<HubAssetImbalance<T>>::try_mutate(|current_imbalance| -> DispatchResult {
-			*current_imbalance = match delta_imbalance {
-				BalanceUpdate::Decrease(amount) => (*current_imbalance).sub(amount).ok_or(ArithmeticError::Overflow)?,
-				BalanceUpdate::Increase(amount) => (*current_imbalance).add(amount).ok_or(ArithmeticError::Overflow)?,
-			};
+	/// Values of all positions owned by an account.
+	///
+	/// Positions which cannot be valued ( eg. positions of delisted assets ) are skipped.
+	pub fn account_positions_value(who: &T::AccountId) -> Vec<(T::PositionItemId, PositionValue<T::AssetId, Balance>)> {
+		T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), who)
+			.filter_map(|position_id| Self::position_value(position_id).ok().map(|value| (position_id, value)))
+			.collect()
+	}
 
-			ensure!(current_imbalance.negative, Error::<T>::PositiveImbalance);
+	/// Calculate state changes of removing given amount of protocol shares of an asset.
+	///
+	/// Protocol liquidity is removed as a position created at current spot price without withdrawal fee.
+	fn calculate_protocol_liquidity_removal(
+		asset_state: &AssetReserveState<Balance>,
+		shares: Balance,
+	) -> Result<LiquidityStateChange<Balance>, DispatchError> {
+		ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+		ensure!(
+			asset_state.protocol_shares >= shares,
+			Error::<T>::InsufficientProtocolShares
+		);
 
-			Ok(())
-		})
+		let position = hydra_dx_math::omnipool::types::Position {
+			amount: hydra_dx_math::omnipool::calculate_shares_reserve(asset_state.reserve, shares, asset_state.shares)
+				.ok_or(ArithmeticError::Overflow)?,
+			shares,
+			price: (asset_state.hub_reserve, asset_state.reserve),
+		};
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&asset_state.into(),
+			shares,
+			&position,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+			FixedU128::zero(),
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Remove given amount of protocol shares of an asset from the pool and transfer withdrawn asset to `dest`.
+	///
+	/// Returns amount of asset withdrawn.
+	#[require_transactional]
+	fn remove_protocol_liquidity(
+		origin: T::RuntimeOrigin,
+		asset_id: T::AssetId,
+		shares: Balance,
+		dest: &T::AccountId,
+	) -> Result<Balance, DispatchError> {
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		// Price cannot be manipulated when trading of the asset is frozen.
+		if !Self::is_trading_frozen(&asset_state) {
+			T::PriceBarrier::ensure_price(
+				&Self::pol_account(),
+				T::HubAssetId::get(),
+				asset_id,
+				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+			)
+			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
+		}
+
+		let state_changes = Self::calculate_protocol_liquidity_removal(&asset_state, shares)?;
+
+		let mut new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		new_asset_state.protocol_shares = new_asset_state
+			.protocol_shares
+			.checked_sub(shares)
+			.ok_or(Error::<T>::InsufficientProtocolShares)?;
+
+		T::Currency::transfer(
+			asset_id,
+			&Self::protocol_account(),
+			dest,
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::update_hub_asset_liquidity(
+			&state_changes
+				.asset
+				.delta_hub_reserve
+				.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
+				.ok_or(ArithmeticError::Overflow)?,
+		)?;
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset);
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok(*state_changes.asset.delta_reserve)
+	}
+
+	/// Add given amount of asset held by `from` to the pool as protocol owned liquidity.
+	///
+	/// Returns amount of protocol shares added.
+	#[require_transactional]
+	fn add_protocol_liquidity(
+		origin: T::RuntimeOrigin,
+		asset_id: T::AssetId,
+		amount: Balance,
+		from: &T::AccountId,
+	) -> Result<Balance, DispatchError> {
+		ensure!(
+			amount >= T::MinimumPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::ADD_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let mut new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let hub_reserve_ratio = FixedU128::checked_from_rational(
+			new_asset_state.hub_reserve,
+			current_hub_asset_liquidity
+				.checked_add(*state_changes.asset.delta_hub_reserve)
+				.ok_or(ArithmeticError::Overflow)?,
+		)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+		ensure!(
+			hub_reserve_ratio <= new_asset_state.weight_cap(),
+			Error::<T>::AssetWeightCapExceeded
+		);
+
+		new_asset_state.protocol_shares = new_asset_state
+			.protocol_shares
+			.checked_add(*state_changes.asset.delta_shares)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(
+			asset_id,
+			from,
+			&Self::protocol_account(),
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::update_hub_asset_liquidity(&state_changes.asset.delta_hub_reserve)?;
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		Self::ensure_tvl_cap()?;
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok(*state_changes.asset.delta_shares)
 	}
 
 	/// Calculate new tvl balance and ensure that it is below TVL Cap.
@@ -1632,6 +3128,11 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Check if trading of an asset is frozen - asset is allowed to be neither sold nor bought.
+	fn is_trading_frozen(asset_state: &AssetReserveState<Balance>) -> bool {
+		!asset_state.tradable.intersects(Tradability::SELL | Tradability::BUY)
+	}
+
 	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
 	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
 		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
@@ -1667,7 +3168,7 @@ impl<T: Config> Pallet<T> {
 
 		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
 
-		let (asset_fee, _) = T::Fee::get(&asset_out);
+		let (asset_fee, _) = Self::get_fee(&asset_out);
 
 		let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
 			&(&asset_state).into(),
@@ -1769,7 +3270,7 @@ impl<T: Config> Pallet<T> {
 
 		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
 
-		let (asset_fee, _) = T::Fee::get(&asset_out);
+		let (asset_fee, _) = Self::get_fee(&asset_out);
 
 		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
 			&(&asset_state).into(),
@@ -1841,35 +3342,197 @@ impl<T: Config> Pallet<T> {
 
 	/// Buy hub asset from the pool
 	/// Special handling of buy trade where asset out is Hub Asset.
-	fn buy_hub_asset(_who: &T::AccountId, _asset_in: T::AssetId, _amount: Balance, _limit: Balance) -> DispatchResult {
+	fn buy_hub_asset(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		amount: Balance,
+		limit: Balance,
+	) -> DispatchResult {
 		ensure!(
 			HubAssetTradability::<T>::get().contains(Tradability::BUY),
 			Error::<T>::NotAllowed
 		);
 
-		// Note: Currently not allowed at all, neither math is done for this case
-		// this is already ready when hub asset will be allowed to be bought from the pool
+		let asset_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::SELL), Error::<T>::NotAllowed);
+
+		ensure!(
+			amount
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+		let (_, protocol_fee) = Self::get_fee(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			protocol_fee,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			T::Currency::ensure_can_withdraw(asset_in, who, *state_changes.asset.delta_reserve).is_ok(),
+			Error::<T>::InsufficientBalance
+		);
+
+		ensure!(
+			*state_changes.asset.delta_reserve <= limit,
+			Error::<T>::SellLimitExceeded
+		);
+
+		ensure!(
+			*state_changes.asset.delta_reserve
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let new_asset_in_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(
+			asset_in,
+			who,
+			&Self::protocol_account(),
+			*state_changes.asset.delta_reserve,
+		)?;
+		T::Currency::transfer(T::HubAssetId::get(), &Self::protocol_account(), who, amount)?;
+
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_in, &asset_state, &new_asset_in_state, &state_changes.asset);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::set_asset_state(asset_in, new_asset_in_state);
+
+		Self::deposit_event(Event::BuyExecuted {
+			who: who.clone(),
+			asset_in,
+			asset_out: T::HubAssetId::get(),
+			amount_in: *state_changes.asset.delta_reserve,
+			amount_out: amount,
+			asset_fee_amount: state_changes.fee.asset_fee,
+			protocol_fee_amount: state_changes.fee.protocol_fee,
+		});
 
-		Err(Error::<T>::NotAllowed.into())
+		T::OmnipoolHooks::on_hub_asset_trade(origin.clone(), info)?;
+
+		// Protocol fee stays in the pool as hub asset of HDX subpool.
+		Self::update_hdx_subpool_hub_asset(origin, state_changes.fee.protocol_fee)?;
+
+		Ok(())
 	}
 
 	/// Swap asset for Hub Asset
 	/// Special handling of sell trade where asset out is Hub Asset.
 	fn sell_asset_for_hub_asset(
-		_who: &T::AccountId,
-		_asset_in: T::AssetId,
-		_amount: Balance,
-		_limit: Balance,
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		amount: Balance,
+		limit: Balance,
 	) -> DispatchResult {
 		ensure!(
 			HubAssetTradability::<T>::get().contains(Tradability::BUY),
 			Error::<T>::NotAllowed
 		);
 
-		// Note: Currently not allowed at all, neither math is done for this case
-		// this is already ready when hub asset will be allowed to be bought from the pool
+		let asset_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::SELL), Error::<T>::NotAllowed);
+
+		ensure!(
+			amount
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+		let (_, protocol_fee) = Self::get_fee(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_for_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			protocol_fee,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let amount_out = (*state_changes.asset.delta_hub_reserve)
+			.checked_sub(state_changes.fee.protocol_fee)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(amount_out >= limit, Error::<T>::BuyLimitNotReached);
+
+		ensure!(
+			*state_changes.asset.delta_hub_reserve
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let new_asset_in_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(asset_in, who, &Self::protocol_account(), amount)?;
+		T::Currency::transfer(T::HubAssetId::get(), &Self::protocol_account(), who, amount_out)?;
+
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_in, &asset_state, &new_asset_in_state, &state_changes.asset);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::set_asset_state(asset_in, new_asset_in_state);
+
+		Self::deposit_event(Event::SellExecuted {
+			who: who.clone(),
+			asset_in,
+			asset_out: T::HubAssetId::get(),
+			amount_in: amount,
+			amount_out,
+			asset_fee_amount: state_changes.fee.asset_fee,
+			protocol_fee_amount: state_changes.fee.protocol_fee,
+		});
+
+		T::OmnipoolHooks::on_hub_asset_trade(origin.clone(), info)?;
 
-		Err(Error::<T>::NotAllowed.into())
+		// Protocol fee stays in the pool as hub asset of HDX subpool.
+		Self::update_hdx_subpool_hub_asset(origin, state_changes.fee.protocol_fee)?;
+
+		Ok(())
 	}
 
 	/// Get hub asset balance of protocol account
@@ -1933,11 +3596,13 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
-	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
+	/// Diverts share of the fee into impermanent loss fund, calls `on_trade_fee` hook with the rest
+	/// and ensures that no more than the fee amount is transferred.
 	fn process_trade_fee(asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let account = Self::protocol_account();
 		let original_asset_reserve = T::Currency::free_balance(asset, &account);
-		let unused = T::OmnipoolHooks::on_trade_fee(account.clone(), asset, amount)?;
+		let il_fund_amount = Self::transfer_fee_to_il_fund(&account, asset, amount);
+		let unused = T::OmnipoolHooks::on_trade_fee(account.clone(), asset, amount.saturating_sub(il_fund_amount))?;
 		let asset_reserve = T::Currency::free_balance(asset, &account);
 		let updated_asset_reserve = asset_reserve.saturating_add(amount.saturating_sub(unused));
 		ensure!(
@@ -1946,4 +3611,67 @@ impl<T: Config> Pallet<T> {
 		);
 		Ok(())
 	}
+
+	/// Transfers share of trade fee of the asset into impermanent loss fund if protection of the asset is enabled.
+	/// Returns transferred amount.
+	fn transfer_fee_to_il_fund(fee_account: &T::AccountId, asset: T::AssetId, amount: Balance) -> Balance {
+		let fee_share = match ILProtection::<T>::get(asset) {
+			Some(params) => params.fee_share,
+			None => return Balance::zero(),
+		};
+
+		let il_fund_amount = fee_share.mul_floor(amount);
+
+		// Transfer can fail eg. when amount is below existential deposit of the fund account.
+		// Trade must not fail in that case - fee is processed as usual.
+		if il_fund_amount.is_zero()
+			|| T::Currency::transfer(asset, fee_account, &Self::il_fund_account(), il_fund_amount).is_err()
+		{
+			return Balance::zero();
+		}
+
+		il_fund_amount
+	}
+
+	/// Calculate impermanent loss compensation of LP removing liquidity of a position.
+	///
+	/// Returns zero if protection of the asset is not enabled or creation block of the position is not known.
+	/// Compensation is capped by balance of impermanent loss fund.
+	fn calculate_il_compensation(
+		position_id: T::PositionItemId,
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+		state_changes: &LiquidityStateChange<Balance>,
+		withdrawal_fee: FixedU128,
+	) -> Result<Balance, DispatchError> {
+		let (params, created_at) = match (
+			ILProtection::<T>::get(asset_id),
+			PositionCreatedAt::<T>::get(position_id),
+		) {
+			(Some(params), Some(created_at)) => (params, created_at),
+			_ => return Ok(Balance::zero()),
+		};
+
+		let held_period = frame_system::Pallet::<T>::block_number().saturating_sub(created_at);
+
+		let vesting_fraction = hydra_dx_math::omnipool::calculate_il_vesting_fraction(
+			held_period.saturated_into(),
+			params.min_holding_period.saturated_into(),
+			params.vesting_period.saturated_into(),
+		);
+
+		if vesting_fraction.is_zero() {
+			return Ok(Balance::zero());
+		}
+
+		let compensation = hydra_dx_math::omnipool::calculate_il_compensation(
+			&asset_state.into(),
+			state_changes,
+			withdrawal_fee,
+			vesting_fraction,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(compensation.min(T::Currency::free_balance(asset_id, &Self::il_fund_account())))
+	}
 }
@@ -32,7 +32,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 			let current_hub_asset_liquidity =
 				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
 
-			let (asset_fee, _) = T::Fee::get(&asset_out);
+			let (asset_fee, _) = Self::get_fee(&asset_out);
 
 			let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
 				&(&asset_out_state).into(),
@@ -49,7 +49,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 			return Ok(*state_changes.asset.delta_reserve);
 		}
 
-		let (asset_fee, protocol_fee) = T::Fee::get(&asset_out);
+		let (asset_fee, protocol_fee) = Self::get_fee(&asset_out);
 
 		let asset_in_state = Self::load_asset_state(asset_in).map_err(ExecutorError::Error)?;
 		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
@@ -85,7 +85,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 			let current_hub_asset_liquidity =
 				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
 
-			let (asset_fee, _) = T::Fee::get(&asset_out);
+			let (asset_fee, _) = Self::get_fee(&asset_out);
 
 			let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
 				&(&asset_out_state).into(),
@@ -104,7 +104,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 
 		let asset_in_state = Self::load_asset_state(asset_in).map_err(ExecutorError::Error)?;
 
-		let (asset_fee, protocol_fee) = T::Fee::get(&asset_in);
+		let (asset_fee, protocol_fee) = Self::get_fee(&asset_in);
 
 		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
 			&(&asset_in_state).into(),
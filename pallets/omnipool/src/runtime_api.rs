@@ -0,0 +1,21 @@
+//! Runtime API definition of Omnipool pallet.
+
+use crate::types::{Balance, PositionValue};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query value of Omnipool LP positions.
+	pub trait OmnipoolApi<AccountId, AssetId, PositionItemId>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		PositionItemId: Codec,
+	{
+		/// Value of a position if all its liquidity was removed at current state of the pool.
+		fn position_value(position_id: PositionItemId) -> Option<PositionValue<AssetId, Balance>>;
+
+		/// Values of all positions owned by an account.
+		fn account_positions_value(who: AccountId) -> Vec<(PositionItemId, PositionValue<AssetId, Balance>)>;
+	}
+}
@@ -0,0 +1,203 @@
+use super::*;
+use crate::types::FeeBounds;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 100 * ONE))
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_percent(2))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+fn bounds(min_asset_fee: u32, max_asset_fee: u32, min_protocol_fee: u32, max_protocol_fee: u32) -> FeeBounds {
+	FeeBounds {
+		min_asset_fee: Permill::from_percent(min_asset_fee),
+		max_asset_fee: Permill::from_percent(max_asset_fee),
+		min_protocol_fee: Permill::from_percent(min_protocol_fee),
+		max_protocol_fee: Permill::from_percent(max_protocol_fee),
+	}
+}
+
+#[test]
+fn get_fee_should_return_provided_fees_when_no_override_or_bounds_are_set() {
+	ext().execute_with(|| {
+		assert_eq!(
+			Omnipool::get_fee(&ASSET_ID),
+			(Permill::from_percent(1), Permill::from_percent(2))
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_override_should_pin_fees_of_asset() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let fees = (Permill::from_rational(5u32, 10_000u32), Permill::zero());
+
+		assert_ok!(Omnipool::set_asset_fee_override(RuntimeOrigin::root(), DAI, Some(fees)));
+
+		assert_eq!(Omnipool::asset_fee_override(DAI), Some(fees));
+		assert_eq!(Omnipool::get_fee(&DAI), fees);
+		assert_eq!(
+			Omnipool::get_fee(&ASSET_ID),
+			(Permill::from_percent(1), Permill::from_percent(2))
+		);
+
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::AssetFeeOverrideUpdated {
+				asset_id: DAI,
+				fees: Some(fees),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_override_should_remove_override_when_none_is_given() {
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_fee_override(
+			RuntimeOrigin::root(),
+			DAI,
+			Some((Permill::zero(), Permill::zero()))
+		));
+		assert_ok!(Omnipool::set_asset_fee_override(RuntimeOrigin::root(), DAI, None));
+
+		assert_eq!(Omnipool::asset_fee_override(DAI), None);
+		assert_eq!(
+			Omnipool::get_fee(&DAI),
+			(Permill::from_percent(1), Permill::from_percent(2))
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_override_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_asset_fee_override(RuntimeOrigin::root(), 1234, Some((Permill::zero(), Permill::zero()))),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_override_should_fail_when_origin_is_not_authority() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_asset_fee_override(
+				RuntimeOrigin::signed(LP1),
+				DAI,
+				Some((Permill::zero(), Permill::zero()))
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_bounds_should_clamp_provided_fees() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Asset fee is raised to the minimum, protocol fee is lowered to the maximum
+		assert_ok!(Omnipool::set_asset_fee_bounds(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Some(bounds(3, 5, 0, 1))
+		));
+
+		assert_eq!(
+			Omnipool::get_fee(&ASSET_ID),
+			(Permill::from_percent(3), Permill::from_percent(1))
+		);
+
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::AssetFeeBoundsUpdated {
+				asset_id: ASSET_ID,
+				bounds: Some(bounds(3, 5, 0, 1)),
+			}
+			.into(),
+		);
+
+		// Fees within bounds are not changed
+		assert_ok!(Omnipool::set_asset_fee_bounds(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Some(bounds(0, 5, 0, 5))
+		));
+
+		assert_eq!(
+			Omnipool::get_fee(&ASSET_ID),
+			(Permill::from_percent(1), Permill::from_percent(2))
+		);
+	});
+}
+
+#[test]
+fn fee_override_should_take_precedence_over_fee_bounds() {
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_fee_bounds(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Some(bounds(3, 5, 3, 5))
+		));
+		assert_ok!(Omnipool::set_asset_fee_override(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Some((Permill::zero(), Permill::zero()))
+		));
+
+		assert_eq!(Omnipool::get_fee(&ASSET_ID), (Permill::zero(), Permill::zero()));
+	});
+}
+
+#[test]
+fn set_asset_fee_bounds_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_asset_fee_bounds(RuntimeOrigin::root(), ASSET_ID, Some(bounds(5, 3, 0, 1))),
+			Error::<Test>::InvalidFeeBounds
+		);
+		assert_noop!(
+			Omnipool::set_asset_fee_bounds(RuntimeOrigin::root(), ASSET_ID, Some(bounds(0, 1, 2, 1))),
+			Error::<Test>::InvalidFeeBounds
+		);
+	});
+}
+
+#[test]
+fn sell_should_use_fee_override_of_asset_out() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Omnipool::set_asset_fee_override(
+			RuntimeOrigin::root(),
+			DAI,
+			Some((Permill::zero(), Permill::zero()))
+		));
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, DAI, 10 * ONE, 0));
+
+		assert!(System::events().into_iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Omnipool(crate::Event::SellExecuted {
+				asset_in: ASSET_ID,
+				asset_out: DAI,
+				asset_fee_amount: 0,
+				protocol_fee_amount: 0,
+				..
+			})
+		)));
+	});
+}
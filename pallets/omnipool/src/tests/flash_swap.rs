@@ -0,0 +1,279 @@
+use super::*;
+use frame_support::assert_noop;
+use sp_runtime::Permill;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn transfer_call(dest: AccountId, currency_id: AssetId, amount: Balance) -> RuntimeCall {
+	RuntimeCall::Tokens(orml_tokens::Call::transfer {
+		dest,
+		currency_id,
+		amount,
+	})
+}
+
+fn default_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+#[test]
+fn flash_swap_should_result_in_same_state_as_buy_when_no_calls_given() {
+	let mut buy_result = None;
+
+	default_ext().execute_with(|| {
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP3),
+			DAI,
+			ASSET_ID,
+			50 * ONE,
+			u128::MAX
+		));
+
+		buy_result = Some((
+			Tokens::free_balance(ASSET_ID, &LP3),
+			Tokens::free_balance(DAI, &LP3),
+			Omnipool::load_asset_state(ASSET_ID).unwrap(),
+			Omnipool::load_asset_state(DAI).unwrap(),
+			HubAssetImbalance::<Test>::get(),
+		));
+	});
+
+	default_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Omnipool::flash_swap(
+			RuntimeOrigin::signed(LP3),
+			DAI,
+			50 * ONE,
+			ASSET_ID,
+			vec![]
+		));
+
+		let (asset_balance, dai_balance, asset_state, dai_state, imbalance) = buy_result.unwrap();
+
+		assert_eq!(Tokens::free_balance(ASSET_ID, &LP3), asset_balance);
+		assert_eq!(Tokens::free_balance(DAI, &LP3), dai_balance);
+		assert_eq!(Omnipool::load_asset_state(ASSET_ID).unwrap(), asset_state);
+		assert_eq!(Omnipool::load_asset_state(DAI).unwrap(), dai_state);
+		assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+
+		assert!(!FlashSwapInProgress::<Test>::get());
+
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::FlashSwapExecuted {
+				who: LP3,
+				asset_in: ASSET_ID,
+				asset_out: DAI,
+				amount_in: 100 * ONE - asset_balance,
+				amount_out: 50 * ONE,
+				asset_fee_amount: 0,
+				protocol_fee_amount: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_work_when_calls_spend_asset_out() {
+	default_ext().execute_with(|| {
+		let pool_asset_balance = Tokens::free_balance(ASSET_ID, &Omnipool::protocol_account());
+
+		assert_ok!(Omnipool::flash_swap(
+			RuntimeOrigin::signed(LP3),
+			DAI,
+			50 * ONE,
+			ASSET_ID,
+			vec![transfer_call(LP1, DAI, 50 * ONE)]
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &LP3), 0);
+		assert_eq!(Tokens::free_balance(DAI, &LP1), 50 * ONE);
+
+		let amount_in = 100 * ONE - Tokens::free_balance(ASSET_ID, &LP3);
+		assert!(amount_in > 0);
+		assert_eq!(
+			Tokens::free_balance(ASSET_ID, &Omnipool::protocol_account()),
+			pool_asset_balance + amount_in
+		);
+		assert_eq!(
+			Omnipool::load_asset_state(ASSET_ID).unwrap().reserve,
+			pool_asset_balance + amount_in
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_asset_in_is_not_repaid() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::flash_swap(RuntimeOrigin::signed(LP3), DAI, 50 * ONE, ASSET_ID, vec![]),
+				Error::<Test>::FlashSwapNotRepaid
+			);
+		});
+}
+
+#[test]
+fn flash_swap_should_fail_when_calls_transfer_asset_in_away() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::flash_swap(
+				RuntimeOrigin::signed(LP3),
+				DAI,
+				50 * ONE,
+				ASSET_ID,
+				vec![transfer_call(LP1, ASSET_ID, 100 * ONE)]
+			),
+			Error::<Test>::FlashSwapNotRepaid
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_revert_when_call_fails() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::flash_swap(
+				RuntimeOrigin::signed(LP3),
+				DAI,
+				50 * ONE,
+				ASSET_ID,
+				vec![transfer_call(LP1, DAI, 51 * ONE)]
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_omnipool_is_traded_in_calls() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::flash_swap(
+				RuntimeOrigin::signed(LP3),
+				DAI,
+				50 * ONE,
+				ASSET_ID,
+				vec![RuntimeCall::Omnipool(Call::sell {
+					asset_in: DAI,
+					asset_out: ASSET_ID,
+					amount: 50 * ONE,
+					min_buy_amount: 0,
+				})]
+			),
+			Error::<Test>::FlashSwapInProgress
+		);
+
+		assert_noop!(
+			Omnipool::flash_swap(
+				RuntimeOrigin::signed(LP3),
+				DAI,
+				50 * ONE,
+				ASSET_ID,
+				vec![RuntimeCall::Omnipool(Call::add_liquidity {
+					asset: ASSET_ID,
+					amount: 50 * ONE,
+				})]
+			),
+			Error::<Test>::FlashSwapInProgress
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_positions_are_changed_in_calls() {
+	default_ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP3), ASSET_ID, 50 * ONE));
+
+		let calls = vec![
+			RuntimeCall::Omnipool(Call::sacrifice_position { position_id }),
+			RuntimeCall::Omnipool(Call::claim_delisted_position { position_id }),
+			RuntimeCall::Omnipool(Call::merge_positions {
+				asset_id: ASSET_ID,
+				position_ids: vec![position_id, position_id + 1],
+			}),
+			RuntimeCall::Omnipool(Call::split_position {
+				position_id,
+				shares: ONE,
+			}),
+		];
+
+		for call in calls {
+			assert_noop!(
+				Omnipool::flash_swap(RuntimeOrigin::signed(LP3), DAI, 50 * ONE, ASSET_ID, vec![call]),
+				Error::<Test>::FlashSwapInProgress
+			);
+		}
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_too_many_calls_given() {
+	default_ext().execute_with(|| {
+		let calls = (0..=MAX_FLASH_SWAP_CALLS)
+			.map(|_| transfer_call(LP1, DAI, ONE))
+			.collect::<Vec<_>>();
+
+		assert_noop!(
+			Omnipool::flash_swap(RuntimeOrigin::signed(LP3), DAI, 50 * ONE, ASSET_ID, calls),
+			Error::<Test>::TooManyCalls
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_hub_asset_is_traded() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::flash_swap(RuntimeOrigin::signed(LP3), LRNA, 50 * ONE, ASSET_ID, vec![]),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Omnipool::flash_swap(RuntimeOrigin::signed(LP3), DAI, 50 * ONE, LRNA, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_same_asset_is_traded() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::flash_swap(RuntimeOrigin::signed(LP3), ASSET_ID, 50 * ONE, ASSET_ID, vec![]),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn flash_swap_should_fail_when_prices_differ() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_max_allowed_price_difference(Permill::from_percent(1))
+		.with_external_price_adjustment((3, 100, false))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::flash_swap(RuntimeOrigin::signed(LP3), DAI, 50 * ONE, ASSET_ID, vec![]),
+				Error::<Test>::PriceDifferenceTooHigh
+			);
+		});
+}
@@ -0,0 +1,257 @@
+use super::*;
+use crate::types::Tradability;
+use frame_support::assert_noop;
+use sp_runtime::Permill;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn ext(protocol_fee: Permill) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 100 * ONE))
+		.with_protocol_fee(protocol_fee)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+fn allow_hub_asset_trades(state: Tradability) {
+	assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), LRNA, state));
+}
+
+/// Sells hub asset to the pool so there is negative imbalance to cover hub asset bought from the pool.
+fn sell_hub_asset_to_pool(amount: Balance) {
+	assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, HDX, amount, 0));
+}
+
+fn total_hub_reserve() -> Balance {
+	<Assets<Test>>::iter().fold(0, |acc, v| acc + v.1.hub_reserve)
+}
+
+#[test]
+fn sell_for_hub_asset_should_work_when_hub_asset_can_be_bought() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let lrna_balance = Tokens::free_balance(LRNA, &LP3);
+		let imbalance = HubAssetImbalance::<Test>::get();
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let amount_out = Tokens::free_balance(LRNA, &LP3) - lrna_balance;
+
+		assert!(amount_out > 0);
+		assert_eq!(Tokens::free_balance(ASSET_ID, &LP3), 90 * ONE);
+		assert_eq!(new_asset_state.reserve, asset_state.reserve + 10 * ONE);
+		assert_eq!(new_asset_state.hub_reserve, asset_state.hub_reserve - amount_out);
+
+		assert_eq!(
+			Tokens::free_balance(LRNA, &Omnipool::protocol_account()),
+			total_hub_reserve()
+		);
+
+		let new_imbalance = HubAssetImbalance::<Test>::get();
+		assert!(new_imbalance.negative);
+		assert!(new_imbalance.value < imbalance.value);
+	});
+}
+
+#[test]
+fn sell_for_hub_asset_should_add_protocol_fee_to_hdx_subpool() {
+	ext(Permill::from_percent(1)).execute_with(|| {
+		System::set_block_number(1);
+
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+		let lrna_balance = Tokens::free_balance(LRNA, &LP3);
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let new_hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+		let amount_out = Tokens::free_balance(LRNA, &LP3) - lrna_balance;
+		let protocol_fee = new_hdx_state.hub_reserve - hdx_state.hub_reserve;
+
+		assert!(protocol_fee > 0);
+		assert_eq!(
+			new_asset_state.hub_reserve,
+			asset_state.hub_reserve - amount_out - protocol_fee
+		);
+		assert_eq!(
+			Tokens::free_balance(LRNA, &Omnipool::protocol_account()),
+			total_hub_reserve()
+		);
+
+		expect_sell_executed(amount_out, protocol_fee);
+	});
+}
+
+fn expect_sell_executed(amount_out: Balance, protocol_fee_amount: Balance) {
+	assert!(System::events().into_iter().any(|record| record.event
+		== RuntimeEvent::Omnipool(crate::Event::SellExecuted {
+			who: LP3,
+			asset_in: ASSET_ID,
+			asset_out: LRNA,
+			amount_in: 10 * ONE,
+			amount_out,
+			asset_fee_amount: 0,
+			protocol_fee_amount,
+		})));
+}
+
+#[test]
+fn sell_for_hub_asset_should_fail_when_min_limit_is_not_reached() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 100 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn sell_for_hub_asset_should_fail_when_imbalance_would_become_positive() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0),
+			Error::<Test>::PositiveImbalance
+		);
+	});
+}
+
+#[test]
+fn buy_hub_asset_should_work_when_hub_asset_can_be_bought() {
+	ext(Permill::from_percent(1)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+		let lrna_balance = Tokens::free_balance(LRNA, &LP3);
+		let imbalance = HubAssetImbalance::<Test>::get();
+
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP3),
+			LRNA,
+			ASSET_ID,
+			5 * ONE,
+			100 * ONE
+		));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let new_hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+		let amount_in = 100 * ONE - Tokens::free_balance(ASSET_ID, &LP3);
+		let protocol_fee = new_hdx_state.hub_reserve - hdx_state.hub_reserve;
+
+		assert_eq!(Tokens::free_balance(LRNA, &LP3), lrna_balance + 5 * ONE);
+		assert!(protocol_fee > 0);
+		assert_eq!(new_asset_state.reserve, asset_state.reserve + amount_in);
+		assert_eq!(
+			new_asset_state.hub_reserve,
+			asset_state.hub_reserve - 5 * ONE - protocol_fee
+		);
+		assert_eq!(
+			Tokens::free_balance(LRNA, &Omnipool::protocol_account()),
+			total_hub_reserve()
+		);
+
+		let new_imbalance = HubAssetImbalance::<Test>::get();
+		assert!(new_imbalance.negative);
+		assert!(new_imbalance.value < imbalance.value);
+	});
+}
+
+#[test]
+fn buy_hub_asset_should_fail_when_max_limit_is_exceeded() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP3), LRNA, ASSET_ID, 5 * ONE, ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_hub_asset_should_fail_when_imbalance_would_become_positive() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP3), LRNA, ASSET_ID, 5 * ONE, 100 * ONE),
+			Error::<Test>::PositiveImbalance
+		);
+	});
+}
+
+#[test]
+fn hub_asset_trades_should_be_allowed_per_direction() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		// Hub asset can be sold to the pool only
+		allow_hub_asset_trades(Tradability::SELL);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP3), LRNA, ASSET_ID, 5 * ONE, 100 * ONE),
+			Error::<Test>::NotAllowed
+		);
+
+		// Hub asset can be bought from the pool only
+		allow_hub_asset_trades(Tradability::BUY);
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, HDX, ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0));
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP3),
+			LRNA,
+			ASSET_ID,
+			ONE,
+			100 * ONE
+		));
+	});
+}
+
+#[test]
+fn hub_asset_trades_should_fail_when_asset_cannot_be_sold() {
+	ext(Permill::from_percent(0)).execute_with(|| {
+		allow_hub_asset_trades(Tradability::SELL | Tradability::BUY);
+		sell_hub_asset_to_pool(50 * ONE);
+
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Tradability::BUY
+		));
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, LRNA, 10 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Omnipool::buy(RuntimeOrigin::signed(LP3), LRNA, ASSET_ID, 5 * ONE, 100 * ONE),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
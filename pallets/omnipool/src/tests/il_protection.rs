@@ -0,0 +1,367 @@
+use super::*;
+use crate::types::ILProtectionParams;
+use frame_support::assert_noop;
+use sp_runtime::Permill;
+
+const ASSET_ID: AssetId = 1_000;
+const IL_FUND: Balance = 1_000 * ONE;
+
+fn il_params(fee_share: Permill) -> ILProtectionParams<u64> {
+	ILProtectionParams {
+		fee_share,
+		min_holding_period: 10,
+		vesting_period: 100,
+	}
+}
+
+/// Adds liquidity in block 1, lowers price of the asset by selling it and removes the liquidity after `blocks_held` blocks.
+/// Returns ( amount of asset received by LP, balance of impermanent loss fund ).
+fn remove_liquidity_after_price_decrease(
+	blocks_held: u64,
+	params: Option<ILProtectionParams<u64>>,
+) -> (Balance, Balance) {
+	let mut result = (0, 0);
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((Omnipool::il_fund_account(), ASSET_ID, IL_FUND))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			if params.is_some() {
+				assert_ok!(Omnipool::set_il_protection(RuntimeOrigin::root(), ASSET_ID, params));
+			}
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, DAI, 1000 * ONE, 0));
+
+			System::set_block_number(1 + blocks_held);
+
+			let balance_before = Tokens::free_balance(ASSET_ID, &LP1);
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+
+			assert!(Omnipool::position_created_at(position_id).is_none());
+
+			result = (
+				Tokens::free_balance(ASSET_ID, &LP1) - balance_before,
+				Tokens::free_balance(ASSET_ID, &Omnipool::il_fund_account()),
+			);
+		});
+
+	result
+}
+
+#[test]
+fn set_il_protection_should_work_when_asset_is_in_pool() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let params = il_params(Permill::from_percent(10));
+
+			assert_ok!(Omnipool::set_il_protection(
+				RuntimeOrigin::root(),
+				ASSET_ID,
+				Some(params.clone())
+			));
+
+			assert_eq!(Omnipool::il_protection(ASSET_ID), Some(params.clone()));
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::ILProtectionUpdated {
+					asset_id: ASSET_ID,
+					params: Some(params),
+				}
+				.into(),
+			);
+
+			assert_ok!(Omnipool::set_il_protection(RuntimeOrigin::root(), ASSET_ID, None));
+
+			assert!(Omnipool::il_protection(ASSET_ID).is_none());
+		});
+}
+
+#[test]
+fn set_il_protection_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::set_il_protection(
+					RuntimeOrigin::root(),
+					ASSET_ID,
+					Some(il_params(Permill::from_percent(10)))
+				),
+				Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn set_il_protection_should_fail_when_called_by_non_authority_origin() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::set_il_protection(
+					RuntimeOrigin::signed(LP1),
+					ASSET_ID,
+					Some(il_params(Permill::from_percent(10)))
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn trade_fee_should_be_diverted_to_il_fund_when_protection_is_enabled() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 5000 * ONE))
+		.with_asset_fee(Permill::from_percent(10))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::set_il_protection(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(il_params(Permill::from_percent(50)))
+			));
+
+			let hdx_reserve_before = Tokens::free_balance(HDX, &Omnipool::protocol_account());
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, HDX, 100 * ONE, 0));
+
+			let (amount_out, asset_fee_amount) = System::events()
+				.into_iter()
+				.find_map(|record| match record.event {
+					RuntimeEvent::Omnipool(crate::Event::SellExecuted {
+						amount_out,
+						asset_fee_amount,
+						..
+					}) => Some((amount_out, asset_fee_amount)),
+					_ => None,
+				})
+				.unwrap();
+
+			assert!(asset_fee_amount > 0);
+
+			let il_fund_amount = Permill::from_percent(50).mul_floor(asset_fee_amount);
+
+			assert_balance!(Omnipool::il_fund_account(), HDX, il_fund_amount);
+			assert_balance!(
+				Omnipool::protocol_account(),
+				HDX,
+				hdx_reserve_before - amount_out - il_fund_amount
+			);
+		});
+}
+
+#[test]
+fn trade_fee_should_not_be_diverted_when_protection_is_not_enabled() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 5000 * ONE))
+		.with_asset_fee(Permill::from_percent(10))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, HDX, 100 * ONE, 0));
+
+			assert_balance!(Omnipool::il_fund_account(), HDX, 0);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_compensate_il_when_protection_is_enabled() {
+	let (received_unprotected, fund_unprotected) = remove_liquidity_after_price_decrease(100, None);
+	let (received, fund) = remove_liquidity_after_price_decrease(100, Some(il_params(Permill::zero())));
+
+	assert_eq!(fund_unprotected, IL_FUND);
+
+	let compensation = IL_FUND - fund;
+	assert!(compensation > 0);
+	assert_eq!(received, received_unprotected + compensation);
+
+	// LP is compensated to the amount of asset provided
+	assert_eq!(received, 400 * ONE);
+}
+
+#[test]
+fn remove_liquidity_should_compensate_vested_fraction_of_il_when_vesting_period_not_reached() {
+	let (_, fund_full) = remove_liquidity_after_price_decrease(100, Some(il_params(Permill::zero())));
+	let (_, fund_half) = remove_liquidity_after_price_decrease(50, Some(il_params(Permill::zero())));
+
+	assert_eq!(IL_FUND - fund_half, (IL_FUND - fund_full) / 2);
+}
+
+#[test]
+fn remove_liquidity_should_not_compensate_il_when_min_holding_period_not_reached() {
+	let (received_unprotected, _) = remove_liquidity_after_price_decrease(9, None);
+	let (received, fund) = remove_liquidity_after_price_decrease(9, Some(il_params(Permill::zero())));
+
+	assert_eq!(fund, IL_FUND);
+	assert_eq!(received, received_unprotected);
+}
+
+#[test]
+fn remove_liquidity_should_emit_event_when_il_is_compensated() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((Omnipool::il_fund_account(), ASSET_ID, IL_FUND))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::set_il_protection(
+				RuntimeOrigin::root(),
+				ASSET_ID,
+				Some(il_params(Permill::zero()))
+			));
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, DAI, 1000 * ONE, 0));
+
+			System::set_block_number(101);
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+
+			let compensation = IL_FUND - Tokens::free_balance(ASSET_ID, &Omnipool::il_fund_account());
+
+			assert!(System::events().into_iter().any(|record| record.event
+				== RuntimeEvent::Omnipool(crate::Event::ILCompensationPaid {
+					who: LP1,
+					position_id,
+					asset_id: ASSET_ID,
+					amount: compensation,
+				})));
+		});
+}
+
+#[test]
+fn il_compensation_should_be_capped_by_il_fund_balance() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((Omnipool::il_fund_account(), ASSET_ID, ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::set_il_protection(
+				RuntimeOrigin::root(),
+				ASSET_ID,
+				Some(il_params(Permill::zero()))
+			));
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET_ID, DAI, 1000 * ONE, 0));
+
+			System::set_block_number(101);
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+
+			assert_balance!(Omnipool::il_fund_account(), ASSET_ID, 0);
+		});
+}
+
+#[test]
+fn split_position_should_keep_creation_block_of_original_position() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+			System::set_block_number(10);
+
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			assert_eq!(Omnipool::position_created_at(position_id), Some(1));
+			assert_eq!(Omnipool::position_created_at(new_position_id), Some(1));
+		});
+}
+
+#[test]
+fn merge_positions_should_store_share_weighted_creation_block() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(10);
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 100 * ONE));
+
+			System::set_block_number(20);
+
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 300 * ONE));
+
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				ASSET_ID,
+				vec![position_id, second_position_id]
+			));
+
+			assert_eq!(Omnipool::position_created_at(position_id), Some(17));
+			assert!(Omnipool::position_created_at(second_position_id).is_none());
+		});
+}
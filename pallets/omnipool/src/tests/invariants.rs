@@ -1,11 +1,15 @@
 use super::*;
+use crate::types::Tradability;
 use crate::{AssetReserveState, FixedU128};
 use frame_support::assert_noop;
+use hydra_dx_math::omnipool::types::I129;
 use primitive_types::U256;
 use proptest::prelude::*;
 
 pub const ONE: Balance = 1_000_000_000_000;
 pub const TOLERANCE: Balance = 1_000_000_000;
+// Rounding of liquidity removal calculated separately for each merged position
+const MERGE_TOLERANCE: Balance = 100;
 
 const BALANCE_RANGE: (Balance, Balance) = (100_000 * ONE, 10_000_000 * ONE);
 
@@ -57,6 +61,11 @@ fn assert_asset_invariant(
 	let invariant = FixedU128::from((s1_u128, ONE)) / FixedU128::from((s2_u128, ONE));
 	assert_eq_approx!(invariant, FixedU128::from(1u128), tolerance, desc);
 }
+
+// Hub asset value of `amount` of an asset at the spot price of given state
+fn hub_asset_value(amount: Balance, state: &AssetReserveState<Balance>) -> U256 {
+	U256::from(amount) * U256::from(state.hub_reserve) / U256::from(state.reserve)
+}
 fn fee() -> impl Strategy<Value = Permill> {
 	// Allow values between 0.001 and 0.1
 	(
@@ -1213,3 +1222,544 @@ proptest! {
 			});
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn remove_token_invariants(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		token_3 in pool_token(300),
+		token_4 in pool_token(400),
+		asset_fee in fee(),
+		protocol_fee in fee(),
+		buy_amount in trade_amount(),
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let lp3: u64 = 300;
+		let lp4: u64 = 400;
+		let seller: u64 = 500;
+		let buyer: u64 = 600;
+		let beneficiary: u64 = 700;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(lp3, 300, token_3.amount + 2 * ONE),
+				(lp4, 400, token_4.amount + 2 * ONE),
+				(seller, 200, amount + 200 * ONE),
+				(buyer, DAI, 200_000_000 * ONE),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_registered_asset(300)
+			.with_registered_asset(400)
+			.with_asset_fee(asset_fee)
+			.with_asset_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.with_token(token_3.asset_id, token_3.price, lp3, token_3.amount)
+			.with_token(token_4.asset_id, token_4.price, lp4, token_4.amount)
+			.build()
+			.execute_with(|| {
+				let lp2_position_id = 1;
+				let seller_position_id = <NextPositionId<Test>>::get();
+				assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(seller), 200, amount));
+
+				// Let's do a trade so imbalance and price changes
+				assert_ok!(Omnipool::buy(RuntimeOrigin::signed(buyer), 200, DAI, buy_amount, Balance::max_value()));
+
+				assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(lp2), lp2_position_id));
+				assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), 200, Tradability::FROZEN));
+
+				let old_imbalance = <HubAssetImbalance<Test>>::get();
+				let old_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+				let old_state_200 = Omnipool::load_asset_state(200).unwrap();
+				let old_state_100 = Omnipool::load_asset_state(100).unwrap();
+
+				assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 200, beneficiary));
+
+				let new_imbalance = <HubAssetImbalance<Test>>::get();
+				let new_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+				// Hub reserve of removed asset is burned
+				assert_eq!(old_hub_liquidity - new_hub_liquidity, old_state_200.hub_reserve);
+				assert_eq!(new_hub_liquidity, sum_asset_hub_liquidity());
+
+				assert_eq_approx!( FixedU128::from((old_imbalance.value, old_hub_liquidity)),
+								   FixedU128::from((new_imbalance.value, new_hub_liquidity)),
+								   FixedU128::from_float(0.000000001),
+								   "L/Q ratio changed after remove token"
+				);
+
+				// Other assets are not affected
+				assert_eq!(Omnipool::load_asset_state(100).unwrap(), old_state_100);
+
+				// Whole reserve is distributed between protocol and remaining LPs
+				let delisted_state = Omnipool::delisted_assets(200).unwrap();
+				assert_eq!(Tokens::free_balance(200, &beneficiary) + delisted_state.reserve, old_state_200.reserve);
+				assert_eq!(Tokens::free_balance(200, &Omnipool::protocol_account()), delisted_state.reserve);
+
+				// Price of remaining liquidity is the frozen price
+				assert_eq_approx!(FixedU128::from((delisted_state.hub_reserve, delisted_state.reserve)),
+								   old_state_200.price().unwrap(),
+								   FixedU128::from_float(0.000000001),
+								   "Price of delisted asset changed"
+				);
+
+				let seller_balance = Tokens::free_balance(200, &seller);
+				let beneficiary_balance = Tokens::free_balance(200, &beneficiary);
+				let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+				assert_ok!(Omnipool::claim_delisted_position(RuntimeOrigin::signed(seller), seller_position_id));
+
+				// Seller is the only remaining LP, rest of the reserve is owned by protocol
+				assert_eq!(Tokens::free_balance(200, &seller) - seller_balance
+					+ Tokens::free_balance(200, &beneficiary) - beneficiary_balance, delisted_state.reserve);
+				assert_eq!(Tokens::free_balance(200, &Omnipool::protocol_account()), 0);
+				assert!(Omnipool::delisted_assets(200).is_none());
+
+				// Hub liquidity of the pool is not affected by claim
+				assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), hub_liquidity);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn merge_and_split_positions_invariants(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		asset_fee in fee(),
+		protocol_fee in fee(),
+		buy_amount in trade_amount(),
+		split_percent in 1u128..100u128,
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let seller: u64 = 500;
+		let buyer: u64 = 600;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(seller, 200, 2 * amount + 200 * ONE),
+				(buyer, DAI, 200_000_000 * ONE),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_asset_fee(asset_fee)
+			.with_asset_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.build()
+			.execute_with(|| {
+				let first_position_id = <NextPositionId<Test>>::get();
+				assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(seller), 200, amount));
+
+				// Positions with the same price
+				let first_position = Omnipool::positions(first_position_id).unwrap();
+				let second_position_id = <NextPositionId<Test>>::get();
+				assert_ok!(Omnipool::split_position(RuntimeOrigin::signed(seller), first_position_id, first_position.shares * split_percent / 100));
+
+				// Let's do a trade so price of the asset differs from price of the positions
+				assert_ok!(Omnipool::buy(RuntimeOrigin::signed(buyer), 200, DAI, buy_amount, Balance::max_value()));
+
+				let first_position = Omnipool::positions(first_position_id).unwrap();
+				let second_position = Omnipool::positions(second_position_id).unwrap();
+
+				let old_state_200 = Omnipool::load_asset_state(200).unwrap();
+				let old_imbalance = <HubAssetImbalance<Test>>::get();
+				let old_seller_balance = Tokens::free_balance(200, &seller);
+				let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+				let remove_all_liquidity = |position: &Position<Balance, AssetId>| {
+					hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+						&(&old_state_200).into(),
+						position.shares,
+						&position.into(),
+						I129 {
+							value: old_imbalance.value,
+							negative: old_imbalance.negative,
+						},
+						hub_liquidity,
+						FixedU128::zero(),
+					)
+					.unwrap()
+				};
+
+				let first_changes = remove_all_liquidity(&first_position);
+				let second_changes = remove_all_liquidity(&second_position);
+
+				assert_ok!(Omnipool::merge_positions(RuntimeOrigin::signed(seller), 200, vec![first_position_id, second_position_id]));
+
+				let merged_position = Omnipool::positions(first_position_id).unwrap();
+
+				// Amount, shares and price are preserved
+				assert_eq!(merged_position.amount, first_position.amount + second_position.amount);
+				assert_eq!(merged_position.shares, first_position.shares + second_position.shares);
+				assert_eq!(merged_position.price, first_position.price);
+				assert!(Omnipool::positions(second_position_id).is_none());
+
+				// Removing liquidity of merged position is equivalent to removing liquidity of the positions separately
+				let merged_changes = remove_all_liquidity(&merged_position);
+
+				assert_eq_approx!(*merged_changes.asset.delta_reserve,
+					*first_changes.asset.delta_reserve + *second_changes.asset.delta_reserve,
+					MERGE_TOLERANCE,
+					"Asset received from merged position changed");
+				assert_eq_approx!(merged_changes.lp_hub_amount,
+					first_changes.lp_hub_amount + second_changes.lp_hub_amount,
+					MERGE_TOLERANCE,
+					"Hub asset received from merged position changed");
+				assert_eq_approx!(*merged_changes.asset.delta_protocol_shares,
+					*first_changes.asset.delta_protocol_shares + *second_changes.asset.delta_protocol_shares,
+					MERGE_TOLERANCE,
+					"Protocol shares from merged position changed");
+
+				let shares = merged_position.shares * split_percent / 100;
+				let new_position_id = <NextPositionId<Test>>::get();
+
+				assert_ok!(Omnipool::split_position(RuntimeOrigin::signed(seller), first_position_id, shares));
+
+				let remaining_position = Omnipool::positions(first_position_id).unwrap();
+				let new_position = Omnipool::positions(new_position_id).unwrap();
+
+				// Amount and shares are preserved and price is not changed
+				assert_eq!(remaining_position.amount + new_position.amount, merged_position.amount);
+				assert_eq!(remaining_position.shares + new_position.shares, merged_position.shares);
+				assert_eq!(remaining_position.price, merged_position.price);
+				assert_eq!(new_position.price, merged_position.price);
+
+				// Pool is not affected
+				assert_eq!(Omnipool::load_asset_state(200).unwrap(), old_state_200);
+				assert_eq!(<HubAssetImbalance<Test>>::get(), old_imbalance);
+				assert_eq!(Tokens::free_balance(200, &seller), old_seller_balance);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn merge_positions_with_different_prices_should_preserve_value(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		asset_fee in fee(),
+		protocol_fee in fee(),
+		buy_amount in trade_amount(),
+		sell_amount in trade_amount(),
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let seller: u64 = 500;
+		let buyer: u64 = 600;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(seller, 200, 2 * amount + sell_amount + 200 * ONE),
+				(buyer, DAI, 200_000_000 * ONE),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_asset_fee(asset_fee)
+			.with_asset_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.build()
+			.execute_with(|| {
+				// Positions created at different prices
+				let first_position_id = <NextPositionId<Test>>::get();
+				assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(seller), 200, amount));
+
+				assert_ok!(Omnipool::buy(RuntimeOrigin::signed(buyer), 200, DAI, buy_amount, Balance::max_value()));
+
+				let second_position_id = <NextPositionId<Test>>::get();
+				assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(seller), 200, amount));
+
+				assert_ok!(Omnipool::sell(RuntimeOrigin::signed(seller), 200, DAI, sell_amount, Balance::zero()));
+
+				let first_position = Omnipool::positions(first_position_id).unwrap();
+				let second_position = Omnipool::positions(second_position_id).unwrap();
+
+				let old_state_200 = Omnipool::load_asset_state(200).unwrap();
+				let old_imbalance = <HubAssetImbalance<Test>>::get();
+				let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+				// Value of removed liquidity in hub asset at current state: dR * Q / R + dq
+				let remove_all_liquidity_value = |position: &Position<Balance, AssetId>| {
+					let changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+						&(&old_state_200).into(),
+						position.shares,
+						&position.into(),
+						I129 {
+							value: old_imbalance.value,
+							negative: old_imbalance.negative,
+						},
+						hub_liquidity,
+						FixedU128::zero(),
+					)
+					.unwrap();
+					U256::from(*changes.asset.delta_reserve) * U256::from(old_state_200.hub_reserve) / U256::from(old_state_200.reserve)
+						+ U256::from(changes.lp_hub_amount)
+				};
+
+				let separate_value = remove_all_liquidity_value(&first_position) + remove_all_liquidity_value(&second_position);
+
+				assert_ok!(Omnipool::merge_positions(RuntimeOrigin::signed(seller), 200, vec![first_position_id, second_position_id]));
+
+				let merged_position = Omnipool::positions(first_position_id).unwrap();
+
+				assert_eq!(merged_position.amount, first_position.amount + second_position.amount);
+				assert_eq!(merged_position.shares, first_position.shares + second_position.shares);
+
+				let merged_value = remove_all_liquidity_value(&merged_position);
+
+				assert_eq_approx!(merged_value,
+					separate_value,
+					separate_value / U256::from(1_000_000_000u128) + U256::from(MERGE_TOLERANCE),
+					"Value of merged position changed");
+
+				// Pool is not affected
+				assert_eq!(Omnipool::load_asset_state(200).unwrap(), old_state_200);
+				assert_eq!(<HubAssetImbalance<Test>>::get(), old_imbalance);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn sell_for_hub_asset_should_never_make_imbalance_positive(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		protocol_fee in fee(),
+		imbalance in some_imbalance(),
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let seller: u64 = 500;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(seller, 200, amount + 200 * ONE),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_protocol_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.build()
+			.execute_with(|| {
+				assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), LRNA, Tradability::SELL | Tradability::BUY));
+				HubAssetImbalance::<Test>::set(imbalance);
+
+				let old_state_200 = Omnipool::load_asset_state(200).unwrap();
+				let old_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+				let result = Omnipool::sell(RuntimeOrigin::signed(seller), 200, LRNA, amount, Balance::zero());
+
+				let new_imbalance = HubAssetImbalance::<Test>::get();
+				assert!(new_imbalance.negative, "Imbalance is positive");
+
+				match result {
+					Ok(()) => {
+						let new_state_200 = Omnipool::load_asset_state(200).unwrap();
+						assert_asset_invariant(&old_state_200, &new_state_200, FixedU128::from((TOLERANCE,ONE)), "Invariant 200");
+
+						let new_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+						assert_imbalance_update(imbalance.value, new_imbalance.value, old_hub_liquidity, new_hub_liquidity, "Imbalance invariant in sell for hub asset is incorrect");
+
+						// Seller never receives more hub asset than the spot value of sold amount
+						let received = Tokens::free_balance(LRNA, &seller);
+						assert!(U256::from(received) <= hub_asset_value(amount, &old_state_200), "Hub asset received exceeds spot value");
+
+						// Hub asset held by the pool is still equal to sum of hub reserves of subpools
+						assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), sum_asset_hub_liquidity());
+					}
+					Err(e) => assert_eq!(e, Error::<Test>::PositiveImbalance.into()),
+				}
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn buy_hub_asset_should_never_make_imbalance_positive(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		protocol_fee in fee(),
+		imbalance in some_imbalance(),
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let buyer: u64 = 500;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(buyer, 200, 10 * token_2.amount),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_protocol_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.build()
+			.execute_with(|| {
+				assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), LRNA, Tradability::SELL | Tradability::BUY));
+				HubAssetImbalance::<Test>::set(imbalance);
+
+				let old_state_200 = Omnipool::load_asset_state(200).unwrap();
+				let old_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+				let old_buyer_balance = Tokens::free_balance(200, &buyer);
+
+				let result = Omnipool::buy(RuntimeOrigin::signed(buyer), LRNA, 200, amount, Balance::MAX);
+
+				let new_imbalance = HubAssetImbalance::<Test>::get();
+				assert!(new_imbalance.negative, "Imbalance is positive");
+
+				match result {
+					Ok(()) => {
+						assert_eq!(Tokens::free_balance(LRNA, &buyer), amount);
+
+						let new_state_200 = Omnipool::load_asset_state(200).unwrap();
+						assert_asset_invariant(&old_state_200, &new_state_200, FixedU128::from((TOLERANCE,ONE)), "Invariant 200");
+
+						let new_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+						assert_imbalance_update(imbalance.value, new_imbalance.value, old_hub_liquidity, new_hub_liquidity, "Imbalance invariant in buy hub asset is incorrect");
+
+						// Buyer never pays less than the spot value of bought hub asset
+						let paid = old_buyer_balance - Tokens::free_balance(200, &buyer);
+						assert!(hub_asset_value(paid, &old_state_200) >= U256::from(amount), "Asset paid is below spot value");
+
+						// Hub asset held by the pool is still equal to sum of hub reserves of subpools
+						assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), sum_asset_hub_liquidity());
+					}
+					Err(e) => assert_eq!(e, Error::<Test>::PositiveImbalance.into()),
+				}
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn hub_asset_round_trip_should_never_make_imbalance_positive(amount in trade_amount(),
+		stable_price in price(),
+		stable_reserve in asset_reserve(),
+		native_reserve in asset_reserve(),
+		token_1 in pool_token(100),
+		token_2 in pool_token(200),
+		protocol_fee in fee(),
+	) {
+		let lp1: u64 = 100;
+		let lp2: u64 = 200;
+		let trader: u64 = 500;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, stable_reserve ),
+				(Omnipool::protocol_account(), HDX, native_reserve ),
+				(lp1, 100, token_1.amount + 2 * ONE),
+				(lp2, 200, token_2.amount + 2 * ONE),
+				(trader, LRNA, amount),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_protocol_fee(protocol_fee)
+			.with_initial_pool(
+				stable_price,
+				FixedU128::from(1),
+			)
+			.with_token(token_1.asset_id, token_1.price, lp1, token_1.amount)
+			.with_token(token_2.asset_id, token_2.price, lp2, token_2.amount)
+			.build()
+			.execute_with(|| {
+				assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), LRNA, Tradability::SELL | Tradability::BUY));
+
+				assert_ok!(Omnipool::sell(RuntimeOrigin::signed(trader), LRNA, 200, amount, Balance::zero()));
+				let imbalance = HubAssetImbalance::<Test>::get();
+				assert!(imbalance.negative);
+
+				let received = Tokens::free_balance(200, &trader);
+
+				// Asset received for hub asset is never worth more than the hub asset sold at the new spot price
+				let state_200 = Omnipool::load_asset_state(200).unwrap();
+				assert!(hub_asset_value(received, &state_200) <= U256::from(amount), "Asset received exceeds spot value");
+
+				let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+				let result = Omnipool::sell(RuntimeOrigin::signed(trader), 200, LRNA, received, Balance::zero());
+
+				let new_imbalance = HubAssetImbalance::<Test>::get();
+				assert!(new_imbalance.negative, "Imbalance is positive");
+
+				match result {
+					Ok(()) => {
+						let new_hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+						assert_imbalance_update(imbalance.value, new_imbalance.value, hub_liquidity, new_hub_liquidity, "Imbalance invariant in round trip is incorrect");
+
+						// Round trip is never profitable
+						assert!(Tokens::free_balance(LRNA, &trader) <= amount);
+						assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), sum_asset_hub_liquidity());
+					}
+					Err(e) => assert_eq!(e, Error::<Test>::PositiveImbalance.into()),
+				}
+			});
+	}
+}
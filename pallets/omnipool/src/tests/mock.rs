@@ -172,6 +172,7 @@ parameter_types! {
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type PositionItemId = u32;
 	type Currency = Tokens;
@@ -462,7 +463,7 @@ impl ExtBuilder {
 }
 
 use crate::traits::EnsurePriceWithin;
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate};
 use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::support::rational::Rounding;
 use hydra_dx_math::to_u128_wrapper;
@@ -485,6 +486,37 @@ impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
 	}
 }
 
+impl<AccountId: From<u64> + Into<u64> + Copy> InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = u32>>;
+	type ItemsIterator = Box<dyn Iterator<Item = u32>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (u32, u32)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = u32>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_class: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(_class: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let who: u64 = (*who).into();
+		let owned: Vec<u32> = POSITIONS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| **owner == who)
+				.map(|(instance, _)| *instance)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
 impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
 	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
 		Ok(())
@@ -6,14 +6,21 @@ use sp_runtime::{FixedPointNumber, FixedU128};
 mod add_liquidity;
 mod add_token;
 mod buy;
+mod fees;
+mod flash_swap;
+mod hub_asset_trade;
+mod il_protection;
 mod invariants;
+mod protocol_liquidity;
 mod remove_liquidity;
+mod remove_token;
 mod sell;
 
 mod barrier;
 mod imbalance;
 mod init_pool;
 pub(crate) mod mock;
+mod position_value;
 mod positions;
 mod refund;
 mod tradability;
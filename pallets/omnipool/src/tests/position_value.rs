@@ -0,0 +1,106 @@
+use super::*;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 1000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+fn add_liquidity(who: AccountId, amount: Balance) -> u32 {
+	let position_id = <NextPositionId<Test>>::get();
+	assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(who), ASSET_ID, amount));
+	position_id
+}
+
+#[test]
+fn position_value_should_be_equal_to_removed_liquidity_when_price_has_not_changed() {
+	ext().execute_with(|| {
+		let position_id = add_liquidity(LP1, 100 * ONE);
+		let position = Positions::<Test>::get(position_id).unwrap();
+
+		let value = Omnipool::position_value(position_id).unwrap();
+
+		assert_eq!(value.asset_id, ASSET_ID);
+		assert_eq!(value.amount, position.amount);
+		assert_eq!(value.shares, position.shares);
+		assert_eq!(value.hub_amount, 0);
+		assert_eq!(value.withdrawal_fee, FixedU128::zero());
+		assert_eq!(value.il_compensation, 0);
+
+		let asset_balance = Tokens::free_balance(ASSET_ID, &LP1);
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			position.shares
+		));
+
+		assert_balance!(LP1, ASSET_ID, asset_balance + value.asset_amount);
+		assert_balance!(LP1, LRNA, 0);
+	});
+}
+
+#[test]
+fn position_value_should_include_hub_asset_and_impermanent_loss_when_price_has_risen() {
+	ext().execute_with(|| {
+		let position_id = add_liquidity(LP1, 100 * ONE);
+		let position = Positions::<Test>::get(position_id).unwrap();
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, ASSET_ID, 100 * ONE, 0));
+
+		let value = Omnipool::position_value(position_id).unwrap();
+
+		assert!(value.hub_amount > 0);
+		assert!(value.asset_amount < position.amount);
+		assert!(value.impermanent_loss > 0);
+
+		let asset_balance = Tokens::free_balance(ASSET_ID, &LP1);
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			position.shares
+		));
+
+		assert_balance!(LP1, ASSET_ID, asset_balance + value.asset_amount);
+		assert_balance!(LP1, LRNA, value.hub_amount);
+	});
+}
+
+#[test]
+fn account_positions_value_should_return_positions_owned_by_account() {
+	ext().execute_with(|| {
+		let first_position = add_liquidity(LP1, 100 * ONE);
+		let _ = add_liquidity(LP2, 100 * ONE);
+		let second_position = add_liquidity(LP1, 200 * ONE);
+
+		let mut values = Omnipool::account_positions_value(&LP1);
+		values.sort_by_key(|(position_id, _)| *position_id);
+
+		assert_eq!(
+			values,
+			vec![
+				(first_position, Omnipool::position_value(first_position).unwrap()),
+				(second_position, Omnipool::position_value(second_position).unwrap()),
+			]
+		);
+
+		assert!(Omnipool::account_positions_value(&LP3).is_empty());
+	});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ext().execute_with(|| {
+		assert_eq!(
+			Omnipool::position_value(1234),
+			Err(Error::<Test>::PositionNotFound.into())
+		);
+	});
+}
@@ -122,3 +122,449 @@ fn sacrifice_position_should_emit_event_when_succesful() {
 			);
 		});
 }
+
+#[test]
+fn merge_positions_should_work_when_positions_have_same_price() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// Arrange - create positions
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 600 * ONE));
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				asset_id,
+				vec![first_position_id, second_position_id]
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(first_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 1000 * ONE,
+					shares: 1000 * ONE,
+					price: (650_000_000_000_000_000, FixedU128::DIV),
+				}
+			);
+			assert_eq!(Positions::<Test>::get(second_position_id), None);
+			assert_eq!(
+				POSITIONS.with(|v| v.borrow().get(&first_position_id).copied()),
+				Some(LP1)
+			);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&second_position_id).copied()), None);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::PositionsMerged {
+					owner: LP1,
+					asset_id,
+					position_id: first_position_id,
+					merged_positions: vec![second_position_id],
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_preserve_value_when_positions_have_different_price() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP3, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange - create positions at different prices
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), asset_id, DAI, 100 * ONE, 0));
+
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 600 * ONE));
+
+			let first_position = Positions::<Test>::get(first_position_id).unwrap();
+			let second_position = Positions::<Test>::get(second_position_id).unwrap();
+			assert_ne!(first_position.price, second_position.price);
+
+			let asset_state = Omnipool::load_asset_state(asset_id).unwrap();
+			let imbalance = <HubAssetImbalance<Test>>::get();
+			let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+			let remove_all_liquidity_value = |position: &Position<Balance, AssetId>| {
+				let changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+					&(&asset_state).into(),
+					position.shares,
+					&position.into(),
+					hydra_dx_math::omnipool::types::I129 {
+						value: imbalance.value,
+						negative: imbalance.negative,
+					},
+					hub_liquidity,
+					FixedU128::zero(),
+				)
+				.unwrap();
+				*changes.asset.delta_reserve * asset_state.hub_reserve / asset_state.reserve + changes.lp_hub_amount
+			};
+
+			let separate_value =
+				remove_all_liquidity_value(&first_position) + remove_all_liquidity_value(&second_position);
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				asset_id,
+				vec![first_position_id, second_position_id]
+			));
+
+			// Assert
+			let merged_position = Positions::<Test>::get(first_position_id).unwrap();
+			assert_eq!(merged_position.amount, 1000 * ONE);
+			assert_eq!(merged_position.shares, first_position.shares + second_position.shares);
+			assert_eq!(Positions::<Test>::get(second_position_id), None);
+
+			assert_eq_approx!(
+				remove_all_liquidity_value(&merged_position),
+				separate_value,
+				100u128,
+				"Value of merged position changed"
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_price_differs_from_oracle_price() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_max_allowed_price_difference(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 600 * ONE));
+
+			EXT_PRICE_ADJUSTMENT.with(|v| {
+				*v.borrow_mut() = (3, 100, false);
+			});
+
+			assert_noop!(
+				Omnipool::merge_positions(
+					RuntimeOrigin::signed(LP1),
+					asset_id,
+					vec![first_position_id, second_position_id]
+				),
+				Error::<Test>::PriceDifferenceTooHigh
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_less_than_two_positions_provided() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), asset_id, vec![position_id]),
+				Error::<Test>::InsufficientPositions
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_too_many_positions_provided() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_ids: Vec<u32> = (0..MAX_MERGED_POSITIONS + 1).collect();
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), asset_id, position_ids),
+				Error::<Test>::TooManyPositions
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_same_position_provided_twice() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 600 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(
+					RuntimeOrigin::signed(LP1),
+					asset_id,
+					vec![first_position_id, second_position_id, first_position_id]
+				),
+				Error::<Test>::DuplicatePosition
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_is_not_owner_of_all_positions() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), asset_id, 600 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(
+					RuntimeOrigin::signed(LP1),
+					asset_id,
+					vec![first_position_id, second_position_id]
+				),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	let asset_id: AssetId = 1_000;
+	let other_asset_id: AssetId = 1_001;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP1, other_asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, other_asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(other_asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let first_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let second_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(
+				RuntimeOrigin::signed(LP1),
+				other_asset_id,
+				600 * ONE
+			));
+
+			assert_noop!(
+				Omnipool::merge_positions(
+					RuntimeOrigin::signed(LP1),
+					asset_id,
+					vec![first_position_id, second_position_id]
+				),
+				Error::<Test>::PositionAssetMismatch
+			);
+		});
+}
+
+#[test]
+fn split_position_should_work_when_shares_are_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// Arrange - create a position
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			let new_position_id = <NextPositionId<Test>>::get();
+
+			// Act
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&new_position_id).copied()), Some(LP1));
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::PositionSplit {
+					owner: LP1,
+					position_id,
+					new_position_id,
+					shares: 100 * ONE,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_zero() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_not_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_restore_position_when_split_positions_are_merged() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				150 * ONE
+			));
+
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				asset_id,
+				vec![position_id, new_position_id]
+			));
+
+			let merged_position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged_position.amount, position.amount);
+			assert_eq!(merged_position.shares, position.shares);
+			assert_eq!(merged_position.price_from_rational(), position.price_from_rational());
+			assert_eq!(Positions::<Test>::get(new_position_id), None);
+		});
+}
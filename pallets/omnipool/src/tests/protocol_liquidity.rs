@@ -0,0 +1,274 @@
+use super::*;
+use crate::types::Tradability;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+/// Adds liquidity by LP1 and sacrifices the position so the protocol owns its shares.
+fn sacrifice_liquidity(amount: Balance) -> Balance {
+	let position_id = <NextPositionId<Test>>::get();
+	assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, amount));
+
+	let position = Positions::<Test>::get(position_id).unwrap();
+	assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP1), position_id));
+
+	position.shares
+}
+
+#[test]
+fn withdraw_protocol_liquidity_should_work_when_protocol_owns_shares() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let imbalance = HubAssetImbalance::<Test>::get();
+
+		assert_ok!(Omnipool::withdraw_protocol_liquidity(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			shares,
+			LP3
+		));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+
+		// Position was created at the same price, so the whole amount is withdrawn
+		let amount = Tokens::free_balance(ASSET_ID, &LP3);
+		assert_eq_approx!(amount, 100 * ONE, 10, "withdrawn amount");
+		assert_balance!(LP3, LRNA, 0);
+		assert_eq!(new_asset_state.reserve, asset_state.reserve - amount);
+		assert_eq!(new_asset_state.shares, asset_state.shares - shares);
+		assert_eq!(new_asset_state.protocol_shares, asset_state.protocol_shares - shares);
+		assert!(new_asset_state.hub_reserve < asset_state.hub_reserve);
+		assert_eq!(new_asset_state.price(), asset_state.price());
+		assert_ne!(HubAssetImbalance::<Test>::get(), imbalance);
+
+		assert_hub_asset!();
+
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::ProtocolLiquidityWithdrawn {
+				asset_id: ASSET_ID,
+				shares,
+				amount,
+				beneficiary: LP3,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn withdraw_protocol_liquidity_should_fail_when_protocol_shares_are_insufficient() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_noop!(
+			Omnipool::withdraw_protocol_liquidity(RuntimeOrigin::root(), ASSET_ID, shares + 1, LP3),
+			Error::<Test>::InsufficientProtocolShares
+		);
+		assert_noop!(
+			Omnipool::withdraw_protocol_liquidity(RuntimeOrigin::root(), ASSET_ID, 0, LP3),
+			Error::<Test>::InvalidSharesAmount
+		);
+	});
+}
+
+#[test]
+fn withdraw_protocol_liquidity_should_fail_when_origin_is_not_authority() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_noop!(
+			Omnipool::withdraw_protocol_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, shares, LP1),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn withdraw_protocol_liquidity_should_fail_when_remove_liquidity_is_not_allowed() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			Tradability::SELL | Tradability::BUY | Tradability::ADD_LIQUIDITY
+		));
+
+		assert_noop!(
+			Omnipool::withdraw_protocol_liquidity(RuntimeOrigin::root(), ASSET_ID, shares, LP3),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn redeploy_protocol_liquidity_should_move_liquidity_to_target_asset() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let dai_state = Omnipool::load_asset_state(DAI).unwrap();
+
+		assert_ok!(Omnipool::redeploy_protocol_liquidity(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			shares,
+			DAI,
+			ONE
+		));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let new_dai_state = Omnipool::load_asset_state(DAI).unwrap();
+
+		let shares_added = new_dai_state.protocol_shares - dai_state.protocol_shares;
+
+		assert!(shares_added > 0);
+		assert_eq!(new_dai_state.shares, dai_state.shares + shares_added);
+		assert_eq!(new_asset_state.protocol_shares, asset_state.protocol_shares - shares);
+		assert_eq!(new_asset_state.shares, asset_state.shares - shares);
+
+		// Nothing is left on the protocol owned liquidity account
+		assert_balance!(Omnipool::pol_account(), ASSET_ID, 0);
+		assert_balance!(Omnipool::pol_account(), DAI, 0);
+
+		assert_hub_asset!();
+
+		assert!(System::events().into_iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Omnipool(crate::Event::ProtocolLiquidityRedeployed {
+				asset_from: ASSET_ID,
+				shares_removed,
+				asset_to: DAI,
+				shares_added: added,
+				..
+			}) if shares_removed == shares && added == shares_added
+		)));
+	});
+}
+
+#[test]
+fn redeploy_protocol_liquidity_should_fail_when_min_amount_out_is_not_reached() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_noop!(
+			Omnipool::redeploy_protocol_liquidity(RuntimeOrigin::root(), ASSET_ID, shares, DAI, 1000 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn redeploy_protocol_liquidity_should_fail_when_target_is_same_asset() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_noop!(
+			Omnipool::redeploy_protocol_liquidity(RuntimeOrigin::root(), ASSET_ID, shares, ASSET_ID, 0),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn convert_protocol_liquidity_to_position_should_create_position_when_protocol_owns_shares() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+		let position_id = <NextPositionId<Test>>::get();
+
+		assert_ok!(Omnipool::convert_protocol_liquidity_to_position(
+			RuntimeOrigin::root(),
+			ASSET_ID,
+			shares,
+			LP3
+		));
+
+		let new_asset_state = Omnipool::load_asset_state(ASSET_ID).unwrap();
+
+		let position = Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.asset_id, ASSET_ID);
+		assert_eq!(position.shares, shares);
+		assert_eq!(position.price, (asset_state.hub_reserve, asset_state.reserve));
+		assert_eq_approx!(position.amount, 100 * ONE, 10, "position amount");
+		assert_eq!(get_mock_minted_position(position_id), Some(LP3));
+
+		// Only ownership of the shares is changed
+		assert_eq!(new_asset_state.protocol_shares, asset_state.protocol_shares - shares);
+		assert_eq!(new_asset_state.shares, asset_state.shares);
+		assert_eq!(new_asset_state.reserve, asset_state.reserve);
+		assert_eq!(new_asset_state.hub_reserve, asset_state.hub_reserve);
+
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::ProtocolLiquidityConverted {
+				asset_id: ASSET_ID,
+				shares,
+				position_id,
+				owner: LP3,
+			}
+			.into(),
+		);
+
+		// Owner can remove liquidity of the converted position
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP3),
+			position_id,
+			shares
+		));
+		assert_balance!(LP3, ASSET_ID, position.amount);
+	});
+}
+
+#[test]
+fn convert_protocol_liquidity_to_position_should_fail_when_protocol_shares_are_insufficient() {
+	ext().execute_with(|| {
+		let shares = sacrifice_liquidity(100 * ONE);
+
+		assert_noop!(
+			Omnipool::convert_protocol_liquidity_to_position(RuntimeOrigin::root(), ASSET_ID, shares + 1, LP3),
+			Error::<Test>::InsufficientProtocolShares
+		);
+	});
+}
+
+#[test]
+fn convert_protocol_liquidity_to_position_should_fail_when_price_differs_from_oracle() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_max_allowed_price_difference(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			let shares = sacrifice_liquidity(100 * ONE);
+
+			EXT_PRICE_ADJUSTMENT.with(|v| {
+				*v.borrow_mut() = (3, 100, false);
+			});
+
+			assert_noop!(
+				Omnipool::convert_protocol_liquidity_to_position(RuntimeOrigin::root(), ASSET_ID, shares, LP3),
+				Error::<Test>::PriceDifferenceTooHigh
+			);
+		});
+}
@@ -0,0 +1,517 @@
+use super::*;
+use crate::types::{AssetReserveState, DelistedAssetState, Tradability};
+use frame_support::assert_noop;
+use hydra_dx_math::omnipool::types::I129;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const BENEFICIARY: AccountId = 1_000_000;
+
+#[test]
+fn remove_token_should_work_when_all_shares_are_owned_by_protocol() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP2),
+				lp2_position_id
+			));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			assert!(Omnipool::assets(1_000).is_none());
+			assert!(Omnipool::delisted_assets(1_000).is_none());
+			assert_balance!(BENEFICIARY, 1_000, 2000 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 1_000, 0);
+			assert_pool_state!(10_500 * ONE, 21_000 * ONE, SimpleImbalance::default());
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::TokenRemoved {
+					asset_id: 1_000,
+					beneficiary: BENEFICIARY,
+					amount: 2000 * ONE,
+					hub_withdrawn: 1300 * ONE,
+					unclaimed_amount: 0,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_keep_liquidity_of_remaining_positions_when_not_all_shares_are_owned_by_protocol() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP2),
+				lp2_position_id
+			));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			assert!(Omnipool::assets(1_000).is_none());
+			assert_eq!(
+				Omnipool::delisted_assets(1_000),
+				Some(DelistedAssetState {
+					reserve: 400 * ONE,
+					hub_reserve: 260 * ONE,
+					shares: 400 * ONE,
+					protocol_shares: 0,
+					beneficiary: BENEFICIARY,
+				})
+			);
+			assert_balance!(BENEFICIARY, 1_000, 2000 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 1_000, 400 * ONE);
+			assert_hub_asset!();
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_asset_is_not_frozen() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY),
+				Error::<Test>::AssetNotFrozen
+			);
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::REMOVE_LIQUIDITY
+			));
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY),
+				Error::<Test>::AssetNotFrozen
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_asset_is_native_or_stable_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				HDX,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				DAI,
+				Tradability::FROZEN
+			));
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), HDX, BENEFICIARY),
+				Error::<Test>::AssetRemovalNotAllowed
+			);
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), DAI, BENEFICIARY),
+				Error::<Test>::AssetRemovalNotAllowed
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY),
+				Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::signed(LP2), 1_000, BENEFICIARY),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_apply_withdrawal_fee_when_trading_is_frozen() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_withdrawal_fee(Permill::from_float(0.01))
+		.build()
+		.execute_with(|| {
+			let current_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::REMOVE_LIQUIDITY
+			));
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				current_position_id,
+				400 * ONE
+			));
+
+			assert_balance!(LP1, 1_000, 4996 * ONE);
+		});
+}
+
+#[test]
+fn claim_delisted_position_should_work_when_asset_is_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			let lp3_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP3), 1_000, 100 * ONE));
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP2),
+				lp2_position_id
+			));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			// Act
+			assert_ok!(Omnipool::claim_delisted_position(
+				RuntimeOrigin::signed(LP1),
+				lp1_position_id
+			));
+
+			// Assert
+			assert_balance!(LP1, 1_000, 5000 * ONE);
+			assert!(Positions::<Test>::get(lp1_position_id).is_none());
+			assert!(get_mock_minted_position(lp1_position_id).is_none());
+			assert_eq!(
+				Omnipool::delisted_assets(1_000),
+				Some(DelistedAssetState {
+					reserve: 100 * ONE,
+					hub_reserve: 65 * ONE,
+					shares: 100 * ONE,
+					protocol_shares: 0,
+					beneficiary: BENEFICIARY,
+				})
+			);
+			frame_system::Pallet::<Test>::assert_has_event(
+				crate::Event::DelistedPositionClaimed {
+					who: LP1,
+					position_id: lp1_position_id,
+					asset_id: 1_000,
+					amount: 400 * ONE,
+					hub_amount: 0,
+				}
+				.into(),
+			);
+
+			assert_ok!(Omnipool::claim_delisted_position(
+				RuntimeOrigin::signed(LP3),
+				lp3_position_id
+			));
+
+			assert_balance!(LP3, 1_000, 5000 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 1_000, 0);
+			assert!(Omnipool::delisted_assets(1_000).is_none());
+		});
+}
+
+#[test]
+fn claim_delisted_position_should_fail_when_asset_is_not_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+
+			assert_noop!(
+				Omnipool::claim_delisted_position(RuntimeOrigin::signed(LP2), lp2_position_id),
+				Error::<Test>::AssetNotDelisted
+			);
+		});
+}
+
+#[test]
+fn claim_delisted_position_should_fail_when_signed_account_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			assert_noop!(
+				Omnipool::claim_delisted_position(RuntimeOrigin::signed(LP2), lp1_position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn add_token_and_refund_should_fail_when_delisted_liquidity_is_not_claimed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			assert_noop!(
+				Omnipool::add_token(
+					RuntimeOrigin::root(),
+					1_000,
+					FixedU128::from_float(0.65),
+					Permill::from_percent(100),
+					LP1
+				),
+				Error::<Test>::UnclaimedDelistedLiquidity
+			);
+			assert_noop!(
+				Omnipool::refund_refused_asset(RuntimeOrigin::root(), 1_000, 2000 * ONE, LP1),
+				Error::<Test>::UnclaimedDelistedLiquidity
+			);
+		});
+}
+
+#[test]
+fn claim_delisted_position_should_remove_liquidity_at_frozen_price_when_price_decreased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP2),
+				lp2_position_id
+			));
+			// Price of the asset decreases
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), 1_000, DAI, 500 * ONE, 0));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			let delisted_state = Omnipool::delisted_assets(1_000).unwrap();
+			let position = Positions::<Test>::get(lp1_position_id).unwrap();
+			let imbalance = <HubAssetImbalance<Test>>::get();
+			let beneficiary_balance = Tokens::free_balance(1_000, &BENEFICIARY);
+
+			// Act
+			assert_ok!(Omnipool::claim_delisted_position(
+				RuntimeOrigin::signed(LP1),
+				lp1_position_id
+			));
+
+			// Assert
+			let received = Tokens::free_balance(1_000, &LP1) - 4600 * ONE;
+			let expected_state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+				&(&AssetReserveState::from(&delisted_state)).into(),
+				position.shares,
+				&(&position).into(),
+				I129 {
+					value: imbalance.value,
+					negative: imbalance.negative,
+				},
+				Tokens::free_balance(LRNA, &Omnipool::protocol_account()),
+				FixedU128::zero(),
+			)
+			.unwrap();
+
+			// LP provided liquidity at higher price, part of its shares is owned by protocol
+			assert_eq!(received, *expected_state_changes.asset.delta_reserve);
+			assert!(received < delisted_state.reserve);
+			assert_balance!(LP1, LRNA, 0);
+
+			// Reserve owned by protocol is transferred to the beneficiary once all positions are claimed
+			assert!(Omnipool::delisted_assets(1_000).is_none());
+			assert_balance!(Omnipool::protocol_account(), 1_000, 0);
+			assert_eq!(
+				Tokens::free_balance(1_000, &BENEFICIARY) - beneficiary_balance,
+				delisted_state.reserve - received
+			);
+		});
+}
+
+#[test]
+fn claim_delisted_position_should_update_imbalance_when_hub_asset_is_minted() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, DAI, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP2),
+				lp2_position_id
+			));
+			// Price of the asset increases
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, 1_000, 200 * ONE, 0));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, BENEFICIARY));
+
+			let imbalance = <HubAssetImbalance<Test>>::get();
+
+			// Act
+			assert_ok!(Omnipool::claim_delisted_position(
+				RuntimeOrigin::signed(LP1),
+				lp1_position_id
+			));
+
+			// Assert
+			let hub_amount = Tokens::free_balance(LRNA, &LP1);
+			assert!(hub_amount > 0);
+			assert_eq!(
+				<HubAssetImbalance<Test>>::get(),
+				SimpleImbalance {
+					value: imbalance.value + hub_amount,
+					negative: true,
+				}
+			);
+			assert_hub_asset!();
+		});
+}
@@ -80,6 +80,99 @@ where
 	}
 }
 
+/// State of an asset which has been removed from Omnipool while some LP positions still remained.
+/// Remaining LP positions can claim their liquidity at the frozen price of the asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct DelistedAssetState<AccountId, Balance> {
+	/// Quantity of asset which has not been claimed yet
+	pub reserve: Balance,
+	/// Quantity of Hub Asset matching the unclaimed reserve at the frozen price.
+	/// Hub asset itself is burned when the asset is removed.
+	pub hub_reserve: Balance,
+	/// Quantity of LP shares which have not been claimed yet, including shares owned by protocol
+	pub shares: Balance,
+	/// Quantity of shares which became protocol owned when positions were claimed
+	pub protocol_shares: Balance,
+	/// Account which receives the reserve owned by protocol once all positions are claimed
+	pub beneficiary: AccountId,
+}
+
+impl<AccountId, Balance> From<&DelistedAssetState<AccountId, Balance>> for AssetReserveState<Balance>
+where
+	Balance: Copy,
+{
+	fn from(state: &DelistedAssetState<AccountId, Balance>) -> Self {
+		Self {
+			reserve: state.reserve,
+			hub_reserve: state.hub_reserve,
+			shares: state.shares,
+			protocol_shares: state.protocol_shares,
+			cap: 0,
+			tradable: Tradability::FROZEN,
+		}
+	}
+}
+
+/// Value of LP position if all its liquidity was removed at current state of the pool.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PositionValue<AssetId, Balance> {
+	/// Asset of the position
+	pub asset_id: AssetId,
+	/// Amount of asset added to omnipool
+	pub amount: Balance,
+	/// Quantity of LP shares of the position
+	pub shares: Balance,
+	/// Amount of asset received, withdrawal fee is already applied
+	pub asset_amount: Balance,
+	/// Amount of hub asset received, withdrawal fee is already applied
+	pub hub_amount: Balance,
+	/// Withdrawal fee applied
+	pub withdrawal_fee: FixedU128,
+	/// Impermanent loss in asset units compared to holding the position amount
+	pub impermanent_loss: Balance,
+	/// Compensation for impermanent loss paid from impermanent loss fund
+	pub il_compensation: Balance,
+}
+
+/// Bounds of fees of an asset. Fees provided by `Config::Fee` are clamped to these bounds.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct FeeBounds {
+	/// Minimum asset fee
+	pub min_asset_fee: Permill,
+	/// Maximum asset fee
+	pub max_asset_fee: Permill,
+	/// Minimum protocol fee
+	pub min_protocol_fee: Permill,
+	/// Maximum protocol fee
+	pub max_protocol_fee: Permill,
+}
+
+impl FeeBounds {
+	/// Check if minimum fees do not exceed maximum fees.
+	pub fn is_valid(&self) -> bool {
+		self.min_asset_fee <= self.max_asset_fee && self.min_protocol_fee <= self.max_protocol_fee
+	}
+
+	/// Clamp given asset fee and protocol fee to the bounds.
+	pub fn clamp(&self, fees: (Permill, Permill)) -> (Permill, Permill) {
+		(
+			fees.0.max(self.min_asset_fee).min(self.max_asset_fee),
+			fees.1.max(self.min_protocol_fee).min(self.max_protocol_fee),
+		)
+	}
+}
+
+/// Impermanent loss protection parameters of an asset.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ILProtectionParams<BlockNumber> {
+	/// Fraction of trade fee of the asset which is diverted into impermanent loss fund
+	pub fee_share: Permill,
+	/// Minimum number of blocks a position has to be held to be eligible for compensation
+	pub min_holding_period: BlockNumber,
+	/// Number of blocks a position has to be held to be compensated for full impermanent loss
+	pub vesting_period: BlockNumber,
+}
+
 /// Position in Omnipool represents a moment when LP provided liquidity of an asset at that moment’s price.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Position<Balance, AssetId> {
@@ -59,6 +59,17 @@ pub trait WeightInfo {
 	fn refund_refused_asset() -> Weight;
 	fn sacrifice_position() -> Weight;
 	fn set_asset_weight_cap() -> Weight;
+	fn remove_token() -> Weight;
+	fn claim_delisted_position() -> Weight;
+	fn merge_positions(n: u32) -> Weight;
+	fn split_position() -> Weight;
+	fn set_il_protection() -> Weight;
+	fn flash_swap() -> Weight;
+	fn withdraw_protocol_liquidity() -> Weight;
+	fn redeploy_protocol_liquidity() -> Weight;
+	fn convert_protocol_liquidity_to_position() -> Weight;
+	fn set_asset_fee_override() -> Weight;
+	fn set_asset_fee_bounds() -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
@@ -73,26 +84,26 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn add_token() -> Weight {
 		Weight::from_ref_time(103_310_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(14 as u64))
-			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	fn add_liquidity() -> Weight {
 		Weight::from_ref_time(139_531_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(16 as u64))
-			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
 	}
 	fn remove_liquidity() -> Weight {
 		Weight::from_ref_time(175_741_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(18 as u64))
-			.saturating_add(T::DbWeight::get().writes(15 as u64))
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(16 as u64))
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(121_641_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(121_521_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	fn set_asset_tradable_state() -> Weight {
@@ -108,13 +119,71 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn sacrifice_position() -> Weight {
 		Weight::from_ref_time(56_001_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	fn set_asset_weight_cap() -> Weight {
 		Weight::from_ref_time(20_790_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn remove_token() -> Weight {
+		Weight::from_ref_time(91_355_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	fn claim_delisted_position() -> Weight {
+		Weight::from_ref_time(88_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	fn merge_positions(n: u32) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(33_867_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
+	}
+	fn split_position() -> Weight {
+		Weight::from_ref_time(61_278_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	fn set_il_protection() -> Weight {
+		Weight::from_ref_time(22_418_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn flash_swap() -> Weight {
+		Weight::from_ref_time(273_512_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(28 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
+	}
+	fn withdraw_protocol_liquidity() -> Weight {
+		Weight::from_ref_time(118_627_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	fn redeploy_protocol_liquidity() -> Weight {
+		Weight::from_ref_time(392_175_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(34 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	fn convert_protocol_liquidity_to_position() -> Weight {
+		Weight::from_ref_time(83_941_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	fn set_asset_fee_override() -> Weight {
+		Weight::from_ref_time(21_573_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_asset_fee_bounds() -> Weight {
+		Weight::from_ref_time(21_846_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -127,26 +196,26 @@ impl WeightInfo for () {
 	fn add_token() -> Weight {
 		Weight::from_ref_time(103_310_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(14 as u64))
-			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
 	fn add_liquidity() -> Weight {
 		Weight::from_ref_time(139_531_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(16 as u64))
-			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
 	}
 	fn remove_liquidity() -> Weight {
 		Weight::from_ref_time(175_741_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(18 as u64))
-			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().writes(16 as u64))
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(121_641_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
 			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(121_521_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
 			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 	fn set_asset_tradable_state() -> Weight {
@@ -162,11 +231,69 @@ impl WeightInfo for () {
 	fn sacrifice_position() -> Weight {
 		Weight::from_ref_time(56_001_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(4 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	fn set_asset_weight_cap() -> Weight {
 		Weight::from_ref_time(20_790_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn remove_token() -> Weight {
+		Weight::from_ref_time(91_355_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	fn claim_delisted_position() -> Weight {
+		Weight::from_ref_time(88_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	fn merge_positions(n: u32) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(33_867_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
+	}
+	fn split_position() -> Weight {
+		Weight::from_ref_time(61_278_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	fn set_il_protection() -> Weight {
+		Weight::from_ref_time(22_418_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn flash_swap() -> Weight {
+		Weight::from_ref_time(273_512_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(28 as u64))
+			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+	}
+	fn withdraw_protocol_liquidity() -> Weight {
+		Weight::from_ref_time(118_627_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	fn redeploy_protocol_liquidity() -> Weight {
+		Weight::from_ref_time(392_175_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(34 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
+	}
+	fn convert_protocol_liquidity_to_position() -> Weight {
+		Weight::from_ref_time(83_941_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	fn set_asset_fee_override() -> Weight {
+		Weight::from_ref_time(21_573_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_asset_fee_bounds() -> Weight {
+		Weight::from_ref_time(21_846_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
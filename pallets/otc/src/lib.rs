@@ -34,13 +34,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, require_transactional};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use hydradx_traits::Registry;
+use frame_support::{pallet_prelude::*, require_transactional, PalletId};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use hydradx_traits::{pools::PoolLimitHandler, Registry, Source};
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
 use sp_runtime::{
-	traits::{One, Zero},
+	traits::{AccountIdConversion, One, Zero},
 	DispatchError,
 };
 use sp_std::vec::Vec;
@@ -50,6 +50,7 @@ mod tests;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+pub mod migration;
 pub mod weights;
 
 use weights::WeightInfo;
@@ -63,6 +64,10 @@ pub type NamedReserveIdentifier = [u8; 8];
 
 pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"otcorder";
 
+/// Source of OTC orders used to identify them in `PoolLimitHandler`.
+/// All orders are tracked together as a single pool whose reserves are the amounts reserved by open orders.
+pub const OTC_SOURCE: Source = *b"otcorder";
+
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Order<AccountId, AssetId> {
 	pub owner: AccountId,
@@ -78,8 +83,11 @@ pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -107,6 +115,9 @@ pub mod pallet {
 
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
+
+		/// Handler which enforces per block trade limits (e.g. circuit breaker).
+		type PoolLimitHandler: PoolLimitHandler<Self::RuntimeOrigin, Self::AccountId, Self::AssetId, Balance>;
 	}
 
 	#[pallet::event]
@@ -170,6 +181,11 @@ pub mod pallet {
 	#[pallet::getter(fn orders)]
 	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
 
+	/// Total amount of an asset reserved by open orders
+	#[pallet::storage]
+	#[pallet::getter(fn asset_liquidity)]
+	pub type AssetLiquidity<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new OTC order
@@ -218,6 +234,10 @@ pub mod pallet {
 				let order_id = *next_id;
 
 				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
+				<AssetLiquidity<T>>::try_mutate(order.asset_out, |liquidity| -> DispatchResult {
+					*liquidity = liquidity.checked_add(order.amount_out).ok_or(Error::<T>::MathError)?;
+					Ok(())
+				})?;
 				<Orders<T>>::insert(order_id, &order);
 
 				Self::deposit_event(Event::Placed {
@@ -250,7 +270,8 @@ pub mod pallet {
 		/// Events:
 		/// `PartiallyFilled` event when successful.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::partial_fill_order())]
+		#[pallet::weight(<T as Config>::WeightInfo::partial_fill_order()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight()))]
 		pub fn partial_fill_order(origin: OriginFor<T>, order_id: OrderId, amount_in: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			<Orders<T>>::try_mutate(order_id, |maybe_order| -> DispatchResult {
@@ -290,7 +311,8 @@ pub mod pallet {
 		/// Events:
 		/// `Filled` event when successful.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::fill_order())]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight()))]
 		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
@@ -330,6 +352,7 @@ pub mod pallet {
 				let remaining_to_unreserve =
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+				Self::decrease_asset_liquidity(order.asset_out, order.amount_out)?;
 				*maybe_order = None;
 
 				Self::deposit_event(Event::Cancelled { order_id });
@@ -357,12 +380,43 @@ impl<T: Config> Pallet<T> {
 		amount_in: Balance,
 		amount_out: Balance,
 	) -> DispatchResult {
+		// the limits are relative to the amounts reserved by all open orders.
+		// asset_in is paid to the order owner directly, so it does not flow into the orders' liquidity.
+		T::PoolLimitHandler::ensure_trade_limit(
+			RawOrigin::Signed(who.clone()).into(),
+			OTC_SOURCE,
+			Self::pool_account(),
+			order.asset_in,
+			Self::asset_liquidity(order.asset_in),
+			Zero::zero(),
+			order.asset_out,
+			Self::asset_liquidity(order.asset_out),
+			amount_out,
+		)?;
+
 		T::Currency::transfer(order.asset_in, who, &order.owner, amount_in)?;
 		let remaining_to_unreserve =
 			T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, amount_out);
 		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
 		T::Currency::transfer(order.asset_out, &order.owner, who, amount_out)?;
+		Self::decrease_asset_liquidity(order.asset_out, amount_out)?;
 
 		Ok(())
 	}
+
+	fn decrease_asset_liquidity(asset: T::AssetId, amount: Balance) -> DispatchResult {
+		<AssetLiquidity<T>>::try_mutate_exists(asset, |maybe_liquidity| -> DispatchResult {
+			let liquidity = maybe_liquidity
+				.unwrap_or_default()
+				.checked_sub(amount)
+				.ok_or(Error::<T>::MathError)?;
+			*maybe_liquidity = Some(liquidity).filter(|l| !l.is_zero());
+			Ok(())
+		})
+	}
+
+	/// Account representing all OTC orders in `PoolLimitHandler`.
+	pub fn pool_account() -> T::AccountId {
+		PalletId(NAMED_RESERVE_ID).into_account_truncating()
+	}
 }
@@ -0,0 +1,89 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::Saturating;
+
+/// Migrate the pallet storage to v1. `AssetLiquidity` is initialized from the open orders.
+pub fn migrate_to_v1<T: Config, P: GetStorageVersion + PalletInfoAccess>() -> Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::otc",
+		"Running migration storage v1 for otc with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let mut orders: u64 = 0;
+
+		for order in Orders::<T>::iter_values() {
+			orders.saturating_inc();
+			AssetLiquidity::<T>::mutate(order.asset_out, |liquidity| {
+				*liquidity = liquidity.saturating_add(order.amount_out)
+			});
+		}
+
+		StorageVersion::new(1).put::<P>();
+
+		weight =
+			weight.saturating_add(T::DbWeight::get().reads_writes(orders.saturating_mul(2), orders.saturating_add(1)));
+
+		log::info!(
+			target: "runtime::otc",
+			"Migration storage v1 for otc was complete, {:?} orders were migrated",
+			orders,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::otc",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn pre_migrate<T: Config, P: GetStorageVersion>() -> Result<Vec<u8>, &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 0,
+		"Storage version too high."
+	);
+
+	Ok(Vec::new())
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate<T: Config, P: GetStorageVersion>(_state: Vec<u8>) -> Result<(), &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 1,
+		"Unexpected storage version."
+	);
+
+	let reserved = Orders::<T>::iter_values().fold(Balance::zero(), |acc, order| acc.saturating_add(order.amount_out));
+	let liquidity = AssetLiquidity::<T>::iter_values().fold(Balance::zero(), |acc, l| acc.saturating_add(l));
+	ensure!(reserved == liquidity, "Asset liquidity does not match the open orders.");
+
+	Ok(())
+}
@@ -86,3 +86,32 @@ fn cancel_order_should_throw_error_when_called_by_non_owner() {
 		);
 	});
 }
+
+#[test]
+fn cancel_order_should_remove_asset_liquidity_of_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true
+		));
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			50 * ONE,
+			true
+		));
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(OTC::asset_liquidity(HDX), 50 * ONE);
+	});
+}
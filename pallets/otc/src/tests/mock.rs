@@ -87,6 +87,7 @@ impl Config for Test {
 	type ExistentialDeposits = ExistentialDeposits;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
 	type WeightInfo = ();
+	type PoolLimitHandler = ();
 }
 
 parameter_types! {
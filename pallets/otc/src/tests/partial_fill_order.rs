@@ -311,3 +311,26 @@ fn partial_fill_order_should_throw_error_when_amount_is_larger_than_order() {
 		assert_eq!(bob_dai_balance_after, bob_dai_balance_before);
 	});
 }
+
+#[test]
+fn partial_fill_order_should_decrease_asset_liquidity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true
+		));
+		assert_eq!(OTC::asset_liquidity(HDX), 100 * ONE);
+
+		// Act
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE));
+
+		// Assert
+		assert_eq!(OTC::asset_liquidity(HDX), 75 * ONE);
+		assert_eq!(OTC::asset_liquidity(DAI), 0);
+	});
+}
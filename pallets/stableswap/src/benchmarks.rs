@@ -26,11 +26,11 @@ use frame_support::traits::EnsureOrigin;
 use frame_system::{Pallet as System, RawOrigin};
 use orml_traits::MultiCurrency;
 use orml_traits::MultiCurrencyExtended;
-use sp_runtime::Permill;
+use sp_runtime::{FixedU128, Permill};
 
 use hydradx_traits::Registry;
 
-use crate::types::{AssetBalance, Balance};
+use crate::types::{AssetBalance, Balance, FeeParams};
 
 // Stable benchmarks
 // Worst case scenarios in any stableswap calculations are scenarios where "math" does max number of iterations.
@@ -242,6 +242,17 @@ benchmarks! {
 			1000u32.into(),
 		)?;
 
+		// Worst case is when dynamic fee is recalculated
+		crate::Pallet::<T>::set_pool_fee_params(RawOrigin::Root.into(),
+			pool_id,
+			Some(FeeParams {
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(10),
+				decay: FixedU128::from_rational(1, 1000),
+				amplification: FixedU128::from(2),
+			}),
+		)?;
+
 		System::<T>::set_block_number(500u32.into());
 
 	}: _(RawOrigin::Signed(seller.clone()), pool_id, asset_in, asset_out, amount_sell, buy_min_amount)
@@ -313,6 +324,17 @@ benchmarks! {
 			1000u32.into(),
 		)?;
 
+		// Worst case is when dynamic fee is recalculated
+		crate::Pallet::<T>::set_pool_fee_params(RawOrigin::Root.into(),
+			pool_id,
+			Some(FeeParams {
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(10),
+				decay: FixedU128::from_rational(1, 1000),
+				amplification: FixedU128::from(2),
+			}),
+		)?;
+
 		System::<T>::set_block_number(500u32.into());
 
 	}: _(RawOrigin::Signed(buyer.clone()), pool_id, asset_out, asset_in, amount_buy, sell_max_limit)
@@ -469,5 +491,36 @@ benchmarks! {
 		assert_eq!(pool.final_block, 1000u32.into());
 	}
 
+	set_pool_fee_params{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let name: Vec<u8> = idx.to_ne_bytes().to_vec();
+			let asset_id = T::AssetRegistry::create_asset(&name, 1u128)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, 1_000_000_000_000_000i128)?;
+		}
+		let pool_id = T::AssetRegistry::create_asset(&b"pool".to_vec(), 1u128)?;
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+			Permill::from_percent(1),
+		)?;
+
+		let params = FeeParams {
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+			decay: FixedU128::from_rational(1, 1000),
+			amplification: FixedU128::from(2),
+		};
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Some(params))
+	verify {
+		assert_eq!(crate::Pallet::<T>::pool_fee_params(pool_id), Some(params));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -37,6 +37,14 @@
 //!
 //! When LP decides to withdraw liquidity, it receives selected asset.
 //!
+//! Trade fee of a pool is static unless dynamic fee parameters are set for the pool by `set_pool_fee_params`.
+//! Dynamic trade fee rises with the pool's imbalance, measured as distance of reserves from the balanced point
+//! given by the pool's invariant D, and with the pool's recent trade volume provided by `Oracle`.
+//! Balances and volumes of the pool's assets are normalized to the same number of decimals using `AssetDecimals`
+//! before the fee is calculated.
+//!
+//! Trades are reported to `OnTradeHandler` (e.g. oracle) under `STABLESWAP_SOURCE`.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -44,8 +52,8 @@ extern crate core;
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::{ensure, require_transactional, transactional};
-use hydradx_traits::{AccountIdFor, Registry};
-use sp_runtime::traits::{BlockNumberProvider, Zero};
+use hydradx_traits::{AccountIdFor, OnTradeHandler, Registry, Source};
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
 use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
@@ -53,14 +61,17 @@ use sp_std::prelude::*;
 pub use pallet::*;
 
 mod trade_execution;
+pub mod traits;
 pub mod types;
 pub mod weights;
 
 pub use trade_execution::*;
 
-use crate::types::{AssetBalance, Balance, PoolInfo, Tradability};
-use hydradx_traits::pools::DustRemovalAccountWhitelist;
-use orml_traits::MultiCurrency;
+use crate::traits::VolumeProvider;
+use crate::types::{AssetBalance, Balance, FeeEntry, FeeParams, PoolInfo, Tradability};
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
+use hydradx_traits::pools::{DustRemovalAccountWhitelist, PoolLimitHandler};
+use orml_traits::{GetByKey, MultiCurrency};
 use sp_std::collections::btree_map::BTreeMap;
 use weights::WeightInfo;
 
@@ -76,6 +87,12 @@ pub const POOL_IDENTIFIER: &[u8] = b"sts";
 
 pub const MAX_ASSETS_IN_POOL: u32 = 5;
 
+/// Source of stableswap pools used to identify them in `PoolLimitHandler` and in the oracle.
+pub const STABLESWAP_SOURCE: Source = primitives::constants::chain::STABLESWAP_SOURCE;
+
+/// Number of decimals which balances of pool assets are normalized to when calculating dynamic trade fee.
+const FEE_NORMALIZATION_DECIMALS: u8 = 18;
+
 const D_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_D_ITERATIONS;
 const Y_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_Y_ITERATIONS;
 
@@ -142,6 +159,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type AmplificationRange: Get<RangeInclusive<NonZeroU16>>;
 
+		/// Handler which enforces per block trade and liquidity limits of pools (e.g. circuit breaker).
+		type PoolLimitHandler: PoolLimitHandler<Self::RuntimeOrigin, Self::AccountId, Self::AssetId, Balance>;
+
+		/// Provider of recent trade volume of pools used to calculate dynamic trade fees.
+		type Oracle: VolumeProvider<Self::AssetId, Balance>;
+
+		/// Provider of decimals of assets. Used to normalize balances of pool assets for dynamic trade fees.
+		type AssetDecimals: GetByKey<Self::AssetId, Option<u8>>;
+
+		/// Handler notified about trades in pools (e.g. oracle).
+		type OnTradeHandler: OnTradeHandler<Self::AssetId, Balance>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -157,6 +186,16 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Dynamic trade fee parameters of pools. Static `trade_fee` of a pool is used if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fee_params)]
+	pub type PoolFeeParams<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FeeParams>;
+
+	/// Last calculated dynamic trade fee of pools.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fee)]
+	pub type PoolFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FeeEntry<T::BlockNumber>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -225,6 +264,12 @@ pub mod pallet {
 			start_block: T::BlockNumber,
 			end_block: T::BlockNumber,
 		},
+
+		/// Dynamic trade fee parameters of a pool were set or removed.
+		FeeParamsUpdated {
+			pool_id: T::AssetId,
+			params: Option<FeeParams>,
+		},
 	}
 
 	#[pallet::error]
@@ -307,6 +352,12 @@ pub mod pallet {
 
 		/// Desired amount not reached.
 		MinimumAmountNotReached,
+
+		/// Dynamic fee parameters are invalid. Min fee must not exceed max fee and amplification must not be zero.
+		InvalidFeeParams,
+
+		/// Decimals of a pool asset are not known.
+		UnknownDecimals,
 	}
 
 	#[pallet::call]
@@ -480,14 +531,27 @@ pub mod pallet {
 		///
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::PoolLimitHandler::ensure_liquidity_limit_weight().saturating_mul(assets.len() as u64)))]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
 			pool_id: T::AssetId,
 			assets: Vec<AssetBalance<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+			let who = ensure_signed(origin.clone())?;
+
+			let pool_account = Self::pool_account(pool_id);
+			for asset in assets.iter() {
+				T::PoolLimitHandler::ensure_add_liquidity_limit(
+					origin.clone(),
+					STABLESWAP_SOURCE,
+					pool_account.clone(),
+					asset.asset_id,
+					T::Currency::free_balance(asset.asset_id, &pool_account),
+					asset.amount,
+				)?;
+			}
 
 			let shares = Self::do_add_liquidity(&who, pool_id, &assets)?;
 
@@ -518,7 +582,8 @@ pub mod pallet {
 		///
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
+			.saturating_add(T::PoolLimitHandler::ensure_liquidity_limit_weight()))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -527,7 +592,7 @@ pub mod pallet {
 			share_amount: Balance,
 			min_amount_out: Balance,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+			let who = ensure_signed(origin.clone())?;
 
 			ensure!(
 				Self::is_asset_allowed(pool_id, asset_id, Tradability::REMOVE_LIQUIDITY),
@@ -571,6 +636,15 @@ pub mod pallet {
 
 			ensure!(amount >= min_amount_out, Error::<T>::MinimumAmountNotReached);
 
+			T::PoolLimitHandler::ensure_remove_liquidity_limit(
+				origin,
+				STABLESWAP_SOURCE,
+				pool_account.clone(),
+				asset_id,
+				balances[asset_idx],
+				amount,
+			)?;
+
 			T::Currency::withdraw(pool_id, &who, share_amount)?;
 			T::Currency::transfer(asset_id, &pool_account, &who, amount)?;
 
@@ -598,7 +672,9 @@ pub mod pallet {
 		/// Emits `SellExecuted` event when successful.
 		///
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight())
+			.saturating_add(T::OnTradeHandler::on_trade_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -608,7 +684,7 @@ pub mod pallet {
 			amount_in: Balance,
 			min_buy_amount: Balance,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+			let who = ensure_signed(origin.clone())?;
 
 			ensure!(
 				Self::is_asset_allowed(pool_id, asset_in, Tradability::SELL)
@@ -626,14 +702,20 @@ pub mod pallet {
 				Error::<T>::InsufficientBalance
 			);
 
-			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
+			let (amount_out, fee_amount, trade_fee) =
+				Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
 
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
 			let pool_account = Self::pool_account(pool_id);
+			Self::ensure_trade_limit(origin, &pool_account, asset_in, amount_in, asset_out, amount_out)?;
+
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			Self::store_trade_fee(pool_id, trade_fee);
+			Self::notify_trade(&pool_account, asset_in, asset_out, amount_in, amount_out)?;
+
 			Self::deposit_event(Event::SellExecuted {
 				who,
 				pool_id,
@@ -660,7 +742,9 @@ pub mod pallet {
 		/// Emits `BuyExecuted` event when successful.
 		///
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::PoolLimitHandler::ensure_trade_limit_weight())
+			.saturating_add(T::OnTradeHandler::on_trade_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -670,7 +754,7 @@ pub mod pallet {
 			amount_out: Balance,
 			max_sell_amount: Balance,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+			let who = ensure_signed(origin.clone())?;
 
 			ensure!(
 				Self::is_asset_allowed(pool_id, asset_in, Tradability::SELL)
@@ -683,7 +767,8 @@ pub mod pallet {
 				Error::<T>::InsufficientTradingAmount
 			);
 
-			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
+			let (amount_in, fee_amount, trade_fee) =
+				Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
 
 			let pool_account = Self::pool_account(pool_id);
 
@@ -694,9 +779,14 @@ pub mod pallet {
 				Error::<T>::InsufficientBalance
 			);
 
+			Self::ensure_trade_limit(origin, &pool_account, asset_in, amount_in, asset_out, amount_out)?;
+
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			Self::store_trade_fee(pool_id, trade_fee);
+			Self::notify_trade(&pool_account, asset_in, asset_out, amount_in, amount_out)?;
+
 			Self::deposit_event(Event::BuyExecuted {
 				who,
 				pool_id,
@@ -736,6 +826,47 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or remove dynamic trade fee parameters of a pool.
+		///
+		/// If set, trade fee of the pool is recalculated once per block from the pool's imbalance and recent volume
+		/// and static `trade_fee` of the pool is not used.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `params`: dynamic fee parameters or None to use static trade fee
+		///
+		/// Emits `FeeParamsUpdated` event if successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_fee_params())]
+		#[transactional]
+		pub fn set_pool_fee_params(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			params: Option<FeeParams>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+			match params {
+				Some(fee_params) => {
+					ensure!(fee_params.is_valid(), Error::<T>::InvalidFeeParams);
+					let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+					Self::asset_decimals(&pool)?;
+					PoolFeeParams::<T>::insert(pool_id, fee_params);
+				}
+				None => {
+					PoolFeeParams::<T>::remove(pool_id);
+					PoolFees::<T>::remove(pool_id);
+				}
+			}
+
+			Self::deposit_event(Event::FeeParamsUpdated { pool_id, params });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -748,7 +879,7 @@ impl<T: Config> Pallet<T> {
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_in: Balance,
-	) -> Result<(Balance, Balance), DispatchError> {
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
@@ -761,14 +892,16 @@ impl<T: Config> Pallet<T> {
 		ensure!(balances[index_out] > Balance::zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
+		let trade_fee = Self::trade_fee(pool_id, &pool, &balances, amplification)?;
 		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&balances,
 			index_in,
 			index_out,
 			amount_in,
 			amplification,
-			pool.trade_fee,
+			trade_fee,
 		)
+		.map(|(amount, fee_amount)| (amount, fee_amount, trade_fee))
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
@@ -777,7 +910,7 @@ impl<T: Config> Pallet<T> {
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_out: Balance,
-	) -> Result<(Balance, Balance), DispatchError> {
+	) -> Result<(Balance, Balance, Permill), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
@@ -790,14 +923,16 @@ impl<T: Config> Pallet<T> {
 		ensure!(balances[index_in] > Balance::zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
+		let trade_fee = Self::trade_fee(pool_id, &pool, &balances, amplification)?;
 		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&balances,
 			index_in,
 			index_out,
 			amount_out,
 			amplification,
-			pool.trade_fee,
+			trade_fee,
 		)
+		.map(|(amount, fee_amount)| (amount, fee_amount, trade_fee))
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
@@ -917,6 +1052,147 @@ impl<T: Config> Pallet<T> {
 		Ok(share_amount)
 	}
 
+	fn ensure_trade_limit(
+		origin: T::RuntimeOrigin,
+		pool_account: &T::AccountId,
+		asset_in: T::AssetId,
+		amount_in: Balance,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> DispatchResult {
+		T::PoolLimitHandler::ensure_trade_limit(
+			origin,
+			STABLESWAP_SOURCE,
+			pool_account.clone(),
+			asset_in,
+			T::Currency::free_balance(asset_in, pool_account),
+			amount_in,
+			asset_out,
+			T::Currency::free_balance(asset_out, pool_account),
+			amount_out,
+		)?;
+		Ok(())
+	}
+
+	/// Returns trade fee of a pool.
+	///
+	/// If dynamic fee parameters are set for the pool, the fee is recalculated from the pool's imbalance and volume,
+	/// unless it has been already calculated in current block. Otherwise static `trade_fee` of the pool is returned.
+	fn trade_fee(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		balances: &[Balance],
+		amplification: Balance,
+	) -> Result<Permill, DispatchError> {
+		let Some(params) = PoolFeeParams::<T>::get(pool_id) else {
+			return Ok(pool.trade_fee);
+		};
+
+		let block_number = T::BlockNumberProvider::current_block_number();
+		let current_fee_entry = PoolFees::<T>::get(pool_id).unwrap_or(FeeEntry {
+			fee: params.min_fee,
+			timestamp: T::BlockNumber::default(),
+		});
+
+		// Update only if it has not yet been updated this block
+		if block_number == current_fee_entry.timestamp {
+			return Ok(current_fee_entry.fee);
+		}
+
+		// assets with different decimals are compared in the same units
+		let decimals = Self::asset_decimals(pool)?;
+		let balances = balances
+			.iter()
+			.zip(decimals.iter())
+			.map(|(balance, decimals)| normalize_value(*balance, *decimals))
+			.collect::<Vec<_>>();
+
+		let d = hydra_dx_math::stableswap::calculate_d::<D_ITERATIONS>(&balances, amplification)
+			.ok_or(ArithmeticError::Overflow)?;
+		let imbalance = hydra_dx_math::dynamic_fees::calculate_stableswap_imbalance(&balances, d);
+
+		let (amount_in, amount_out) = pool
+			.assets
+			.iter()
+			.zip(decimals.iter())
+			.filter_map(|(asset_id, decimals)| {
+				T::Oracle::asset_volume(pool_id, *asset_id).map(|(amount_in, amount_out)| {
+					(
+						normalize_value(amount_in, *decimals),
+						normalize_value(amount_out, *decimals),
+					)
+				})
+			})
+			.fold(
+				(Balance::zero(), Balance::zero()),
+				|(acc_in, acc_out), (amount_in, amount_out)| {
+					(acc_in.saturating_add(amount_in), acc_out.saturating_add(amount_out))
+				},
+			);
+		let liquidity = balances
+			.iter()
+			.fold(Balance::zero(), |acc, balance| acc.saturating_add(*balance));
+
+		let delta_blocks: u128 = block_number
+			.saturating_sub(current_fee_entry.timestamp)
+			.saturated_into();
+
+		Ok(hydra_dx_math::dynamic_fees::recalculate_stableswap_fee(
+			imbalance,
+			OracleEntry {
+				amount_in,
+				amount_out,
+				liquidity,
+			},
+			current_fee_entry.fee,
+			delta_blocks,
+			params.into(),
+		))
+	}
+
+	/// Returns decimals of the pool's assets in the order of the pool's assets.
+	fn asset_decimals(pool: &PoolInfo<T::AssetId, T::BlockNumber>) -> Result<Vec<u8>, DispatchError> {
+		pool.assets
+			.iter()
+			.map(|asset_id| T::AssetDecimals::get(asset_id).ok_or_else(|| Error::<T>::UnknownDecimals.into()))
+			.collect()
+	}
+
+	/// Notify `OnTradeHandler` about the trade. Reserves of the pool after the trade are reported as liquidity.
+	fn notify_trade(
+		pool_account: &T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		T::OnTradeHandler::on_trade(
+			STABLESWAP_SOURCE,
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			T::Currency::free_balance(asset_in, pool_account),
+			T::Currency::free_balance(asset_out, pool_account),
+		)
+		.map_err(|(_, e)| e)?;
+
+		Ok(())
+	}
+
+	/// Stores trade fee calculated in current block if the pool uses dynamic fee.
+	fn store_trade_fee(pool_id: T::AssetId, fee: Permill) {
+		if PoolFeeParams::<T>::contains_key(pool_id) {
+			PoolFees::<T>::insert(
+				pool_id,
+				FeeEntry {
+					fee,
+					timestamp: T::BlockNumberProvider::current_block_number(),
+				},
+			);
+		}
+	}
+
 	#[inline]
 	fn is_asset_allowed(pool_id: T::AssetId, asset_id: T::AssetId, operation: Tradability) -> bool {
 		AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
@@ -938,3 +1214,12 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 }
+
+/// Scales `value` given in `decimals` to `FEE_NORMALIZATION_DECIMALS`.
+fn normalize_value(value: Balance, decimals: u8) -> Balance {
+	if decimals <= FEE_NORMALIZATION_DECIMALS {
+		value.saturating_mul(10u128.saturating_pow((FEE_NORMALIZATION_DECIMALS - decimals).into()))
+	} else {
+		value / 10u128.saturating_pow((decimals - FEE_NORMALIZATION_DECIMALS).into())
+	}
+}
@@ -0,0 +1,299 @@
+use crate::tests::mock::*;
+use crate::types::{AssetBalance, FeeEntry, FeeParams, PoolInfo};
+use crate::Error;
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::{FixedU128, Permill};
+
+fn fee_params() -> FeeParams {
+	FeeParams {
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(10),
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+fn with_balanced_pool() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), 1)
+		.with_registered_asset("two".as_bytes().to_vec(), 2)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![1, 2].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetBalance {
+						asset_id: 1,
+						amount: 100 * ONE,
+					},
+					AssetBalance {
+						asset_id: 2,
+						amount: 100 * ONE,
+					},
+				],
+			},
+		)
+}
+
+#[test]
+fn set_pool_fee_params_should_work_when_params_are_valid() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+
+		assert_eq!(Stableswap::pool_fee_params(pool_id), Some(fee_params()));
+	});
+}
+
+#[test]
+fn set_pool_fee_params_should_fail_when_min_fee_exceeds_max_fee() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_fee_params(
+				RuntimeOrigin::root(),
+				pool_id,
+				Some(FeeParams {
+					min_fee: Permill::from_percent(20),
+					..fee_params()
+				})
+			),
+			Error::<Test>::InvalidFeeParams
+		);
+	});
+}
+
+#[test]
+fn set_pool_fee_params_should_fail_when_pool_does_not_exist() {
+	with_balanced_pool().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_pool_fee_params(RuntimeOrigin::root(), 1_000, Some(fee_params())),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_pool_fee_params_should_remove_calculated_fee_when_params_are_removed() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+		assert!(Stableswap::pool_fee(pool_id).is_some());
+
+		assert_ok!(Stableswap::set_pool_fee_params(RuntimeOrigin::root(), pool_id, None));
+
+		assert_eq!(Stableswap::pool_fee_params(pool_id), None);
+		assert_eq!(Stableswap::pool_fee(pool_id), None);
+	});
+}
+
+#[test]
+fn sell_should_use_min_fee_when_pool_is_balanced_and_there_is_no_volume() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		assert_eq!(
+			Stableswap::pool_fee(pool_id),
+			Some(FeeEntry {
+				fee: Permill::from_percent(1),
+				timestamp: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn sell_should_use_higher_fee_when_volume_is_high() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		// (Vi + Vo) / L = 10 / 200
+		set_asset_volume(1, 5 * ONE, 0);
+		set_asset_volume(2, 0, 5 * ONE);
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		assert_eq!(
+			Stableswap::pool_fee(pool_id),
+			Some(FeeEntry {
+				fee: Permill::from_percent(6),
+				timestamp: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn sell_should_use_higher_fee_when_pool_is_imbalanced() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 50 * ONE, 0));
+		let balanced_pool_fee = Stableswap::pool_fee(pool_id).unwrap().fee;
+
+		System::set_block_number(2);
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		assert!(Stableswap::pool_fee(pool_id).unwrap().fee > balanced_pool_fee);
+	});
+}
+
+#[test]
+fn sell_should_not_recalculate_fee_when_already_calculated_in_same_block() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		assert_ok!(Stableswap::set_pool_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 50 * ONE, 0));
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		assert_eq!(
+			Stableswap::pool_fee(pool_id),
+			Some(FeeEntry {
+				fee: Permill::from_percent(1),
+				timestamp: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn set_pool_fee_params_should_fail_when_decimals_of_asset_are_unknown() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		set_asset_decimals(2, None);
+
+		assert_noop!(
+			Stableswap::set_pool_fee_params(RuntimeOrigin::root(), pool_id, Some(fee_params())),
+			Error::<Test>::UnknownDecimals
+		);
+	});
+}
+
+#[test]
+fn sell_should_use_min_fee_when_pool_is_balanced_in_assets_with_different_decimals() {
+	set_asset_decimals(1, Some(6));
+	set_asset_decimals(2, Some(18));
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, 1, 200 * 1_000_000),
+			(ALICE, 1, 200 * 1_000_000),
+			(ALICE, 2, 200 * 1_000_000_000_000_000_000),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), 1)
+		.with_registered_asset("two".as_bytes().to_vec(), 2)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![1, 2].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetBalance {
+						asset_id: 1,
+						amount: 100 * 1_000_000,
+					},
+					AssetBalance {
+						asset_id: 2,
+						amount: 100 * 1_000_000_000_000_000_000,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			System::set_block_number(1);
+			assert_ok!(Stableswap::set_pool_fee_params(
+				RuntimeOrigin::root(),
+				pool_id,
+				Some(fee_params())
+			));
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				1,
+				2,
+				1_000_000,
+				0
+			));
+
+			assert_eq!(
+				Stableswap::pool_fee(pool_id),
+				Some(FeeEntry {
+					fee: Permill::from_percent(1),
+					timestamp: 1,
+				})
+			);
+		});
+}
+
+#[test]
+fn sell_should_report_trade_to_on_trade_handler() {
+	with_balanced_pool().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let balance_before = Tokens::free_balance(2, &BOB);
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		let amount_out = Tokens::free_balance(2, &BOB) - balance_before;
+		assert_eq!(recorded_trades(), vec![(1, 2, 10 * ONE, amount_out)]);
+	});
+}
@@ -30,13 +30,15 @@ use crate::Config;
 
 use frame_support::assert_ok;
 use frame_support::traits::{Contains, Everything, GenesisBuild};
+use frame_support::weights::Weight;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use frame_system::EnsureRoot;
-use orml_traits::parameter_type_with_key;
+use hydradx_traits::{OnTradeHandler, Source};
 pub use orml_traits::MultiCurrency;
+use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -71,6 +73,9 @@ thread_local! {
 	pub static ASSET_IDENTS: RefCell<HashMap<Vec<u8>, u32>> = RefCell::new(HashMap::default());
 	pub static POOL_IDS: RefCell<Vec<AssetId>> = RefCell::new(Vec::new());
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static ASSET_VOLUMES: RefCell<HashMap<AssetId, (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static ASSET_DECIMALS: RefCell<HashMap<AssetId, Option<u8>>> = RefCell::new(HashMap::default());
+	pub static TRADES: RefCell<Vec<(AssetId, AssetId, Balance, Balance)>> = RefCell::new(Vec::new());
 }
 
 construct_runtime!(
@@ -140,6 +145,60 @@ parameter_types! {
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
 }
 
+pub struct MockVolumeProvider;
+
+impl VolumeProvider<AssetId, Balance> for MockVolumeProvider {
+	fn asset_volume(_pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)> {
+		ASSET_VOLUMES.with(|v| v.borrow().get(&asset_id).copied())
+	}
+}
+
+pub fn set_asset_volume(asset_id: AssetId, amount_in: Balance, amount_out: Balance) {
+	ASSET_VOLUMES.with(|v| {
+		v.borrow_mut().insert(asset_id, (amount_in, amount_out));
+	});
+}
+
+/// Decimals of assets are 12 unless set by `set_asset_decimals`.
+pub struct MockAssetDecimals;
+
+impl GetByKey<AssetId, Option<u8>> for MockAssetDecimals {
+	fn get(asset_id: &AssetId) -> Option<u8> {
+		ASSET_DECIMALS.with(|v| v.borrow().get(asset_id).copied().unwrap_or(Some(12)))
+	}
+}
+
+pub fn set_asset_decimals(asset_id: AssetId, decimals: Option<u8>) {
+	ASSET_DECIMALS.with(|v| {
+		v.borrow_mut().insert(asset_id, decimals);
+	});
+}
+
+pub struct MockOnTradeHandler;
+
+impl OnTradeHandler<AssetId, Balance> for MockOnTradeHandler {
+	fn on_trade(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		_liquidity_a: Balance,
+		_liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADES.with(|v| v.borrow_mut().push((asset_a, asset_b, amount_a, amount_b)));
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub fn recorded_trades() -> Vec<(AssetId, AssetId, Balance, Balance)> {
+	TRADES.with(|v| v.borrow().clone())
+}
+
 pub struct Whitelist;
 
 impl Contains<AccountId> for Whitelist {
@@ -181,6 +240,10 @@ impl Config for Test {
 	type WeightInfo = ();
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
+	type PoolLimitHandler = ();
+	type Oracle = MockVolumeProvider;
+	type AssetDecimals = MockAssetDecimals;
+	type OnTradeHandler = MockOnTradeHandler;
 }
 
 pub struct InitialLiquidity {
@@ -300,6 +363,7 @@ impl ExtBuilder {
 	}
 }
 
+use crate::traits::VolumeProvider;
 use crate::types::{AssetBalance, PoolInfo};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::{AccountIdFor, AssetKind, Registry, ShareTokenRegistry};
@@ -1,6 +1,7 @@
 mod add_liquidity;
 mod amplification;
 mod creation;
+mod dynamic_fees;
 mod invariants;
 pub(crate) mod mock;
 mod remove_liquidity;
@@ -13,7 +13,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
-				let (amount_out, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
+				let (amount_out, _, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
 					.map_err(ExecutorError::Error)?;
 
 				Ok(amount_out)
@@ -30,7 +30,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
-				let (amount_in, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
+				let (amount_in, _, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
 					.map_err(ExecutorError::Error)?;
 
 				Ok(amount_in)
@@ -0,0 +1,11 @@
+/// Provider of recent trade volume of assets in stableswap pools (e.g. oracle).
+pub trait VolumeProvider<AssetId, Balance> {
+	/// Returns `(amount_in, amount_out)` volume of an asset in a pool.
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)>;
+}
+
+impl<AssetId, Balance> VolumeProvider<AssetId, Balance> for () {
+	fn asset_volume(_pool_id: AssetId, _asset_id: AssetId) -> Option<(Balance, Balance)> {
+		None
+	}
+}
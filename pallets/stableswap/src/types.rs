@@ -2,7 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{Config, MAX_ASSETS_IN_POOL};
-use sp_runtime::Permill;
+use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
+use sp_runtime::{FixedU128, Permill};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
@@ -13,6 +14,7 @@ use frame_support::BoundedVec;
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
+use sp_runtime::traits::Zero;
 
 pub(crate) type Balance = u128;
 
@@ -64,6 +66,45 @@ where
 	}
 }
 
+/// Parameters of a pool's dynamic trade fee.
+/// `min_fee`: minimum trade fee, used when the pool is balanced and there is no volume
+/// `max_fee`: maximum trade fee
+/// `decay`: decrease of the fee per block
+/// `amplification`: multiplier of the pool's imbalance and volume
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeeParams {
+	pub min_fee: Permill,
+	pub max_fee: Permill,
+	pub decay: FixedU128,
+	pub amplification: FixedU128,
+}
+
+impl FeeParams {
+	pub(crate) fn is_valid(&self) -> bool {
+		self.min_fee <= self.max_fee && !self.amplification.is_zero()
+	}
+}
+
+impl From<FeeParams> for MathFeeParams<Permill> {
+	fn from(value: FeeParams) -> Self {
+		MathFeeParams {
+			min_fee: value.min_fee,
+			max_fee: value.max_fee,
+			decay: value.decay,
+			amplification: value.amplification,
+		}
+	}
+}
+
+/// Last calculated dynamic trade fee of a pool and the block in which it was calculated.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeeEntry<BlockNumber> {
+	pub fee: Permill,
+	pub timestamp: BlockNumber,
+}
+
 #[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct AssetBalance<AssetId> {
 	pub asset_id: AssetId,
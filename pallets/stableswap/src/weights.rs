@@ -53,6 +53,7 @@ pub trait WeightInfo {
 	fn set_asset_tradable_state() -> Weight;
 	fn update_pool_fees() -> Weight;
 	fn update_amplification() -> Weight;
+	fn set_pool_fee_params() -> Weight;
 }
 
 pub struct BasiliskWeight<T>(PhantomData<T>);
@@ -93,6 +94,9 @@ impl<T: frame_system::Config> WeightInfo for BasiliskWeight<T> {
 	fn update_amplification() -> Weight {
 		Weight::from_ref_time(0)
 	}
+	fn set_pool_fee_params() -> Weight {
+		Weight::from_ref_time(0)
+	}
 }
 
 // For backwards compatibility and tests
@@ -132,4 +136,7 @@ impl WeightInfo for () {
 	fn update_amplification() -> Weight {
 		Weight::from_ref_time(0)
 	}
+	fn set_pool_fee_params() -> Weight {
+		Weight::from_ref_time(0)
+	}
 }
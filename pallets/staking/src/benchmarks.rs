@@ -17,9 +17,11 @@
 
 use super::*;
 
-use crate::types::{Conviction, Vote};
+use crate::traits::ActionHook;
+use crate::types::{Action, Conviction, LiquidityProvision, UnbondingChunk, Vote};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
+use frame_support::BoundedVec;
 use frame_system::{Pallet as System, RawOrigin};
 use orml_traits::MultiCurrencyExtended;
 use sp_std::vec::Vec;
@@ -86,6 +88,7 @@ benchmarks! {
 	 where_clause { where
 		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
 		T: crate::pallet::Config,
+		T::AssetId: From<u32>,
 	}
 
 	initialize_staking {
@@ -199,5 +202,228 @@ benchmarks! {
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
+	unstake_partial {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+		let amount = 30_000 * UNIT;
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		generate_max_votes::<T>(position_id);
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(2);
+	}: _(RawOrigin::Signed(caller_1.clone()), position_id, amount)
+	verify {
+		let staked_amount = Pallet::<T>::positions(position_id).unwrap().stake;
+		assert_eq!(staked_amount, 20_000 * UNIT)
+	}
+
+	withdraw_unbonded {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let chunks: BoundedVec<UnbondingChunk, T::MaxUnbondingChunks> =
+			sp_std::vec![UnbondingChunk::new(30_000 * UNIT, 0)].try_into().unwrap();
+		crate::Unbondings::<T>::insert(&caller_1, chunks);
+		T::Currency::set_lock(STAKING_LOCK_ID, hdx, &caller_1, 80_000 * UNIT)?;
+	}: _(RawOrigin::Signed(caller_1.clone()))
+	verify {
+		assert!(Pallet::<T>::unbondings(&caller_1).is_empty())
+	}
+
+	set_auto_compound {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), position_id, true)
+	verify {
+		assert!(Pallet::<T>::is_auto_compound(position_id))
+	}
+
+	set_reward_beneficiary {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let beneficiary: T::AccountId = account("beneficiary", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), position_id, Some(beneficiary.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::reward_beneficiary(position_id), Some(beneficiary))
+	}
+
+	auto_compound {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		Pallet::<T>::set_auto_compound(RawOrigin::Signed(caller_1).into(), position_id, true)?;
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(<u128 as TryInto<u32>>::try_into(T::UnclaimablePeriods::get()).unwrap() + 1_u32);
+	}: {
+		Pallet::<T>::compound_position(position_id)?
+	}
+	verify {
+		assert!(Pallet::<T>::positions(position_id).unwrap().stake > 50_000 * UNIT)
+	}
+
+	on_action {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+		let data = LiquidityProvision::new(100_000 * UNIT, 10);
+	}: {
+		<Pallet::<T> as ActionHook<T::AccountId>>::on_action(&caller, Action::OmnipoolLiquidity, data)?
+	}
+	verify {
+		assert!(Pallet::<T>::positions(position_id).unwrap().action_points > 0)
+	}
+
+	transfer_position {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let new_owner: T::AccountId = account("new_owner", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+		generate_max_votes::<T>(position_id);
+	}: _(RawOrigin::Signed(caller.clone()), position_id, new_owner.clone())
+	verify {
+		assert_eq!(Pallet::<T>::get_user_position_id(&new_owner).unwrap(), Some(position_id));
+		assert_eq!(T::Currency::free_balance(hdx, &new_owner), 50_000 * UNIT);
+	}
+
+	merge {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (200_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0.clone()).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let into_position_id = Pallet::<T>::get_user_position_id(&caller_0).unwrap().unwrap();
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		generate_max_votes::<T>(position_id);
+
+		// Accounts can't own 2 positions so NFT is moved directly.
+		let collection_id = T::NFTCollectionId::get();
+		T::NFTHandler::burn(&collection_id, &position_id, Some(&caller_1))?;
+		T::NFTHandler::mint_into(&collection_id, &position_id, &caller_0)?;
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(<u128 as TryInto<u32>>::try_into(T::UnclaimablePeriods::get()).unwrap() + 1_u32);
+	}: _(RawOrigin::Signed(caller_0.clone()), position_id, into_position_id)
+	verify {
+		assert!(Pallet::<T>::positions(position_id).is_none());
+		assert_eq!(Pallet::<T>::positions(into_position_id).unwrap().stake, 100_000 * UNIT);
+	}
+
+	add_reward_currency {
+		let asset_id: T::AssetId = 1_000_u32.into();
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(Pallet::<T>::reward_currency(asset_id).is_some())
+	}
+
+	set_swap_rewards {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), position_id, true)
+	verify {
+		assert!(Pallet::<T>::is_swap_rewards(position_id))
+	}
+
+	reward_currencies {
+		let c in 0 .. T::MaxRewardCurrencies::get();
+
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+		let pot = Pallet::<T>::pot_account_id();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+
+		let mut currencies = Vec::new();
+		for i in 0..c {
+			let asset_id: T::AssetId = (1_000_u32 + i).into();
+			Pallet::<T>::add_reward_currency(RawOrigin::Root.into(), asset_id)?;
+			currencies.push(asset_id);
+		}
+
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+
+		for asset_id in currencies.iter() {
+			T::Currency::update_balance(*asset_id, &pot, (10_000 * UNIT) as i128)?;
+		}
+		let total_stake = Pallet::<T>::staking().total_stake;
+	}: {
+		Pallet::<T>::update_currency_rewards(total_stake)?;
+		Pallet::<T>::settle_currency_rewards(position_id, 50_000 * UNIT)?;
+		Pallet::<T>::pay_currency_rewards(&caller, position_id, FixedU128::one(), true)?;
+	}
+	verify {
+		for asset_id in currencies {
+			assert!(T::Currency::free_balance(asset_id, &caller) > 0);
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -1,5 +1,5 @@
 use crate::pallet::{PositionVotes, Positions};
-use crate::traits::DemocracyReferendum;
+use crate::traits::{DemocracyReferendum, VotingLock};
 use crate::types::{Balance, Conviction, Vote};
 use crate::{Config, Error, Pallet};
 use frame_support::defensive;
@@ -7,6 +7,7 @@ use frame_support::dispatch::DispatchResult;
 use orml_traits::MultiCurrencyExtended;
 use pallet_democracy::traits::DemocracyHooks;
 use pallet_democracy::{AccountVote, ReferendumIndex, ReferendumInfo};
+use sp_runtime::SaturatedConversion;
 
 pub struct StakingDemocracy<T>(sp_std::marker::PhantomData<T>);
 
@@ -100,6 +101,11 @@ where
 		Ok(())
 	}
 
+	fn locked_balance(who: &T::AccountId) -> Balance {
+		// Democracy locks the votes itself if staking lock is not known.
+		Pallet::<T>::get_locked_balance(who).unwrap_or_default()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn on_vote_worst_case(who: &T::AccountId) {
 		use frame_system::Origin;
@@ -160,3 +166,24 @@ impl<T: pallet_democracy::Config> DemocracyReferendum for ReferendumStatus<T> {
 		matches!(maybe_info, Some(ReferendumInfo::Finished { .. }))
 	}
 }
+
+pub struct DemocracyVotingLock<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: pallet_democracy::Config> VotingLock<T::AccountId> for DemocracyVotingLock<T> {
+	fn locked_balance(who: &T::AccountId) -> Balance {
+		pallet_democracy::Pallet::<T>::voting_locked_balance(who).saturated_into()
+	}
+
+	fn has_vote(who: &T::AccountId, ref_index: ReferendumIndex) -> bool {
+		match pallet_democracy::VotingOf::<T>::get(who) {
+			pallet_democracy::Voting::Direct { votes, .. } => {
+				votes.binary_search_by_key(&ref_index, |(i, _)| *i).is_ok()
+			}
+			pallet_democracy::Voting::Delegating { .. } => false,
+		}
+	}
+
+	fn update_lock(who: &T::AccountId) {
+		pallet_democracy::Pallet::<T>::update_lock(who);
+	}
+}
@@ -17,12 +17,19 @@
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::traits::{ActionData, DemocracyReferendum, PayablePercentage, VestingDetails};
-use crate::types::{Action, Balance, Period, Point, Position, StakingData, Voting};
+use crate::traits::{
+	ActionData, ActionHook, DemocracyReferendum, PayablePercentage, RewardSwap, VestingDetails, VotingLock,
+};
+use crate::types::{
+	Action, Balance, Period, Point, Position, PositionCurrencyRewards, RewardCurrencyData, StakingData, UnbondingChunk,
+	Voting,
+};
 use frame_support::ensure;
 use frame_support::{
+	defensive,
 	pallet_prelude::DispatchResult,
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
 	traits::{DefensiveOption, LockIdentifier},
 };
@@ -36,6 +43,7 @@ use sp_runtime::{
 };
 use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
 use sp_std::num::NonZeroU128;
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -44,6 +52,7 @@ mod tests;
 mod benchmarks;
 
 pub mod integrations;
+pub mod migration;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -55,6 +64,8 @@ pub use weights::WeightInfo;
 /// Lock ID for staked assets.
 pub const STAKING_LOCK_ID: LockIdentifier = *b"stk_stks";
 
+const LOG_TARGET: &str = "runtime::staking";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -67,7 +78,7 @@ pub mod pallet {
 	use sp_runtime::traits::AtLeast32BitUnsigned;
 
 	/// Current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
@@ -106,6 +117,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type NativeAssetId: Get<Self::AssetId>;
 
+		/// Hub asset ID of the Omnipool. Hub asset can't be used as a reward currency because its
+		/// trade fees stay in the Omnipool.
+		#[pallet::constant]
+		type HubAssetId: Get<Self::AssetId>;
+
 		/// Min amount user must stake.
 		#[pallet::constant]
 		type MinStake: Get<Balance>;
@@ -135,6 +151,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotes: Get<u32>;
 
+		/// Number of periods unstaked tokens stay locked before they can be withdrawn.
+		/// Unstaked tokens are unlocked immediately if set to 0.
+		#[pallet::constant]
+		type UnbondingPeriods: Get<Period>;
+
+		/// Max amount of unbonding chunks the user can have at any time.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
+		/// Max amount of non-native reward currencies.
+		#[pallet::constant]
+		type MaxRewardCurrencies: Get<u32>;
+
+		/// Swaps non-native rewards to the native asset.
+		type RewardSwap: RewardSwap<Self::AccountId, Self::AssetId>;
+
 		/// NFT collection id.
 		#[pallet::constant]
 		type NFTCollectionId: Get<Self::CollectionId>;
@@ -169,6 +201,10 @@ pub mod pallet {
 		/// Democracy referendum state.
 		type ReferendumInfo: DemocracyReferendum;
 
+		/// Provides amount of user's balance locked by democracy votes. Staked tokens backing
+		/// the votes stay locked in staking until the votes are unlocked in democracy.
+		type VotingLock: VotingLock<Self::AccountId>;
+
 		/// Provides information about amount of vested tokens.
 		type Vesting: VestingDetails<Self::AccountId, Balance>;
 
@@ -197,6 +233,69 @@ pub mod pallet {
 	pub(super) type PositionVotes<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PositionItemId, Voting<T::MaxVotes>, ValueQuery>;
 
+	#[pallet::storage]
+	/// List of user's unstaked amounts waiting for the end of the unbonding period.
+	#[pallet::getter(fn unbondings)]
+	pub(super) type Unbondings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<UnbondingChunk, T::MaxUnbondingChunks>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Positions which rewards are added to the stake instead of being paid out.
+	#[pallet::getter(fn is_auto_compound)]
+	pub(super) type AutoCompound<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Last auto-compounded position in `on_idle`. Next `on_idle` continues from the following position.
+	#[pallet::getter(fn auto_compound_cursor)]
+	pub(super) type AutoCompoundCursor<T: Config> = StorageValue<_, T::PositionItemId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Whether votes of the positions still have to be migrated to storage v2 in `on_idle`.
+	#[pallet::getter(fn is_votes_migration_pending)]
+	pub(super) type VotesMigrationPending<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Last position which votes were migrated. Next `on_idle` continues from the following position.
+	#[pallet::getter(fn votes_migration_cursor)]
+	pub(super) type VotesMigrationCursor<T: Config> = StorageValue<_, T::PositionItemId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Account receiving position's rewards instead of the position's owner.
+	#[pallet::getter(fn reward_beneficiary)]
+	pub(super) type RewardBeneficiary<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PositionItemId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Period in which position was last rewarded with action points for the action reported by
+	/// `ActionHook`. Position is rewarded for each such action at most once per period.
+	#[pallet::getter(fn action_rewarded_at)]
+	pub(super) type ActionRewardedAt<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PositionItemId, Blake2_128Concat, Action, Period, OptionQuery>;
+
+	#[pallet::storage]
+	/// Non-native currencies distributed to stakers as rewards.
+	#[pallet::getter(fn reward_currency)]
+	pub(super) type RewardCurrencies<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AssetId, RewardCurrencyData, OptionQuery>;
+
+	#[pallet::storage]
+	/// Position's rewards in non-native reward currencies.
+	#[pallet::getter(fn position_currency_rewards)]
+	pub(super) type PositionRewards<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PositionItemId,
+		Twox64Concat,
+		T::AssetId,
+		PositionCurrencyRewards,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Positions which non-native rewards are swapped to the native asset when paid.
+	#[pallet::getter(fn is_swap_rewards)]
+	pub(super) type SwapRewards<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -236,6 +335,93 @@ pub mod pallet {
 			unlocked_rewards: Balance,
 		},
 
+		/// Part of the staked amount was unstaked.
+		PartiallyUnstaked {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			unstaked: Balance,
+			remaining_stake: Balance,
+			locked_rewards: Balance,
+			slashed_points: Point,
+		},
+
+		/// Unstaked amount stays locked until `unlock_at` period.
+		UnbondingScheduled {
+			who: T::AccountId,
+			amount: Balance,
+			unlock_at: Period,
+		},
+
+		/// Unbonded tokens were unlocked.
+		UnbondedWithdrawn { who: T::AccountId, amount: Balance },
+
+		/// Auto-compounding of position's rewards was enabled or disabled.
+		AutoCompoundUpdated {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			enabled: bool,
+		},
+
+		/// Position's rewards were added to the staked amount.
+		RewardsCompounded {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			rewards: Balance,
+			total_stake: Balance,
+			slashed_points: Point,
+		},
+
+		/// Beneficiary of position's rewards was set or removed.
+		RewardBeneficiaryUpdated {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			beneficiary: Option<T::AccountId>,
+		},
+
+		/// Action points were added to the position.
+		ActionPointsAdded {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			action: Action,
+			points: Point,
+		},
+
+		/// Staking position was transferred to the new owner.
+		PositionTransferred {
+			who: T::AccountId,
+			new_owner: T::AccountId,
+			position_id: T::PositionItemId,
+			locked: Balance,
+		},
+
+		/// Staking position was merged into another position of the same owner and NFT was burned.
+		PositionsMerged {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			into_position_id: T::PositionItemId,
+			total_stake: Balance,
+		},
+
+		/// Non-native currency was added to the reward currencies.
+		RewardCurrencyAdded { asset_id: T::AssetId },
+
+		/// Swapping of position's non-native rewards to the native asset was enabled or disabled.
+		SwapRewardsUpdated {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			enabled: bool,
+		},
+
+		/// Position's rewards in non-native currency were paid. `native_amount` is amount of the native
+		/// asset received for the rewards if they were swapped.
+		CurrencyRewardsPaid {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			native_amount: Balance,
+		},
+
 		/// Staking was initialized.
 		StakingInitialized { non_dustable_balance: Balance },
 
@@ -278,6 +464,30 @@ pub mod pallet {
 		/// Signer is not an owner of the staking position.
 		Forbidden,
 
+		/// Unstaked amount is zero or remaining stake would be lower than `MinStake`.
+		InvalidUnstakeAmount,
+
+		/// Maximum amount of unbonding chunks were reached.
+		MaxUnbondingChunksReached,
+
+		/// There are no unbonded tokens to withdraw.
+		NothingToWithdraw,
+
+		/// Rewards can't be auto-compounded and paid to the beneficiary at the same time.
+		IncompatibleRewardSettings,
+
+		/// Position can't be merged into itself.
+		SamePosition,
+
+		/// Native asset or already added currency can't be added to the reward currencies.
+		InvalidRewardCurrency,
+
+		/// Maximum amount of reward currencies were reached.
+		MaxRewardCurrenciesReached,
+
+		/// Tokens can't be unlocked because they back democracy votes.
+		LockedByVotes,
+
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
@@ -357,7 +567,8 @@ pub mod pallet {
 		/// Emits `PositionCreated` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::stake())]
+		#[pallet::weight(<T as Config>::WeightInfo::stake()
+			.saturating_add(<T as Config>::WeightInfo::reward_currencies(T::MaxRewardCurrencies::get())))]
 		pub fn stake(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -376,8 +587,9 @@ pub mod pallet {
 				Self::ensure_stakeable_balance(&who, amount, None)?;
 				let position_id =
 					Self::create_position_and_mint_nft(&who, amount, staking.accumulated_reward_per_stake)?;
+				Self::settle_currency_rewards(position_id, Zero::zero())?;
 
-				T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who, amount)?;
+				Self::update_lock(&who, amount)?;
 
 				staking.add_stake(amount)?;
 
@@ -408,7 +620,8 @@ pub mod pallet {
 		/// Emits `StakeAdded` event when successful.
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::increase_stake())]
+		#[pallet::weight(<T as Config>::WeightInfo::increase_stake()
+			.saturating_add(<T as Config>::WeightInfo::reward_currencies(T::MaxRewardCurrencies::get())))]
 		pub fn increase_stake(origin: OriginFor<T>, position_id: T::PositionItemId, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -429,6 +642,11 @@ pub mod pallet {
 					Self::ensure_stakeable_balance(&who, amount, Some(position))?;
 
 					Self::process_votes(position_id, position)?;
+					Self::settle_currency_rewards(position_id, position.stake)?;
+
+					if Self::is_auto_compound(position_id) {
+						Self::do_compound(&who, position_id, position, staking)?;
+					}
 
 					let current_period = Self::get_current_period()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
@@ -447,6 +665,8 @@ pub mod pallet {
 						.checked_add(claimable_unpaid_rewards)
 						.ok_or(Error::<T>::Arithmetic)?;
 
+					// Rewards stay locked in the position so they are paid to the owner even if
+					// the reward beneficiary is set.
 					let pot = Self::pot_account_id();
 					T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
 
@@ -486,12 +706,7 @@ pub mod pallet {
 
 					staking.add_stake(amount)?;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
-						&who,
-						position.get_total_locked()?,
-					)?;
+					Self::update_lock(&who, position.get_total_locked()?)?;
 
 					Self::deposit_event(Event::StakeAdded {
 						who,
@@ -512,6 +727,7 @@ pub mod pallet {
 		/// Function calculates amount of rewards to pay for specified staking position based on
 		/// the amount of points position accumulated. Function also unlocks portion of the rewards locked
 		/// from `increase_stake` based on the amount of the points.
+		/// Unlocked rewards backing democracy votes of the owner stay locked until the votes are unlocked.
 		///
 		/// This action is penalized by removing all the points and returning allocated unpaid rewards
 		/// for redistribution.
@@ -522,7 +738,7 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` event when successful.
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim().saturating_add(Pallet::<T>::reward_currencies_weight()))]
 		pub fn claim(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -538,7 +754,13 @@ pub mod pallet {
 						.as_mut()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
 
+					let locked_before = position
+						.get_total_locked()?
+						.checked_add(Self::get_unbonding_balance(&who))
+						.ok_or(Error::<T>::Arithmetic)?;
+
 					Self::process_votes(position_id, position)?;
+					Self::settle_currency_rewards(position_id, position.stake)?;
 
 					let current_period = Self::get_current_period()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
@@ -558,8 +780,12 @@ pub mod pallet {
 						.checked_add(claimable_unpaid_rewards)
 						.ok_or(Error::<T>::Arithmetic)?;
 
+					let beneficiary = Self::reward_beneficiary(position_id).unwrap_or_else(|| who.clone());
 					let pot = Self::pot_account_id();
-					T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
+					T::Currency::transfer(T::NativeAssetId::get(), &pot, &beneficiary, rewards_to_pay)?;
+
+					let is_claimable = current_period.saturating_sub(created_at) > T::UnclaimablePeriods::get();
+					Self::pay_currency_rewards(&beneficiary, position_id, payable_percentage, is_claimable)?;
 
 					let rewards_to_unlock =
 						math::calculate_percentage_amount(position.accumulated_locked_rewards, payable_percentage);
@@ -586,22 +812,24 @@ pub mod pallet {
 						.checked_add(points_to_slash)
 						.ok_or(Error::<T>::Arithmetic)?;
 
-					let slashed_unpaid_rewards =
-						if current_period.saturating_sub(created_at) > T::UnclaimablePeriods::get() {
-							let p = position.accumulated_unpaid_rewards;
-							position.accumulated_unpaid_rewards = Zero::zero();
-							p
-						} else {
-							Zero::zero()
-						};
+					let slashed_unpaid_rewards = if is_claimable {
+						let p = position.accumulated_unpaid_rewards;
+						position.accumulated_unpaid_rewards = Zero::zero();
+						p
+					} else {
+						Zero::zero()
+					};
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
+					let position_locked = position.get_total_locked()?;
+					Self::keep_locked_by_votes(
 						&who,
-						position.get_total_locked()?,
+						locked_before,
+						position_locked
+							.checked_add(Self::get_unbonding_balance(&who))
+							.ok_or(Error::<T>::Arithmetic)?,
 					)?;
+					Self::update_lock(&who, position_locked)?;
 
 					staking.pot_reserved_balance = staking
 						.pot_reserved_balance
@@ -630,6 +858,9 @@ pub mod pallet {
 		/// Function calculates and pays latest rewards, unlocks all the locked rewards and staked
 		/// tokens for staking position and burns NFT representing staking position.
 		/// Unpaid allocated rewards are returned to the Staking for redistribution.
+		/// Staked tokens stay locked for `UnbondingPeriods` and have to be withdrawn by
+		/// `withdraw_unbonded` if `UnbondingPeriods` is not 0.
+		/// Tokens backing democracy votes of the owner stay locked until the votes are unlocked.
 		///
 		/// Parameters:
 		/// - `position_id`: The identifier of the position to be destroyed.
@@ -637,7 +868,7 @@ pub mod pallet {
 		/// Emits `Unstaked` event when successful.
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::unstake())]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake().saturating_add(Pallet::<T>::reward_currencies_weight()))]
 		pub fn unstake(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -654,26 +885,37 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
 
 					Self::process_votes(position_id, position)?;
+					Self::settle_currency_rewards(position_id, position.stake)?;
+
+					let locked_before = position
+						.get_total_locked()?
+						.checked_add(Self::get_unbonding_balance(&who))
+						.ok_or(Error::<T>::Arithmetic)?;
 
 					let current_period = Self::get_current_period()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 					let created_at = Self::get_period_number(position.created_at)
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
-					let (claimable_rewards, claimable_unpaid_rewards, unpaid_rewards, _) = Self::calculate_rewards(
-						position,
-						staking.accumulated_reward_per_stake,
-						current_period,
-						created_at,
-					)
-					.ok_or(Error::<T>::Arithmetic)?;
+					let (claimable_rewards, claimable_unpaid_rewards, unpaid_rewards, payable_percentage) =
+						Self::calculate_rewards(
+							position,
+							staking.accumulated_reward_per_stake,
+							current_period,
+							created_at,
+						)
+						.ok_or(Error::<T>::Arithmetic)?;
 
 					let rewards_to_pay = claimable_rewards
 						.checked_add(claimable_unpaid_rewards)
 						.ok_or(Error::<T>::Arithmetic)?;
 
+					let beneficiary = Self::reward_beneficiary(position_id).unwrap_or_else(|| who.clone());
 					let pot = Self::pot_account_id();
-					T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
+					T::Currency::transfer(T::NativeAssetId::get(), &pot, &beneficiary, rewards_to_pay)?;
+
+					Self::pay_currency_rewards(&beneficiary, position_id, payable_percentage, true)?;
+					let _ = PositionRewards::<T>::clear_prefix(position_id, u32::MAX, None);
 
 					staking.total_stake = staking
 						.total_stake
@@ -695,7 +937,9 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
-					T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who)?;
+					Self::schedule_unbonding(&who, position.stake)?;
+					Self::keep_locked_by_votes(&who, locked_before, Self::get_unbonding_balance(&who))?;
+					Self::update_lock(&who, Zero::zero())?;
 
 					Self::deposit_event(Event::Unstaked {
 						who,
@@ -706,114 +950,981 @@ pub mod pallet {
 					});
 
 					PositionVotes::<T>::remove(position_id);
+					AutoCompound::<T>::remove(position_id);
+					RewardBeneficiary::<T>::remove(position_id);
+					SwapRewards::<T>::remove(position_id);
+					let _ = ActionRewardedAt::<T>::clear_prefix(position_id, u32::MAX, None);
 					*maybe_position = None;
 
 					Ok(())
 				})
 			})
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
-}
+		/// Extrinsic to decrease staked amount of existing staking position by specified `amount`.
+		///
+		/// `unstake_partial` pays and locks rewards accumulated until this point the same way as
+		/// `increase_stake` and slashes position's points proportionally to the unstaked `amount`.
+		/// Votes of the position are capped to the remaining stake.
+		/// Unstaked `amount` stays locked for `UnbondingPeriods` and has to be withdrawn by
+		/// `withdraw_unbonded` after that. It is unlocked immediately if `UnbondingPeriods` is 0.
+		/// Tokens backing democracy votes of the owner stay locked until the votes are unlocked.
+		///
+		/// Staking position must exist and `origin` has to be the owner of the position.
+		/// Remaining stake can't be lower than `MinStake`, use `unstake` to destroy the position.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position which stake will be decreased.
+		/// - `amount`: Amount of native asset to be removed from staked amount.
+		///
+		/// Emits `PartiallyUnstaked` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake_partial()
+			.saturating_add(<T as Config>::WeightInfo::reward_currencies(T::MaxRewardCurrencies::get())))]
+		pub fn unstake_partial(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-impl<T: Config> Pallet<T> {
-	/// Account id holding rewards to pay.
-	pub fn pot_account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
-	}
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
 
-	fn ensure_stakeable_balance(
-		who: &T::AccountId,
-		stake: Balance,
-		position: Option<&Position<T::BlockNumber>>,
-	) -> Result<(), DispatchError> {
-		let free_balance = T::Currency::free_balance(T::NativeAssetId::get(), who);
-		let staked = position.map(|p| p.stake).unwrap_or_default();
-		let vested = T::Vesting::locked(who.clone());
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
 
-		let stakeable = free_balance
-			.checked_sub(vested)
-			.ok_or(Error::<T>::Arithmetic)?
-			.checked_sub(staked)
-			.ok_or(Error::<T>::Arithmetic)?;
+			Staking::<T>::try_mutate(|staking| {
+				Self::update_rewards(staking)?;
 
-		ensure!(stakeable >= stake, Error::<T>::InsufficientBalance);
+				Positions::<T>::try_mutate(position_id, |maybe_position| {
+					let position = maybe_position
+						.as_mut()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
 
-		Ok(())
-	}
+					let locked_before = position
+						.get_total_locked()?
+						.checked_add(Self::get_unbonding_balance(&who))
+						.ok_or(Error::<T>::Arithmetic)?;
 
-	/// Returns staking `PositionItemId` for `who` or `None`.
-	pub fn get_user_position_id(who: &T::AccountId) -> Result<Option<T::PositionItemId>, DispatchError> {
-		let mut user_position_ids = T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), who);
+					Self::process_votes(position_id, position)?;
+					Self::settle_currency_rewards(position_id, position.stake)?;
+
+					if Self::is_auto_compound(position_id) {
+						Self::do_compound(&who, position_id, position, staking)?;
+					}
+
+					// Remaining stake includes the rewards compounded above.
+					let remaining_stake = position
+						.stake
+						.checked_sub(amount)
+						.ok_or(Error::<T>::InvalidUnstakeAmount)?;
+					ensure!(
+						!amount.is_zero() && remaining_stake >= T::MinStake::get(),
+						Error::<T>::InvalidUnstakeAmount
+					);
 
-		let position_id = user_position_ids.next();
-		if position_id.is_some() {
-			ensure!(
-				user_position_ids.next().is_none(),
-				Error::<T>::InconsistentState(InconsistentStateError::TooManyPositions)
-			);
-		}
+					let current_period = Self::get_current_period()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+					let created_at = Self::get_period_number(position.created_at)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
-		Ok(position_id)
-	}
+					let (claimable_rewards, claimable_unpaid_rewards, unpaid_rewards, _) = Self::calculate_rewards(
+						position,
+						staking.accumulated_reward_per_stake,
+						current_period,
+						created_at,
+					)
+					.ok_or(Error::<T>::Arithmetic)?;
 
-	fn is_owner(who: &T::AccountId, id: T::PositionItemId) -> bool {
-		if let Some(owner) =
-			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &id)
-		{
-			owner == *who
-		} else {
-			false
-		}
-	}
+					let rewards = claimable_rewards
+						.checked_add(claimable_unpaid_rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
 
-	fn create_position_and_mint_nft(
-		who: &T::AccountId,
-		staked_amount: Balance,
-		accumulated_reward_per_stake: FixedU128,
-	) -> Result<T::PositionItemId, DispatchError> {
-		let position_id = Self::get_next_position_id()?;
-		Positions::<T>::insert(
-			position_id,
-			Position::new(
-				staked_amount,
-				accumulated_reward_per_stake,
-				T::BlockNumberProvider::current_block_number(),
-			),
-		);
+					// Rewards stay locked in the position so they are paid to the owner even if
+					// the reward beneficiary is set.
+					let pot = Self::pot_account_id();
+					T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
 
-		T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &position_id, who)?;
+					position.accumulated_unpaid_rewards = position
+						.accumulated_unpaid_rewards
+						.checked_add(unpaid_rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_unpaid_rewards = position
+						.accumulated_unpaid_rewards
+						.checked_sub(claimable_unpaid_rewards)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::NegativeUnpaidRewards.into())?;
 
-		Ok(position_id)
-	}
+					position.accumulated_locked_rewards = position
+						.accumulated_locked_rewards
+						.checked_add(rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
 
-	fn get_next_position_id() -> Result<T::PositionItemId, DispatchError> {
-		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
-			let next_id = *current_value;
+					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
-			*current_value = current_value
-				.checked_add(&T::PositionItemId::one())
-				.ok_or(Error::<T>::Arithmetic)?;
+					let points =
+						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+					let slash_points =
+						math::calculate_slashed_points(points, position.stake, amount, T::CurrentStakeWeight::get())
+							.ok_or(Error::<T>::Arithmetic)?;
 
-			Ok(next_id)
-		})
-	}
+					position.accumulated_slash_points = position
+						.accumulated_slash_points
+						.checked_add(slash_points)
+						.ok_or(Error::<T>::Arithmetic)?;
 
-	/// This function "distributes" pending rewards if possible and updates `StakingData`
-	pub(crate) fn update_rewards(staking: &mut StakingData) -> Result<(), DispatchError> {
-		if staking.total_stake.is_zero() {
-			return Ok(());
-		}
+					position.stake = remaining_stake;
 
-		let pending_rewards = T::Currency::free_balance(T::NativeAssetId::get(), &Self::pot_account_id())
-			.checked_sub(staking.pot_reserved_balance)
-			.defensive_ok_or::<Error<T>>(InconsistentStateError::NegativePendingRewards.into())?;
+					PositionVotes::<T>::mutate(position_id, |voting| {
+						for (_, vote) in voting.votes.iter_mut() {
+							vote.amount = vote.amount.min(remaining_stake);
+						}
+					});
 
-		if pending_rewards.is_zero() {
-			return Ok(());
-		}
+					staking.pot_reserved_balance = staking
+						.pot_reserved_balance
+						.checked_sub(rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					staking.total_stake = staking
+						.total_stake
+						.checked_sub(amount)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					Self::schedule_unbonding(&who, amount)?;
+					let position_locked = position.get_total_locked()?;
+					Self::keep_locked_by_votes(
+						&who,
+						locked_before,
+						position_locked
+							.checked_add(Self::get_unbonding_balance(&who))
+							.ok_or(Error::<T>::Arithmetic)?,
+					)?;
+					Self::update_lock(&who, position_locked)?;
+
+					Self::deposit_event(Event::PartiallyUnstaked {
+						who,
+						position_id,
+						unstaked: amount,
+						remaining_stake,
+						locked_rewards: rewards,
+						slashed_points: slash_points,
+					});
+
+					Ok(())
+				})
+			})
+		}
+
+		/// Unlock all the unstaked tokens of the `origin` which unbonding period has passed.
+		///
+		/// Tokens backing democracy votes of the `origin` stay locked until the votes are removed and
+		/// unlocked in democracy.
+		///
+		/// Emits `UnbondedWithdrawn` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let current_period =
+				Self::get_current_period().defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+			let position_locked = Self::get_position_locked(&who)?;
+			let locked_before = position_locked
+				.checked_add(Self::get_unbonding_balance(&who))
+				.ok_or(Error::<T>::Arithmetic)?;
+			let locked_by_votes = Self::get_locked_by_votes(&who, locked_before);
+
+			let withdrawn =
+				Unbondings::<T>::try_mutate_exists(&who, |maybe_chunks| -> Result<Balance, DispatchError> {
+					let chunks = maybe_chunks.as_mut().ok_or(Error::<T>::NothingToWithdraw)?;
+
+					let unlocked = chunks
+						.iter()
+						.filter(|chunk| chunk.unlock_at <= current_period)
+						.fold(Balance::zero(), |acc, chunk| acc.saturating_add(chunk.amount));
+
+					ensure!(!unlocked.is_zero(), Error::<T>::NothingToWithdraw);
+
+					// Tokens backing the votes stay locked in the unbonding chunks.
+					let withdrawn = unlocked.min(locked_before.saturating_sub(locked_by_votes));
+
+					ensure!(!withdrawn.is_zero(), Error::<T>::LockedByVotes);
+
+					let mut to_withdraw = withdrawn;
+					for chunk in chunks.iter_mut().filter(|chunk| chunk.unlock_at <= current_period) {
+						let amount = chunk.amount.min(to_withdraw);
+						chunk.amount = chunk.amount.saturating_sub(amount);
+						to_withdraw = to_withdraw.saturating_sub(amount);
+					}
+					chunks.retain(|chunk| !chunk.amount.is_zero());
+
+					if chunks.is_empty() {
+						*maybe_chunks = None;
+					}
+
+					Ok(withdrawn)
+				})?;
+
+			Self::update_lock(&who, position_locked)?;
+
+			Self::deposit_event(Event::UnbondedWithdrawn { who, amount: withdrawn });
+
+			Ok(())
+		}
+
+		/// Enable or disable auto-compounding of position's rewards.
+		///
+		/// Rewards of the auto-compounding position are added to its stake whenever the position's
+		/// stake is changed and periodically in `on_idle`. Points are slashed for compounded rewards
+		/// the same way as for `increase_stake`.
+		/// Auto-compounding can't be enabled if reward beneficiary is set.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position.
+		/// - `enabled`: Whether rewards should be auto-compounded.
+		///
+		/// Emits `AutoCompoundUpdated` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			if enabled {
+				ensure!(
+					!RewardBeneficiary::<T>::contains_key(position_id),
+					Error::<T>::IncompatibleRewardSettings
+				);
+				AutoCompound::<T>::insert(position_id, true);
+			} else {
+				AutoCompound::<T>::remove(position_id);
+			}
+
+			Self::deposit_event(Event::AutoCompoundUpdated {
+				who,
+				position_id,
+				enabled,
+			});
+
+			Ok(())
+		}
+
+		/// Set or remove account receiving position's rewards.
+		///
+		/// Only rewards paid by `claim` and `unstake` are paid to the beneficiary. Rewards paid by
+		/// `increase_stake` and `unstake_partial` stay locked in the position of the owner.
+		/// Beneficiary can't be set for auto-compounding position.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position.
+		/// - `beneficiary`: Account to receive rewards. Rewards are paid to position's owner if `None`.
+		///
+		/// Emits `RewardBeneficiaryUpdated` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_beneficiary())]
+		pub fn set_reward_beneficiary(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			match beneficiary {
+				Some(ref account) => {
+					ensure!(
+						!Self::is_auto_compound(position_id),
+						Error::<T>::IncompatibleRewardSettings
+					);
+					RewardBeneficiary::<T>::insert(position_id, account);
+				}
+				None => RewardBeneficiary::<T>::remove(position_id),
+			}
+
+			Self::deposit_event(Event::RewardBeneficiaryUpdated {
+				who,
+				position_id,
+				beneficiary,
+			});
+
+			Ok(())
+		}
+
+		/// Transfer staking position to another account.
+		///
+		/// Tokens locked by the position are transferred to and locked at `new_owner` and position's
+		/// NFT is re-minted to `new_owner`. Position keeps its stake, points and rewards.
+		/// Points from the finished referenda are accounted and votes in ongoing referenda are
+		/// removed from the position. Auto-compounding and reward beneficiary settings are reset.
+		/// Unbonding tokens stay with the original owner.
+		/// Position can't be transferred while its tokens back democracy votes of the owner.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position to transfer.
+		/// - `new_owner`: Account to receive the position. Account can't have staking position.
+		///
+		/// Emits `PositionTransferred` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_position())]
+		pub fn transfer_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			ensure!(
+				Self::get_user_position_id(&new_owner)?.is_none(),
+				Error::<T>::PositionAlreadyExists
+			);
+
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = maybe_position
+					.as_mut()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				let locked = position.get_total_locked()?;
+
+				let unbonding = Self::get_unbonding_balance(&who);
+				let locked_before = locked.checked_add(unbonding).ok_or(Error::<T>::Arithmetic)?;
+				ensure!(
+					Self::get_locked_by_votes(&who, locked_before) <= unbonding,
+					Error::<T>::LockedByVotes
+				);
+
+				Self::process_votes(position_id, position)?;
+				PositionVotes::<T>::remove(position_id);
+				AutoCompound::<T>::remove(position_id);
+				RewardBeneficiary::<T>::remove(position_id);
+				SwapRewards::<T>::remove(position_id);
+
+				Self::update_lock(&who, Zero::zero())?;
+				T::Currency::transfer(T::NativeAssetId::get(), &who, &new_owner, locked)?;
+
+				let collection_id = T::NFTCollectionId::get();
+				T::NFTHandler::burn(&collection_id, &position_id, Some(&who))?;
+				T::NFTHandler::mint_into(&collection_id, &position_id, &new_owner)?;
+
+				Self::update_lock(&new_owner, locked)?;
+
+				Self::deposit_event(Event::PositionTransferred {
+					who,
+					new_owner,
+					position_id,
+					locked,
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Merge staking position into another position of the same owner.
+		///
+		/// Stakes, action points, unpaid and locked rewards of both positions are added together.
+		/// Pending rewards of both positions are added to the unpaid rewards. Merged position keeps
+		/// the newer creation block of the two so merging can't increase its time points. Slash
+		/// points of the older position are reduced in proportion to the time points it loses.
+		/// Votes of the merged position are moved to `into_position_id`. Votes of both positions in
+		/// the same referendum are combined with the lower conviction and capped to the merged stake.
+		/// NFT of the merged position is burned.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position to merge. Position is destroyed.
+		/// - `into_position_id`: The identifier of the position to merge into.
+		///
+		/// Emits `PositionsMerged` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge()
+			.saturating_add(<T as Config>::WeightInfo::reward_currencies(T::MaxRewardCurrencies::get())))]
+		pub fn merge(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			into_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(position_id != into_position_id, Error::<T>::SamePosition);
+
+			ensure!(
+				Self::is_owner(&who, position_id) && Self::is_owner(&who, into_position_id),
+				Error::<T>::Forbidden
+			);
+
+			Staking::<T>::try_mutate(|staking| {
+				Self::update_rewards(staking)?;
+
+				let mut merged = Positions::<T>::take(position_id)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				Positions::<T>::try_mutate(into_position_id, |maybe_position| {
+					let position = maybe_position
+						.as_mut()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+					Self::process_votes(position_id, &mut merged)?;
+					Self::process_votes(into_position_id, position)?;
+
+					for p in [&mut merged, &mut *position] {
+						let pending_rewards =
+							math::calculate_rewards(staking.accumulated_reward_per_stake, p.reward_per_stake, p.stake)
+								.ok_or(Error::<T>::Arithmetic)?;
+
+						p.accumulated_unpaid_rewards = p
+							.accumulated_unpaid_rewards
+							.checked_add(pending_rewards)
+							.ok_or(Error::<T>::Arithmetic)?;
+						p.reward_per_stake = staking.accumulated_reward_per_stake;
+					}
+
+					Self::settle_currency_rewards(position_id, merged.stake)?;
+					Self::settle_currency_rewards(into_position_id, position.stake)?;
+					for (asset_id, rewards) in PositionRewards::<T>::drain_prefix(position_id) {
+						PositionRewards::<T>::try_mutate(into_position_id, asset_id, |r| -> DispatchResult {
+							r.pending_rewards = r
+								.pending_rewards
+								.checked_add(rewards.pending_rewards)
+								.ok_or(Error::<T>::Arithmetic)?;
+							Ok(())
+						})?;
+					}
+
+					let current_period = Self::get_current_period()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+					let created_at = position.created_at.max(merged.created_at);
+					let created_at_period = Self::get_period_number(created_at)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					let mut slash_points = Point::zero();
+					for p in [&merged, &*position] {
+						let p_created_at = Self::get_period_number(p.created_at)
+							.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+						let p_slash_points =
+							Self::scale_slash_points(p, p_created_at, created_at_period, current_period)
+								.ok_or(Error::<T>::Arithmetic)?;
+						slash_points = slash_points.checked_add(p_slash_points).ok_or(Error::<T>::Arithmetic)?;
+					}
+
+					position.stake = position.stake.checked_add(merged.stake).ok_or(Error::<T>::Arithmetic)?;
+					position.action_points = position.action_points.saturating_add(merged.action_points);
+					position.created_at = created_at;
+
+					// Time points of only one position are kept so slash points can't exceed points of
+					// the merged position.
+					position.accumulated_slash_points = Zero::zero();
+					let max_slash_points =
+						Self::get_points(position, current_period, created_at_period).ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_slash_points = slash_points.min(max_slash_points);
+					position.accumulated_unpaid_rewards = position
+						.accumulated_unpaid_rewards
+						.checked_add(merged.accumulated_unpaid_rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_locked_rewards = position
+						.accumulated_locked_rewards
+						.checked_add(merged.accumulated_locked_rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					let merged_votes = PositionVotes::<T>::take(position_id);
+					PositionVotes::<T>::try_mutate(into_position_id, |voting| -> DispatchResult {
+						for (ref_index, vote) in merged_votes.votes {
+							match voting.votes.binary_search_by_key(&ref_index, |value| value.0) {
+								// Both positions voted in the referendum. Votes are combined with the
+								// lower conviction and capped to the merged stake.
+								Ok(idx) => {
+									let (_, existing) = &mut voting.votes[idx];
+									existing.amount = existing.amount.saturating_add(vote.amount).min(position.stake);
+									if vote.conviction.multiplier() < existing.conviction.multiplier() {
+										existing.conviction = vote.conviction;
+									}
+								}
+								Err(idx) => voting
+									.votes
+									.try_insert(idx, (ref_index, vote))
+									.map_err(|_| Error::<T>::MaxVotesReached)?,
+							}
+						}
+						Ok(())
+					})?;
+
+					AutoCompound::<T>::remove(position_id);
+					RewardBeneficiary::<T>::remove(position_id);
+					SwapRewards::<T>::remove(position_id);
+					let _ = ActionRewardedAt::<T>::clear_prefix(position_id, u32::MAX, None);
+
+					T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+					Self::update_lock(&who, position.get_total_locked()?)?;
+
+					Self::deposit_event(Event::PositionsMerged {
+						who,
+						position_id,
+						into_position_id,
+						total_stake: position.stake,
+					});
+
+					Ok(())
+				})
+			})
+		}
+
+		/// Add non-native currency to the reward currencies.
+		///
+		/// Trade fees in the reward currencies are added to the `pot` and distributed to stakers
+		/// the same way as the native asset. Native asset and the Omnipool's hub asset can't be added.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `AuthorityOrigin`.
+		/// - `asset_id`: The identifier of the currency to add.
+		///
+		/// Emits `RewardCurrencyAdded` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_reward_currency())]
+		pub fn add_reward_currency(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				asset_id != T::NativeAssetId::get()
+					&& asset_id != T::HubAssetId::get()
+					&& !RewardCurrencies::<T>::contains_key(asset_id),
+				Error::<T>::InvalidRewardCurrency
+			);
+
+			ensure!(
+				RewardCurrencies::<T>::count() < T::MaxRewardCurrencies::get(),
+				Error::<T>::MaxRewardCurrenciesReached
+			);
+
+			RewardCurrencies::<T>::insert(asset_id, RewardCurrencyData::default());
+
+			Self::deposit_event(Event::RewardCurrencyAdded { asset_id });
+
+			Ok(())
+		}
+
+		/// Enable or disable swapping of position's non-native rewards to the native asset.
+		///
+		/// Rewards are swapped by the `RewardSwap` when they are paid. Rewards are paid in the reward
+		/// currency if the swap fails.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position.
+		/// - `enabled`: Swap rewards if `true`.
+		///
+		/// Emits `SwapRewardsUpdated` event when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_swap_rewards())]
+		pub fn set_swap_rewards(origin: OriginFor<T>, position_id: T::PositionItemId, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			if enabled {
+				SwapRewards::<T>::insert(position_id, true);
+			} else {
+				SwapRewards::<T>::remove(position_id);
+			}
+
+			Self::deposit_event(Event::SwapRewardsUpdated {
+				who,
+				position_id,
+				enabled,
+			});
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let used_weight = migration::migrate_position_votes::<T>(remaining_weight);
+
+			used_weight.saturating_add(Self::auto_compound_positions(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account id holding rewards to pay.
+	pub fn pot_account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	fn ensure_stakeable_balance(
+		who: &T::AccountId,
+		stake: Balance,
+		position: Option<&Position<T::BlockNumber>>,
+	) -> Result<(), DispatchError> {
+		let free_balance = T::Currency::free_balance(T::NativeAssetId::get(), who);
+		let staked = position.map(|p| p.stake).unwrap_or_default();
+		let vested = T::Vesting::locked(who.clone());
+		let unbonding = Self::get_unbonding_balance(who);
+
+		let stakeable = free_balance
+			.checked_sub(vested)
+			.ok_or(Error::<T>::Arithmetic)?
+			.checked_sub(staked)
+			.ok_or(Error::<T>::Arithmetic)?
+			.checked_sub(unbonding)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		ensure!(stakeable >= stake, Error::<T>::InsufficientBalance);
+
+		Ok(())
+	}
+
+	/// Returns total amount of `who`'s tokens waiting for the end of the unbonding period.
+	pub fn get_unbonding_balance(who: &T::AccountId) -> Balance {
+		Unbondings::<T>::get(who)
+			.iter()
+			.fold(Balance::zero(), |acc, chunk| acc.saturating_add(chunk.amount))
+	}
+
+	/// Keeps unstaked `amount` locked until the end of the unbonding period.
+	/// Nothing is scheduled if `UnbondingPeriods` is 0.
+	fn schedule_unbonding(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		let unbonding_periods = T::UnbondingPeriods::get();
+		if unbonding_periods.is_zero() || amount.is_zero() {
+			return Ok(());
+		}
+
+		let unlock_at = Self::get_current_period()
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?
+			.checked_add(unbonding_periods)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		Self::add_unbonding_chunk(who, amount, unlock_at)
+	}
+
+	/// Keeps tokens backing `who`'s votes locked when staking lock of `who` is decreased from
+	/// `locked_before` to `locked_after`.
+	///
+	/// Tokens are kept in the unbonding chunk which can be withdrawn by `withdraw_unbonded` once
+	/// the votes are unlocked in democracy.
+	fn keep_locked_by_votes(who: &T::AccountId, locked_before: Balance, locked_after: Balance) -> DispatchResult {
+		let to_keep = Self::get_locked_by_votes(who, locked_before).saturating_sub(locked_after);
+		if to_keep.is_zero() {
+			return Ok(());
+		}
+
+		let current_period =
+			Self::get_current_period().defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+		Self::add_unbonding_chunk(who, to_keep, current_period)
+	}
+
+	/// Adds unbonding chunk of `amount` tokens unlocked at `unlock_at` period to `who`'s
+	/// unbonding tokens.
+	fn add_unbonding_chunk(who: &T::AccountId, amount: Balance, unlock_at: Period) -> DispatchResult {
+		Unbondings::<T>::try_mutate(who, |chunks| -> DispatchResult {
+			match chunks.iter_mut().last() {
+				Some(chunk) if chunk.unlock_at == unlock_at => {
+					chunk.amount = chunk.amount.checked_add(amount).ok_or(Error::<T>::Arithmetic)?;
+				}
+				_ => chunks
+					.try_push(UnbondingChunk::new(amount, unlock_at))
+					.map_err(|_| Error::<T>::MaxUnbondingChunksReached)?,
+			}
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::UnbondingScheduled {
+			who: who.clone(),
+			amount,
+			unlock_at,
+		});
+
+		Ok(())
+	}
+
+	/// Returns amount of `who`'s tokens locked by its staking position.
+	fn get_position_locked(who: &T::AccountId) -> Result<Balance, DispatchError> {
+		match Self::get_user_position_id(who)? {
+			Some(position_id) => Ok(Positions::<T>::get(position_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?
+				.get_total_locked()?),
+			None => Ok(Zero::zero()),
+		}
+	}
+
+	/// Returns total amount of `who`'s tokens locked in staking, i.e. tokens locked by the
+	/// position and all the unbonding tokens.
+	pub fn get_locked_balance(who: &T::AccountId) -> Result<Balance, DispatchError> {
+		Ok(Self::get_position_locked(who)?
+			.checked_add(Self::get_unbonding_balance(who))
+			.ok_or(Error::<T>::Arithmetic)?)
+	}
+
+	/// Returns amount of `who`'s tokens which have to stay locked in staking because they back
+	/// `who`'s votes.
+	///
+	/// Democracy doesn't lock votes which are fully backed by `staking_locked` tokens. Votes
+	/// exceeding `staking_locked` are locked by democracy so staking doesn't have to keep anything.
+	fn get_locked_by_votes(who: &T::AccountId, staking_locked: Balance) -> Balance {
+		let voting_locked = T::VotingLock::locked_balance(who);
+		if voting_locked <= staking_locked {
+			voting_locked
+		} else {
+			Zero::zero()
+		}
+	}
+
+	/// Sets staking lock of `who` to `position_locked` amount plus all the unbonding tokens.
+	/// Lock is removed if there is nothing to lock.
+	fn update_lock(who: &T::AccountId, position_locked: Balance) -> DispatchResult {
+		let to_lock = position_locked
+			.checked_add(Self::get_unbonding_balance(who))
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		if to_lock.is_zero() {
+			T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who)
+		} else {
+			T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who, to_lock)
+		}
+	}
+
+	/// Adds rewards accumulated until now to the position's stake.
+	///
+	/// Rewards are calculated the same way as in `increase_stake` but they are added to the stake
+	/// instead of being locked as `accumulated_locked_rewards`. Points are slashed for the stake increase.
+	fn do_compound(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		position: &mut Position<T::BlockNumber>,
+		staking: &mut StakingData,
+	) -> DispatchResult {
+		let current_period =
+			Self::get_current_period().defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+		let created_at = Self::get_period_number(position.created_at)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+		let (claimable_rewards, claimable_unpaid_rewards, unpaid_rewards, _) = Self::calculate_rewards(
+			position,
+			staking.accumulated_reward_per_stake,
+			current_period,
+			created_at,
+		)
+		.ok_or(Error::<T>::Arithmetic)?;
+
+		let rewards = claimable_rewards
+			.checked_add(claimable_unpaid_rewards)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		position.accumulated_unpaid_rewards = position
+			.accumulated_unpaid_rewards
+			.checked_add(unpaid_rewards)
+			.ok_or(Error::<T>::Arithmetic)?
+			.checked_sub(claimable_unpaid_rewards)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::NegativeUnpaidRewards.into())?;
+
+		position.reward_per_stake = staking.accumulated_reward_per_stake;
+
+		if rewards.is_zero() {
+			return Ok(());
+		}
+
+		T::Currency::transfer(T::NativeAssetId::get(), &Self::pot_account_id(), who, rewards)?;
+
+		let points = Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+		let slash_points =
+			math::calculate_slashed_points(points, position.stake, rewards, T::CurrentStakeWeight::get())
+				.ok_or(Error::<T>::Arithmetic)?;
+
+		position.accumulated_slash_points = position
+			.accumulated_slash_points
+			.checked_add(slash_points)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		position.stake = position.stake.checked_add(rewards).ok_or(Error::<T>::Arithmetic)?;
+
+		staking.pot_reserved_balance = staking
+			.pot_reserved_balance
+			.checked_sub(rewards)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		staking.add_stake(rewards)?;
+
+		Self::update_lock(who, position.get_total_locked()?)?;
+
+		Self::deposit_event(Event::RewardsCompounded {
+			who: who.clone(),
+			position_id,
+			rewards,
+			total_stake: position.stake,
+			slashed_points: slash_points,
+		});
+
+		Ok(())
+	}
+
+	/// Compounds rewards of the auto-compounding position specified by `position_id`.
+	pub(crate) fn compound_position(position_id: T::PositionItemId) -> DispatchResult {
+		let who = T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		Staking::<T>::try_mutate(|staking| {
+			Self::update_rewards(staking)?;
+
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = maybe_position
+					.as_mut()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				Self::settle_currency_rewards(position_id, position.stake)?;
+				Self::do_compound(&who, position_id, position, staking)
+			})
+		})
+	}
+
+	/// Compounds rewards of the auto-compounding positions while there is enough `remaining_weight`.
+	/// Positions are processed in rounds, each call continues after the last processed position.
+	fn auto_compound_positions(remaining_weight: Weight) -> Weight {
+		let compound_weight = T::WeightInfo::auto_compound()
+			.saturating_add(T::WeightInfo::reward_currencies(T::MaxRewardCurrencies::get()));
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+
+		if remaining_weight.any_lt(used_weight.saturating_add(compound_weight)) {
+			return Weight::zero();
+		}
+
+		if !Self::is_initialized() {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut positions = match AutoCompoundCursor::<T>::get() {
+			Some(last) => AutoCompound::<T>::iter_keys_from(AutoCompound::<T>::hashed_key_for(last)),
+			None => AutoCompound::<T>::iter_keys(),
+		};
+
+		let mut last_processed = None;
+		let mut all_processed = false;
+		while remaining_weight.all_gte(used_weight.saturating_add(compound_weight)) {
+			let Some(position_id) = positions.next() else {
+				all_processed = true;
+				break;
+			};
+
+			used_weight.saturating_accrue(compound_weight);
+
+			if let Err(e) = with_storage_layer(|| Self::compound_position(position_id)) {
+				log::warn!(
+					target: LOG_TARGET,
+					"auto-compound of position {:?} failed: {:?}",
+					position_id,
+					e
+				);
+			}
+
+			last_processed = Some(position_id);
+		}
+
+		// Next round starts from the beginning if all the positions were processed.
+		AutoCompoundCursor::<T>::set(if all_processed { None } else { last_processed });
+
+		used_weight
+	}
+
+	/// Returns staking `PositionItemId` for `who` or `None`.
+	pub fn get_user_position_id(who: &T::AccountId) -> Result<Option<T::PositionItemId>, DispatchError> {
+		let mut user_position_ids = T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), who);
+
+		let position_id = user_position_ids.next();
+		if position_id.is_some() {
+			ensure!(
+				user_position_ids.next().is_none(),
+				Error::<T>::InconsistentState(InconsistentStateError::TooManyPositions)
+			);
+		}
+
+		Ok(position_id)
+	}
+
+	fn is_owner(who: &T::AccountId, id: T::PositionItemId) -> bool {
+		if let Some(owner) =
+			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &id)
+		{
+			owner == *who
+		} else {
+			false
+		}
+	}
+
+	fn create_position_and_mint_nft(
+		who: &T::AccountId,
+		staked_amount: Balance,
+		accumulated_reward_per_stake: FixedU128,
+	) -> Result<T::PositionItemId, DispatchError> {
+		let position_id = Self::get_next_position_id()?;
+		Positions::<T>::insert(
+			position_id,
+			Position::new(
+				staked_amount,
+				accumulated_reward_per_stake,
+				T::BlockNumberProvider::current_block_number(),
+			),
+		);
+
+		T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &position_id, who)?;
+
+		Ok(position_id)
+	}
+
+	fn get_next_position_id() -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_id = *current_value;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(Error::<T>::Arithmetic)?;
+
+			Ok(next_id)
+		})
+	}
+
+	/// This function "distributes" pending rewards if possible and updates `StakingData`
+	pub(crate) fn update_rewards(staking: &mut StakingData) -> Result<(), DispatchError> {
+		if staking.total_stake.is_zero() {
+			return Ok(());
+		}
+
+		Self::update_currency_rewards(staking.total_stake)?;
+
+		let pending_rewards = T::Currency::free_balance(T::NativeAssetId::get(), &Self::pot_account_id())
+			.checked_sub(staking.pot_reserved_balance)
+			.defensive_ok_or::<Error<T>>(InconsistentStateError::NegativePendingRewards.into())?;
+
+		if pending_rewards.is_zero() {
+			return Ok(());
+		}
 
 		let accumulated_rps = math::calculate_accumulated_rps(
 			staking.accumulated_reward_per_stake,
@@ -841,6 +1952,147 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Distributes pending rewards of the non-native reward currencies to the stakers.
+	pub(crate) fn update_currency_rewards(total_stake: Balance) -> DispatchResult {
+		let pot = Self::pot_account_id();
+
+		for (asset_id, mut data) in RewardCurrencies::<T>::iter().collect::<Vec<_>>() {
+			let pending_rewards = T::Currency::free_balance(asset_id, &pot)
+				.checked_sub(data.pot_reserved_balance)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::NegativePendingRewards.into())?;
+
+			if pending_rewards.is_zero() {
+				continue;
+			}
+
+			let accumulated_rps =
+				math::calculate_accumulated_rps(data.accumulated_reward_per_stake, pending_rewards, total_stake)
+					.ok_or(Error::<T>::Arithmetic)?;
+
+			if data.accumulated_reward_per_stake == accumulated_rps {
+				continue;
+			}
+
+			data.accumulated_reward_per_stake = accumulated_rps;
+			data.pot_reserved_balance = data
+				.pot_reserved_balance
+				.checked_add(pending_rewards)
+				.ok_or(Error::<T>::Arithmetic)?;
+
+			RewardCurrencies::<T>::insert(asset_id, data);
+		}
+
+		Ok(())
+	}
+
+	/// Moves non-native rewards accumulated by position's `stake` since the last update to the
+	/// position's pending rewards. It must be called before position's stake is changed.
+	pub(crate) fn settle_currency_rewards(position_id: T::PositionItemId, stake: Balance) -> DispatchResult {
+		for (asset_id, data) in RewardCurrencies::<T>::iter() {
+			PositionRewards::<T>::try_mutate(position_id, asset_id, |rewards| -> DispatchResult {
+				let pending_rewards =
+					math::calculate_rewards(data.accumulated_reward_per_stake, rewards.reward_per_stake, stake)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+				rewards.pending_rewards = rewards
+					.pending_rewards
+					.checked_add(pending_rewards)
+					.ok_or(Error::<T>::Arithmetic)?;
+				rewards.reward_per_stake = data.accumulated_reward_per_stake;
+
+				Ok(())
+			})?;
+		}
+
+		Ok(())
+	}
+
+	/// Pays `payable_percentage` of the position's pending non-native rewards to `who`.
+	/// Rest of the pending rewards is returned for redistribution if `slash_rest` is `true`.
+	/// Paid rewards are swapped to the native asset if it's enabled for the position.
+	pub(crate) fn pay_currency_rewards(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		payable_percentage: FixedU128,
+		slash_rest: bool,
+	) -> DispatchResult {
+		let pot = Self::pot_account_id();
+		let swap = Self::is_swap_rewards(position_id);
+
+		for (asset_id, mut data) in RewardCurrencies::<T>::iter().collect::<Vec<_>>() {
+			let mut rewards = PositionRewards::<T>::get(position_id, asset_id);
+
+			let rewards_to_pay = math::calculate_percentage_amount(rewards.pending_rewards, payable_percentage);
+			let remaining_rewards = rewards
+				.pending_rewards
+				.checked_sub(rewards_to_pay)
+				.ok_or(Error::<T>::Arithmetic)?;
+			let slashed_rewards = if slash_rest { remaining_rewards } else { Zero::zero() };
+
+			rewards.pending_rewards = remaining_rewards.saturating_sub(slashed_rewards);
+			data.pot_reserved_balance = data
+				.pot_reserved_balance
+				.checked_sub(rewards_to_pay)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?
+				.checked_sub(slashed_rewards)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+			PositionRewards::<T>::insert(position_id, asset_id, rewards);
+			RewardCurrencies::<T>::insert(asset_id, data);
+
+			if rewards_to_pay.is_zero() {
+				continue;
+			}
+
+			T::Currency::transfer(asset_id, &pot, who, rewards_to_pay)?;
+
+			let native_amount = if swap {
+				Self::swap_rewards(who, asset_id, rewards_to_pay)
+			} else {
+				Zero::zero()
+			};
+
+			Self::deposit_event(Event::CurrencyRewardsPaid {
+				who: who.clone(),
+				position_id,
+				asset_id,
+				amount: rewards_to_pay,
+				native_amount,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Swaps `amount` of the reward currency to the native asset and returns received amount.
+	/// Failed swap is reverted and rewards stay in the reward currency.
+	fn swap_rewards(who: &T::AccountId, asset_id: T::AssetId, amount: Balance) -> Balance {
+		let native_asset = T::NativeAssetId::get();
+		let balance_before = T::Currency::free_balance(native_asset, who);
+
+		match with_storage_layer(|| T::RewardSwap::swap_to_native(who, asset_id, amount)) {
+			Ok(()) => T::Currency::free_balance(native_asset, who).saturating_sub(balance_before),
+			Err(e) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"swap of rewards {:?} of asset {:?} failed: {:?}",
+					amount,
+					asset_id,
+					e
+				);
+				Zero::zero()
+			}
+		}
+	}
+
+	/// Returns weight of the non-native rewards processing in the worst case.
+	pub fn reward_currencies_weight() -> Weight {
+		let max_currencies = T::MaxRewardCurrencies::get();
+
+		T::WeightInfo::reward_currencies(max_currencies)
+			.saturating_add(T::RewardSwap::swap_to_native_weight().saturating_mul(max_currencies as u64))
+	}
+
 	/// This function calculates total amount of points `position` accumulated until now.
 	/// Slash points are subtracted from returned value.
 	#[inline]
@@ -860,6 +2112,23 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Returns position's slash points scaled down in proportion to the time points the position
+	/// loses when its creation is moved from `position_created_at` to `created_at` period.
+	fn scale_slash_points(
+		position: &Position<T::BlockNumber>,
+		position_created_at: Period,
+		created_at: Period,
+		current_period: Period,
+	) -> Option<Point> {
+		let periods = current_period.saturating_sub(position_created_at);
+		if periods.is_zero() {
+			return Some(position.accumulated_slash_points);
+		}
+
+		FixedU128::checked_from_rational(current_period.saturating_sub(created_at), periods)?
+			.checked_mul_int(position.accumulated_slash_points)
+	}
+
 	#[inline]
 	fn get_current_period() -> Option<Period> {
 		Self::get_period_number(T::BlockNumberProvider::current_block_number())
@@ -912,14 +2181,15 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
-	/// Transfer given fee to pot account.
+	/// Transfer given fee to pot account if it is in the native asset or in one of the reward currencies.
 	/// Returns amount of unused fee.
 	pub fn process_trade_fee(
 		source: T::AccountId,
 		asset: T::AssetId,
 		amount: Balance,
 	) -> Result<Balance, DispatchError> {
-		if asset == T::NativeAssetId::get() && Self::is_initialized() {
+		let is_reward_currency = asset == T::NativeAssetId::get() || RewardCurrencies::<T>::contains_key(asset);
+		if is_reward_currency && Self::is_initialized() {
 			T::Currency::transfer(asset, &source, &Self::pot_account_id(), amount)?;
 			Ok(Balance::zero())
 		} else {
@@ -977,6 +2247,65 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> ActionHook<T::AccountId> for Pallet<T> {
+	fn on_action<D: ActionData>(who: &T::AccountId, action: Action, data: D) -> DispatchResult {
+		if !Self::is_initialized() {
+			return Ok(());
+		}
+
+		let Some(position_id) = Self::get_user_position_id(who)? else {
+			return Ok(());
+		};
+
+		Positions::<T>::try_mutate(position_id, |maybe_position| -> DispatchResult {
+			let Some(position) = maybe_position.as_mut() else {
+				let e = Error::<T>::InconsistentState(InconsistentStateError::PositionNotFound);
+				defensive!(e);
+
+				//NOTE: This is intentional, user can't recover from this state and we don't want
+				//to block the action.
+				return Ok(());
+			};
+
+			let Some(current_period) = Self::get_current_period() else {
+				defensive!(Error::<T>::InconsistentState(InconsistentStateError::Arithmetic));
+				return Ok(());
+			};
+
+			// Position is rewarded for each action at most once per period so actions can't be
+			// repeated to farm points.
+			if Self::action_rewarded_at(position_id, action) == Some(current_period) {
+				return Ok(());
+			}
+
+			// Actions reported by other pallets are not limited by position's stake so points are
+			// capped to max points of the action.
+			let max_value = Conviction::max_multiplier().saturating_mul_int(position.stake);
+			let points = Self::calculate_points_for_action(action, data, max_value)
+				.min(T::MaxPointsPerAction::get(&action) as u128);
+			if points.is_zero() {
+				return Ok(());
+			}
+
+			position.action_points = position.action_points.saturating_add(points);
+			ActionRewardedAt::<T>::insert(position_id, action, current_period);
+
+			Self::deposit_event(Event::ActionPointsAdded {
+				who: who.clone(),
+				position_id,
+				action,
+				points,
+			});
+
+			Ok(())
+		})
+	}
+
+	fn on_action_weight() -> Weight {
+		T::WeightInfo::on_action()
+	}
+}
+
 pub struct SigmoidPercentage<T, B>(sp_std::marker::PhantomData<(T, B)>);
 
 impl<T, B: Get<u32>> PayablePercentage<Point> for SigmoidPercentage<T, B>
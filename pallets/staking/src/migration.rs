@@ -0,0 +1,161 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the pallet storage to v2.
+///
+/// Votes of the positions are migrated in `on_idle` over multiple blocks by `migrate_position_votes`
+/// so the number of the positions is not limited by the weight of the single block.
+pub fn migrate_to_v2<T: Config, P: GetStorageVersion + PalletInfoAccess>() -> Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: LOG_TARGET,
+		"Running migration storage v2 for staking with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 2 {
+		VotesMigrationPending::<T>::put(true);
+		VotesMigrationCursor::<T>::kill();
+		StorageVersion::new(2).put::<P>();
+
+		weight = weight.saturating_add(T::DbWeight::get().writes(3));
+
+		log::info!(
+			target: LOG_TARGET,
+			"Migration storage v2 for staking was complete, votes of the positions will be migrated in on_idle",
+		);
+	} else {
+		log::warn!(
+			target: LOG_TARGET,
+			"Attempted to apply migration to v2 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
+
+/// Migrates votes of the positions while there is enough `remaining_weight`.
+/// Each call continues after the last migrated position until all the positions are migrated.
+///
+/// Votes of the positions are reconciled with the owners' votes in democracy: votes which were
+/// removed from democracy are removed from the positions and votes' amounts are capped to the stake.
+/// Democracy locks of the position owners are updated so votes backed by the staked tokens are not
+/// locked twice.
+pub(crate) fn migrate_position_votes<T: Config>(remaining_weight: Weight) -> Weight {
+	// position, owner, position votes, democracy voting, staking lock (3 reads) and democracy lock
+	let position_weight = T::DbWeight::get().reads_writes(8, 3);
+	let mut used_weight = T::DbWeight::get().reads_writes(2, 2);
+
+	if remaining_weight.any_lt(used_weight.saturating_add(position_weight)) {
+		return Weight::zero();
+	}
+
+	if !VotesMigrationPending::<T>::get() {
+		return T::DbWeight::get().reads(1);
+	}
+
+	let mut positions = match VotesMigrationCursor::<T>::get() {
+		Some(last) => Positions::<T>::iter_from(Positions::<T>::hashed_key_for(last)),
+		None => Positions::<T>::iter(),
+	};
+	let collection_id = T::NFTCollectionId::get();
+
+	let mut last_migrated = None;
+	while remaining_weight.all_gte(used_weight.saturating_add(position_weight)) {
+		let Some((position_id, position)) = positions.next() else {
+			VotesMigrationPending::<T>::kill();
+			VotesMigrationCursor::<T>::kill();
+
+			log::info!(target: LOG_TARGET, "Migration of the positions' votes was complete");
+
+			return used_weight;
+		};
+
+		used_weight.saturating_accrue(position_weight);
+		last_migrated = Some(position_id);
+
+		let Some(owner) = T::NFTHandler::owner(&collection_id, &position_id) else {
+			continue;
+		};
+
+		PositionVotes::<T>::mutate_exists(position_id, |maybe_voting| {
+			if let Some(voting) = maybe_voting {
+				voting
+					.votes
+					.retain(|(ref_index, _)| T::VotingLock::has_vote(&owner, *ref_index));
+				for (_, vote) in voting.votes.iter_mut() {
+					vote.amount = vote.amount.min(position.stake);
+				}
+
+				if voting.votes.is_empty() {
+					*maybe_voting = None;
+				}
+			}
+		});
+
+		T::VotingLock::update_lock(&owner);
+	}
+
+	VotesMigrationCursor::<T>::set(last_migrated);
+
+	used_weight
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn pre_migrate<T: Config, P: GetStorageVersion>() -> Result<Vec<u8>, &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 1,
+		"Storage version too high."
+	);
+
+	let positions = Positions::<T>::iter_keys().count() as u32;
+
+	Ok(positions.encode())
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate<T: Config, P: GetStorageVersion>(state: Vec<u8>) -> Result<(), &'static str> {
+	ensure!(
+		<P as GetStorageVersion>::on_chain_storage_version() == 2,
+		"Unexpected storage version."
+	);
+
+	ensure!(
+		VotesMigrationPending::<T>::get(),
+		"Migration of the positions' votes was not scheduled."
+	);
+
+	let positions = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode number of positions")?;
+
+	ensure!(
+		Positions::<T>::iter_keys().count() as u32 == positions,
+		"Number of positions does not match."
+	);
+
+	Ok(())
+}
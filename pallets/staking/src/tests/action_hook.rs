@@ -0,0 +1,210 @@
+use super::*;
+
+use crate::traits::ActionHook;
+use crate::types::{Action, CollectiveVote, DcaTrade, LiquidityProvision};
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn on_action_should_add_max_points_when_max_value_is_used() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::on_action(
+				&ALICE,
+				Action::OmnipoolLiquidity,
+				LiquidityProvision::new(100_000 * ONE, 6)
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::ActionPointsAdded {
+				who: ALICE,
+				position_id: alice_position_id,
+				action: Action::OmnipoolLiquidity,
+				points: 100,
+			}
+			.into());
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 100);
+		});
+}
+
+#[test]
+fn on_action_should_add_points_proportionally_when_partial_value_is_used() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::on_action(
+				&ALICE,
+				Action::OmnipoolLiquidity,
+				LiquidityProvision::new(50_000 * ONE, 3)
+			));
+			assert_ok!(Staking::on_action(
+				&ALICE,
+				Action::DcaTrade,
+				DcaTrade::new(60_000 * ONE)
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::ActionPointsAdded {
+				who: ALICE,
+				position_id: alice_position_id,
+				action: Action::DcaTrade,
+				points: 2,
+			}
+			.into());
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 25 + 2);
+		});
+}
+
+#[test]
+fn on_action_should_cap_points_when_value_is_bigger_than_max_value() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::on_action(
+				&ALICE,
+				Action::OmnipoolLiquidity,
+				LiquidityProvision::new(1_000_000 * ONE, 100)
+			));
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+
+			//Assert
+			assert_last_event!(Event::<Test>::ActionPointsAdded {
+				who: ALICE,
+				position_id: alice_position_id,
+				action: Action::CollectiveVote,
+				points: 50,
+			}
+			.into());
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 100 + 50);
+		});
+}
+
+#[test]
+fn on_action_should_do_nothing_when_account_has_no_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(Staking::on_action(&BOB, Action::CollectiveVote, CollectiveVote));
+
+			//Assert
+			assert!(!has_event(
+				Event::<Test>::ActionPointsAdded {
+					who: BOB,
+					position_id: 0,
+					action: Action::CollectiveVote,
+					points: 50,
+				}
+				.into()
+			));
+			assert_eq!(Staking::positions(0).unwrap().action_points, 0);
+		});
+}
+
+#[test]
+fn on_action_should_do_nothing_when_staking_is_not_initialized() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+		});
+}
+
+#[test]
+fn on_action_should_add_points_once_per_period_when_action_is_repeated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+
+			//Act
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+
+			//Assert
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 50);
+			assert_eq!(
+				Staking::action_rewarded_at(alice_position_id, Action::CollectiveVote),
+				Some(145)
+			);
+
+			//Act - next period
+			set_block_number(1_462_987);
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+
+			//Assert
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 100);
+			assert_eq!(
+				Staking::action_rewarded_at(alice_position_id, Action::CollectiveVote),
+				Some(146)
+			);
+		});
+}
+
+#[test]
+fn unstake_should_remove_action_rewarded_at_of_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 50_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 0)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			assert_ok!(Staking::on_action(&ALICE, Action::CollectiveVote, CollectiveVote));
+			assert_ok!(Staking::on_action(
+				&ALICE,
+				Action::DcaTrade,
+				DcaTrade::new(60_000 * ONE)
+			));
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(ALICE), alice_position_id));
+
+			//Assert
+			assert_eq!(
+				Staking::action_rewarded_at(alice_position_id, Action::CollectiveVote),
+				None
+			);
+			assert_eq!(Staking::action_rewarded_at(alice_position_id, Action::DcaTrade), None);
+		});
+}
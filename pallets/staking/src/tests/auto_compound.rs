@@ -0,0 +1,322 @@
+use super::*;
+
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn set_auto_compound_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::set_auto_compound(RuntimeOrigin::signed(BOB), 0, true),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn set_auto_compound_should_not_work_when_reward_beneficiary_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				Some(DAVE)
+			));
+
+			//Act & assert
+			assert_noop!(
+				Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, true),
+				Error::<Test>::IncompatibleRewardSettings
+			);
+		});
+}
+
+#[test]
+fn set_auto_compound_should_work_when_origin_is_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, true));
+
+			//Assert
+			assert_last_event!(Event::<Test>::AutoCompoundUpdated {
+				who: ALICE,
+				position_id: 0,
+				enabled: true,
+			}
+			.into());
+			assert!(Staking::is_auto_compound(0));
+
+			//Act
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, false));
+
+			//Assert
+			assert!(!Staking::is_auto_compound(0));
+		});
+}
+
+#[test]
+fn on_idle_should_compound_rewards_into_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				true
+			));
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			//Act
+			Staking::on_idle(1_600_000, Weight::MAX);
+
+			//Assert
+			assert_last_event!(Event::<Test>::RewardsCompounded {
+				who: ALICE,
+				position_id: alice_position_id,
+				rewards: 432_086_451_705_829_u128,
+				total_stake: 100_432_086_451_705_829_u128,
+				slashed_points: 0,
+			}
+			.into());
+			assert_staking_data!(
+				150_432_086_451_705_829_u128,
+				FixedU128::from_inner(1_033_333_333_333_333_333_u128),
+				104_567_913_548_294_171_u128 + NON_DUSTABLE_BALANCE
+			);
+			assert_hdx_lock!(ALICE, 100_432_086_451_705_829_u128, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 150_000 * ONE);
+
+			assert_eq!(
+				Staking::positions(alice_position_id).unwrap(),
+				Position {
+					stake: 100_432_086_451_705_829_u128,
+					reward_per_stake: FixedU128::from_inner(1_033_333_333_333_333_333_u128),
+					created_at: 1_452_987,
+					accumulated_unpaid_rewards: 102_901_246_881_627_504,
+					action_points: 0,
+					accumulated_slash_points: 0,
+					accumulated_locked_rewards: 0,
+				}
+			);
+			assert_eq!(Staking::positions(1).unwrap().stake, 50_000 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_continue_from_last_compounded_position_when_weight_is_limited() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, true));
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(BOB), 1, true));
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			let one_compound_weight = <() as crate::weights::WeightInfo>::auto_compound();
+
+			//Act
+			Staking::on_idle(1_600_000, one_compound_weight);
+
+			//Assert
+			let initial_stakes = [100_000 * ONE, 50_000 * ONE];
+			let first = Staking::auto_compound_cursor().unwrap();
+			let second = if first == 0 { 1 } else { 0 };
+			assert!(Staking::positions(first).unwrap().stake > initial_stakes[first as usize]);
+			assert_eq!(
+				Staking::positions(second).unwrap().stake,
+				initial_stakes[second as usize]
+			);
+
+			//Act
+			Staking::on_idle(1_600_001, one_compound_weight);
+
+			//Assert
+			assert_eq!(Staking::auto_compound_cursor(), Some(second));
+			assert!(Staking::positions(second).unwrap().stake > initial_stakes[second as usize]);
+
+			//Act
+			Staking::on_idle(1_600_002, one_compound_weight);
+
+			//Assert
+			assert_eq!(Staking::auto_compound_cursor(), None);
+		});
+}
+
+#[test]
+fn on_idle_should_not_compound_when_weight_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, true));
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			//Act
+			assert_eq!(Staking::on_idle(1_600_000, Weight::zero()), Weight::zero());
+
+			//Assert
+			assert_eq!(Staking::positions(0).unwrap().stake, 100_000 * ONE);
+		});
+}
+
+#[test]
+fn increase_stake_should_compound_rewards_when_auto_compound_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				true
+			));
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			//Act
+			assert_ok!(Staking::increase_stake(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				10 * ONE
+			));
+
+			//Assert
+			assert!(has_event(
+				Event::<Test>::RewardsCompounded {
+					who: ALICE,
+					position_id: alice_position_id,
+					rewards: 432_086_451_705_829_u128,
+					total_stake: 100_432_086_451_705_829_u128,
+					slashed_points: 0,
+				}
+				.into()
+			));
+			assert_last_event!(Event::<Test>::StakeAdded {
+				who: ALICE,
+				position_id: alice_position_id,
+				stake: 10 * ONE,
+				total_stake: 100_442_086_451_705_829_u128,
+				locked_rewards: 0,
+				slashed_points: 0,
+			}
+			.into());
+			assert_eq!(
+				Staking::positions(alice_position_id)
+					.unwrap()
+					.accumulated_locked_rewards,
+				0
+			);
+			assert_hdx_lock!(ALICE, 100_442_086_451_705_829_u128, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn unstake_partial_should_keep_compounded_rewards_in_stake_when_position_is_auto_compound() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			let bob_position_id = 1;
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				true
+			));
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			//Act
+			assert_ok!(Staking::unstake_partial(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				10_000 * ONE
+			));
+
+			//Assert
+			let alice_position = Staking::positions(alice_position_id).unwrap();
+			let bob_position = Staking::positions(bob_position_id).unwrap();
+			assert!(alice_position.stake > 90_000 * ONE);
+			assert_eq!(
+				Staking::staking().total_stake,
+				alice_position.stake + bob_position.stake
+			);
+			assert_hdx_lock!(
+				ALICE,
+				alice_position.get_total_locked().unwrap() + Staking::get_unbonding_balance(&ALICE),
+				STAKING_LOCK
+			);
+		});
+}
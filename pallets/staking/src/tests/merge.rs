@@ -0,0 +1,252 @@
+use super::*;
+
+use crate::types::{Conviction, Vote};
+use frame_support::traits::{tokens::nonfungibles::Mutate, Get};
+use mock::{Staking, Uniques};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+/// Moves position's NFT to `to` so `to` owns two positions.
+fn move_position_nft(position_id: PositionId, to: u64) {
+	let collection_id = <Test as crate::Config>::NFTCollectionId::get();
+	assert_ok!(<Uniques as Mutate<u64>>::burn(&collection_id, &position_id, None));
+	assert_ok!(<Uniques as Mutate<u64>>::mint_into(&collection_id, &position_id, &to));
+}
+
+#[test]
+fn merge_should_not_work_when_origin_is_not_owner_of_both_positions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_should_not_work_when_position_is_merged_into_itself() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::merge(RuntimeOrigin::signed(ALICE), 0, 0),
+				Error::<Test>::SamePosition
+			);
+		});
+}
+
+#[test]
+fn merge_should_combine_positions_when_account_owns_both_positions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PositionsMerged {
+				who: ALICE,
+				position_id: 1,
+				into_position_id: 0,
+				total_stake: 150_000 * ONE,
+			}
+			.into());
+
+			assert_eq!(Staking::positions(1), None);
+			assert_eq!(
+				Staking::positions(0).unwrap(),
+				Position {
+					stake: 150_000 * ONE,
+					reward_per_stake: FixedU128::from(1),
+					created_at: 1_452_987,
+					accumulated_unpaid_rewards: 100_000 * ONE,
+					action_points: 0,
+					accumulated_slash_points: 0,
+					accumulated_locked_rewards: 0,
+				}
+			);
+			assert_eq!(Staking::get_user_position_id(&ALICE).unwrap(), Some(0));
+
+			assert_hdx_lock!(ALICE, 150_000 * ONE, STAKING_LOCK);
+			assert_staking_data!(150_000 * ONE, FixedU128::from(1), 100_000 * ONE + NON_DUSTABLE_BALANCE);
+		});
+}
+
+#[test]
+fn merge_should_keep_newer_creation_block_when_positions_are_merged() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_552_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			assert_eq!(Staking::positions(0).unwrap().created_at, 1_552_987);
+		});
+}
+
+#[test]
+fn merge_should_move_votes_when_positions_are_merged() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![
+			(0, vec![(3_u32, Vote::new(10_000 * ONE, Conviction::Locked1x))]),
+			(
+				1,
+				vec![
+					(1_u32, Vote::new(10_000 * ONE, Conviction::Locked1x)),
+					(2_u32, Vote::new(30_000 * ONE, Conviction::Locked1x)),
+				],
+			),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			assert_eq!(
+				Staking::position_votes(0).votes.to_vec(),
+				vec![
+					(1_u32, Vote::new(10_000 * ONE, Conviction::Locked1x)),
+					(3_u32, Vote::new(10_000 * ONE, Conviction::Locked1x)),
+				]
+			);
+			assert!(Staking::position_votes(1).votes.is_empty());
+			assert_eq!(Staking::positions(0).unwrap().action_points, 10);
+		});
+}
+
+#[test]
+fn merge_should_scale_down_slash_points_of_older_position_when_positions_are_merged() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_552_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+			Positions::<Test>::mutate(0, |p| p.as_mut().unwrap().accumulated_slash_points = 10);
+			Positions::<Test>::mutate(1, |p| p.as_mut().unwrap().accumulated_slash_points = 2);
+			set_block_number(1_652_987);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			let position = Staking::positions(0).unwrap();
+			assert_eq!(position.created_at, 1_552_987);
+			// older position lost half of its time points
+			assert_eq!(position.accumulated_slash_points, 5 + 2);
+		});
+}
+
+#[test]
+fn merge_should_cap_slash_points_to_points_of_merged_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+			Positions::<Test>::mutate(0, |p| p.as_mut().unwrap().accumulated_slash_points = 15);
+			Positions::<Test>::mutate(1, |p| p.as_mut().unwrap().accumulated_slash_points = 15);
+			set_block_number(1_552_987);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			// 10 periods * 2 time points per period * 80% time points weight
+			assert_eq!(Staking::positions(0).unwrap().accumulated_slash_points, 16);
+		});
+}
+
+#[test]
+fn merge_should_combine_votes_when_both_positions_voted_in_same_referendum() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![
+			(0, vec![(1_u32, Vote::new(10_000 * ONE, Conviction::Locked2x))]),
+			(1, vec![(1_u32, Vote::new(50_000 * ONE, Conviction::Locked1x))]),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			move_position_nft(1, ALICE);
+
+			//Act
+			assert_ok!(Staking::merge(RuntimeOrigin::signed(ALICE), 1, 0));
+
+			//Assert
+			assert_eq!(
+				Staking::position_votes(0).votes.to_vec(),
+				vec![(1_u32, Vote::new(60_000 * ONE, Conviction::Locked1x))]
+			);
+			assert!(Staking::position_votes(1).votes.is_empty());
+		});
+}
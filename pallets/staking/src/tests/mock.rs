@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::traits::{Freeze, VestingDetails};
+use crate::traits::{Freeze, RewardSwap, VestingDetails, VotingLock};
 use crate::types::{Vote, Voting};
 use crate::*;
 
@@ -43,6 +43,7 @@ type AssetId = u32;
 type BlockNumber = u64;
 
 pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
 
 pub const ALICE: AccountId = 1_000;
 pub const BOB: AccountId = 1_001;
@@ -196,6 +197,9 @@ parameter_types! {
 	pub const UnclaimablePeriods: BlockNumber = 10;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15,100);
 	pub const MaxVotes: u32 = 10;
+	pub static UnbondingPeriods: Period = 0;
+	pub static VotingLocked: Vec<(AccountId, Balance)> = vec![];
+	pub static DemocracyVotes: Vec<(AccountId, ReferendumIndex)> = vec![];
 }
 
 impl pallet_staking::Config for Test {
@@ -206,6 +210,7 @@ impl pallet_staking::Config for Test {
 	type PeriodLength = PeriodLength;
 	type PalletId = StakingPalletId;
 	type NativeAssetId = ConstU32<HDX>;
+	type HubAssetId = ConstU32<LRNA>;
 	type MinStake = MinStake;
 	type TimePointsWeight = TimePointsW;
 	type ActionPointsWeight = ActionPointsW;
@@ -220,8 +225,13 @@ impl pallet_staking::Config for Test {
 
 	type PayablePercentage = SigmoidPercentage<PointPercentage, ConstU32<40_000>>;
 	type MaxVotes = MaxVotes;
+	type UnbondingPeriods = UnbondingPeriods;
+	type MaxUnbondingChunks = ConstU32<3>;
+	type MaxRewardCurrencies = ConstU32<3>;
+	type RewardSwap = DummyRewardSwap;
 	type MaxPointsPerAction = DummyMaxPointsPerAction;
 	type ReferendumInfo = DummyReferendumStatus;
+	type VotingLock = DummyVotingLock;
 	type Vesting = DummyVesting;
 	type Collections = FreezableUniques;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
@@ -233,10 +243,35 @@ impl GetByKey<Action, u32> for DummyMaxPointsPerAction {
 	fn get(k: &Action) -> u32 {
 		match k {
 			Action::DemocracyVote => 100_u32,
+			Action::OmnipoolLiquidity => 100_u32,
+			Action::DcaTrade => 20_u32,
+			Action::CollectiveVote => 50_u32,
 		}
 	}
 }
 
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const NOT_SWAPPABLE: AssetId = 99;
+
+/// Swaps reward currencies to HDX 1:2.
+pub struct DummyRewardSwap;
+
+impl RewardSwap<AccountId, AssetId> for DummyRewardSwap {
+	fn swap_to_native(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult {
+		if asset == NOT_SWAPPABLE {
+			return Err(DispatchError::Other("Not swappable"));
+		}
+
+		<Tokens as MultiCurrency<AccountId>>::withdraw(asset, who, amount)?;
+		<Tokens as MultiCurrency<AccountId>>::deposit(HDX, who, amount * 2)
+	}
+
+	fn swap_to_native_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct DummyReferendumStatus;
 
 impl DemocracyReferendum for DummyReferendumStatus {
@@ -245,6 +280,27 @@ impl DemocracyReferendum for DummyReferendumStatus {
 	}
 }
 
+/// Balance locked by democracy votes is set by `VotingLocked`.
+pub struct DummyVotingLock;
+
+impl VotingLock<AccountId> for DummyVotingLock {
+	fn locked_balance(who: &AccountId) -> Balance {
+		VotingLocked::get()
+			.iter()
+			.find(|(account, _)| account == who)
+			.map(|(_, amount)| *amount)
+			.unwrap_or_default()
+	}
+
+	fn has_vote(who: &AccountId, ref_index: ReferendumIndex) -> bool {
+		DemocracyVotes::get()
+			.iter()
+			.any(|(account, index)| account == who && *index == ref_index)
+	}
+
+	fn update_lock(_who: &AccountId) {}
+}
+
 pub struct DummyVesting;
 
 impl VestingDetails<AccountId, Balance> for DummyVesting {
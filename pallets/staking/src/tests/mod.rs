@@ -1,17 +1,26 @@
 use mock::*;
 
-use crate::types::{Position, StakingData};
+use crate::types::{Position, PositionCurrencyRewards, RewardCurrencyData, StakingData, UnbondingChunk};
 use crate::*;
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::BalanceLock;
 
+mod action_hook;
+mod auto_compound;
 mod claim;
 mod increase_stake;
+mod merge;
 pub(crate) mod mock;
+mod reward_beneficiary;
+mod reward_currencies;
 mod stake;
 #[allow(clippy::module_inception)]
 mod tests;
+mod transfer_position;
+mod unbonding;
 mod unstake;
+mod unstake_partial;
+mod voting_lock;
 
 /// Assert amount of locked tokens. `amount == 0` asserts no lock.
 ///
@@ -0,0 +1,261 @@
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+const BENEFICIARY: u64 = 2_000;
+
+#[test]
+fn set_reward_beneficiary_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::set_reward_beneficiary(RuntimeOrigin::signed(BOB), 0, Some(BENEFICIARY)),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn set_reward_beneficiary_should_not_work_when_auto_compound_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), 0, true));
+
+			//Act & assert
+			assert_noop!(
+				Staking::set_reward_beneficiary(RuntimeOrigin::signed(ALICE), 0, Some(BENEFICIARY)),
+				Error::<Test>::IncompatibleRewardSettings
+			);
+		});
+}
+
+#[test]
+fn set_reward_beneficiary_should_work_when_origin_is_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				Some(BENEFICIARY)
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::RewardBeneficiaryUpdated {
+				who: ALICE,
+				position_id: 0,
+				beneficiary: Some(BENEFICIARY),
+			}
+			.into());
+			assert_eq!(Staking::reward_beneficiary(0), Some(BENEFICIARY));
+
+			//Act
+			assert_ok!(Staking::set_reward_beneficiary(RuntimeOrigin::signed(ALICE), 0, None));
+
+			//Assert
+			assert_eq!(Staking::reward_beneficiary(0), None);
+		});
+}
+
+#[test]
+fn claim_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				Some(BENEFICIARY)
+			));
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert_last_event!(Event::<Test>::RewardsClaimed {
+				who: BOB,
+				position_id: bob_position_id,
+				paid_rewards: 334_912_244_857_841_u128,
+				unlocked_rewards: 0,
+				slashed_points: 40,
+				slashed_unpaid_rewards: 10_336_797_680_797_565_u128,
+			}
+			.into());
+
+			assert_eq!(Tokens::free_balance(HDX, &BENEFICIARY), 334_912_244_857_841_u128);
+			assert_unlocked_balance!(&BOB, HDX, 130_000 * ONE);
+			assert_hdx_lock!(BOB, 120_000 * ONE, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn increase_stake_should_lock_rewards_in_position_when_beneficiary_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+			let alice_position_id = 0;
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				Some(BENEFICIARY)
+			));
+
+			//Act
+			assert_ok!(Staking::increase_stake(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				100_000 * ONE
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::StakeAdded {
+				who: ALICE,
+				position_id: alice_position_id,
+				stake: 100_000 * ONE,
+				total_stake: 200_000 * ONE,
+				locked_rewards: 432_086_451_705_829_u128,
+				slashed_points: 12
+			}
+			.into());
+			assert_eq!(Tokens::free_balance(HDX, &BENEFICIARY), 0);
+			assert_hdx_lock!(ALICE, 200_432_086_451_705_829_u128, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 50_000 * ONE);
+			assert_eq!(
+				Staking::positions(alice_position_id)
+					.unwrap()
+					.accumulated_locked_rewards,
+				432_086_451_705_829_u128
+			);
+		});
+}
+
+#[test]
+fn repeated_unstake_partial_should_keep_rewards_locked_when_beneficiary_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+			let alice_position_id = 0;
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				Some(BENEFICIARY)
+			));
+
+			//Act
+			for _ in 0..3 {
+				assert_ok!(Staking::unstake_partial(
+					RuntimeOrigin::signed(ALICE),
+					alice_position_id,
+					1
+				));
+			}
+
+			//Assert
+			let position = Staking::positions(alice_position_id).unwrap();
+			assert_eq!(Tokens::free_balance(HDX, &BENEFICIARY), 0);
+			assert_eq!(position.stake, 100_000 * ONE - 3);
+			assert!(position.accumulated_locked_rewards > 432_086_451_705_829_u128);
+			assert_hdx_lock!(
+				ALICE,
+				position.stake + position.accumulated_locked_rewards,
+				STAKING_LOCK
+			);
+			assert_unlocked_balance!(ALICE, HDX, 150_000 * ONE + 3);
+		});
+}
+
+#[test]
+fn unstake_should_remove_reward_settings_of_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				Some(BENEFICIARY)
+			));
+			assert_ok!(Staking::set_auto_compound(RuntimeOrigin::signed(BOB), 1, true));
+			set_block_number(1_600_000);
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(ALICE), 0));
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), 1));
+
+			//Assert
+			assert_eq!(Staking::reward_beneficiary(0), None);
+			assert!(!Staking::is_auto_compound(1));
+		});
+}
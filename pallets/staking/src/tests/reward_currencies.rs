@@ -0,0 +1,337 @@
+use super::*;
+
+use frame_system::RawOrigin;
+use mock::Staking;
+use orml_traits::MultiCurrencyExtended;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128};
+
+fn set_pending_currency_rewards(asset_id: u32, amount: u128) {
+	let pot = Staking::pot_account_id();
+	assert_ok!(Tokens::update_balance(asset_id, &pot, amount as i128));
+}
+
+fn currency_rewards_paid_events() -> Vec<(u128, u128)> {
+	System::events()
+		.into_iter()
+		.filter_map(|e| match e.event {
+			RuntimeEvent::Staking(Event::<Test>::CurrencyRewardsPaid {
+				amount, native_amount, ..
+			}) => Some((amount, native_amount)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn add_reward_currency_should_not_work_when_origin_is_not_authority() {
+	ExtBuilder::default()
+		.with_initialized_staking()
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::add_reward_currency(RuntimeOrigin::signed(ALICE), DAI),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn add_reward_currency_should_not_work_when_currency_is_native_hub_asset_or_already_added() {
+	ExtBuilder::default()
+		.with_initialized_staking()
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+
+			//Act & assert
+			assert_noop!(
+				Staking::add_reward_currency(RawOrigin::Root.into(), HDX),
+				Error::<Test>::InvalidRewardCurrency
+			);
+			assert_noop!(
+				Staking::add_reward_currency(RawOrigin::Root.into(), LRNA),
+				Error::<Test>::InvalidRewardCurrency
+			);
+			assert_noop!(
+				Staking::add_reward_currency(RawOrigin::Root.into(), DAI),
+				Error::<Test>::InvalidRewardCurrency
+			);
+		});
+}
+
+#[test]
+fn add_reward_currency_should_not_work_when_max_reward_currencies_is_reached() {
+	ExtBuilder::default()
+		.with_initialized_staking()
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), USDT));
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), NOT_SWAPPABLE));
+
+			//Act & assert
+			assert_noop!(
+				Staking::add_reward_currency(RawOrigin::Root.into(), 4),
+				Error::<Test>::MaxRewardCurrenciesReached
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_transfer_fee_to_pot_when_asset_is_reward_currency() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DAI, 1_000 * ONE), (ALICE, USDT, 1_000 * ONE)])
+		.with_initialized_staking()
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+
+			//Act & assert
+			assert_eq!(Staking::process_trade_fee(ALICE, DAI, 100 * ONE), Ok(0));
+			assert_eq!(Staking::process_trade_fee(ALICE, USDT, 100 * ONE), Ok(100 * ONE));
+
+			assert_eq!(Tokens::free_balance(DAI, &Staking::pot_account_id()), 100 * ONE);
+			assert_eq!(Tokens::free_balance(USDT, &Staking::pot_account_id()), 0);
+		});
+}
+
+#[test]
+fn claim_should_pay_currency_rewards_and_slash_the_rest_when_position_is_claimable() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = 1;
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			let paid = Tokens::free_balance(DAI, &BOB);
+			assert!(paid > 0 && paid < 1_000 * ONE);
+			assert_eq!(currency_rewards_paid_events(), vec![(paid, 0)]);
+
+			assert_eq!(
+				Staking::reward_currency(DAI).unwrap(),
+				RewardCurrencyData {
+					accumulated_reward_per_stake: FixedU128::from_rational(2, 100),
+					pot_reserved_balance: 2_000 * ONE,
+				}
+			);
+			assert_eq!(
+				Staking::position_currency_rewards(bob_position_id, DAI),
+				PositionCurrencyRewards {
+					reward_per_stake: FixedU128::from_rational(2, 100),
+					pending_rewards: 0,
+				}
+			);
+		});
+}
+
+#[test]
+fn claim_should_keep_currency_rewards_when_position_is_in_unclaimable_periods() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+			set_block_number(1_460_000);
+			let bob_position_id = 1;
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+			assert_eq!(Staking::reward_currency(DAI).unwrap().pot_reserved_balance, 3_000 * ONE);
+			assert_eq!(
+				Staking::position_currency_rewards(bob_position_id, DAI).pending_rewards,
+				1_000 * ONE
+			);
+		});
+}
+
+#[test]
+fn claim_should_swap_currency_rewards_when_swap_rewards_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			assert_ok!(Staking::set_swap_rewards(RuntimeOrigin::signed(BOB), 1, true));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+			set_block_number(1_700_000);
+			let hdx_before = Tokens::free_balance(HDX, &BOB);
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), 1));
+
+			//Assert
+			let events = currency_rewards_paid_events();
+			assert_eq!(events.len(), 1);
+			let (paid, native_amount) = events[0];
+			assert!(paid > 0);
+			assert_eq!(native_amount, 2 * paid);
+
+			assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+			assert!(Tokens::free_balance(HDX, &BOB) >= hdx_before + native_amount);
+		});
+}
+
+#[test]
+fn claim_should_pay_currency_rewards_in_reward_currency_when_swap_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), NOT_SWAPPABLE));
+			assert_ok!(Staking::set_swap_rewards(RuntimeOrigin::signed(BOB), 1, true));
+			set_pending_currency_rewards(NOT_SWAPPABLE, 3_000 * ONE);
+			set_block_number(1_700_000);
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), 1));
+
+			//Assert
+			let paid = Tokens::free_balance(NOT_SWAPPABLE, &BOB);
+			assert!(paid > 0);
+			assert_eq!(currency_rewards_paid_events(), vec![(paid, 0)]);
+		});
+}
+
+#[test]
+fn stake_should_not_receive_currency_rewards_distributed_before_position_was_created() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 250_000 * ONE),
+			(BOB, HDX, 150_000 * ONE),
+			(CHARLIE, HDX, 150_000 * ONE),
+		])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+
+			//Act
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(CHARLIE), 50_000 * ONE));
+
+			//Assert
+			let charlie_position_id = Staking::get_user_position_id(&CHARLIE).unwrap().unwrap();
+			assert_eq!(
+				Staking::position_currency_rewards(charlie_position_id, DAI),
+				PositionCurrencyRewards {
+					reward_per_stake: FixedU128::from_rational(2, 100),
+					pending_rewards: 0,
+				}
+			);
+		});
+}
+
+#[test]
+fn increase_stake_should_settle_currency_rewards_when_stake_is_increased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+
+			//Act
+			assert_ok!(Staking::increase_stake(RuntimeOrigin::signed(ALICE), 0, 50_000 * ONE));
+
+			//Assert
+			assert_eq!(
+				Staking::position_currency_rewards(0, DAI),
+				PositionCurrencyRewards {
+					reward_per_stake: FixedU128::from_rational(2, 100),
+					pending_rewards: 2_000 * ONE,
+				}
+			);
+		});
+}
+
+#[test]
+fn unstake_should_pay_currency_rewards_and_remove_position_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::add_reward_currency(RawOrigin::Root.into(), DAI));
+			assert_ok!(Staking::set_swap_rewards(RuntimeOrigin::signed(BOB), 1, true));
+			set_pending_currency_rewards(DAI, 3_000 * ONE);
+			set_block_number(1_700_000);
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), 1));
+
+			//Assert
+			let events = currency_rewards_paid_events();
+			assert_eq!(events.len(), 1);
+			assert!(events[0].0 > 0);
+
+			assert_eq!(Staking::reward_currency(DAI).unwrap().pot_reserved_balance, 2_000 * ONE);
+			assert_eq!(
+				Staking::position_currency_rewards(1, DAI),
+				PositionCurrencyRewards::default()
+			);
+			assert!(!Staking::is_swap_rewards(1));
+		});
+}
@@ -0,0 +1,177 @@
+use super::*;
+
+use crate::types::{Conviction, Vote};
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn transfer_position_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(BOB), 0, CHARLIE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_new_owner_has_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(ALICE), 0, BOB),
+				Error::<Test>::PositionAlreadyExists
+			);
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(ALICE), 0, ALICE),
+				Error::<Test>::PositionAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn transfer_position_should_move_position_and_lock_when_new_owner_has_no_position() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			let position_before = Staking::positions(alice_position_id).unwrap();
+
+			//Act
+			assert_ok!(Staking::transfer_position(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				CHARLIE
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PositionTransferred {
+				who: ALICE,
+				new_owner: CHARLIE,
+				position_id: alice_position_id,
+				locked: 100_000 * ONE,
+			}
+			.into());
+
+			assert_eq!(Staking::get_user_position_id(&ALICE).unwrap(), None);
+			assert_eq!(
+				Staking::get_user_position_id(&CHARLIE).unwrap(),
+				Some(alice_position_id)
+			);
+			assert_eq!(Staking::positions(alice_position_id).unwrap(), position_before);
+
+			assert_hdx_lock!(ALICE, 0, STAKING_LOCK);
+			assert_hdx_lock!(CHARLIE, 100_000 * ONE, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 150_000 * ONE);
+			assert_unlocked_balance!(CHARLIE, HDX, 0);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 100_000 * ONE);
+
+			assert_staking_data!(150_000 * ONE, FixedU128::from(1), 100_000 * ONE + NON_DUSTABLE_BALANCE);
+		});
+}
+
+#[test]
+fn transfer_position_should_keep_unbonding_lock_at_original_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			let alice_position_id = 0;
+			assert_ok!(Staking::unstake_partial(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				20_000 * ONE
+			));
+
+			//Act
+			assert_ok!(Staking::transfer_position(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				CHARLIE
+			));
+
+			//Assert
+			assert_hdx_lock!(ALICE, 20_000 * ONE, STAKING_LOCK);
+			assert_hdx_lock!(CHARLIE, 80_000 * ONE, STAKING_LOCK);
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 80_000 * ONE);
+			assert_eq!(Staking::get_unbonding_balance(&ALICE), 20_000 * ONE);
+			assert_eq!(Staking::get_unbonding_balance(&CHARLIE), 0);
+		});
+}
+
+#[test]
+fn transfer_position_should_remove_votes_and_reward_settings_when_position_is_transferred() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![(
+			0,
+			vec![
+				(2_u32, Vote::new(60_000 * ONE, Conviction::Locked1x)),
+				(3_u32, Vote::new(60_000 * ONE, Conviction::Locked1x)),
+			],
+		)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let alice_position_id = 0;
+			assert_ok!(Staking::set_reward_beneficiary(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				Some(DAVE)
+			));
+
+			//Act
+			assert_ok!(Staking::transfer_position(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				CHARLIE
+			));
+
+			//Assert
+			assert_eq!(Staking::positions(alice_position_id).unwrap().action_points, 10);
+			assert!(Staking::position_votes(alice_position_id).votes.is_empty());
+			assert_eq!(Staking::reward_beneficiary(alice_position_id), None);
+		});
+}
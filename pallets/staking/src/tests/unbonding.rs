@@ -0,0 +1,226 @@
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn unstake_should_keep_stake_locked_when_unbonding_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert!(has_event(
+				Event::<Test>::UnbondingScheduled {
+					who: BOB,
+					amount: 120_000 * ONE,
+					unlock_at: 172,
+				}
+				.into()
+			));
+			assert_eq!(Staking::positions(bob_position_id), None);
+			assert_hdx_lock!(BOB, 120_000 * ONE, STAKING_LOCK);
+			assert_unlocked_balance!(&BOB, HDX, 130_334_912_244_857_841_u128);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_not_work_when_unbonding_period_has_not_passed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 50_000 * ONE));
+
+			set_block_number(1_619_999);
+
+			//Act & assert
+			assert_noop!(
+				Staking::withdraw_unbonded(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::NothingToWithdraw
+			);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_not_work_when_nothing_is_unbonding() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::withdraw_unbonded(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::NothingToWithdraw
+			);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_unlock_only_unbonded_chunks() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 20_000 * ONE));
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 10_000 * ONE));
+
+			set_block_number(1_610_000);
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 30_000 * ONE));
+
+			assert_eq!(
+				Staking::unbondings(ALICE).to_vec(),
+				vec![
+					UnbondingChunk::new(30_000 * ONE, 162),
+					UnbondingChunk::new(30_000 * ONE, 163)
+				]
+			);
+
+			set_block_number(1_620_000);
+
+			//Act
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+
+			//Assert
+			assert_last_event!(Event::<Test>::UnbondedWithdrawn {
+				who: ALICE,
+				amount: 30_000 * ONE,
+			}
+			.into());
+			assert_eq!(
+				Staking::unbondings(ALICE).to_vec(),
+				vec![UnbondingChunk::new(30_000 * ONE, 163)]
+			);
+			assert_hdx_lock!(ALICE, 70_000 * ONE, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 180_000 * ONE);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_remove_lock_when_position_was_destroyed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(ALICE), 0));
+
+			set_block_number(1_620_000);
+
+			//Act
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+
+			//Assert
+			assert!(Staking::unbondings(ALICE).is_empty());
+			assert_hdx_lock!(ALICE, 0, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 250_000 * ONE);
+		});
+}
+
+#[test]
+fn unstake_partial_should_not_work_when_max_unbonding_chunks_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(10);
+			for block in [1_600_000, 1_610_000, 1_620_000] {
+				set_block_number(block);
+				assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 10_000 * ONE));
+			}
+
+			set_block_number(1_630_000);
+
+			//Act & assert
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 10_000 * ONE),
+				Error::<Test>::MaxUnbondingChunksReached
+			);
+		});
+}
+
+#[test]
+fn stake_should_not_work_when_balance_is_unbonding() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(ALICE), 0));
+
+			//Act & assert
+			assert_noop!(
+				Staking::stake(RuntimeOrigin::signed(ALICE), 100_000 * ONE),
+				Error::<Test>::InsufficientBalance
+			);
+
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(ALICE), 50_000 * ONE));
+			assert_hdx_lock!(ALICE, 150_000 * ONE, STAKING_LOCK);
+		});
+}
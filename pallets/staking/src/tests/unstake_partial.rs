@@ -0,0 +1,255 @@
+use crate::types::{Conviction, Vote};
+
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn unstake_partial_should_not_work_when_staking_is_not_initialized() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act & assert
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 10_000 * ONE),
+				Error::<Test>::NotInitialized
+			);
+		});
+}
+
+#[test]
+fn unstake_partial_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_600_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), bob_position_id, 10_000 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn unstake_partial_should_not_work_when_amount_is_invalid() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_600_000);
+			let alice_position_id = 0;
+
+			//Act & assert
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), alice_position_id, 0),
+				Error::<Test>::InvalidUnstakeAmount
+			);
+
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), alice_position_id, 100_000 * ONE),
+				Error::<Test>::InvalidUnstakeAmount
+			);
+
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), alice_position_id, 100_001 * ONE),
+				Error::<Test>::InvalidUnstakeAmount
+			);
+
+			//NOTE: remaining stake is lower than `MinStake`
+			assert_noop!(
+				Staking::unstake_partial(RuntimeOrigin::signed(ALICE), alice_position_id, 99_991 * ONE),
+				Error::<Test>::InvalidUnstakeAmount
+			);
+		});
+}
+
+#[test]
+fn unstake_partial_should_work_when_unbonding_is_disabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::unstake_partial(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				50_000 * ONE
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PartiallyUnstaked {
+				who: ALICE,
+				position_id: alice_position_id,
+				unstaked: 50_000 * ONE,
+				remaining_stake: 50_000 * ONE,
+				locked_rewards: 432_086_451_705_829_u128,
+				slashed_points: 6
+			}
+			.into());
+			assert_staking_data!(
+				100_000 * ONE,
+				FixedU128::from_inner(1_033_333_333_333_333_333_u128),
+				104_567_913_548_294_171_u128 + NON_DUSTABLE_BALANCE
+			);
+			assert_hdx_lock!(ALICE, 50_432_086_451_705_829_u128, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 200_000 * ONE);
+			assert!(Staking::unbondings(ALICE).is_empty());
+
+			assert_eq!(
+				Staking::positions(alice_position_id).unwrap(),
+				Position {
+					stake: 50_000 * ONE,
+					reward_per_stake: FixedU128::from_inner(1_033_333_333_333_333_333_u128),
+					created_at: 1_452_987,
+					accumulated_unpaid_rewards: 102_901_246_881_627_504,
+					action_points: 0,
+					accumulated_slash_points: 6,
+					accumulated_locked_rewards: 432_086_451_705_829_u128,
+				}
+			);
+		});
+}
+
+#[test]
+fn unstake_partial_should_keep_unstaked_amount_locked_when_unbonding_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_pending_rewards(5_000 * ONE);
+			set_block_number(1_600_000);
+
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::unstake_partial(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				50_000 * ONE
+			));
+
+			//Assert
+			assert!(has_event(
+				Event::<Test>::UnbondingScheduled {
+					who: ALICE,
+					amount: 50_000 * ONE,
+					unlock_at: 162,
+				}
+				.into()
+			));
+			assert_eq!(
+				Staking::unbondings(ALICE).to_vec(),
+				vec![UnbondingChunk::new(50_000 * ONE, 162)]
+			);
+			assert_hdx_lock!(ALICE, 100_432_086_451_705_829_u128, STAKING_LOCK);
+			assert_unlocked_balance!(ALICE, HDX, 150_000 * ONE);
+			assert_eq!(Staking::positions(alice_position_id).unwrap().stake, 50_000 * ONE);
+		});
+}
+
+#[test]
+fn unstake_partial_should_cap_votes_to_remaining_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![(
+			0,
+			vec![
+				(
+					1_u32,
+					Vote {
+						amount: 100_000 * ONE,
+						conviction: Conviction::Locked2x,
+					},
+				),
+				(
+					3_u32,
+					Vote {
+						amount: 10_000 * ONE,
+						conviction: Conviction::None,
+					},
+				),
+			],
+		)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_600_000);
+			let alice_position_id = 0;
+
+			//Act
+			assert_ok!(Staking::unstake_partial(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				60_000 * ONE
+			));
+
+			//Assert
+			assert_eq!(
+				Staking::position_votes(alice_position_id).votes.to_vec(),
+				vec![
+					(
+						1_u32,
+						Vote {
+							amount: 40_000 * ONE,
+							conviction: Conviction::Locked2x,
+						},
+					),
+					(
+						3_u32,
+						Vote {
+							amount: 10_000 * ONE,
+							conviction: Conviction::None,
+						},
+					),
+				]
+			);
+		});
+}
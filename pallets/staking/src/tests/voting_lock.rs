@@ -0,0 +1,405 @@
+use super::*;
+
+use crate::types::{Conviction, Vote};
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn unstake_should_keep_tokens_locked_when_tokens_back_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			VotingLocked::set(vec![(BOB, 30_000 * ONE)]);
+			set_block_number(1_600_000);
+			let bob_position_id = 1;
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert!(has_event(
+				Event::<Test>::UnbondingScheduled {
+					who: BOB,
+					amount: 30_000 * ONE,
+					unlock_at: 160,
+				}
+				.into()
+			));
+			assert_eq!(Staking::positions(bob_position_id), None);
+			assert_eq!(Staking::get_unbonding_balance(&BOB), 30_000 * ONE);
+			assert_hdx_lock!(BOB, 30_000 * ONE, STAKING_LOCK);
+			assert_unlocked_balance!(&BOB, HDX, 120_000 * ONE);
+		});
+}
+
+#[test]
+fn unstake_partial_should_keep_only_tokens_not_backed_by_remaining_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			VotingLocked::set(vec![(BOB, 40_000 * ONE)]);
+			set_block_number(1_600_000);
+
+			//Act
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(BOB), 1, 20_000 * ONE));
+
+			//Assert
+			assert_eq!(Staking::get_unbonding_balance(&BOB), 10_000 * ONE);
+			assert_hdx_lock!(BOB, 40_000 * ONE, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn unstake_partial_should_not_keep_tokens_when_votes_are_not_fully_backed_by_staking() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			// Votes exceeding staked tokens are locked by democracy.
+			VotingLocked::set(vec![(BOB, 60_000 * ONE)]);
+			set_block_number(1_600_000);
+
+			//Act
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(BOB), 1, 20_000 * ONE));
+
+			//Assert
+			assert_eq!(Staking::get_unbonding_balance(&BOB), 0);
+			assert_hdx_lock!(BOB, 30_000 * ONE, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn unstake_partial_should_slash_points_when_tokens_stay_locked_by_votes() {
+	let unstake_partial = |voting_locked: Balance| {
+		let mut result = None;
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+			.with_initialized_staking()
+			.with_stakes(vec![
+				(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE),
+				(BOB, 50_000 * ONE, 1_452_987, 0),
+			])
+			.start_at_block(1_452_987)
+			.build()
+			.execute_with(|| {
+				VotingLocked::set(vec![(ALICE, voting_locked)]);
+				set_block_number(1_600_000);
+
+				assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(ALICE), 0, 60_000 * ONE));
+
+				result = Some((
+					Staking::positions(0).unwrap(),
+					Tokens::free_balance(HDX, &ALICE),
+					Staking::get_unbonding_balance(&ALICE),
+				));
+			});
+		result.unwrap()
+	};
+
+	//Act
+	let (position, balance, unbonding) = unstake_partial(0);
+	let (position_with_votes, balance_with_votes, unbonding_with_votes) = unstake_partial(100_000 * ONE);
+
+	//Assert
+	assert!(position.accumulated_slash_points > 0);
+	assert_eq!(position_with_votes, position);
+	assert_eq!(balance_with_votes, balance);
+	assert_eq!(unbonding, 0);
+	// locked rewards back the votes so only the rest of the unstaked tokens has to stay locked
+	assert_eq!(
+		unbonding_with_votes,
+		100_000 * ONE - position_with_votes.get_total_locked().unwrap()
+	);
+}
+
+#[test]
+fn claim_should_keep_unlocked_rewards_locked_when_tokens_back_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_462_987, 100_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let bob_position_id = 1;
+			let locked_before = Staking::positions(bob_position_id).unwrap().get_total_locked().unwrap();
+			assert!(locked_before > 100_000 * ONE);
+
+			VotingLocked::set(vec![(BOB, locked_before)]);
+			set_block_number(1_700_000);
+
+			//Act
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			let locked_after = Staking::positions(bob_position_id).unwrap().get_total_locked().unwrap();
+			assert!(locked_after < locked_before);
+			assert_eq!(Staking::get_unbonding_balance(&BOB), locked_before - locked_after);
+			assert_hdx_lock!(BOB, locked_before, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_keep_tokens_locked_when_tokens_back_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), 1));
+			VotingLocked::set(vec![(BOB, 30_000 * ONE)]);
+			set_block_number(1_620_000);
+
+			//Act
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+
+			//Assert
+			assert_last_event!(Event::<Test>::UnbondedWithdrawn {
+				who: BOB,
+				amount: 20_000 * ONE,
+			}
+			.into());
+			assert_eq!(
+				Staking::unbondings(BOB).to_vec(),
+				vec![UnbondingChunk::new(30_000 * ONE, 162)]
+			);
+			assert_hdx_lock!(BOB, 30_000 * ONE, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_not_work_when_all_unbonded_tokens_back_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(BOB), 1, 20_000 * ONE));
+			VotingLocked::set(vec![(BOB, 50_000 * ONE)]);
+			set_block_number(1_620_000);
+
+			//Act & assert
+			assert_noop!(
+				Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::LockedByVotes
+			);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_unlock_tokens_when_votes_are_unlocked() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			VotingLocked::set(vec![(BOB, 30_000 * ONE)]);
+			set_block_number(1_600_000);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), 1));
+			assert_hdx_lock!(BOB, 30_000 * ONE, STAKING_LOCK);
+
+			VotingLocked::set(vec![]);
+
+			//Act
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+
+			//Assert
+			assert_eq!(Staking::get_unbonding_balance(&BOB), 0);
+			assert_hdx_lock!(BOB, 0, STAKING_LOCK);
+			assert_unlocked_balance!(&BOB, HDX, 150_000 * ONE);
+		});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_position_tokens_back_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			VotingLocked::set(vec![(BOB, 10_000 * ONE)]);
+
+			//Act & assert
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(BOB), 1, CHARLIE),
+				Error::<Test>::LockedByVotes
+			);
+		});
+}
+
+#[test]
+fn get_locked_balance_should_include_position_and_unbonding_tokens() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			UnbondingPeriods::set(2);
+			assert_ok!(Staking::unstake_partial(RuntimeOrigin::signed(BOB), 1, 20_000 * ONE));
+
+			//Act & assert
+			assert_eq!(Staking::get_locked_balance(&BOB), Ok(50_000 * ONE));
+			assert_eq!(Staking::get_locked_balance(&CHARLIE), Ok(0));
+		});
+}
+
+#[test]
+fn on_idle_should_migrate_position_votes_when_votes_migration_is_pending() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![
+			(
+				0,
+				vec![
+					(
+						1_u32,
+						Vote {
+							amount: 10_000 * ONE,
+							conviction: Conviction::None,
+						},
+					),
+					(
+						2_u32,
+						Vote {
+							amount: 150_000 * ONE,
+							conviction: Conviction::Locked1x,
+						},
+					),
+				],
+			),
+			(
+				1,
+				vec![(
+					3_u32,
+					Vote {
+						amount: 10_000 * ONE,
+						conviction: Conviction::None,
+					},
+				)],
+			),
+		])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			DemocracyVotes::set(vec![(ALICE, 2)]);
+			VotesMigrationPending::<Test>::put(true);
+
+			//Act
+			Staking::on_idle(1_600_000, Weight::MAX);
+
+			//Assert
+			assert_eq!(
+				PositionVotes::<Test>::get(0).votes.to_vec(),
+				vec![(
+					2_u32,
+					Vote {
+						amount: 100_000 * ONE,
+						conviction: Conviction::Locked1x,
+					}
+				)]
+			);
+			assert!(!PositionVotes::<Test>::contains_key(1));
+			assert!(!Staking::is_votes_migration_pending());
+			assert_eq!(Staking::votes_migration_cursor(), None);
+		});
+}
+
+#[test]
+fn on_idle_should_not_migrate_position_votes_when_votes_migration_is_not_pending() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 250_000 * ONE), (BOB, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 50_000 * ONE, 1_452_987, 0),
+		])
+		.with_votings(vec![(
+			0,
+			vec![(
+				1_u32,
+				Vote {
+					amount: 10_000 * ONE,
+					conviction: Conviction::None,
+				},
+			)],
+		)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Act
+			Staking::on_idle(1_600_000, Weight::MAX);
+
+			//Assert
+			assert_eq!(PositionVotes::<Test>::get(0).votes.len(), 1);
+		});
+}
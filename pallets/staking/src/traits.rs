@@ -1,7 +1,8 @@
-use crate::types::Balance;
+use crate::types::{Action, Balance};
 use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
 use pallet_democracy::ReferendumIndex;
-use sp_runtime::FixedU128;
+use sp_runtime::{traits::Zero, DispatchError, FixedU128};
 
 pub trait PayablePercentage<Point> {
 	/// Returns percentage to pay based of amount of points.
@@ -12,11 +13,78 @@ pub trait DemocracyReferendum {
 	fn is_referendum_finished(index: ReferendumIndex) -> bool;
 }
 
-pub(crate) trait ActionData {
+/// Provides amount of account's balance locked by its democracy votes.
+pub trait VotingLock<AccountId> {
+	/// Returns amount of `who`'s balance which has to stay locked because of its votes and
+	/// delegations.
+	fn locked_balance(who: &AccountId) -> Balance;
+
+	/// Returns `true` if `who` has vote in referendum `ref_index`.
+	fn has_vote(who: &AccountId, ref_index: ReferendumIndex) -> bool;
+
+	/// Recalculates lock of `who`'s votes.
+	fn update_lock(who: &AccountId);
+}
+
+impl<AccountId> VotingLock<AccountId> for () {
+	fn locked_balance(_who: &AccountId) -> Balance {
+		Balance::zero()
+	}
+
+	fn has_vote(_who: &AccountId, _ref_index: ReferendumIndex) -> bool {
+		true
+	}
+
+	fn update_lock(_who: &AccountId) {}
+}
+
+/// Data of the action used to calculate action points.
+pub trait ActionData {
+	/// Amount used by the action.
 	fn amount(&self) -> Balance;
+	/// Conviction multiplier of the action.
 	fn conviction(&self) -> FixedU128;
 }
 
+/// Hook which rewards staking positions with action points for actions performed in other pallets.
+pub trait ActionHook<AccountId> {
+	/// Adds action points for `action` to staking position of `who`. It does nothing if `who` has
+	/// no staking position.
+	fn on_action<D: ActionData>(who: &AccountId, action: Action, data: D) -> DispatchResult;
+
+	/// Returns weight of the `on_action`.
+	fn on_action_weight() -> Weight;
+}
+
+impl<AccountId> ActionHook<AccountId> for () {
+	fn on_action<D: ActionData>(_who: &AccountId, _action: Action, _data: D) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_action_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Swaps non-native staking rewards to the native asset.
+pub trait RewardSwap<AccountId, AssetId> {
+	/// Sells `amount` of `asset` owned by `who` for the native asset.
+	fn swap_to_native(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult;
+
+	/// Returns weight of the `swap_to_native`.
+	fn swap_to_native_weight() -> Weight;
+}
+
+impl<AccountId, AssetId> RewardSwap<AccountId, AssetId> for () {
+	fn swap_to_native(_who: &AccountId, _asset: AssetId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("Reward swap is not supported"))
+	}
+
+	fn swap_to_native_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub trait Freeze<AccountId, CollectionId> {
 	/// Freezes given item so it is not transferable.
 	fn freeze_collection(owner: AccountId, collection: CollectionId) -> DispatchResult;
@@ -11,8 +11,17 @@ pub type Balance = u128;
 pub type Point = u128;
 pub type Period = u128;
 
+/// Actions rewarded with action points.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub enum Action {
+	/// Vote in democracy referendum. Points are added when referendum is finished.
 	DemocracyVote,
+	/// Liquidity provided to the Omnipool.
+	OmnipoolLiquidity,
+	/// Executed DCA schedule's trade.
+	DcaTrade,
+	/// Vote in council or technical committee collective.
+	CollectiveVote,
 }
 
 /// Staking position, represents user's state in staking, e.g. staked amount, slashed points,...
@@ -60,6 +69,21 @@ impl<BlockNumber> Position<BlockNumber> {
 	}
 }
 
+/// Unstaked amount which stays locked until the `unlock_at` period.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct UnbondingChunk {
+	/// Amount of unbonding tokens.
+	pub(crate) amount: Balance,
+	/// Period number from which the tokens can be withdrawn.
+	pub(crate) unlock_at: Period,
+}
+
+impl UnbondingChunk {
+	pub fn new(amount: Balance, unlock_at: Period) -> Self {
+		Self { amount, unlock_at }
+	}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub struct StakingData {
 	/// Total amount of tokens staked in staking.
@@ -77,6 +101,24 @@ impl StakingData {
 	}
 }
 
+/// State of the non-native reward currency.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub struct RewardCurrencyData {
+	/// Accumulated reward per stake of the currency.
+	pub(crate) accumulated_reward_per_stake: FixedU128,
+	/// Balance of the currency allocated/reserved for stakers in the `pot`.
+	pub(crate) pot_reserved_balance: Balance,
+}
+
+/// Position's rewards in the non-native reward currency.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub struct PositionCurrencyRewards {
+	/// Position's reward per stake of the currency.
+	pub(crate) reward_per_stake: FixedU128,
+	/// Rewards allocated to the position which were not paid yet.
+	pub(crate) pending_rewards: Balance,
+}
+
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub enum Conviction {
 	#[default]
@@ -140,6 +182,82 @@ impl<'a> ActionData for &'a Vote {
 	}
 }
 
+/// Liquidity provided to the Omnipool.
+///
+/// `amount` is value of provided liquidity in native asset. Conviction grows with the number of
+/// periods liquidity was provided for, up to the `Conviction::Locked6x`.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LiquidityProvision {
+	pub(crate) amount: Balance,
+	pub(crate) periods: Period,
+}
+
+impl LiquidityProvision {
+	pub fn new(amount: Balance, periods: Period) -> Self {
+		Self { amount, periods }
+	}
+}
+
+impl ActionData for LiquidityProvision {
+	fn amount(&self) -> Balance {
+		self.amount
+	}
+
+	fn conviction(&self) -> FixedU128 {
+		let conviction = match self.periods {
+			0 => Conviction::None,
+			1 => Conviction::Locked1x,
+			2 => Conviction::Locked2x,
+			3 => Conviction::Locked3x,
+			4 => Conviction::Locked4x,
+			5 => Conviction::Locked5x,
+			_ => Conviction::Locked6x,
+		};
+
+		conviction.multiplier()
+	}
+}
+
+/// Trade executed by DCA schedule.
+///
+/// `amount` is value of the trade in native asset. All trades have the same conviction.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct DcaTrade {
+	pub(crate) amount: Balance,
+}
+
+impl DcaTrade {
+	pub fn new(amount: Balance) -> Self {
+		Self { amount }
+	}
+}
+
+impl ActionData for DcaTrade {
+	fn amount(&self) -> Balance {
+		self.amount
+	}
+
+	fn conviction(&self) -> FixedU128 {
+		Conviction::Locked1x.multiplier()
+	}
+}
+
+/// Vote in council or technical committee collective.
+///
+/// Collective members vote with equal weight so every vote is rewarded with max points of the action.
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub struct CollectiveVote;
+
+impl ActionData for CollectiveVote {
+	fn amount(&self) -> Balance {
+		Balance::MAX
+	}
+
+	fn conviction(&self) -> FixedU128 {
+		Conviction::max_multiplier()
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 #[codec(mel_bound(skip_type_params(MaxVotes)))]
 #[scale_info(skip_type_params(MaxVotes))]
@@ -22,20 +22,28 @@
 //! This pallet provides an implementation of `XcmDeferFilter` that tracks incoming tokens and defers iff they exceed
 //! the rate limit configured in `RateLimitFor`.
 //!
+//! It also provides an implementation of `SendXcm` that wraps the configured `XcmRouter` and tracks outgoing tokens.
+//! Outgoing messages transferring tokens over the rate limit are queued and sent once their defer duration elapses.
+//!
 //! ### Integration
 //!
 //! The `RateLimitFor` associated type is supposed to be provided by the `AssetRegistry`, but could work with any other
 //! implementation.
 //!
-//! This pallet does not provide any extrinsics of its own, but it is meant to provide the implementation of
-//! `XcmDeferFilter` for the `XcmpQueue`.
+//! This pallet is meant to provide the implementation of `XcmDeferFilter` for the `XcmpQueue` and to be used as the
+//! `XcmSender` of the XCM executor config, so that outgoing transfers of `orml_xtokens` and reserve transfers of
+//! `pallet_xcm` are rate limited. `RateLimitedWeigher` is supposed to wrap the `Weigher` of the XCM executor config,
+//! `orml_xtokens` and `pallet_xcm`, so that the weight of the transfers includes the rate limiting.
+//!
+//! Its only extrinsics are `execute_deferred` and `discard_deferred` which allow root to send or discard deferred
+//! outgoing XCMs before their defer duration elapses.
 //!
 //! ### Implementation
 //!
 //! The defer duration for an XCM is the maximum of the individual durations for its included assets.
 //!
 //! The duration for deferring an asset is calculated based on:
-//! - the incoming amount
+//! - the incoming (or outgoing) amount
 //! - the rate limit of the asset
 //! - the configured `DeferDuration`
 //! - the amounts of tokens accumulated over time but decayed based on time and rate limit
@@ -47,19 +55,33 @@
 //! The accumulated amounts decay linearly at the rate limit. For example: With rate limit 1000 tokens per 10 blocks,
 //! the accumulated amount will be reduced by 100 tokens per block.
 //!
+//! Incoming and outgoing amounts are accumulated separately but they share the rate limit of the asset.
+//!
 //! The filter works with XCM v3 and so assumes that other versions can be converted to it.
 //!
 //! The filter processes only the first instruction of the XCM message, because that is how assets will arrive on chain.
 //! This is guaranteed by `AllowTopLevelExecution` which is standard in the ecosystem.
 //!
+//! Outgoing messages are inspected the same way. Assets of outgoing messages are reanchored back from the point of
+//! view of the destination to the local point of view in order to determine their local id.
+//!
+//! Deferred outgoing messages are kept in `DeferredMessages` by their id. Number of the deferred messages is bounded by
+//! `MaxDeferredMessages` and transfers are rejected when the limit is reached. Deferred messages are sent in `on_idle`
+//! once their defer duration elapses. Messages which fail to be sent are kept and retried after `DeferDuration`,
+//! because the transferred tokens have already left the sender's account.
+//!
 //! ### Limitations
 //!
 //! - The filter assumes that it is fine to ignore (neither track nor limit) tokens that don't have a defined local id
 //!   or don't have a configured rate limit.
-//! - It counts accumulated amounts via `MultiLocation`s of incoming messages without reanchoring or canonicalizing.
-//! - It only tracks and limits incoming tokens, not outgoing.
-//! - Only tracks and limits `ReserveAssetDeposited` and `ReceiveTeleportedAsset`, meaning that core asset tokens
-//!   "returning" from other chains are not tracked or limited.
+//! - It counts accumulated amounts of incoming tokens via `MultiLocation`s of incoming messages without reanchoring or
+//!   canonicalizing.
+//! - Only tracks and limits incoming `ReserveAssetDeposited` and `ReceiveTeleportedAsset`, meaning that core asset
+//!   tokens "returning" from other chains are not tracked or limited.
+//! - Only tracks and limits outgoing messages starting with `ReserveAssetDeposited`, `ReceiveTeleportedAsset` or
+//!   `WithdrawAsset`, i.e. messages sent by `TransferReserveAsset`, `DepositReserveAsset`, `InitiateTeleport` and
+//!   `InitiateReserveWithdraw`.
+//! - Tokens of deferred outgoing messages discarded by `discard_deferred` are not returned to the sender.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -79,35 +101,60 @@ use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::traits::Convert;
 use sp_runtime::RuntimeDebug;
 use sp_runtime::SaturatedConversion;
+use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 use xcm::lts::prelude::*;
+use xcm::lts::{send_xcm, Error as XcmError, SendError, SendResult, SendXcm, XcmHash};
+use xcm::VersionedMultiLocation;
 use xcm::VersionedXcm;
 use xcm::VersionedXcm::V3;
+use xcm_executor::traits::WeightBounds;
 
 #[cfg(test)]
 mod tests;
 
+pub mod weights;
+
+use weights::WeightInfo;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+const LOG_TARGET: &str = "runtime::xcm-rate-limiter";
+
 #[derive(Clone, Default, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 pub struct AccumulatedAmount {
 	pub amount: u128,
 	pub last_updated: RelayChainBlockNumber,
 }
 
+pub type DeferredMessageId = u64;
+
+/// Outgoing XCM deferred because of exceeding the rate limit of a transferred asset.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, Eq, PartialEq)]
+pub struct DeferredMessage {
+	pub dest: VersionedMultiLocation,
+	pub message: VersionedXcm<()>,
+	pub deferred_until: RelayChainBlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
 
+	use frame_system::pallet_prelude::*;
 	use polkadot_parachain::primitives::RelayChainBlockNumber;
 	use sp_runtime::traits::BlockNumberProvider;
 	use xcm::lts::MultiLocation;
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::send_deferred_messages(remaining_weight)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -141,6 +188,19 @@ pub mod pallet {
 
 		/// Xcm rate limit getter for each asset
 		type RateLimitFor: GetByKey<Self::AssetId, Option<u128>>;
+
+		/// Router used to send outgoing XCMs which are not deferred, or once their defer duration elapsed.
+		type XcmRouter: SendXcm;
+
+		/// Location of this chain, used to reanchor assets of outgoing XCMs back to the local point of view.
+		type UniversalLocation: Get<InteriorMultiLocation>;
+
+		/// The maximum number of deferred outgoing XCMs.
+		#[pallet::constant]
+		type MaxDeferredMessages: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -153,15 +213,117 @@ pub mod pallet {
 	pub type AccumulatedAmounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, MultiLocation, AccumulatedAmount, ValueQuery>;
 
+	#[pallet::storage]
+	/// Accumulated amounts of outgoing tokens for each asset
+	#[pallet::getter(fn outgoing_accumulated_amount)]
+	pub type OutgoingAccumulatedAmounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, AccumulatedAmount, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::unbounded]
+	/// Outgoing XCMs deferred until their defer duration elapses. Number of the messages is bounded by
+	/// `MaxDeferredMessages`.
+	#[pallet::getter(fn deferred_message)]
+	pub type DeferredMessages<T: Config> = StorageMap<_, Twox64Concat, DeferredMessageId, DeferredMessage, OptionQuery>;
+
+	#[pallet::storage]
+	/// Number of deferred outgoing XCMs
+	#[pallet::getter(fn deferred_messages_count)]
+	pub type DeferredMessagesCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Id of the next deferred outgoing XCM
+	#[pallet::getter(fn next_deferred_message_id)]
+	pub type NextDeferredMessageId<T: Config> = StorageValue<_, DeferredMessageId, ValueQuery>;
+
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Outgoing XCM was deferred because it exceeded the rate limit of a transferred asset.
+		OutgoingXcmDeferred {
+			id: DeferredMessageId,
+			dest: MultiLocation,
+			message_hash: XcmHash,
+			deferred_until: RelayChainBlockNumber,
+		},
+		/// Deferred outgoing XCM was sent.
+		DeferredXcmSent {
+			id: DeferredMessageId,
+			dest: MultiLocation,
+			message_hash: XcmHash,
+		},
+		/// Deferred outgoing XCM could not be sent and was kept to be retried later.
+		DeferredXcmSendFailed {
+			id: DeferredMessageId,
+			error: XcmError,
+			retry_at: RelayChainBlockNumber,
+		},
+		/// Deferred outgoing XCM was discarded.
+		DeferredXcmDiscarded { id: DeferredMessageId },
+	}
 
 	#[pallet::error]
 	#[cfg_attr(test, derive(PartialEq, Eq))]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// Deferred outgoing XCM does not exist.
+		DeferredMessageNotFound,
+		/// Deferred outgoing XCM could not be sent.
+		SendFailure,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Send deferred outgoing XCM without waiting for its defer duration to elapse.
+		///
+		/// Can only be called by root. The message is kept if it cannot be sent.
+		///
+		/// Parameters:
+		/// - `origin`: Must be root.
+		/// - `id`: Id of the deferred outgoing XCM.
+		///
+		/// Emits `DeferredXcmSent` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_deferred())]
+		pub fn execute_deferred(origin: OriginFor<T>, id: DeferredMessageId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let deferred = DeferredMessages::<T>::get(id).ok_or(Error::<T>::DeferredMessageNotFound)?;
+			let (dest, message_hash) = Self::send_deferred_message(&deferred).map_err(|_| Error::<T>::SendFailure)?;
+
+			Self::remove_deferred_message(id);
+
+			Self::deposit_event(Event::DeferredXcmSent { id, dest, message_hash });
+
+			Ok(())
+		}
+
+		/// Discard deferred outgoing XCM without sending it, e.g. to stop draining of an exploited asset.
+		///
+		/// Can only be called by root. Tokens transferred by the message are not returned to the sender, they stay
+		/// where the transfer moved them to, i.e. in the sovereign account of the destination, or burnt.
+		///
+		/// Parameters:
+		/// - `origin`: Must be root.
+		/// - `id`: Id of the deferred outgoing XCM.
+		///
+		/// Emits `DeferredXcmDiscarded` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::discard_deferred())]
+		pub fn discard_deferred(origin: OriginFor<T>, id: DeferredMessageId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				DeferredMessages::<T>::contains_key(id),
+				Error::<T>::DeferredMessageNotFound
+			);
+
+			Self::remove_deferred_message(id);
+
+			Self::deposit_event(Event::DeferredXcmDiscarded { id });
+
+			Ok(())
+		}
+	}
 }
 
 fn get_loc_and_amount(m: &MultiAsset) -> Option<(MultiLocation, u128)> {
@@ -174,6 +336,17 @@ fn get_loc_and_amount(m: &MultiAsset) -> Option<(MultiLocation, u128)> {
 	}
 }
 
+fn get_outgoing_locations_and_amounts(instruction: &Instruction<()>) -> Vec<(MultiLocation, u128)> {
+	use Instruction::*;
+	match instruction {
+		// Sent by `TransferReserveAsset`, `DepositReserveAsset`, `InitiateTeleport` and `InitiateReserveWithdraw`.
+		ReserveAssetDeposited(multi_assets) | ReceiveTeleportedAsset(multi_assets) | WithdrawAsset(multi_assets) => {
+			multi_assets.inner().iter().flat_map(get_loc_and_amount).collect()
+		}
+		_ => Vec::new(),
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	fn get_locations_and_amounts(instruction: &Instruction<T::RuntimeCall>) -> Vec<(MultiLocation, u128)> {
 		use Instruction::*;
@@ -185,6 +358,160 @@ impl<T: Config> Pallet<T> {
 			_ => Vec::new(),
 		}
 	}
+
+	/// Calculate the new accumulated amount and the number of blocks to defer by when `amount` is added to
+	/// `accumulated`.
+	fn calculate_accumulation(
+		accumulated: &AccumulatedAmount,
+		limit_per_duration: u128,
+		amount: u128,
+		current_time: RelayChainBlockNumber,
+	) -> (u128, RelayChainBlockNumber) {
+		let defer_duration: u32 = T::DeferDuration::get();
+		let time_difference = current_time.saturating_sub(accumulated.last_updated);
+
+		let new_accumulated_amount = calculate_new_accumulated_amount(
+			defer_duration,
+			limit_per_duration,
+			amount,
+			accumulated.amount,
+			time_difference.saturated_into(),
+		);
+
+		let deferred_by = calculate_deferred_duration(defer_duration, limit_per_duration, new_accumulated_amount);
+
+		(new_accumulated_amount, deferred_by)
+	}
+
+	/// Reanchor `location` of an asset in an XCM sent to `dest` back to the local point of view.
+	fn reanchor_to_local(dest: &MultiLocation, location: MultiLocation) -> Option<MultiLocation> {
+		let universal_location = T::UniversalLocation::get();
+		// how `dest` sees this chain
+		let here_from_dest = universal_location.invert_target(dest).ok()?;
+		// universal location of `dest`
+		let mut dest_context = MultiLocation::from(universal_location);
+		dest_context.append_with(*dest).ok()?;
+		if dest_context.parents != 0 {
+			return None;
+		}
+
+		location.reanchored(&here_from_dest, dest_context.interior).ok()
+	}
+
+	/// Track the outgoing tokens of `message` sent to `dest` and return the number of blocks to defer it by, if any.
+	fn outgoing_deferred_by(dest: &MultiLocation, message: &Xcm<()>) -> Option<RelayChainBlockNumber> {
+		// Same as for incoming XCMs, the assets are transferred by the first instruction.
+		let instruction = message.first()?;
+		let current_time = T::RelayBlockNumberProvider::current_block_number();
+		let mut total_deferred_by: RelayChainBlockNumber = 0;
+		for (location, amount) in get_outgoing_locations_and_amounts(instruction) {
+			// We assume that it's fine to not track assets whose id cannot be determined...
+			let Some(asset_id) = Self::reanchor_to_local(dest, location).and_then(T::CurrencyIdConvert::convert) else {
+				continue
+			};
+			// ... or that don't have a rate limit configured.
+			let Some(limit_per_duration) = T::RateLimitFor::get(&asset_id) else {
+				continue
+			};
+
+			let accumulated = OutgoingAccumulatedAmounts::<T>::get(asset_id);
+			let (new_accumulated_amount, deferred_by) =
+				Self::calculate_accumulation(&accumulated, limit_per_duration, amount, current_time);
+
+			OutgoingAccumulatedAmounts::<T>::insert(
+				asset_id,
+				AccumulatedAmount {
+					amount: new_accumulated_amount,
+					last_updated: current_time,
+				},
+			);
+
+			total_deferred_by = deferred_by.max(total_deferred_by);
+		}
+
+		if total_deferred_by > 0 {
+			Some(total_deferred_by.min(T::MaxDeferDuration::get()))
+		} else {
+			None
+		}
+	}
+
+	/// Send deferred outgoing XCMs whose defer duration elapsed, as long as they fit into `max_weight`.
+	fn send_deferred_messages(max_weight: Weight) -> Weight {
+		// read of `DeferredMessagesCount` and of the relay chain block number
+		let mut weight = T::DbWeight::get().reads(2);
+		let read_weight = T::DbWeight::get().reads(1);
+		let send_weight = T::WeightInfo::send_deferred_message();
+
+		if weight.saturating_add(read_weight).any_gt(max_weight) {
+			return Weight::zero();
+		}
+
+		if DeferredMessagesCount::<T>::get() == 0 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let current_time = T::RelayBlockNumberProvider::current_block_number();
+
+		let mut to_send = Vec::new();
+		for (id, deferred) in DeferredMessages::<T>::iter() {
+			if weight
+				.saturating_add(read_weight)
+				.saturating_add(send_weight)
+				.any_gt(max_weight)
+			{
+				break;
+			}
+			weight.saturating_accrue(read_weight);
+
+			if deferred.deferred_until <= current_time {
+				weight.saturating_accrue(send_weight);
+				to_send.push((id, deferred));
+			}
+		}
+
+		for (id, deferred) in to_send {
+			Self::release_deferred_message(id, deferred, current_time);
+		}
+
+		weight
+	}
+
+	/// Send deferred outgoing XCM `id` and remove it. The message is kept and retried after `DeferDuration` if it
+	/// cannot be sent, because the transferred tokens have already left the sender's account.
+	fn release_deferred_message(
+		id: DeferredMessageId,
+		mut deferred: DeferredMessage,
+		current_time: RelayChainBlockNumber,
+	) {
+		match Self::send_deferred_message(&deferred) {
+			Ok((dest, message_hash)) => {
+				Self::remove_deferred_message(id);
+				Self::deposit_event(Event::DeferredXcmSent { id, dest, message_hash });
+			}
+			Err(error) => {
+				let retry_at = current_time.saturating_add(T::DeferDuration::get());
+				deferred.deferred_until = retry_at;
+				DeferredMessages::<T>::insert(id, deferred);
+
+				Self::deposit_event(Event::DeferredXcmSendFailed { id, error, retry_at });
+			}
+		}
+	}
+
+	fn send_deferred_message(deferred: &DeferredMessage) -> Result<(MultiLocation, XcmHash), XcmError> {
+		let dest = MultiLocation::try_from(deferred.dest.clone()).map_err(|_| XcmError::UnhandledXcmVersion)?;
+		let message = Xcm::<()>::try_from(deferred.message.clone()).map_err(|_| XcmError::UnhandledXcmVersion)?;
+
+		let (message_hash, _) = send_xcm::<T::XcmRouter>(dest, message)?;
+
+		Ok((dest, message_hash))
+	}
+
+	fn remove_deferred_message(id: DeferredMessageId) {
+		DeferredMessages::<T>::remove(id);
+		DeferredMessagesCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+	}
 }
 
 impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
@@ -214,7 +541,6 @@ impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
 				total_weight.saturating_accrue(T::DbWeight::get().reads(2));
 				continue
 			};
-			let defer_duration: u32 = T::DeferDuration::get();
 
 			let current_time = T::RelayBlockNumberProvider::current_block_number();
 			// let's assume one read for `RateLimitFor` as well as a read and write for `AccumulatedAmounts` updates.
@@ -223,17 +549,9 @@ impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
 			} else {
 				T::DbWeight::get().reads_writes(2, 1)
 			};
-			let time_difference = current_time.saturating_sub(accumulated_liquidity.last_updated);
-
-			let new_accumulated_amount = calculate_new_accumulated_amount(
-				defer_duration,
-				limit_per_duration,
-				amount,
-				accumulated_liquidity.amount,
-				time_difference.saturated_into(),
-			);
 
-			let deferred_by = calculate_deferred_duration(defer_duration, limit_per_duration, new_accumulated_amount);
+			let (new_accumulated_amount, deferred_by) =
+				Self::calculate_accumulation(&accumulated_liquidity, limit_per_duration, amount, current_time);
 
 			AccumulatedAmounts::<T>::insert(
 				location,
@@ -254,3 +572,92 @@ impl<T: Config> XcmDeferFilter<T::RuntimeCall> for Pallet<T> {
 		}
 	}
 }
+
+/// Rate limits outgoing XCMs before handing them over to `T::XcmRouter`.
+///
+/// Messages transferring tokens over the rate limit are not passed to the router but queued in `DeferredMessages`
+/// and sent once their defer duration elapses. Delivery fails if `MaxDeferredMessages` messages are already deferred.
+impl<T: Config> SendXcm for Pallet<T> {
+	type Ticket = (MultiLocation, Xcm<()>, <T::XcmRouter as SendXcm>::Ticket);
+
+	fn validate(destination: &mut Option<MultiLocation>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		let (Some(dest), Some(msg)) = (*destination, message.clone()) else {
+			return Err(SendError::MissingArgument)
+		};
+		let (ticket, price) = T::XcmRouter::validate(destination, message)?;
+
+		Ok(((dest, msg, ticket), price))
+	}
+
+	fn deliver((dest, message, ticket): Self::Ticket) -> Result<XcmHash, SendError> {
+		let Some(deferred_by) = Self::outgoing_deferred_by(&dest, &message) else {
+			return T::XcmRouter::deliver(ticket)
+		};
+
+		let deferred_until = T::RelayBlockNumberProvider::current_block_number().saturating_add(deferred_by);
+		let message_hash = message.using_encoded(sp_io::hashing::blake2_256);
+
+		if DeferredMessagesCount::<T>::get() >= T::MaxDeferredMessages::get() {
+			return Err(SendError::Transport("Too many deferred messages"));
+		}
+
+		let id = NextDeferredMessageId::<T>::mutate(|next_id| {
+			let id = *next_id;
+			*next_id = next_id.saturating_add(1);
+			id
+		});
+
+		DeferredMessages::<T>::insert(
+			id,
+			DeferredMessage {
+				dest: dest.into(),
+				message: VersionedXcm::from(message),
+				deferred_until,
+			},
+		);
+		DeferredMessagesCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+		Self::deposit_event(Event::OutgoingXcmDeferred {
+			id,
+			dest,
+			message_hash,
+			deferred_until,
+		});
+
+		Ok(message_hash)
+	}
+}
+
+/// Weighs XCMs by `W` and adds weight of rate limiting for every instruction sending an XCM, so the weight of
+/// the transfers includes the rate limiting of outgoing XCMs by `Pallet::deliver`.
+pub struct RateLimitedWeigher<T, W>(PhantomData<(T, W)>);
+
+impl<T: Config, W: WeightBounds<C>, C> WeightBounds<C> for RateLimitedWeigher<T, W> {
+	fn weight(message: &mut Xcm<C>) -> Result<Weight, ()> {
+		let sending_instructions = message.0.iter().filter(|i| is_sending_instruction(i)).count();
+
+		W::weight(message)
+			.map(|weight| weight.saturating_add(T::WeightInfo::deliver().saturating_mul(sending_instructions as u64)))
+	}
+
+	fn instr_weight(instruction: &Instruction<C>) -> Result<Weight, ()> {
+		W::instr_weight(instruction).map(|weight| {
+			if is_sending_instruction(instruction) {
+				weight.saturating_add(T::WeightInfo::deliver())
+			} else {
+				weight
+			}
+		})
+	}
+}
+
+fn is_sending_instruction<C>(instruction: &Instruction<C>) -> bool {
+	use Instruction::*;
+	matches!(
+		instruction,
+		TransferReserveAsset { .. }
+			| DepositReserveAsset { .. }
+			| InitiateReserveWithdraw { .. }
+			| InitiateTeleport { .. }
+	)
+}
@@ -39,7 +39,9 @@ use sp_runtime::{
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+use codec::Encode;
 use xcm::lts::prelude::*;
+use xcm::lts::{SendError, SendResult, SendXcm, XcmHash};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -154,6 +156,33 @@ impl BlockNumberProvider for TreatSystemAsRelayBlockNumberProvider {
 	}
 }
 
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(NetworkId::Polkadot), Parachain(2034));
+	pub const MaxDeferredMessages: u32 = 2;
+	pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+	pub static SendFails: bool = false;
+}
+
+/// Router which just records the sent messages in `SentXcm`. Sending fails when `SendFails` is set.
+pub struct TestXcmRouter;
+impl SendXcm for TestXcmRouter {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(destination: &mut Option<MultiLocation>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		let ticket = (destination.take().unwrap(), message.take().unwrap());
+		Ok((ticket, MultiAssets::new()))
+	}
+
+	fn deliver((destination, message): Self::Ticket) -> Result<XcmHash, SendError> {
+		if SendFails::get() {
+			return Err(SendError::Transport("Send failed"));
+		}
+		let hash = sp_io::hashing::blake2_256(&message.encode());
+		SentXcm::mutate(|sent| sent.push((destination, message)));
+		Ok(hash)
+	}
+}
+
 impl pallet_xcm_rate_limiter::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -163,6 +192,10 @@ impl pallet_xcm_rate_limiter::Config for Test {
 	type RelayBlockNumberProvider = TreatSystemAsRelayBlockNumberProvider;
 	type RateLimitFor = XcmRateLimitFor;
 	type CurrencyIdConvert = ConvertIdMock;
+	type XcmRouter = TestXcmRouter;
+	type UniversalLocation = UniversalLocation;
+	type MaxDeferredMessages = MaxDeferredMessages;
+	type WeightInfo = ();
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -235,6 +268,7 @@ parameter_types! {
 
 impl pallet_omnipool::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type PositionItemId = u32;
 	type Currency = Tokens;
@@ -258,7 +292,7 @@ impl pallet_omnipool::Config for Test {
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
 }
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate};
 use frame_support::weights::Weight;
 use hydra_dx_math::ema::EmaPrice;
 
@@ -280,6 +314,37 @@ impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
 	}
 }
 
+impl<AccountId: From<u64> + Into<u64> + Copy> InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = u32>>;
+	type ItemsIterator = Box<dyn Iterator<Item = u32>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (u32, u32)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = u32>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		unimplemented!()
+	}
+
+	fn items(_class: &Self::CollectionId) -> Self::ItemsIterator {
+		unimplemented!()
+	}
+
+	fn owned(_who: &AccountId) -> Self::OwnedIterator {
+		unimplemented!()
+	}
+
+	fn owned_in_collection(_class: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let who: u64 = (*who).into();
+		let owned: Vec<u32> = POSITIONS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| **owner == who)
+				.map(|(instance, _)| *instance)
+				.collect()
+		});
+		Box::new(owned.into_iter())
+	}
+}
+
 impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
 	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
 		Ok(())
@@ -389,6 +454,8 @@ impl Default for ExtBuilder {
 		MAX_OUT_RATIO.with(|v| {
 			*v.borrow_mut() = 1u128;
 		});
+		SentXcm::set(vec![]);
+		SendFails::set(false);
 
 		Self {
 			endowed_accounts: vec![
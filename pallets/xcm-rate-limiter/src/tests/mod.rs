@@ -1,2 +1,3 @@
 pub(crate) mod defer_xcm;
 pub(crate) mod mock;
+pub(crate) mod send_xcm;
@@ -0,0 +1,508 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::traits::OnIdle;
+
+pub use pretty_assertions::assert_eq;
+
+use sp_runtime::DispatchError;
+use xcm::lts::{send_xcm, Error as XcmError, SendError, XcmHash};
+
+#[test]
+fn send_should_pass_message_to_router_when_asset_has_no_rate_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(1000), 2000 * ONE);
+
+		//Act
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message)]);
+		assert!(deferred_messages().is_empty());
+		assert_eq!(
+			XcmRateLimiter::outgoing_accumulated_amount(1000),
+			AccumulatedAmount::default()
+		);
+	});
+}
+
+#[test]
+fn send_should_track_outgoing_amount_when_limit_is_not_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 500 * ONE);
+
+		//Act
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message)]);
+		assert!(deferred_messages().is_empty());
+		assert_eq!(
+			XcmRateLimiter::outgoing_accumulated_amount(42),
+			AccumulatedAmount {
+				amount: 500 * ONE,
+				last_updated: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn send_should_defer_message_when_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		let message_hash = hash_of(&message);
+
+		//Act
+		assert_eq!(send(sibling(), message.clone()), Ok(message_hash));
+
+		//Assert
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(
+			deferred_messages(),
+			vec![DeferredMessage {
+				dest: sibling().into(),
+				message: VersionedXcm::from(message),
+				deferred_until: 11,
+			}]
+		);
+		assert_eq!(
+			XcmRateLimiter::outgoing_accumulated_amount(42),
+			AccumulatedAmount {
+				amount: 2000 * ONE,
+				last_updated: 1,
+			}
+		);
+		System::assert_last_event(
+			Event::<Test>::OutgoingXcmDeferred {
+				id: 0,
+				dest: sibling(),
+				message_hash,
+				deferred_until: 11,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn send_should_defer_native_asset_transfer_when_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let native_from_sibling = MultiLocation::new(1, X1(Parachain(2034)));
+		let message = create_reserve_asset_deposited(native_from_sibling, 3000 * ONE);
+
+		//Act
+		assert_ok!(send(sibling(), message));
+
+		//Assert
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(deferred_messages()[0].deferred_until, 21);
+		assert_eq!(XcmRateLimiter::outgoing_accumulated_amount(HDX).amount, 3000 * ONE);
+	});
+}
+
+#[test]
+fn send_should_defer_reserve_withdrawal_when_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let relay = MultiLocation::parent();
+		// asset with id 42 as seen by the relay chain
+		let asset_from_relay = MultiLocation::new(0, X1(GeneralIndex(42)));
+		let multi_assets =
+			MultiAssets::from_sorted_and_deduplicated(vec![(asset_from_relay, 2000 * ONE).into()]).unwrap();
+		let message = Xcm::<()>(vec![Instruction::WithdrawAsset(multi_assets), Instruction::ClearOrigin]);
+
+		//Act
+		assert_ok!(send(relay, message));
+
+		//Assert
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(deferred_messages()[0].deferred_until, 11);
+		assert_eq!(XcmRateLimiter::outgoing_accumulated_amount(42).amount, 2000 * ONE);
+	});
+}
+
+#[test]
+fn send_should_defer_message_when_accumulated_amount_exceeds_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let first_message = create_reserve_asset_deposited(local_asset_from_sibling(42), 500 * ONE);
+		let second_message = create_reserve_asset_deposited(local_asset_from_sibling(42), 1000 * ONE);
+		assert_ok!(send(sibling(), first_message.clone()));
+
+		//Act
+		assert_ok!(send(sibling(), second_message));
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), first_message)]);
+		assert_eq!(deferred_messages()[0].deferred_until, 6);
+		assert_eq!(XcmRateLimiter::outgoing_accumulated_amount(42).amount, 1500 * ONE);
+	});
+}
+
+#[test]
+fn send_should_not_defer_message_when_accumulated_amount_decays() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 1000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+
+		System::set_block_number(11);
+
+		//Act
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message.clone()), (sibling(), message)]);
+		assert!(deferred_messages().is_empty());
+		assert_eq!(
+			XcmRateLimiter::outgoing_accumulated_amount(42),
+			AccumulatedAmount {
+				amount: 1000 * ONE,
+				last_updated: 11,
+			}
+		);
+	});
+}
+
+#[test]
+fn send_should_not_share_accumulated_amount_with_incoming_transfers() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 1000 * ONE);
+
+		//Act
+		assert_ok!(send(sibling(), message));
+
+		//Assert
+		assert_eq!(
+			XcmRateLimiter::accumulated_amount(MultiLocation::new(0, X1(GeneralIndex(42)))),
+			AccumulatedAmount::default()
+		);
+	});
+}
+
+#[test]
+fn send_should_fail_when_deferred_messages_queue_is_full() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Act & assert
+		assert_eq!(
+			send(sibling(), message),
+			Err(SendError::Transport("Too many deferred messages"))
+		);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 2);
+	});
+}
+
+#[test]
+fn on_idle_should_send_deferred_message_when_defer_duration_elapsed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+
+		System::set_block_number(10);
+		XcmRateLimiter::on_idle(10, Weight::MAX);
+		assert!(SentXcm::get().is_empty());
+
+		//Act
+		System::set_block_number(11);
+		XcmRateLimiter::on_idle(11, Weight::MAX);
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message.clone())]);
+		assert!(deferred_messages().is_empty());
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmSent {
+				id: 0,
+				dest: sibling(),
+				message_hash: hash_of(&message),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_keep_deferred_messages_whose_defer_duration_did_not_elapse() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let first_message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		let second_message = create_reserve_asset_deposited(local_asset_from_sibling(42), 1000 * ONE);
+		assert_ok!(send(sibling(), first_message.clone()));
+		assert_ok!(send(sibling(), second_message.clone()));
+
+		//Act
+		System::set_block_number(11);
+		XcmRateLimiter::on_idle(11, Weight::MAX);
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), first_message)]);
+		assert_eq!(
+			deferred_messages(),
+			vec![DeferredMessage {
+				dest: sibling().into(),
+				message: VersionedXcm::from(second_message),
+				deferred_until: 21,
+			}]
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_keep_deferred_message_and_retry_it_when_send_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+		SendFails::set(true);
+
+		//Act
+		System::set_block_number(11);
+		XcmRateLimiter::on_idle(11, Weight::MAX);
+
+		//Assert
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(
+			deferred_messages(),
+			vec![DeferredMessage {
+				dest: sibling().into(),
+				message: VersionedXcm::from(message.clone()),
+				deferred_until: 21,
+			}]
+		);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmSendFailed {
+				id: 0,
+				error: XcmError::Transport("Send failed"),
+				retry_at: 21,
+			}
+			.into(),
+		);
+
+		//Act
+		SendFails::set(false);
+		System::set_block_number(21);
+		XcmRateLimiter::on_idle(21, Weight::MAX);
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message)]);
+		assert!(deferred_messages().is_empty());
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 0);
+	});
+}
+
+#[test]
+fn on_idle_should_not_send_deferred_message_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message));
+		System::set_block_number(11);
+
+		//Act
+		let used_weight = XcmRateLimiter::on_idle(11, Weight::zero());
+
+		//Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+	});
+}
+
+#[test]
+fn execute_deferred_should_send_message_before_defer_duration_elapses() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Act
+		assert_ok!(XcmRateLimiter::execute_deferred(RuntimeOrigin::root(), 0));
+
+		//Assert
+		assert_eq!(SentXcm::get(), vec![(sibling(), message.clone())]);
+		assert_eq!(XcmRateLimiter::deferred_message(0), None);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 0);
+		System::assert_last_event(
+			Event::<Test>::DeferredXcmSent {
+				id: 0,
+				dest: sibling(),
+				message_hash: hash_of(&message),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn execute_deferred_should_keep_message_when_send_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message));
+		SendFails::set(true);
+
+		//Act & assert
+		assert_noop!(
+			XcmRateLimiter::execute_deferred(RuntimeOrigin::root(), 0),
+			Error::<Test>::SendFailure
+		);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+	});
+}
+
+#[test]
+fn execute_deferred_should_fail_when_message_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::execute_deferred(RuntimeOrigin::root(), 0),
+			Error::<Test>::DeferredMessageNotFound
+		);
+	});
+}
+
+#[test]
+fn execute_deferred_should_fail_when_origin_is_not_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message));
+
+		//Act & assert
+		assert_noop!(
+			XcmRateLimiter::execute_deferred(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn discard_deferred_should_remove_message_without_sending_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message.clone()));
+		assert_ok!(send(sibling(), message.clone()));
+
+		//Act
+		assert_ok!(XcmRateLimiter::discard_deferred(RuntimeOrigin::root(), 0));
+
+		//Assert
+		assert_eq!(XcmRateLimiter::deferred_message(0), None);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+		System::assert_last_event(Event::<Test>::DeferredXcmDiscarded { id: 0 }.into());
+
+		System::set_block_number(100);
+		XcmRateLimiter::on_idle(100, Weight::MAX);
+		assert_eq!(SentXcm::get(), vec![(sibling(), message)]);
+	});
+}
+
+#[test]
+fn discard_deferred_should_fail_when_origin_is_not_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = create_reserve_asset_deposited(local_asset_from_sibling(42), 2000 * ONE);
+		assert_ok!(send(sibling(), message));
+
+		//Act & assert
+		assert_noop!(
+			XcmRateLimiter::discard_deferred(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+	});
+}
+
+#[test]
+fn rate_limited_weigher_should_add_deliver_weight_to_instructions_sending_xcm() {
+	//Arrange
+	let mut message = Xcm::<()>(vec![
+		Instruction::ClearOrigin,
+		Instruction::InitiateReserveWithdraw {
+			assets: Wild(All),
+			reserve: sibling(),
+			xcm: Xcm(vec![]),
+		},
+	]);
+
+	//Act & assert
+	assert_eq!(
+		RateLimitedWeigher::<Test, UnitWeigher>::weight(&mut message),
+		Ok(Weight::from_ref_time(2).saturating_add(<() as WeightInfo>::deliver()))
+	);
+	assert_eq!(
+		RateLimitedWeigher::<Test, UnitWeigher>::instr_weight(&Instruction::<()>::ClearOrigin),
+		Ok(Weight::from_ref_time(1))
+	);
+}
+
+/// Weighs every instruction by 1.
+struct UnitWeigher;
+impl WeightBounds<()> for UnitWeigher {
+	fn weight(message: &mut Xcm<()>) -> Result<Weight, ()> {
+		Ok(Weight::from_ref_time(message.0.len() as u64))
+	}
+
+	fn instr_weight(_instruction: &Instruction<()>) -> Result<Weight, ()> {
+		Ok(Weight::from_ref_time(1))
+	}
+}
+
+fn deferred_messages() -> Vec<DeferredMessage> {
+	let mut messages: Vec<_> = DeferredMessages::<Test>::iter().collect();
+	messages.sort_by_key(|(id, _)| *id);
+	messages.into_iter().map(|(_, deferred)| deferred).collect()
+}
+
+fn sibling() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(1000)))
+}
+
+/// Location of a local asset with `GeneralIndex(id)` as seen by the sibling.
+fn local_asset_from_sibling(id: u128) -> MultiLocation {
+	MultiLocation::new(1, X2(Parachain(2034), GeneralIndex(id)))
+}
+
+fn send(dest: MultiLocation, message: Xcm<()>) -> Result<XcmHash, SendError> {
+	send_xcm::<XcmRateLimiter>(dest, message).map(|(hash, _)| hash)
+}
+
+fn hash_of(message: &Xcm<()>) -> XcmHash {
+	sp_io::hashing::blake2_256(&message.encode())
+}
+
+pub fn create_reserve_asset_deposited(loc: MultiLocation, amount: u128) -> Xcm<()> {
+	let multi_assets = MultiAssets::from_sorted_and_deduplicated(vec![(loc, amount).into()]).unwrap();
+	Xcm::<()>(vec![
+		Instruction::ReserveAssetDeposited(multi_assets),
+		Instruction::ClearOrigin,
+	])
+}
@@ -0,0 +1,107 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_xcm_rate_limiter
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-06-16, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --pallet=pallet-xcm-rate-limiter
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --chain=dev
+// --extrinsic=*
+// --steps=5
+// --repeat=20
+// --output
+// xcm_rate_limiter.rs
+// --template
+// .maintain/pallet-weight-template.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_rate_limiter.
+pub trait WeightInfo {
+	fn deliver() -> Weight;
+	fn send_deferred_message() -> Weight;
+	fn execute_deferred() -> Weight;
+	fn discard_deferred() -> Weight;
+}
+
+/// Weights for pallet_xcm_rate_limiter using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	fn deliver() -> Weight {
+		Weight::from_ref_time(30_212_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	fn send_deferred_message() -> Weight {
+		Weight::from_ref_time(44_871_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn execute_deferred() -> Weight {
+		Weight::from_ref_time(41_563_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn discard_deferred() -> Weight {
+		Weight::from_ref_time(19_804_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn deliver() -> Weight {
+		Weight::from_ref_time(30_212_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	fn send_deferred_message() -> Weight {
+		Weight::from_ref_time(44_871_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn execute_deferred() -> Weight {
+		Weight::from_ref_time(41_563_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn discard_deferred() -> Weight {
+		Weight::from_ref_time(19_804_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
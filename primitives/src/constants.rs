@@ -93,6 +93,12 @@ pub mod chain {
 
 	/// The source of the data for the oracle.
 	pub const OMNIPOOL_SOURCE: [u8; 8] = *b"omnipool";
+
+	/// The source of the data for the oracle provided by LBP pools.
+	pub const LBP_SOURCE: [u8; 8] = *b"lbp_pool";
+
+	/// The source of the data for the oracle provided by stableswap pools.
+	pub const STABLESWAP_SOURCE: [u8; 8] = *b"stablesw";
 }
 
 #[cfg(test)]
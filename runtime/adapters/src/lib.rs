@@ -20,9 +20,10 @@
 use codec::FullCodec;
 use cumulus_primitives_core::relay_chain::Hash;
 use frame_support::{
+	ensure,
 	sp_runtime::{
-		traits::{AtLeast32BitUnsigned, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
-		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+		traits::{AtLeast32BitUnsigned, CheckedMul, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 		SaturatedConversion,
 	},
 	traits::{Contains, LockIdentifier, OriginTrait},
@@ -34,17 +35,29 @@ use hydra_dx_math::{
 	support::rational::{round_to_rational, Rounding},
 };
 use hydradx_traits::{
-	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle,
-	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
+	liquidity_mining::PriceAdjustment,
+	pools::{GraduationHandler, SpotPriceProvider},
+	router::PoolType,
+	AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle, OnLiquidityChangedHandler,
+	OnScheduledTradeHandler, OnTradeHandler, OraclePeriod, PriceOracle,
 };
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
 use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use pallet_route_executor::Trade;
+use pallet_stableswap::traits::VolumeProvider;
+use pallet_staking::{
+	traits::ActionHook,
+	types::{Action, DcaTrade, LiquidityProvision},
+};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::latest::prelude::*;
 use primitive_types::U128;
-use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
+use primitives::{
+	constants::chain::{LBP_SOURCE, OMNIPOOL_SOURCE, STABLESWAP_SOURCE},
+	AccountId, AssetId, Balance, BlockNumber, CollectionId,
+};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData};
 use warehouse_liquidity_mining::GlobalFarmData;
@@ -325,13 +338,17 @@ where
 	Runtime: pallet_ema_oracle::Config
 		+ pallet_circuit_breaker::Config
 		+ frame_system::Config<RuntimeOrigin = Origin>
-		+ pallet_staking::Config,
+		+ pallet_staking::Config
+		+ pallet_omnipool::Config<AssetId = AssetId>,
 	<Runtime as frame_system::Config>::AccountId: From<AccountId>,
 	<Runtime as pallet_staking::Config>::AssetId: From<AssetId>,
+	Origin: Clone + Into<Result<frame_system::RawOrigin<<Runtime as frame_system::Config>::AccountId>, Origin>>,
 {
 	type Error = DispatchError;
 
 	fn on_liquidity_changed(origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
+		Self::add_liquidity_action_points(origin.clone(), &asset)?;
+
 		OnActivityHandler::<Runtime>::on_liquidity_changed(
 			OMNIPOOL_SOURCE,
 			asset.asset_id,
@@ -364,7 +381,7 @@ where
 	}
 
 	fn on_trade(
-		_origin: Origin,
+		origin: Origin,
 		asset_in: AssetInfo<AssetId, Balance>,
 		asset_out: AssetInfo<AssetId, Balance>,
 	) -> Result<Weight, Self::Error> {
@@ -394,6 +411,7 @@ where
 		let amount_out = *asset_out.delta_changes.delta_reserve;
 
 		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+			origin,
 			asset_in.asset_id.into(),
 			asset_in.before.reserve.into(),
 			amount_in.into(),
@@ -405,28 +423,55 @@ where
 		Ok(Self::on_trade_weight())
 	}
 
-	fn on_hub_asset_trade(_origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
-		OnActivityHandler::<Runtime>::on_trade(
-			OMNIPOOL_SOURCE,
-			Lrna::get(),
-			asset.asset_id,
-			*asset.delta_changes.delta_hub_reserve,
-			*asset.delta_changes.delta_reserve,
-			asset.after.hub_reserve,
-			asset.after.reserve,
-		)
-		.map_err(|(_, e)| e)?;
-
-		let amount_out = *asset.delta_changes.delta_reserve;
-
-		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
-			Lrna::get().into(),
-			Balance::zero().into(),
-			Balance::zero().into(),
-			asset.asset_id.into(),
-			asset.before.reserve.into(),
-			amount_out.into(),
-		)?;
+	fn on_hub_asset_trade(origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
+		match asset.delta_changes.delta_reserve {
+			// Asset is sold to the pool for hub asset
+			BalanceUpdate::Increase(amount_in) => {
+				OnActivityHandler::<Runtime>::on_trade(
+					OMNIPOOL_SOURCE,
+					asset.asset_id,
+					Lrna::get(),
+					amount_in,
+					*asset.delta_changes.delta_hub_reserve,
+					asset.after.reserve,
+					asset.after.hub_reserve,
+				)
+				.map_err(|(_, e)| e)?;
+
+				pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+					origin,
+					asset.asset_id.into(),
+					asset.before.reserve.into(),
+					amount_in.into(),
+					Lrna::get().into(),
+					Balance::zero().into(),
+					Balance::zero().into(),
+				)?;
+			}
+			// Asset is bought from the pool for hub asset
+			BalanceUpdate::Decrease(amount_out) => {
+				OnActivityHandler::<Runtime>::on_trade(
+					OMNIPOOL_SOURCE,
+					Lrna::get(),
+					asset.asset_id,
+					*asset.delta_changes.delta_hub_reserve,
+					amount_out,
+					asset.after.hub_reserve,
+					asset.after.reserve,
+				)
+				.map_err(|(_, e)| e)?;
+
+				pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+					origin,
+					Lrna::get().into(),
+					Balance::zero().into(),
+					Balance::zero().into(),
+					asset.asset_id.into(),
+					asset.before.reserve.into(),
+					amount_out.into(),
+				)?;
+			}
+		}
 
 		Ok(Self::on_trade_weight())
 	}
@@ -435,14 +480,24 @@ where
 		let w1 = OnActivityHandler::<Runtime>::on_liquidity_changed_weight();
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_add_liquidity_limit()
 			.max(<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_remove_liquidity_limit());
-		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_liquidity_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_liquidity_limit_entry()
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_account_limit_entry(),
+			);
+		// asset states used to calculate value of provided liquidity
+		let w4 = <Runtime as frame_system::Config>::DbWeight::get()
+			.reads(4)
+			.saturating_add(pallet_staking::Pallet::<Runtime>::on_action_weight());
+		w1.saturating_add(w2).saturating_add(w3).saturating_add(w4)
 	}
 
 	fn on_trade_weight() -> Weight {
 		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
-		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
+		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry()
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_account_limit_entry(),
+			);
 		w1.saturating_add(w2).saturating_add(w3)
 	}
 
@@ -451,6 +506,84 @@ where
 	}
 }
 
+impl<Origin, Lrna, Runtime> OmnipoolHookAdapter<Origin, Lrna, Runtime>
+where
+	Runtime: frame_system::Config<RuntimeOrigin = Origin>
+		+ pallet_staking::Config
+		+ pallet_omnipool::Config<AssetId = AssetId>,
+	Origin: Into<Result<frame_system::RawOrigin<<Runtime as frame_system::Config>::AccountId>, Origin>>,
+{
+	/// Rewards staking position of the account adding liquidity to the omnipool with action points.
+	fn add_liquidity_action_points(origin: Origin, asset: &AssetInfo<AssetId, Balance>) -> DispatchResult {
+		let BalanceUpdate::Increase(amount) = asset.delta_changes.delta_reserve else {
+			return Ok(());
+		};
+		let Ok(who) = frame_system::ensure_signed(origin) else {
+			return Ok(());
+		};
+		let Some(value) = omnipool_native_value::<Runtime>(asset.asset_id, amount) else {
+			return Ok(());
+		};
+
+		pallet_staking::Pallet::<Runtime>::on_action(&who, Action::OmnipoolLiquidity, LiquidityProvision::new(value, 0))
+	}
+}
+
+/// Returns value of `amount` of `asset` in native asset using the omnipool's spot prices.
+fn omnipool_native_value<Runtime>(asset: AssetId, amount: Balance) -> Option<Balance>
+where
+	Runtime: pallet_omnipool::Config<AssetId = AssetId>,
+{
+	let native_asset = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+	if asset == native_asset {
+		return Some(amount);
+	}
+
+	// Omnipool state is not consistent while calls of a flash swap are being dispatched.
+	pallet_omnipool::Pallet::<Runtime>::ensure_no_flash_swap().ok()?;
+
+	let asset_state = pallet_omnipool::Pallet::<Runtime>::load_asset_state(asset).ok()?;
+	let native_state = pallet_omnipool::Pallet::<Runtime>::load_asset_state(native_asset).ok()?;
+
+	let hub_amount =
+		FixedU128::checked_from_rational(asset_state.hub_reserve, asset_state.reserve)?.checked_mul_int(amount)?;
+	FixedU128::checked_from_rational(native_state.reserve, native_state.hub_reserve)?.checked_mul_int(hub_amount)
+}
+
+/// Rewards staking position of the DCA schedule's owner with action points for executed trades.
+pub struct DcaTradeActionPoints<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> OnScheduledTradeHandler<AccountId, AssetId, Balance> for DcaTradeActionPoints<Runtime>
+where
+	Runtime: frame_system::Config<AccountId = AccountId>
+		+ pallet_staking::Config
+		+ pallet_omnipool::Config<AssetId = AssetId>,
+{
+	fn on_scheduled_trade(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) {
+		let Some(value) = omnipool_native_value::<Runtime>(asset_in, amount_in)
+			.or_else(|| omnipool_native_value::<Runtime>(asset_out, amount_out))
+		else {
+			return;
+		};
+
+		// Trade is already executed, failing to add action points must not affect it.
+		let _ = pallet_staking::Pallet::<Runtime>::on_action(who, Action::DcaTrade, DcaTrade::new(value));
+	}
+
+	fn on_scheduled_trade_weight() -> Weight {
+		// asset states used to calculate value of the trade
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads(8)
+			.saturating_add(pallet_staking::Pallet::<Runtime>::on_action_weight())
+	}
+}
+
 /// Passes ema oracle price to the omnipool.
 pub struct EmaOraclePriceAdapter<Period, Runtime>(PhantomData<(Period, Runtime)>);
 
@@ -473,6 +606,129 @@ where
 	}
 }
 
+/// Provides recent trade volume of assets in stableswap pools from the ema oracle.
+///
+/// Volume of an asset is the sum of the volumes of its pairs with the other assets of the pool, as reported by
+/// stableswap under `STABLESWAP_SOURCE`. Pools with a common pair of assets share the volume of the pair.
+pub struct StableswapOracleVolume<Period, Runtime>(PhantomData<(Period, Runtime)>);
+
+impl<Period, Runtime> VolumeProvider<AssetId, Balance> for StableswapOracleVolume<Period, Runtime>
+where
+	Period: Get<OraclePeriod>,
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config<AssetId = AssetId>,
+{
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)> {
+		let pool = pallet_stableswap::Pallet::<Runtime>::pools(pool_id)?;
+
+		pool.assets
+			.iter()
+			.filter(|other_asset| **other_asset != asset_id)
+			.filter_map(|other_asset| {
+				pallet_ema_oracle::Pallet::<Runtime>::get_entry(
+					asset_id,
+					*other_asset,
+					Period::get(),
+					STABLESWAP_SOURCE,
+				)
+				.ok()
+			})
+			.map(|entry| (entry.volume.a_in, entry.volume.a_out))
+			.reduce(|(acc_in, acc_out), (amount_in, amount_out)| {
+				(acc_in.saturating_add(amount_in), acc_out.saturating_add(amount_out))
+			})
+	}
+}
+
+/// Lists an asset sold in a liquidity bootstrapping pool in the omnipool.
+///
+/// Price of the listed asset is derived from the given price and the omnipool price of the price asset.
+pub struct OmnipoolGraduation<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> GraduationHandler<AccountId, AssetId, Balance> for OmnipoolGraduation<Runtime>
+where
+	Runtime: pallet_omnipool::Config<AssetId = AssetId> + frame_system::Config<AccountId = AccountId>,
+{
+	fn graduate(
+		from: AccountId,
+		asset: AssetId,
+		amount: Balance,
+		price_asset: AssetId,
+		price: FixedU128,
+		weight_cap: Permill,
+		position_owner: Option<AccountId>,
+	) -> DispatchResult {
+		pallet_omnipool::Pallet::<Runtime>::ensure_no_flash_swap()?;
+
+		let hub_asset = <Runtime as pallet_omnipool::Config>::HubAssetId::get();
+
+		let price_asset_hub_price = if price_asset == hub_asset {
+			FixedU128::from(1)
+		} else {
+			pallet_omnipool::Pallet::<Runtime>::load_asset_state(price_asset)?
+				.price()
+				.ok_or(ArithmeticError::DivisionByZero)?
+		};
+
+		let initial_price = price_asset_hub_price
+			.checked_mul(&price)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let protocol_account = pallet_omnipool::Pallet::<Runtime>::protocol_account();
+
+		<<Runtime as pallet_omnipool::Config>::Currency as orml_traits::MultiCurrency<AccountId>>::transfer(
+			asset,
+			&from,
+			&protocol_account,
+			amount,
+		)?;
+
+		let position_id = pallet_omnipool::Pallet::<Runtime>::next_position_id();
+
+		pallet_omnipool::Pallet::<Runtime>::add_token(
+			frame_system::RawOrigin::Root.into(),
+			asset,
+			initial_price,
+			weight_cap,
+			position_owner.clone().unwrap_or_else(|| from.clone()),
+		)?;
+
+		let asset_state = pallet_omnipool::Pallet::<Runtime>::load_asset_state(asset)?;
+		let hub_reserve_ratio = FixedU128::checked_from_rational(
+			asset_state.hub_reserve,
+			<<Runtime as pallet_omnipool::Config>::Currency as orml_traits::MultiCurrency<AccountId>>::free_balance(
+				hub_asset,
+				&protocol_account,
+			),
+		)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+		ensure!(
+			hub_reserve_ratio <= FixedU128::from(weight_cap),
+			pallet_omnipool::Error::<Runtime>::AssetWeightCapExceeded
+		);
+
+		if position_owner.is_none() {
+			pallet_omnipool::Pallet::<Runtime>::sacrifice_position(
+				frame_system::RawOrigin::Signed(from).into(),
+				position_id,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	fn graduate_weight() -> Weight {
+		let w1 = <<Runtime as pallet_omnipool::Config>::WeightInfo as pallet_omnipool::WeightInfo>::add_token();
+		let w2 =
+			<<Runtime as pallet_omnipool::Config>::WeightInfo as pallet_omnipool::WeightInfo>::sacrifice_position();
+		let w3 = <Runtime as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight();
+		w1.saturating_add(w2).saturating_add(w3)
+	}
+}
+
+/// Provides oracle price of any pair of omnipool assets, the price is derived from the prices of the assets in LRNA.
+///
+/// If any of the assets is not in the omnipool, the price of the pair provided by LBP pools is used.
 pub struct OraclePriceProviderAdapterForOmnipool<AssetId, AggregatedPriceGetter, Lrna>(
 	PhantomData<(AssetId, AggregatedPriceGetter, Lrna)>,
 );
@@ -481,12 +737,29 @@ impl<AssetId, AggregatedPriceGetter, Lrna> PriceOracle<AssetId>
 	for OraclePriceProviderAdapterForOmnipool<AssetId, AggregatedPriceGetter, Lrna>
 where
 	u32: From<AssetId>,
+	AssetId: Copy,
 	AggregatedPriceGetter: AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice, Error = OracleError>,
 	Lrna: Get<AssetId>,
 {
 	type Price = EmaPrice;
 
 	fn price(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Option<EmaPrice> {
+		Self::omnipool_price(asset_a, asset_b, period).or_else(|| {
+			AggregatedPriceGetter::get_price(asset_a, asset_b, period, LBP_SOURCE)
+				.ok()
+				.map(|(price, _)| price)
+		})
+	}
+}
+
+impl<AssetId, AggregatedPriceGetter, Lrna> OraclePriceProviderAdapterForOmnipool<AssetId, AggregatedPriceGetter, Lrna>
+where
+	u32: From<AssetId>,
+	AssetId: Copy,
+	AggregatedPriceGetter: AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice, Error = OracleError>,
+	Lrna: Get<AssetId>,
+{
+	fn omnipool_price(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Option<EmaPrice> {
 		let price_asset_a_lrna = AggregatedPriceGetter::get_price(asset_a, Lrna::get(), period, OMNIPOOL_SOURCE);
 
 		let price_asset_a_lrna = match price_asset_a_lrna {
@@ -513,6 +786,33 @@ where
 	}
 }
 
+/// Provides spot price of a pair of assets from `SpotPrice`.
+///
+/// If `SpotPrice` does not provide the price of the pair, the last block price of the `Oracle` is used,
+/// so assets traded only in LBP pools can be priced too.
+pub struct SpotPriceProviderWithOracleFallback<AssetId, SpotPrice, Oracle>(PhantomData<(AssetId, SpotPrice, Oracle)>);
+
+impl<AssetId, SpotPrice, Oracle> SpotPriceProvider<AssetId>
+	for SpotPriceProviderWithOracleFallback<AssetId, SpotPrice, Oracle>
+where
+	AssetId: Copy,
+	SpotPrice: SpotPriceProvider<AssetId, Price = FixedU128>,
+	Oracle: PriceOracle<AssetId, Price = EmaPrice>,
+{
+	type Price = FixedU128;
+
+	fn pair_exists(asset_a: AssetId, asset_b: AssetId) -> bool {
+		SpotPrice::pair_exists(asset_a, asset_b) || Oracle::price(asset_a, asset_b, OraclePeriod::LastBlock).is_some()
+	}
+
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		SpotPrice::spot_price(asset_a, asset_b).or_else(|| {
+			Oracle::price(asset_a, asset_b, OraclePeriod::LastBlock)
+				.and_then(|price| FixedU128::checked_from_rational(price.n, price.d))
+		})
+	}
+}
+
 pub struct PriceAdjustmentAdapter<Runtime, LMInstance>(PhantomData<(Runtime, LMInstance)>);
 
 impl<Runtime, LMInstance> PriceAdjustment<GlobalFarmData<Runtime, LMInstance>>
@@ -760,6 +1060,55 @@ where
 	}
 }
 
+/// Sells staking rewards for the native asset in the Omnipool through the router.
+/// Min amount out is the last block oracle price amount reduced by `MaxSlippage`.
+pub struct OmnipoolRewardSwap<Runtime, NativeAssetId, Oracle, MaxSlippage>(
+	PhantomData<(Runtime, NativeAssetId, Oracle, MaxSlippage)>,
+);
+
+impl<Runtime, Origin: OriginTrait<AccountId = AccountId>, NativeAssetId, Oracle, MaxSlippage>
+	pallet_staking::traits::RewardSwap<AccountId, AssetId>
+	for OmnipoolRewardSwap<Runtime, NativeAssetId, Oracle, MaxSlippage>
+where
+	Runtime: frame_system::Config<RuntimeOrigin = Origin>
+		+ pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>,
+	NativeAssetId: Get<AssetId>,
+	Oracle: PriceOracle<AssetId, Price = EmaPrice>,
+	MaxSlippage: Get<Permill>,
+{
+	fn swap_to_native(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult {
+		let native_asset = NativeAssetId::get();
+
+		let price = Oracle::price(native_asset, asset, OraclePeriod::LastBlock)
+			.ok_or(DispatchError::Other("Reward swap price is not available"))?;
+		let amount_out = FixedU128::checked_from_rational(price.n, price.d)
+			.and_then(|p| p.checked_mul_int(amount))
+			.ok_or(ArithmeticError::Overflow)?;
+		let min_amount_out = amount_out.saturating_sub(MaxSlippage::get().mul_floor(amount_out));
+
+		pallet_route_executor::Pallet::<Runtime>::sell(
+			Runtime::RuntimeOrigin::signed(who.clone()),
+			asset,
+			native_asset,
+			amount,
+			min_amount_out,
+			sp_std::vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: asset,
+				asset_out: native_asset,
+			}],
+		)
+	}
+
+	fn swap_to_native_weight() -> Weight {
+		// oracle price of the asset pair
+		let w1 = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+		let w2 =
+			<<Runtime as pallet_route_executor::Config>::WeightInfo as pallet_route_executor::weights::WeightInfo>::sell(1);
+		w1.saturating_add(w2)
+	}
+}
+
 pub struct MultiCurrencyLockedBalance<T, NativeAssetId: Get<AssetId>>(PhantomData<(T, NativeAssetId)>);
 
 impl<T: orml_tokens::Config + pallet_balances::Config + frame_system::Config, NativeAssetId: Get<AssetId>>
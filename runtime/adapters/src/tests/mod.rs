@@ -1,4 +1,5 @@
 pub mod mock;
+pub mod spot_price;
 pub mod trader;
 pub mod xcm_exchange;
 pub mod xcm_execute_filter;
@@ -0,0 +1,83 @@
+// This file is part of hydradx-adapters.
+
+// Copyright (C) 2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+
+type AssetId = u32;
+
+const OMNIPOOL_ASSET_A: AssetId = 1;
+const OMNIPOOL_ASSET_B: AssetId = 2;
+const LBP_ASSET: AssetId = 3;
+const UNKNOWN_ASSET: AssetId = 4;
+
+struct DummySpotPrice;
+
+impl SpotPriceProvider<AssetId> for DummySpotPrice {
+	type Price = FixedU128;
+
+	fn pair_exists(asset_a: AssetId, asset_b: AssetId) -> bool {
+		asset_a == OMNIPOOL_ASSET_A && asset_b == OMNIPOOL_ASSET_B
+	}
+
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		if Self::pair_exists(asset_a, asset_b) {
+			Some(FixedU128::from(2))
+		} else {
+			None
+		}
+	}
+}
+
+struct DummyOracle;
+
+impl PriceOracle<AssetId> for DummyOracle {
+	type Price = EmaPrice;
+
+	fn price(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Option<Self::Price> {
+		match (asset_a, asset_b, period) {
+			(OMNIPOOL_ASSET_A, OMNIPOOL_ASSET_B, _) => Some(EmaPrice::new(5, 1)),
+			(LBP_ASSET, OMNIPOOL_ASSET_A, OraclePeriod::LastBlock) => Some(EmaPrice::new(3, 2)),
+			_ => None,
+		}
+	}
+}
+
+type Provider = SpotPriceProviderWithOracleFallback<AssetId, DummySpotPrice, DummyOracle>;
+
+#[test]
+fn spot_price_should_be_provided_by_spot_price_provider_when_pair_exists() {
+	assert!(Provider::pair_exists(OMNIPOOL_ASSET_A, OMNIPOOL_ASSET_B));
+	assert_eq!(
+		Provider::spot_price(OMNIPOOL_ASSET_A, OMNIPOOL_ASSET_B),
+		Some(FixedU128::from(2))
+	);
+}
+
+#[test]
+fn spot_price_should_fall_back_to_last_block_oracle_price_when_pair_does_not_exist() {
+	assert!(Provider::pair_exists(LBP_ASSET, OMNIPOOL_ASSET_A));
+	assert_eq!(
+		Provider::spot_price(LBP_ASSET, OMNIPOOL_ASSET_A),
+		Some(FixedU128::from_rational(3, 2))
+	);
+}
+
+#[test]
+fn spot_price_should_be_none_when_no_price_is_available() {
+	assert!(!Provider::pair_exists(UNKNOWN_ASSET, OMNIPOOL_ASSET_A));
+	assert_eq!(Provider::spot_price(UNKNOWN_ASSET, OMNIPOOL_ASSET_A), None);
+}
@@ -19,8 +19,9 @@ use super::*;
 use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
-	inspect::MultiInspectAdapter, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, OraclePriceProviderAdapterForOmnipool, PriceAdjustmentAdapter, VestingInfo,
+	inspect::MultiInspectAdapter, DcaTradeActionPoints, EmaOraclePriceAdapter, FreezableNFT,
+	MultiCurrencyLockedBalance, OmnipoolGraduation, OmnipoolHookAdapter, OmnipoolRewardSwap, OracleAssetVolumeProvider,
+	OraclePriceProviderAdapterForOmnipool, PriceAdjustmentAdapter, SpotPriceProviderWithOracleFallback, VestingInfo,
 };
 use hydradx_adapters::{RelayChainBlockHashProvider, RelayChainBlockNumberProvider};
 use hydradx_traits::{AssetKind, AssetPairAccountIdFor, OraclePeriod, Source};
@@ -217,6 +218,7 @@ parameter_types! {
 
 impl pallet_omnipool::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type AssetId = AssetId;
 	type Currency = Currencies;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
@@ -356,6 +358,7 @@ parameter_types! {
 	pub const OmnipoolLMCollectionId: CollectionId = 2584_u128;
 	pub const OmnipoolLMOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 	pub const OmnipoolLMOracleSource: Source = OMNIPOOL_SOURCE;
+	pub const OmnipoolLMMaxFarmEntriesPerDeposit: u32 = MaxEntriesPerDeposit::get() as u32;
 }
 
 impl pallet_omnipool_liquidity_mining::Config for Runtime {
@@ -369,6 +372,7 @@ impl pallet_omnipool_liquidity_mining::Config for Runtime {
 	type OracleSource = OmnipoolLMOracleSource;
 	type OraclePeriod = OmnipoolLMOraclePeriod;
 	type PriceOracle = EmaOracle;
+	type MaxFarmEntriesPerDeposit = OmnipoolLMMaxFarmEntriesPerDeposit;
 	type WeightInfo = weights::omnipool_lm::HydraWeight<Runtime>;
 }
 
@@ -409,6 +413,13 @@ parameter_types! {
 	pub MaxNumberOfRetriesOnError: u8 = 3;
 }
 
+/// Spot price of omnipool assets, assets traded only in LBP pools are priced by the oracle.
+pub type OmnipoolSpotPriceProvider = SpotPriceProviderWithOracleFallback<
+	AssetId,
+	Omnipool,
+	OraclePriceProviderAdapterForOmnipool<AssetId, EmaOracle, LRNA>,
+>;
+
 impl pallet_dca::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TechnicalOrigin = SuperMajorityTechCommittee;
@@ -416,7 +427,7 @@ impl pallet_dca::Config for Runtime {
 	type RelayChainBlockHashProvider = RelayChainBlockHashProviderAdapter<Runtime>;
 	type RandomnessProvider = DCA;
 	type OraclePriceProvider = OraclePriceProviderAdapterForOmnipool<AssetId, EmaOracle, LRNA>;
-	type SpotPriceProvider = Omnipool;
+	type SpotPriceProvider = OmnipoolSpotPriceProvider;
 	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
 	type MaxSchedulePerBlock = MaxSchedulesPerBlock;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
@@ -426,6 +437,7 @@ impl pallet_dca::Config for Runtime {
 	type FeeReceiver = TreasuryAccount;
 	type NamedReserveId = NamedReserveId;
 	type WeightToFee = WeightToFee;
+	type OnScheduledTrade = DcaTradeActionPoints<Runtime>;
 	type WeightInfo = weights::dca::HydraWeight<Runtime>;
 }
 
@@ -443,6 +455,22 @@ impl pallet_route_executor::Config for Runtime {
 	type WeightInfo = weights::route_executor::HydraWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const OmnipoolLMVaultPalletId: PalletId = PalletId(*b"OmniVlt#");
+	pub const MaxCompoundingSlippage: Permill = Permill::from_percent(3);
+}
+
+impl pallet_omnipool_lm_vault::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type AssetRegistry = AssetRegistry;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type PalletId = OmnipoolLMVaultPalletId;
+	type OraclePriceProvider = OraclePriceProviderAdapterForOmnipool<AssetId, EmaOracle, LRNA>;
+	type MaxCompoundingSlippage = MaxCompoundingSlippage;
+	type WeightInfo = weights::omnipool_lm_vault::HydraWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
 }
@@ -455,6 +483,7 @@ impl pallet_otc::Config for Runtime {
 	type ExistentialDeposits = AssetRegistry;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
 	type WeightInfo = weights::otc::HydraWeight<Runtime>;
+	type PoolLimitHandler = CircuitBreaker;
 }
 
 // Dynamic fees
@@ -484,6 +513,8 @@ impl pallet_dynamic_fees::Config for Runtime {
 	type Oracle = OracleAssetVolumeProvider<Runtime, LRNA, DynamicFeesOraclePeriod>;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::dynamic_fees::HydraWeight<Runtime>;
 }
 
 // Bonds
@@ -525,6 +556,10 @@ parameter_types! {
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 1;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(2,100);
+	pub const UnbondingPeriods: u128 = 7;
+	pub const MaxUnbondingChunks: u32 = 10;
+	pub const MaxRewardCurrencies: u32 = 5;
+	pub const MaxRewardSwapSlippage: Permill = Permill::from_percent(3);
 }
 
 pub struct PointsPerAction;
@@ -533,6 +568,9 @@ impl GetByKey<Action, u32> for PointsPerAction {
 	fn get(k: &Action) -> u32 {
 		match k {
 			Action::DemocracyVote => 100_u32,
+			Action::OmnipoolLiquidity => 100_u32,
+			Action::DcaTrade => 20_u32,
+			Action::CollectiveVote => 50_u32,
 		}
 	}
 }
@@ -545,6 +583,7 @@ impl pallet_staking::Config for Runtime {
 	type PeriodLength = PeriodLength;
 	type PalletId = StakingPalletId;
 	type NativeAssetId = NativeAssetId;
+	type HubAssetId = LRNA;
 	type MinStake = MinStake;
 	type TimePointsWeight = TimePointsW;
 	type ActionPointsWeight = ActionPointsW;
@@ -559,7 +598,17 @@ impl pallet_staking::Config for Runtime {
 	type Collections = FreezableNFT<Runtime, Self::RuntimeOrigin>;
 	type NFTHandler = Uniques;
 	type MaxVotes = MaxVotes;
+	type UnbondingPeriods = UnbondingPeriods;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type MaxRewardCurrencies = MaxRewardCurrencies;
+	type RewardSwap = OmnipoolRewardSwap<
+		Runtime,
+		NativeAssetId,
+		OraclePriceProviderAdapterForOmnipool<AssetId, EmaOracle, LRNA>,
+		MaxRewardSwapSlippage,
+	>;
 	type ReferendumInfo = pallet_staking::integrations::democracy::ReferendumStatus<Runtime>;
+	type VotingLock = pallet_staking::integrations::democracy::DemocracyVotingLock<Runtime>;
 	type MaxPointsPerAction = PointsPerAction;
 	type Vesting = VestingInfo<Runtime>;
 	type WeightInfo = weights::staking::HydraWeight<Runtime>;
@@ -587,6 +636,8 @@ where
 
 parameter_types! {
 	pub LBPExchangeFee: (u32, u32) = (2, 1_000);
+	pub const LBPMaxAllowlistSize: u32 = 500;
+	pub const LBPPurchasesRemovalLimit: u32 = 250;
 }
 
 impl pallet_lbp::Config for Runtime {
@@ -602,4 +653,11 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type PoolLimitHandler = CircuitBreaker;
+	type CollectionId = CollectionId;
+	type NFTHandler = Uniques;
+	type GraduationHandler = OmnipoolGraduation<Runtime>;
+	type OnTradeHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type MaxAllowlistSize = LBPMaxAllowlistSize;
+	type PurchasesRemovalLimit = LBPPurchasesRemovalLimit;
 }
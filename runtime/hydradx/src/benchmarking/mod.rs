@@ -4,9 +4,11 @@ pub mod currencies;
 pub mod duster;
 pub mod multi_payment;
 pub mod omnipool;
+pub mod omnipool_lm_vault;
 pub mod route_executor;
 pub mod tokens;
 pub mod vesting;
+pub mod xcm_rate_limiter;
 
 use crate::AssetRegistry;
 use frame_system::RawOrigin;
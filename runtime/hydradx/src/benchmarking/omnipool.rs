@@ -15,7 +15,7 @@ use frame_system::RawOrigin;
 use hydradx_traits::Registry;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use pallet_omnipool::types::Tradability;
+use pallet_omnipool::types::{FeeBounds, ILProtectionParams, Tradability};
 
 pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
 	assert_ok!(
@@ -433,6 +433,513 @@ runtime_benchmarks! {
 		assert!(asset_state.cap == 100_000_000_000_000_000u128);
 	}
 
+	remove_token {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		let owner_position_id = Omnipool::next_position_id();
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner.clone())?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		// Make part of the liquidity protocol owned
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), owner_position_id)?;
+
+		Omnipool::set_asset_tradable_state(RawOrigin::Root.into(), token_id, Tradability::FROZEN)?;
+
+		let beneficiary: AccountId = account("beneficiary", 2, 1);
+
+	}: { Omnipool::remove_token(RawOrigin::Root.into(), token_id, beneficiary.clone())? }
+	verify {
+		assert!(Omnipool::assets(token_id).is_none());
+		assert!(Omnipool::delisted_assets(token_id).is_some());
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &beneficiary) > Balance::zero());
+	}
+
+	claim_delisted_position {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		let owner_position_id = Omnipool::next_position_id();
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner.clone())?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		// Make part of the liquidity protocol owned
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), owner_position_id)?;
+
+		// to ensure worst case - price of the token increases, so LP receives some LRNA which is minted
+		let buyer: AccountId = account("buyer", 3, 1);
+		update_balance(stable_id, &buyer, 500_000_000_000_000_u128);
+		Omnipool::buy(RawOrigin::Signed(buyer).into(), token_id, stable_id, 30_000_000_000_000_u128, 100_000_000_000_000_u128)?;
+
+		Omnipool::set_asset_tradable_state(RawOrigin::Root.into(), token_id, Tradability::FROZEN)?;
+
+		let beneficiary: AccountId = account("beneficiary", 2, 1);
+
+		Omnipool::remove_token(RawOrigin::Root.into(), token_id, beneficiary)?;
+
+		let hub_id = <Runtime as pallet_omnipool::Config>::HubAssetId::get();
+
+	}: { Omnipool::claim_delisted_position(RawOrigin::Signed(lp_provider.clone()).into(), current_position_id)? }
+	verify {
+		assert!(Omnipool::positions(current_position_id).is_none());
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(hub_id, &lp_provider) > Balance::zero());
+		assert!(Omnipool::delisted_assets(token_id).is_none());
+	}
+
+	merge_positions {
+		let n in 2 .. pallet_omnipool::MAX_MERGED_POSITIONS;
+
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 100_000_000_000_u128;
+
+		run_to_block(10);
+
+		// to ensure worst case - positions are created at different prices, so merged price is calculated
+		let buyer: AccountId = account("buyer", 3, 1);
+		update_balance(stable_id, &buyer, 500_000_000_000_000_u128);
+
+		let mut position_ids = Vec::new();
+		for _ in 0..n {
+			position_ids.push(Omnipool::next_position_id());
+			Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+			Omnipool::buy(RawOrigin::Signed(buyer.clone()).into(), token_id, stable_id, 1_000_000_000_000_u128, 100_000_000_000_000_u128)?;
+		}
+
+		let first_position_id = position_ids[0];
+		let last_position_id = position_ids[position_ids.len() - 1];
+
+	}: { Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), token_id, position_ids)? }
+	verify {
+		assert_eq!(Omnipool::positions(first_position_id).unwrap().amount, liquidity_added * n as u128);
+		assert!(Omnipool::positions(last_position_id).is_none());
+	}
+
+	split_position {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let position = Omnipool::positions(current_position_id).unwrap();
+		let shares = position.shares / 2;
+
+		let new_position_id = Omnipool::next_position_id();
+
+	}: { Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), current_position_id, shares)? }
+	verify {
+		assert_eq!(Omnipool::positions(new_position_id).unwrap().shares, shares);
+		assert_eq!(Omnipool::positions(current_position_id).unwrap().shares, position.shares - shares);
+	}
+
+	set_il_protection {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		let params = ILProtectionParams {
+			fee_share: Permill::from_percent(10),
+			min_holding_period: 100_u32,
+			vesting_period: 1_000_u32,
+		};
+	}: { Omnipool::set_il_protection(RawOrigin::Root.into(), stable_id, Some(params.clone()))? }
+	verify {
+		assert_eq!(Omnipool::il_protection(stable_id), Some(params));
+	}
+
+	flash_swap {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price,Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), 1_u128)?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner)?;
+
+		//NOTE: price barrier requires oracle prices of both assets.
+		run_to_block(10);
+
+		let seller: AccountId = account("seller", 3, 1);
+		update_balance(token_id, &seller, 500_000_000_000_000_u128);
+
+		let amount_buy = 1_000_000_000_000_u128;
+
+	}: { Omnipool::flash_swap(RawOrigin::Signed(seller.clone()).into(), stable_id, amount_buy, token_id, vec![])? }
+	verify {
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(stable_id, &seller) >= amount_buy);
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &seller) < 500_000_000_000_000_u128);
+	}
+
+	withdraw_protocol_liquidity {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price,Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), 1_u128)?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner.clone())?;
+
+		// Initial liquidity of the token becomes protocol owned liquidity
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), position_id)?;
+
+		//NOTE: price barrier requires oracle prices of both assets.
+		run_to_block(10);
+
+		let shares = Omnipool::assets(token_id).unwrap().protocol_shares / 10;
+		let beneficiary: AccountId = account("beneficiary", 2, 1);
+	}: { Omnipool::withdraw_protocol_liquidity(RawOrigin::Root.into(), token_id, shares, beneficiary.clone())? }
+	verify {
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &beneficiary) > 0u128);
+	}
+
+	redeploy_protocol_liquidity {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price,Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), 1_u128)?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner.clone())?;
+
+		// Initial liquidity of the token becomes protocol owned liquidity
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), position_id)?;
+
+		//NOTE: price barrier requires oracle prices of both assets.
+		run_to_block(10);
+
+		let shares = Omnipool::assets(token_id).unwrap().protocol_shares / 10;
+		let stable_shares = Omnipool::assets(stable_id).unwrap().protocol_shares;
+	}: { Omnipool::redeploy_protocol_liquidity(RawOrigin::Root.into(), token_id, shares, stable_id, 0u128)? }
+	verify {
+		assert!(Omnipool::assets(stable_id).unwrap().protocol_shares > stable_shares);
+	}
+
+	convert_protocol_liquidity_to_position {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price,Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), 1_u128)?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner.clone())?;
+
+		// Initial liquidity of the token becomes protocol owned liquidity
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), position_id)?;
+
+		//NOTE: price barrier requires oracle prices of both assets.
+		run_to_block(10);
+
+		let shares = Omnipool::assets(token_id).unwrap().protocol_shares / 10;
+		let new_owner: AccountId = account("new_owner", 2, 1);
+		let new_position_id = Omnipool::next_position_id();
+	}: { Omnipool::convert_protocol_liquidity_to_position(RawOrigin::Root.into(), token_id, shares, new_owner)? }
+	verify {
+		assert_eq!(Omnipool::positions(new_position_id).unwrap().shares, shares);
+	}
+
+	set_asset_fee_override {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		let fees = (Permill::from_rational(5u32, 10_000u32), Permill::zero());
+	}: { Omnipool::set_asset_fee_override(RawOrigin::Root.into(), stable_id, Some(fees))? }
+	verify {
+		assert_eq!(Omnipool::asset_fee_override(stable_id), Some(fees));
+	}
+
+	set_asset_fee_bounds {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		let bounds = FeeBounds {
+			min_asset_fee: Permill::from_rational(25u32, 10_000u32),
+			max_asset_fee: Permill::from_percent(5),
+			min_protocol_fee: Permill::zero(),
+			max_protocol_fee: Permill::from_percent(1),
+		};
+	}: { Omnipool::set_asset_fee_bounds(RawOrigin::Root.into(), stable_id, Some(bounds.clone()))? }
+	verify {
+		assert_eq!(Omnipool::asset_fee_bounds(stable_id), Some(bounds));
+	}
+
 }
 
 #[cfg(test)]
@@ -0,0 +1,272 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{
+	AccountId, AssetId, Balance, Currencies, EmaOracle, Omnipool, OmnipoolLMVault, OmnipoolLiquidityMining,
+	OmnipoolWarehouseLM, Runtime, System,
+};
+
+use super::route_executor::regi_asset;
+use super::*;
+
+use frame_benchmarking::account;
+use frame_support::{
+	assert_ok,
+	traits::{OnFinalize, OnInitialize},
+};
+use frame_system::RawOrigin;
+use hydradx_traits::router::PoolType;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use pallet_route_executor::Trade;
+use primitives::constants::currency::UNITS;
+use sp_runtime::{
+	traits::{One, Zero},
+	DispatchError, DispatchResult, FixedU128, Permill, Perquintill,
+};
+
+const TVL_CAP: Balance = 222_222_000_000_000_000_000_000;
+
+const HDX: AssetId = 0;
+const LRNA: AssetId = 1;
+const DAI: AssetId = 2;
+
+const ONE: Balance = 1_000_000_000_000;
+const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * ONE;
+
+const GLOBAL_FARM_ID: u32 = 1;
+const YIELD_FARM_ID: u32 = 2;
+
+const SEED: u32 = 1;
+
+fn fund(to: &AccountId, currency: AssetId, amount: Balance) {
+	assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		currency,
+		to,
+		amount as i128
+	));
+}
+
+fn create_funded_account(name: &'static str, index: u32, amount: Balance, currency: AssetId) -> AccountId {
+	let who: AccountId = account(name, index, SEED);
+
+	fund(&who, currency, amount);
+
+	who
+}
+
+fn run_to_block(to: u32) {
+	while System::block_number() < to {
+		let b = System::block_number();
+
+		System::on_finalize(b);
+		EmaOracle::on_finalize(b);
+
+		System::on_initialize(b + 1_u32);
+		EmaOracle::on_initialize(b + 1_u32);
+
+		System::set_block_number(b + 1_u32);
+	}
+}
+
+//NOTE: trades are necessary for oracle to provide prices.
+fn do_lrna_trade(asset_out: AssetId) -> DispatchResult {
+	let trader = create_funded_account("tmp_trader", 0, 100 * ONE, LRNA);
+
+	Omnipool::sell(RawOrigin::Signed(trader).into(), LRNA, asset_out, ONE, 0)
+}
+
+/// Initialize omnipool with new asset, create global farm with yield farm for the asset and
+/// vault for the yield farm.
+///
+/// Returns: `(vault's asset, vault's share asset)`
+fn initialize_vault() -> Result<(AssetId, AssetId), DispatchError> {
+	let acc = Omnipool::protocol_account();
+
+	Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+	let _ = regi_asset(b"HDX".to_vec(), UNITS, HDX);
+	let _ = regi_asset(b"LRNA".to_vec(), UNITS, LRNA);
+	let _ = regi_asset(b"DAI".to_vec(), UNITS, DAI);
+
+	fund(&acc, DAI, 1_000_000_000_000_000_u128);
+	fund(&acc, HDX, 1_000_000_000_000_000_u128);
+
+	Omnipool::initialize_pool(
+		RawOrigin::Root.into(),
+		FixedU128::from((1, 2)),
+		FixedU128::one(),
+		Permill::from_percent(100),
+		Permill::from_percent(100),
+	)?;
+
+	let asset = register_asset(b"VLT_ASSET".to_vec(), 1_u128).map_err(|_| DispatchError::Other("register"))?;
+	let share_asset = register_asset(b"VLT_SHARES".to_vec(), 1_u128).map_err(|_| DispatchError::Other("register"))?;
+
+	let token_owner: AccountId = account("token_owner", 0, SEED);
+	fund(&acc, asset, 200_000_000_000_000_u128);
+
+	Omnipool::add_token(
+		RawOrigin::Root.into(),
+		asset,
+		FixedU128::from((1, 5)),
+		Permill::from_percent(100),
+		token_owner,
+	)?;
+
+	do_lrna_trade(HDX)?;
+	do_lrna_trade(asset)?;
+
+	run_to_block(10);
+
+	do_lrna_trade(HDX)?;
+	do_lrna_trade(asset)?;
+
+	let farm_owner = create_funded_account("farm_owner", 0, G_FARM_TOTAL_REWARDS, HDX);
+
+	OmnipoolLiquidityMining::create_global_farm(
+		RawOrigin::Root.into(),
+		G_FARM_TOTAL_REWARDS,
+		100_000_u32,
+		1_u32,
+		HDX,
+		farm_owner.clone(),
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)?;
+
+	OmnipoolLiquidityMining::create_yield_farm(
+		RawOrigin::Signed(farm_owner).into(),
+		GLOBAL_FARM_ID,
+		asset,
+		FixedU128::one(),
+		None,
+	)?;
+
+	Ok((asset, share_asset))
+}
+
+fn seed_lm_pot() {
+	let pot = OmnipoolWarehouseLM::pot_account_id().unwrap();
+
+	fund(&pot, HDX, 100 * ONE);
+}
+
+runtime_benchmarks! {
+	{ Runtime, pallet_omnipool_lm_vault }
+
+	create_vault {
+		let (asset, share_asset) = initialize_vault()?;
+	}: _(RawOrigin::Root, share_asset, asset, GLOBAL_FARM_ID, YIELD_FARM_ID)
+	verify {
+		assert!(OmnipoolLMVault::vaults(share_asset).is_some());
+	}
+
+	set_compounding_route {
+		let (asset, share_asset) = initialize_vault()?;
+		OmnipoolLMVault::create_vault(RawOrigin::Root.into(), share_asset, asset, GLOBAL_FARM_ID, YIELD_FARM_ID)?;
+
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: asset,
+			},
+		];
+	}: _(RawOrigin::Root, share_asset, route.try_into().unwrap())
+	verify {
+		assert!(OmnipoolLMVault::compounding_routes(share_asset).is_some());
+	}
+
+	deposit {
+		let (asset, share_asset) = initialize_vault()?;
+		OmnipoolLMVault::create_vault(RawOrigin::Root.into(), share_asset, asset, GLOBAL_FARM_ID, YIELD_FARM_ID)?;
+		seed_lm_pot();
+
+		let lp1 = create_funded_account("lp_1", 1, 10_000 * ONE, asset);
+		let lp2 = create_funded_account("lp_2", 2, 10_000 * ONE, asset);
+
+		//NOTE: second deposit to the vault is more expensive than first one.
+		OmnipoolLMVault::deposit(RawOrigin::Signed(lp1).into(), share_asset, 1_000 * ONE)?;
+
+		run_to_block(100);
+	}: _(RawOrigin::Signed(lp2.clone()), share_asset, 1_000 * ONE)
+	verify {
+		assert!(!<Currencies as MultiCurrency<_>>::free_balance(share_asset, &lp2).is_zero());
+	}
+
+	withdraw {
+		let (asset, share_asset) = initialize_vault()?;
+		OmnipoolLMVault::create_vault(RawOrigin::Root.into(), share_asset, asset, GLOBAL_FARM_ID, YIELD_FARM_ID)?;
+		seed_lm_pot();
+
+		let lp1 = create_funded_account("lp_1", 1, 10_000 * ONE, asset);
+		let lp2 = create_funded_account("lp_2", 2, 10_000 * ONE, asset);
+
+		OmnipoolLMVault::deposit(RawOrigin::Signed(lp1).into(), share_asset, 1_000 * ONE)?;
+		OmnipoolLMVault::deposit(RawOrigin::Signed(lp2.clone()).into(), share_asset, 1_000 * ONE)?;
+
+		run_to_block(100);
+
+		//NOTE: partial withdraw claims rewards and splits vault's position.
+		let shares = <Currencies as MultiCurrency<_>>::free_balance(share_asset, &lp2);
+	}: _(RawOrigin::Signed(lp2.clone()), share_asset, shares)
+	verify {
+		assert!(<Currencies as MultiCurrency<_>>::free_balance(share_asset, &lp2).is_zero());
+	}
+
+	compound {
+		let (asset, share_asset) = initialize_vault()?;
+		OmnipoolLMVault::create_vault(RawOrigin::Root.into(), share_asset, asset, GLOBAL_FARM_ID, YIELD_FARM_ID)?;
+		seed_lm_pot();
+
+		let lp1 = create_funded_account("lp_1", 1, 10_000 * ONE, asset);
+		let caller = create_funded_account("caller", 2, ONE, HDX);
+
+		OmnipoolLMVault::deposit(RawOrigin::Signed(lp1).into(), share_asset, 1_000 * ONE)?;
+
+		run_to_block(100);
+
+		let lp_shares = OmnipoolLMVault::vaults(share_asset).unwrap().lp_shares;
+	}: _(RawOrigin::Signed(caller), share_asset)
+	verify {
+		assert!(OmnipoolLMVault::vaults(share_asset).unwrap().lp_shares > lp_shares);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<crate::Runtime>()
+			.unwrap()
+			.into();
+		t
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
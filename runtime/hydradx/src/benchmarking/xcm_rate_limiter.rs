@@ -0,0 +1,111 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetLocation, Runtime, XcmRateLimiter};
+
+use super::*;
+
+use frame_benchmarking::BenchmarkError;
+use frame_support::traits::OnIdle;
+use frame_support::weights::Weight;
+use orml_benchmarking::runtime_benchmarks;
+use pallet_xcm_rate_limiter::DeferredMessageId;
+use polkadot_xcm::v3::{prelude::*, send_xcm};
+
+/// Registers rate limited asset and returns its reserve withdrawal to the relay chain exceeding the rate limit.
+fn rate_limited_transfer() -> Result<(MultiLocation, Xcm<()>), BenchmarkError> {
+	let asset_id = AssetRegistry::register_asset(
+		AssetRegistry::to_bounded_name(b"TST".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to create name"))?,
+		pallet_asset_registry::AssetType::<AssetId>::Token,
+		1,
+		None,
+		Some(1),
+	)
+	.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+	let location = MultiLocation::new(1, X2(Parachain(3000), GeneralIndex(0)));
+	AssetRegistry::set_location(RawOrigin::Root.into(), asset_id, AssetLocation(location))
+		.map_err(|_| BenchmarkError::Stop("Failed to set location"))?;
+
+	// the asset as seen by the relay chain
+	let asset = MultiLocation::new(0, X2(Parachain(3000), GeneralIndex(0)));
+	let message = Xcm(vec![WithdrawAsset((asset, 1_000 * BSX).into()), ClearOrigin]);
+
+	Ok((MultiLocation::parent(), message))
+}
+
+fn defer_transfer() -> Result<DeferredMessageId, BenchmarkError> {
+	let (dest, message) = rate_limited_transfer()?;
+	let id = XcmRateLimiter::next_deferred_message_id();
+
+	send_xcm::<XcmRateLimiter>(dest, message).map_err(|_| BenchmarkError::Stop("Failed to defer transfer"))?;
+	assert!(XcmRateLimiter::deferred_message(id).is_some());
+
+	Ok(id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, pallet_xcm_rate_limiter }
+
+	deliver {
+		let (dest, message) = rate_limited_transfer()?;
+	}: { send_xcm::<XcmRateLimiter>(dest, message).map_err(|_| BenchmarkError::Stop("Failed to defer transfer"))? }
+	verify {
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 1);
+	}
+
+	send_deferred_message {
+		let id = defer_transfer()?;
+		pallet_xcm_rate_limiter::DeferredMessages::<Runtime>::mutate(id, |maybe_deferred| {
+			if let Some(deferred) = maybe_deferred {
+				deferred.deferred_until = 0;
+			}
+		});
+	}: { XcmRateLimiter::on_idle(1, Weight::MAX) }
+	verify {
+		assert_eq!(XcmRateLimiter::deferred_messages_count(), 0);
+	}
+
+	execute_deferred {
+		let id = defer_transfer()?;
+	}: { XcmRateLimiter::execute_deferred(RawOrigin::Root.into(), id)? }
+	verify {
+		assert_eq!(XcmRateLimiter::deferred_message(id), None);
+	}
+
+	discard_deferred {
+		let id = defer_transfer()?;
+	}: { XcmRateLimiter::discard_deferred(RawOrigin::Root.into(), id)? }
+	verify {
+		assert_eq!(XcmRateLimiter::deferred_message(id), None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::default()
+			.build_storage::<crate::Runtime>()
+			.unwrap()
+			.into()
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
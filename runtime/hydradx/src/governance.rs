@@ -22,12 +22,23 @@ use primitives::constants::{
 };
 
 use frame_support::{
+	dispatch::DispatchClass,
 	parameter_types,
 	sp_runtime::{Perbill, Percent, Permill},
 	traits::{ConstU32, EitherOfDiverse, LockIdentifier, NeverEnsureOrigin, PrivilegeCmp, U128CurrencyToVote},
 	PalletId,
 };
 use frame_system::EnsureRoot;
+use pallet_staking::{
+	traits::ActionHook,
+	types::{Action, CollectiveVote},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::TransactionValidityError,
+	DispatchResult,
+};
 use sp_std::cmp::Ordering;
 
 parameter_types! {
@@ -150,6 +161,62 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type WeightInfo = weights::technical_comittee::HydraWeight<Runtime>;
 }
 
+/// Signed extension which rewards staking positions of council and technical committee members
+/// with action points for their votes in the collectives.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo, Default)]
+pub struct CollectiveVoteActionPoints;
+
+impl sp_std::fmt::Debug for CollectiveVoteActionPoints {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CollectiveVoteActionPoints")
+	}
+}
+
+impl SignedExtension for CollectiveVoteActionPoints {
+	const IDENTIFIER: &'static str = "CollectiveVoteActionPoints";
+	type AccountId = AccountId;
+	type Call = RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = Option<AccountId>;
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let is_collective_vote = matches!(
+			call,
+			RuntimeCall::Council(pallet_collective::Call::vote { .. })
+				| RuntimeCall::TechnicalCommittee(pallet_collective::Call::vote { .. })
+		);
+
+		Ok(is_collective_vote.then(|| who.clone()))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(Some(who)), Ok(())) = (pre, result) {
+			System::register_extra_weight_unchecked(Staking::on_action_weight(), DispatchClass::Normal);
+
+			// Vote is already dispatched, failing to add action points must not affect it.
+			let _ = Staking::on_action(&who, Action::CollectiveVote, CollectiveVote);
+		}
+
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{EnactmentPeriod, VoteLockingPeriod};
@@ -94,10 +94,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 176,
+	spec_version: 200,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -160,6 +160,7 @@ construct_runtime!(
 		Staking: pallet_staking = 69,
 		Bonds: pallet_bonds = 71,
 		LBP: pallet_lbp = 73,
+		OmnipoolLMVault: pallet_omnipool_lm_vault = 74,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -182,6 +183,7 @@ construct_runtime!(
 		CumulusXcm: cumulus_pallet_xcm = 109,
 		XcmpQueue: cumulus_pallet_xcmp_queue exclude_parts { Call } = 111,
 		DmpQueue: cumulus_pallet_dmp_queue = 113,
+		XcmRateLimiter: pallet_xcm_rate_limiter = 115,
 
 		// ORML XCM
 		OrmlXcm: orml_xcm = 135,
@@ -222,6 +224,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
+	CollectiveVoteActionPoints,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -371,6 +374,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_omnipool::runtime_api::OmnipoolApi<Block, AccountId, AssetId, ItemId> for Runtime {
+		fn position_value(
+			position_id: ItemId,
+		) -> Option<pallet_omnipool::types::PositionValue<AssetId, Balance>> {
+			Omnipool::position_value(position_id).ok()
+		}
+
+		fn account_positions_value(
+			who: AccountId,
+		) -> Vec<(ItemId, pallet_omnipool::types::PositionValue<AssetId, Balance>)> {
+			Omnipool::account_positions_value(&who)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -401,6 +418,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_omnipool_liquidity_mining, OmnipoolLiquidityMining);
 			list_benchmark!(list, extra, pallet_circuit_breaker, CircuitBreaker);
 			list_benchmark!(list, extra, pallet_dca, DCA);
+			list_benchmark!(list, extra, pallet_dynamic_fees, DynamicFees);
 			list_benchmark!(list, extra, pallet_bonds, Bonds);
 
 			list_benchmark!(list, extra, pallet_asset_registry, AssetRegistry);
@@ -422,6 +440,8 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_duster, benchmarking::duster);
 			orml_list_benchmark!(list, extra, pallet_omnipool, benchmarking::omnipool);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
+			orml_list_benchmark!(list, extra, pallet_omnipool_lm_vault, benchmarking::omnipool_lm_vault);
+			orml_list_benchmark!(list, extra, pallet_xcm_rate_limiter, benchmarking::xcm_rate_limiter);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -470,6 +490,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_omnipool_liquidity_mining, OmnipoolLiquidityMining);
 			add_benchmark!(params, batches, pallet_circuit_breaker, CircuitBreaker);
 			add_benchmark!(params, batches, pallet_dca, DCA);
+			add_benchmark!(params, batches, pallet_dynamic_fees, DynamicFees);
 			add_benchmark!(params, batches, pallet_asset_registry, AssetRegistry);
 			add_benchmark!(params, batches, pallet_claims, Claims);
 			add_benchmark!(params, batches, pallet_ema_oracle, EmaOracle);
@@ -490,6 +511,8 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_duster, benchmarking::duster);
 			orml_add_benchmark!(params, batches, pallet_omnipool, benchmarking::omnipool);
 orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
+			orml_add_benchmark!(params, batches, pallet_omnipool_lm_vault, benchmarking::omnipool_lm_vault);
+			orml_add_benchmark!(params, batches, pallet_xcm_rate_limiter, benchmarking::xcm_rate_limiter);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
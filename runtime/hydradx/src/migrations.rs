@@ -6,15 +6,26 @@ pub struct OnRuntimeUpgradeMigration;
 impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	#[cfg(feature = "try-runtime")]
 	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
-		Ok(vec![])
+		let lbp_state = pallet_lbp::migration::pre_migrate::<Runtime, LBP>()?;
+		let staking_state = pallet_staking::migration::pre_migrate::<Runtime, Staking>()?;
+		let otc_state = pallet_otc::migration::pre_migrate::<Runtime, OTC>()?;
+
+		Ok((lbp_state, staking_state, otc_state).encode())
 	}
 
 	fn on_runtime_upgrade() -> Weight {
-		Weight::zero()
+		pallet_lbp::migration::migrate_to_v1::<Runtime, LBP>()
+			.saturating_add(pallet_staking::migration::migrate_to_v2::<Runtime, Staking>())
+			.saturating_add(pallet_otc::migration::migrate_to_v1::<Runtime, OTC>())
 	}
 
 	#[cfg(feature = "try-runtime")]
-	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
-		Ok(())
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let (lbp_state, staking_state, otc_state): (Vec<u8>, Vec<u8>, Vec<u8>) =
+			Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode migration state")?;
+
+		pallet_lbp::migration::post_migrate::<Runtime, LBP>(lbp_state)?;
+		pallet_staking::migration::post_migrate::<Runtime, Staking>(staking_state)?;
+		pallet_otc::migration::post_migrate::<Runtime, OTC>(otc_state)
 	}
 }
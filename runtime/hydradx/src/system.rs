@@ -466,7 +466,7 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AcceptedCurrencyOrigin = SuperMajorityTechCommittee;
 	type Currencies = Currencies;
-	type SpotPriceProvider = Omnipool;
+	type SpotPriceProvider = OmnipoolSpotPriceProvider;
 	type WeightInfo = weights::payment::HydraWeight<Runtime>;
 	type WeightToFee = WeightToFee;
 	type NativeAssetId = NativeAssetId;
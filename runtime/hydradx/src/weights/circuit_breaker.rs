@@ -71,6 +71,10 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 8_409 nanoseconds.
 		Weight::from_ref_time(8_627_000 as u64)
 	}
+	fn on_finalize_single_account_limit_entry() -> Weight {
+		// Minimum execution time: 8_713 nanoseconds.
+		Weight::from_ref_time(8_985_000 as u64)
+	}
 	fn on_finalize_empty() -> Weight {
 		// Minimum execution time: 8_492 nanoseconds.
 		Weight::from_ref_time(8_759_000 as u64)
@@ -93,6 +97,10 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 12_822 nanoseconds.
 		Weight::from_ref_time(13_140_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: CircuitBreaker AccountLimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker AccountLimitTiers (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker LimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker LimitTiers (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker LiquidityAddLimitPerAsset (r:1 w:0)
 	// Proof: CircuitBreaker LiquidityAddLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedAddLiquidityAmountPerAsset (r:1 w:1)
@@ -102,9 +110,9 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:1)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn ensure_add_liquidity_limit() -> Weight {
-		// Minimum execution time: 19_815 nanoseconds.
-		Weight::from_ref_time(20_251_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
+		// Minimum execution time: 23_042 nanoseconds.
+		Weight::from_ref_time(23_517_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: CircuitBreaker LiquidityAddLimitPerAsset (r:1 w:0)
@@ -115,20 +123,54 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:1)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AccountLimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker AccountLimitTiers (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker LimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker LimitTiers (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAccount (r:1 w:1)
+	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAccount (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	fn ensure_remove_liquidity_limit() -> Weight {
-		// Minimum execution time: 18_011 nanoseconds.
-		Weight::from_ref_time(18_202_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Minimum execution time: 26_203 nanoseconds.
+		Weight::from_ref_time(26_684_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAsset (r:2 w:2)
 	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAsset (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker TradeVolumeLimitPerAsset (r:2 w:0)
 	// Proof: CircuitBreaker TradeVolumeLimitPerAsset (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AccountLimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker AccountLimitTiers (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker LimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker LimitTiers (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAccount (r:2 w:2)
+	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAccount (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn ensure_pool_state_change_limit() -> Weight {
-		// Minimum execution time: 18_080 nanoseconds.
-		Weight::from_ref_time(18_365_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Minimum execution time: 29_514 nanoseconds.
+		Weight::from_ref_time(30_067_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: CircuitBreaker LimitTiers (r:0 w:1)
+	// Proof: CircuitBreaker LimitTiers (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	fn set_limit_tier() -> Weight {
+		// Minimum execution time: 13_104 nanoseconds.
+		Weight::from_ref_time(13_422_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: CircuitBreaker LimitTiers (r:1 w:0)
+	// Proof: CircuitBreaker LimitTiers (max_values: None, max_size: Some(39), added: 2514, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AccountLimitTiers (r:0 w:1)
+	// Proof: CircuitBreaker AccountLimitTiers (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn set_account_limit_tier() -> Weight {
+		// Minimum execution time: 16_378 nanoseconds.
+		Weight::from_ref_time(16_713_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: CircuitBreaker PoolLimitsPerAsset (r:0 w:1)
+	// Proof: CircuitBreaker PoolLimitsPerAsset (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	fn set_pool_limits() -> Weight {
+		// Minimum execution time: 14_012 nanoseconds.
+		Weight::from_ref_time(14_385_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 }
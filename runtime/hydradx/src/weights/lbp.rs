@@ -64,10 +64,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AcceptedCurrencies (r:1 w:0)
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: LBP Purchases (r:1 w:0)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
 	fn create_pool() -> Weight {
 		// Minimum execution time: 69_643 nanoseconds.
 		Weight::from_ref_time(70_487_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: LBP PoolData (r:1 w:1)
@@ -106,13 +108,35 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	// Storage: Tokens Locks (r:1 w:0)
 	// Proof: Tokens Locks (max_values: None, max_size: Some(1261), added: 3736, mode: MaxEncodedLen)
+	// Storage: LBP ApprovedGraduations (r:1 w:1)
+	// Proof Skipped: LBP ApprovedGraduations (max_values: None, max_size: None, mode: Measured)
 	// Storage: LBP FeeCollectorWithAsset (r:0 w:1)
 	// Proof Skipped: LBP FeeCollectorWithAsset (max_values: None, max_size: None, mode: Measured)
-	fn remove_liquidity() -> Weight {
+	// Storage: LBP PoolGraduation (r:0 w:1)
+	// Proof Skipped: LBP PoolGraduation (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolLaunchParameters (r:0 w:1)
+	// Proof Skipped: LBP PoolLaunchParameters (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP BlockPurchases (r:0 w:1)
+	// Proof Skipped: LBP BlockPurchases (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP AllowlistSize (r:0 w:1)
+	// Proof Skipped: LBP AllowlistSize (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:0 w:500)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Purchases (r:0 w:250)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `a` is `[0, 500]`.
+	/// The range of component `p` is `[0, 250]`.
+	fn remove_liquidity(a: u32, p: u32) -> Weight {
 		// Minimum execution time: 68_270 nanoseconds.
 		Weight::from_ref_time(69_701_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(10 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			// Standard Error: 1_148
+			.saturating_add(Weight::from_ref_time(874_516 as u64).saturating_mul(a as u64))
+			// Standard Error: 2_296
+			.saturating_add(Weight::from_ref_time(889_302 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	// Storage: Tokens Accounts (r:5 w:5)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
@@ -124,11 +148,19 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
 	// Storage: System Account (r:3 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: LBP PoolLaunchParameters (r:1 w:0)
+	// Proof Skipped: LBP PoolLaunchParameters (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:1 w:0)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Purchases (r:1 w:1)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP BlockPurchases (r:1 w:1)
+	// Proof Skipped: LBP BlockPurchases (max_values: None, max_size: None, mode: Measured)
 	fn sell() -> Weight {
 		// Minimum execution time: 91_282 nanoseconds.
 		Weight::from_ref_time(94_560_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(12 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: LBP PoolData (r:1 w:0)
 	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
@@ -140,10 +172,137 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
 	// Storage: System Account (r:3 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: LBP PoolLaunchParameters (r:1 w:0)
+	// Proof Skipped: LBP PoolLaunchParameters (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:1 w:0)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Purchases (r:1 w:1)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP BlockPurchases (r:1 w:1)
+	// Proof Skipped: LBP BlockPurchases (max_values: None, max_size: None, mode: Measured)
 	fn buy() -> Weight {
 		// Minimum execution time: 91_220 nanoseconds.
 		Weight::from_ref_time(92_250_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(12 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolLaunchParameters (r:0 w:1)
+	// Proof Skipped: LBP PoolLaunchParameters (max_values: None, max_size: None, mode: Measured)
+	fn set_launch_parameters() -> Weight {
+		// Minimum execution time: 14_012 nanoseconds.
+		Weight::from_ref_time(14_385_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP AllowlistSize (r:1 w:1)
+	// Proof Skipped: LBP AllowlistSize (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:500 w:500)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 500]`.
+	fn add_to_allowlist(n: u32) -> Weight {
+		// Minimum execution time: 14_603 nanoseconds.
+		Weight::from_ref_time(13_118_290 as u64)
+			// Standard Error: 1_404
+			.saturating_add(Weight::from_ref_time(1_396_744 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP AllowlistSize (r:1 w:1)
+	// Proof Skipped: LBP AllowlistSize (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:500 w:500)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 500]`.
+	fn remove_from_allowlist(n: u32) -> Weight {
+		// Minimum execution time: 14_487 nanoseconds.
+		Weight::from_ref_time(13_020_516 as u64)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_ref_time(1_379_165 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolGraduation (r:0 w:1)
+	// Proof Skipped: LBP PoolGraduation (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP ApprovedGraduations (r:0 w:1)
+	// Proof Skipped: LBP ApprovedGraduations (max_values: None, max_size: None, mode: Measured)
+	fn set_graduation() -> Weight {
+		// Minimum execution time: 15_204 nanoseconds.
+		Weight::from_ref_time(15_638_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolGraduation (r:1 w:0)
+	// Proof Skipped: LBP PoolGraduation (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP ApprovedGraduations (r:0 w:1)
+	// Proof Skipped: LBP ApprovedGraduations (max_values: None, max_size: None, mode: Measured)
+	fn approve_graduation() -> Weight {
+		// Minimum execution time: 12_473 nanoseconds.
+		Weight::from_ref_time(12_851_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: LBP PoolData (r:1 w:1)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolGraduation (r:1 w:1)
+	// Proof Skipped: LBP PoolGraduation (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP ApprovedGraduations (r:1 w:1)
+	// Proof Skipped: LBP ApprovedGraduations (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Locks (r:1 w:0)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1261), added: 3736, mode: MaxEncodedLen)
+	// Storage: LBP FeeCollectorWithAsset (r:0 w:1)
+	// Proof Skipped: LBP FeeCollectorWithAsset (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP PoolLaunchParameters (r:0 w:1)
+	// Proof Skipped: LBP PoolLaunchParameters (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP BlockPurchases (r:0 w:1)
+	// Proof Skipped: LBP BlockPurchases (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP AllowlistSize (r:0 w:1)
+	// Proof Skipped: LBP AllowlistSize (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Allowlist (r:0 w:500)
+	// Proof Skipped: LBP Allowlist (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Purchases (r:0 w:250)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `a` is `[0, 500]`.
+	/// The range of component `p` is `[0, 250]`.
+	fn graduate(a: u32, p: u32) -> Weight {
+		// Minimum execution time: 71_846 nanoseconds.
+		Weight::from_ref_time(73_120_000 as u64)
+			// Standard Error: 1_152
+			.saturating_add(Weight::from_ref_time(876_220 as u64).saturating_mul(a as u64))
+			// Standard Error: 2_304
+			.saturating_add(Weight::from_ref_time(890_845 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: LBP PoolData (r:1 w:0)
+	// Proof Skipped: LBP PoolData (max_values: None, max_size: None, mode: Measured)
+	// Storage: LBP Purchases (r:0 w:250)
+	// Proof Skipped: LBP Purchases (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `p` is `[1, 250]`.
+	fn clear_purchases(p: u32) -> Weight {
+		// Minimum execution time: 11_304 nanoseconds.
+		Weight::from_ref_time(10_412_000 as u64)
+			// Standard Error: 2_296
+			.saturating_add(Weight::from_ref_time(889_302 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 }
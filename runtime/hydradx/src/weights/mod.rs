@@ -8,11 +8,13 @@ pub mod currencies;
 pub mod dca;
 pub mod democracy;
 pub mod duster;
+pub mod dynamic_fees;
 pub mod ema_oracle;
 pub mod identity;
 pub mod lbp;
 pub mod omnipool;
 pub mod omnipool_lm;
+pub mod omnipool_lm_vault;
 pub mod otc;
 pub mod payment;
 pub mod preimage;
@@ -30,4 +32,5 @@ pub mod treasury;
 pub mod utility;
 pub mod vesting;
 pub mod xcm;
+pub mod xcm_rate_limiter;
 pub mod xcmp_queue;
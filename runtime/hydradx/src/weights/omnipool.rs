@@ -106,11 +106,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	// Storage: Omnipool Positions (r:0 w:1)
 	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:0 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn add_token() -> Weight {
 		// Minimum execution time: 143_602 nanoseconds.
 		Weight::from_ref_time(145_117_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
-			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: Tokens Accounts (r:4 w:3)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
@@ -150,11 +152,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	// Storage: Omnipool Positions (r:0 w:1)
 	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:0 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn add_liquidity() -> Weight {
 		// Minimum execution time: 225_812 nanoseconds.
 		Weight::from_ref_time(227_537_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(23 as u64))
-			.saturating_add(T::DbWeight::get().writes(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
 	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
@@ -194,11 +198,15 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Omnipool ILProtection (r:1 w:0)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:1 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn remove_liquidity() -> Weight {
 		// Minimum execution time: 282_512 nanoseconds.
 		Weight::from_ref_time(284_772_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(23 as u64))
-			.saturating_add(T::DbWeight::get().writes(16 as u64))
+			.saturating_add(T::DbWeight::get().reads(25 as u64))
+			.saturating_add(T::DbWeight::get().writes(17 as u64))
 	}
 	// Storage: Tokens Accounts (r:4 w:4)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
@@ -226,10 +234,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:0)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Omnipool ILProtection (r:1 w:0)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	fn sell() -> Weight {
 		// Minimum execution time: 231_087 nanoseconds.
 		Weight::from_ref_time(232_886_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
 			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: Omnipool Assets (r:3 w:3)
@@ -258,10 +268,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:0)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Omnipool ILProtection (r:1 w:0)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	fn buy() -> Weight {
 		// Minimum execution time: 232_699 nanoseconds.
 		Weight::from_ref_time(235_189_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
 			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: Omnipool Assets (r:1 w:1)
@@ -302,11 +314,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:0 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn sacrifice_position() -> Weight {
 		// Minimum execution time: 74_980 nanoseconds.
 		Weight::from_ref_time(75_595_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Omnipool Assets (r:1 w:1)
 	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
@@ -316,4 +330,250 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Omnipool DelistedAssets (r:0 w:1)
+	// Proof: Omnipool DelistedAssets (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn remove_token() -> Weight {
+		// Minimum execution time: 89_816 nanoseconds.
+		Weight::from_ref_time(91_355_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool DelistedAssets (r:1 w:1)
+	// Proof: Omnipool DelistedAssets (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:0)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:0 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn claim_delisted_position() -> Weight {
+		// Minimum execution time: 86_907 nanoseconds.
+		Weight::from_ref_time(88_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// Storage: Uniques Asset (r:20 w:19)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:20 w:20)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:19)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:19)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:20 w:20)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 20]`.
+	fn merge_positions(n: u32) -> Weight {
+		// Minimum execution time: 89_102 nanoseconds.
+		Weight::from_ref_time(21_604_000 as u64) // Standard Error: 12_135
+			.saturating_add(Weight::from_ref_time(33_867_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:1 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn split_position() -> Weight {
+		// Minimum execution time: 60_415 nanoseconds.
+		Weight::from_ref_time(61_278_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool ILProtection (r:0 w:1)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_il_protection() -> Weight {
+		// Minimum execution time: 21_905 nanoseconds.
+		Weight::from_ref_time(22_418_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool Assets (r:3 w:3)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:2 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: MultiTransactionPayment AccountCurrencyMap (r:1 w:1)
+	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: MultiTransactionPayment AcceptedCurrencies (r:1 w:0)
+	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: EmaOracle Accumulator (r:1 w:1)
+	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(2961), added: 3456, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAsset (r:2 w:2)
+	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAsset (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker LiquidityAddLimitPerAsset (r:1 w:0)
+	// Proof: CircuitBreaker LiquidityAddLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedAddLiquidityAmountPerAsset (r:1 w:1)
+	// Proof: CircuitBreaker AllowedAddLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker LiquidityRemoveLimitPerAsset (r:1 w:0)
+	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:0)
+	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: CircuitBreakerWhitelist (r:1 w:0)
+	// Proof Skipped: CircuitBreakerWhitelist (max_values: None, max_size: None, mode: Measured)
+	// Storage: EmaOracle Oracles (r:4 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: Omnipool FlashSwapInProgress (r:1 w:1)
+	// Proof: Omnipool FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Omnipool ILProtection (r:1 w:0)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn flash_swap() -> Weight {
+		// Minimum execution time: 271_340 nanoseconds.
+		Weight::from_ref_time(273_512_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(28 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
+	}
+	// Storage: Omnipool FlashSwapInProgress (r:1 w:0)
+	// Proof: Omnipool FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CircuitBreakerWhitelist (r:1 w:0)
+	// Proof Skipped: CircuitBreakerWhitelist (max_values: None, max_size: None, mode: Measured)
+	fn withdraw_protocol_liquidity() -> Weight {
+		// Minimum execution time: 116_982 nanoseconds.
+		Weight::from_ref_time(118_627_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Omnipool FlashSwapInProgress (r:1 w:0)
+	// Proof: Omnipool FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:3 w:3)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:8 w:8)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:6 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:2 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: DynamicFees AssetFee (r:2 w:0)
+	// Proof: DynamicFees AssetFee (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Omnipool TvlCap (r:1 w:0)
+	// Proof: Omnipool TvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAsset (r:2 w:2)
+	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAsset (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: CircuitBreakerWhitelist (r:3 w:0)
+	// Proof Skipped: CircuitBreakerWhitelist (max_values: None, max_size: None, mode: Measured)
+	// Storage: Omnipool ILProtection (r:1 w:0)
+	// Proof: Omnipool ILProtection (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn redeploy_protocol_liquidity() -> Weight {
+		// Minimum execution time: 388_410 nanoseconds.
+		Weight::from_ref_time(392_175_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(34 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:0)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:0 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool PositionCreatedAt (r:0 w:1)
+	// Proof: Omnipool PositionCreatedAt (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn convert_protocol_liquidity_to_position() -> Weight {
+		// Minimum execution time: 82_705 nanoseconds.
+		Weight::from_ref_time(83_941_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool AssetFeeOverrides (r:0 w:1)
+	// Proof: Omnipool AssetFeeOverrides (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_asset_fee_override() -> Weight {
+		// Minimum execution time: 21_102 nanoseconds.
+		Weight::from_ref_time(21_573_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool AssetFeeBounds (r:0 w:1)
+	// Proof: Omnipool AssetFeeBounds (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_asset_fee_bounds() -> Weight {
+		// Minimum execution time: 21_398 nanoseconds.
+		Weight::from_ref_time(21_846_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
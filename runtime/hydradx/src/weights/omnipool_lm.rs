@@ -288,4 +288,76 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetTradability (r:1 w:0)
+	// Proof: Omnipool HubAssetTradability (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:2 w:2)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:2 w:2)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM Deposit (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM Deposit (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM YieldFarm (r:5 w:5)
+	// Proof: OmnipoolWarehouseLM YieldFarm (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM GlobalFarm (r:5 w:5)
+	// Proof: OmnipoolWarehouseLM GlobalFarm (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:4 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: OmnipoolLiquidityMining OmniPositionId (r:1 w:1)
+	// Proof: OmnipoolLiquidityMining OmniPositionId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 5]`.
+	fn add_liquidity_and_join_farms(c: u32) -> Weight {
+		// Minimum execution time: 418_306 nanoseconds.
+		Weight::from_ref_time(262_187_000 as u64) // Standard Error: 31_402
+			.saturating_add(Weight::from_ref_time(158_315_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(27 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(24 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Uniques Asset (r:2 w:2)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: OmnipoolLiquidityMining OmniPositionId (r:1 w:1)
+	// Proof: OmnipoolLiquidityMining OmniPositionId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM Deposit (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM Deposit (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM YieldFarm (r:5 w:5)
+	// Proof: OmnipoolWarehouseLM YieldFarm (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM GlobalFarm (r:5 w:5)
+	// Proof: OmnipoolWarehouseLM GlobalFarm (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	// Storage: System Account (r:7 w:7)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:2 w:2)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:3)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:2)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 5]`.
+	fn exit_farms_and_remove_liquidity(c: u32) -> Weight {
+		// Minimum execution time: 515_872 nanoseconds.
+		Weight::from_ref_time(318_546_000 as u64) // Standard Error: 42_817
+			.saturating_add(Weight::from_ref_time(201_437_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(22 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(c as u64)))
+	}
 }
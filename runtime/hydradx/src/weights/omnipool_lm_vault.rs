@@ -0,0 +1,157 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_omnipool_lm_vault
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-06-16, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --pallet=pallet-omnipool-lm-vault
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --chain=dev
+// --extrinsic=*
+// --steps=5
+// --repeat=20
+// --output
+// omnipool_lm_vault.rs
+// --template
+// .maintain/pallet-weight-template-no-back.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+use pallet_omnipool_lm_vault::weights::WeightInfo;
+
+/// Weights for pallet_omnipool_lm_vault using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	// Storage: OmnipoolLMVault Vaults (r:1 w:1)
+	// Proof: OmnipoolLMVault Vaults (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn create_vault() -> Weight {
+		// Minimum execution time: 32_604_000 nanoseconds.
+		Weight::from_ref_time(33_151_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: OmnipoolLMVault Vaults (r:1 w:0)
+	// Proof: OmnipoolLMVault Vaults (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: OmnipoolLMVault CompoundingRoutes (r:0 w:1)
+	// Proof: OmnipoolLMVault CompoundingRoutes (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn set_compounding_route() -> Weight {
+		// Minimum execution time: 24_911_000 nanoseconds.
+		Weight::from_ref_time(25_430_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: OmnipoolLMVault Vaults (r:1 w:1)
+	// Proof: OmnipoolLMVault Vaults (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:6 w:6)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM Deposit (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM Deposit (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM YieldFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM YieldFarm (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM GlobalFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM GlobalFarm (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:3 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:2 w:2)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:2 w:2)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn deposit() -> Weight {
+		// Minimum execution time: 398_412_000 nanoseconds.
+		Weight::from_ref_time(401_266_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(44 as u64))
+			.saturating_add(T::DbWeight::get().writes(28 as u64))
+	}
+	// Storage: OmnipoolLMVault Vaults (r:1 w:1)
+	// Proof: OmnipoolLMVault Vaults (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:8 w:8)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM Deposit (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM Deposit (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM YieldFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM YieldFarm (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM GlobalFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM GlobalFarm (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:2 w:2)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:2 w:2)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn withdraw() -> Weight {
+		// Minimum execution time: 452_730_000 nanoseconds.
+		Weight::from_ref_time(456_019_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(46 as u64))
+			.saturating_add(T::DbWeight::get().writes(31 as u64))
+	}
+	// Storage: OmnipoolLMVault Vaults (r:1 w:1)
+	// Proof: OmnipoolLMVault Vaults (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: OmnipoolLMVault CompoundingRoutes (r:1 w:0)
+	// Proof: OmnipoolLMVault CompoundingRoutes (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:8 w:8)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:2 w:2)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM Deposit (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM Deposit (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM YieldFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM YieldFarm (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: OmnipoolWarehouseLM GlobalFarm (r:1 w:1)
+	// Proof: OmnipoolWarehouseLM GlobalFarm (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:4 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:2 w:2)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn compound() -> Weight {
+		// Minimum execution time: 509_847_000 nanoseconds.
+		Weight::from_ref_time(513_402_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(50 as u64))
+			.saturating_add(T::DbWeight::get().writes(30 as u64))
+	}
+}
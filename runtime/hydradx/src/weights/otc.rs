@@ -64,11 +64,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: OTC Orders (r:0 w:1)
 	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Storage: OTC AssetLiquidity (r:1 w:1)
+	// Proof: OTC AssetLiquidity (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn place_order() -> Weight {
 		// Minimum execution time: 50_115 nanoseconds.
 		Weight::from_ref_time(50_779_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
 	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
@@ -80,11 +82,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Balances Reserves (r:1 w:1)
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: OTC AssetLiquidity (r:2 w:1)
+	// Proof: OTC AssetLiquidity (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn partial_fill_order() -> Weight {
 		// Minimum execution time: 113_797 nanoseconds.
 		Weight::from_ref_time(114_944_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
 	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
@@ -96,11 +100,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Balances Reserves (r:1 w:1)
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: OTC AssetLiquidity (r:2 w:1)
+	// Proof: OTC AssetLiquidity (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn fill_order() -> Weight {
 		// Minimum execution time: 109_213 nanoseconds.
 		Weight::from_ref_time(109_978_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(7 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
 	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
@@ -108,10 +114,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OTC AssetLiquidity (r:1 w:1)
+	// Proof: OTC AssetLiquidity (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	fn cancel_order() -> Weight {
 		// Minimum execution time: 39_010 nanoseconds.
 		Weight::from_ref_time(39_491_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 }
@@ -84,10 +84,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	// Storage: Staking Positions (r:0 w:1)
 	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
 	fn stake() -> Weight {
 		// Minimum execution time: 93_365 nanoseconds.
 		Weight::from_ref_time(94_660_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: Staking Staking (r:1 w:1)
@@ -104,10 +106,16 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Staking PositionVotes (max_values: None, max_size: Some(2134), added: 4609, mode: MaxEncodedLen)
 	// Storage: Democracy ReferendumInfoOf (r:100 w:0)
 	// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:1 w:0)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:1 w:0)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn increase_stake() -> Weight {
 		// Minimum execution time: 206_722 nanoseconds.
 		Weight::from_ref_time(209_665_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(107 as u64))
+			.saturating_add(T::DbWeight::get().reads(110 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: Staking Staking (r:1 w:1)
@@ -124,10 +132,14 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:1 w:0)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn claim() -> Weight {
 		// Minimum execution time: 225_912 nanoseconds.
 		Weight::from_ref_time(227_912_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(107 as u64))
+			.saturating_add(T::DbWeight::get().reads(109 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: Staking Staking (r:1 w:1)
@@ -150,10 +162,228 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:1)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:1 w:1)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:1 w:1)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Democracy VotingOf (r:1 w:0)
+	// Proof: Democracy VotingOf (max_values: None, max_size: Some(3755), added: 6230, mode: MaxEncodedLen)
 	fn unstake() -> Weight {
 		// Minimum execution time: 246_989 nanoseconds.
 		Weight::from_ref_time(249_570_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(108 as u64))
-			.saturating_add(T::DbWeight::get().writes(9 as u64))
+			.saturating_add(T::DbWeight::get().reads(112 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:1)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:1 w:1)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking PositionVotes (r:1 w:1)
+	// Proof: Staking PositionVotes (max_values: None, max_size: Some(2134), added: 4609, mode: MaxEncodedLen)
+	// Storage: Democracy ReferendumInfoOf (r:100 w:0)
+	// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:1)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:1 w:0)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:1 w:0)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Democracy VotingOf (r:1 w:0)
+	// Proof: Democracy VotingOf (max_values: None, max_size: Some(3755), added: 6230, mode: MaxEncodedLen)
+	fn unstake_partial() -> Weight {
+		// Minimum execution time: 214_387 nanoseconds.
+		Weight::from_ref_time(217_101_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(111 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Staking Unbondings (r:1 w:1)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:1 w:0)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:1 w:0)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Democracy VotingOf (r:1 w:0)
+	// Proof: Democracy VotingOf (max_values: None, max_size: Some(3755), added: 6230, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		// Minimum execution time: 61_204 nanoseconds.
+		Weight::from_ref_time(62_350_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:0)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:1 w:0)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:0 w:1)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_auto_compound() -> Weight {
+		// Minimum execution time: 31_082 nanoseconds.
+		Weight::from_ref_time(31_764_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:0)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:1 w:0)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:0 w:1)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn set_reward_beneficiary() -> Weight {
+		// Minimum execution time: 31_545 nanoseconds.
+		Weight::from_ref_time(32_217_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Staking Staking (r:1 w:1)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:1 w:1)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn auto_compound() -> Weight {
+		// Minimum execution time: 88_412 nanoseconds.
+		Weight::from_ref_time(89_961_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:0)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:2 w:0)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:1 w:1)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking ActionRewardedAt (r:1 w:1)
+	// Proof: Staking ActionRewardedAt (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn on_action() -> Weight {
+		// Minimum execution time: 27_108 nanoseconds.
+		Weight::from_ref_time(27_651_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:0)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:1 w:2)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:1 w:0)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking PositionVotes (r:1 w:1)
+	// Proof: Staking PositionVotes (max_values: None, max_size: Some(2134), added: 4609, mode: MaxEncodedLen)
+	// Storage: Democracy ReferendumInfoOf (r:100 w:0)
+	// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:2 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Tokens Locks (r:2 w:2)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1261), added: 3736, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:0 w:1)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:0 w:1)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Democracy VotingOf (r:1 w:0)
+	// Proof: Democracy VotingOf (max_values: None, max_size: Some(3755), added: 6230, mode: MaxEncodedLen)
+	fn transfer_position() -> Weight {
+		// Minimum execution time: 238_417 nanoseconds.
+		Weight::from_ref_time(241_083_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(113 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:1)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Staking Positions (r:2 w:2)
+	// Proof: Staking Positions (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// Storage: Staking PositionVotes (r:2 w:2)
+	// Proof: Staking PositionVotes (max_values: None, max_size: Some(2134), added: 4609, mode: MaxEncodedLen)
+	// Storage: Democracy ReferendumInfoOf (r:100 w:0)
+	// Proof: Democracy ReferendumInfoOf (max_values: None, max_size: Some(201), added: 2676, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Staking Unbondings (r:1 w:0)
+	// Proof: Staking Unbondings (max_values: None, max_size: Some(369), added: 2844, mode: MaxEncodedLen)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1261), added: 3736, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Staking AutoCompound (r:0 w:1)
+	// Proof: Staking AutoCompound (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Staking RewardBeneficiary (r:0 w:1)
+	// Proof: Staking RewardBeneficiary (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn merge() -> Weight {
+		// Minimum execution time: 251_329 nanoseconds.
+		Weight::from_ref_time(254_760_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(112 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// Storage: Staking CounterForRewardCurrencies (r:1 w:1)
+	// Proof: Staking CounterForRewardCurrencies (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Staking RewardCurrencies (r:1 w:1)
+	// Proof: Staking RewardCurrencies (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn add_reward_currency() -> Weight {
+		// Minimum execution time: 21_930 nanoseconds.
+		Weight::from_ref_time(22_513_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Staking Staking (r:1 w:0)
+	// Proof: Staking Staking (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Staking SwapRewards (r:0 w:1)
+	// Proof: Staking SwapRewards (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_swap_rewards() -> Weight {
+		// Minimum execution time: 29_871 nanoseconds.
+		Weight::from_ref_time(30_402_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Staking RewardCurrencies (r:6 w:5)
+	// Proof: Staking RewardCurrencies (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:10 w:10)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Staking PositionRewards (r:5 w:5)
+	// Proof: Staking PositionRewards (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Staking SwapRewards (r:1 w:0)
+	// Proof: Staking SwapRewards (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 5]`.
+	fn reward_currencies(c: u32) -> Weight {
+		// Minimum execution time: 9_215 nanoseconds.
+		Weight::from_ref_time(10_142_000 as u64)
+			// Standard Error: 12_541
+			.saturating_add(Weight::from_ref_time(61_823_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(c as u64)))
 	}
 }
@@ -0,0 +1,123 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_xcm_rate_limiter
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-06-16, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --pallet=pallet-xcm-rate-limiter
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --chain=dev
+// --extrinsic=*
+// --steps=5
+// --repeat=20
+// --output
+// xcm_rate_limiter.rs
+// --template
+// .maintain/pallet-weight-template-no-back.hbs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+use pallet_xcm_rate_limiter::weights::WeightInfo;
+
+/// Weights for pallet_xcm_rate_limiter using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	// Storage: AssetRegistry LocationAssets (r:1 w:0)
+	// Proof Skipped: AssetRegistry LocationAssets (max_values: None, max_size: None, mode: Measured)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmRateLimiter OutgoingAccumulatedAmounts (r:1 w:1)
+	// Proof: XcmRateLimiter OutgoingAccumulatedAmounts (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: XcmRateLimiter DeferredMessagesCount (r:1 w:1)
+	// Proof: XcmRateLimiter DeferredMessagesCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: XcmRateLimiter NextDeferredMessageId (r:1 w:1)
+	// Proof: XcmRateLimiter NextDeferredMessageId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: XcmRateLimiter DeferredMessages (r:0 w:1)
+	// Proof Skipped: XcmRateLimiter DeferredMessages (max_values: None, max_size: None, mode: Measured)
+	fn deliver() -> Weight {
+		// Minimum execution time: 29_700 nanoseconds.
+		Weight::from_ref_time(30_212_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: XcmRateLimiter DeferredMessagesCount (r:1 w:1)
+	// Proof: XcmRateLimiter DeferredMessagesCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmRateLimiter DeferredMessages (r:2 w:1)
+	// Proof Skipped: XcmRateLimiter DeferredMessages (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	fn send_deferred_message() -> Weight {
+		// Minimum execution time: 44_359 nanoseconds.
+		Weight::from_ref_time(44_871_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: XcmRateLimiter DeferredMessages (r:1 w:1)
+	// Proof Skipped: XcmRateLimiter DeferredMessages (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmRateLimiter DeferredMessagesCount (r:1 w:1)
+	// Proof: XcmRateLimiter DeferredMessagesCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:0)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	fn execute_deferred() -> Weight {
+		// Minimum execution time: 41_051 nanoseconds.
+		Weight::from_ref_time(41_563_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: XcmRateLimiter DeferredMessages (r:1 w:1)
+	// Proof Skipped: XcmRateLimiter DeferredMessages (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmRateLimiter DeferredMessagesCount (r:1 w:1)
+	// Proof: XcmRateLimiter DeferredMessagesCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn discard_deferred() -> Weight {
+		// Minimum execution time: 19_292 nanoseconds.
+		Weight::from_ref_time(19_804_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
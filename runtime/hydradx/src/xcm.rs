@@ -17,7 +17,7 @@ use frame_system::EnsureRoot;
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiNativeAsset};
 use pallet_xcm::XcmPassthrough;
-use polkadot_parachain::primitives::Sibling;
+use polkadot_parachain::primitives::{RelayChainBlockNumber, Sibling};
 use polkadot_xcm::v3::{prelude::*, Weight as XcmWeight};
 use scale_info::TypeInfo;
 use xcm_builder::{
@@ -91,10 +91,17 @@ parameter_types! {
 	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
 }
 
+/// Weighs XCMs including the rate limiting of the sent XCMs by `XcmRateLimiter`.
+pub type XcmWeigher = pallet_xcm_rate_limiter::RateLimitedWeigher<
+	Runtime,
+	FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>,
+>;
+
 pub struct XcmConfig;
 impl Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
-	type XcmSender = XcmRouter;
+	// Outgoing transfers of `XTokens` and reserve transfers of `PolkadotXcm` are rate limited.
+	type XcmSender = XcmRateLimiter;
 
 	type AssetTransactor = LocalAssetTransactor;
 	type OriginConverter = XcmOriginToCallOrigin;
@@ -104,7 +111,7 @@ impl Config for XcmConfig {
 	type UniversalLocation = UniversalLocation;
 
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	// We calculate weight fees the same way as for regular extrinsics and use the prices and choice
 	// of accepted currencies of the transaction payment pallet. Fees go to the same fee receiver as
 	// configured in `MultiTransactionPayment`.
@@ -151,7 +158,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type ExecuteDeferredOrigin = EnsureRoot<AccountId>;
 	type MaxDeferredMessages = ConstU32<100>;
 	type RelayChainBlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
-	type XcmDeferFilter = ();
+	type XcmDeferFilter = XcmRateLimiter;
 }
 
 impl cumulus_pallet_dmp_queue::Config for Runtime {
@@ -160,6 +167,25 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
 }
 
+parameter_types! {
+	pub const DeferDuration: RelayChainBlockNumber = 600 * 10; // 10 hours
+	pub const MaxDeferDuration: RelayChainBlockNumber = 600 * 24 * 10; // 10 days
+}
+
+impl pallet_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type DeferDuration = DeferDuration;
+	type MaxDeferDuration = MaxDeferDuration;
+	type RelayBlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type RateLimitFor = pallet_asset_registry::XcmRateLimitsInRegistry<Runtime>;
+	type XcmRouter = XcmRouter;
+	type UniversalLocation = UniversalLocation;
+	type MaxDeferredMessages = ConstU32<100>;
+	type WeightInfo = weights::xcm_rate_limiter::HydraWeight<Runtime>;
+}
+
 parameter_type_with_key! {
 	pub ParachainMinFee: |_location: MultiLocation| -> Option<u128> {
 		None
@@ -174,7 +200,7 @@ impl orml_xtokens::Config for Runtime {
 	type AccountIdToMultiLocation = AccountIdToMultiLocation;
 	type SelfLocation = SelfLocation;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type BaseXcmWeight = BaseXcmWeight;
 	type MaxAssetsForTransfer = MaxAssetsForTransfer;
 	type MultiLocationsFilter = Everything;
@@ -208,7 +234,7 @@ impl pallet_xcm::Config for Runtime {
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = Nothing;
 	type XcmReserveTransferFilter = Everything;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type UniversalLocation = UniversalLocation;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -233,6 +233,36 @@ impl<AssetId, Balance> OnLiquidityChangedHandler<AssetId, Balance> for () {
 	}
 }
 
+/// Handler used by DCA to perform some tasks when a trade of a schedule is executed.
+pub trait OnScheduledTradeHandler<AccountId, AssetId, Balance> {
+	/// Notify that a trade of `who`'s schedule was executed.
+	/// The trade is already executed when the handler is called, so the handler can't fail it.
+	fn on_scheduled_trade(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	);
+	/// Add this weight to the weight of the schedule's execution.
+	fn on_scheduled_trade_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Balance> OnScheduledTradeHandler<AccountId, AssetId, Balance> for () {
+	fn on_scheduled_trade(
+		_who: &AccountId,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_amount_out: Balance,
+	) {
+	}
+
+	fn on_scheduled_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 /// Implementers of this trait provides information about user's position in the AMM pool.
 pub trait AMMPosition<AssetId, Balance> {
 	type Error;
@@ -131,6 +131,26 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 		get_token_value_of_lp_shares: F,
 	) -> Result<(Self::Balance, Self::AmmPoolId), Self::Error>;
 
+	/// Add LP shares to existing deposit. Added LP shares are added to all yield farm entries of the
+	/// deposit, loyalty of the entries is kept.
+	#[allow(clippy::type_complexity)]
+	fn add_lp_shares<F: Fn(AssetId, Self::AmmPoolId, Self::Balance) -> Result<Self::Balance, Self::Error>>(
+		deposit_id: DepositId,
+		shares_amount: Self::Balance,
+		get_token_value_of_lp_shares: F,
+	) -> Result<(), Self::Error>;
+
+	/// Remove LP shares from existing deposit. Function attempts to claim rewards for `who` from all
+	/// claimable yield farms of the deposit first.
+	///
+	/// Returns: `Vec<(global farm id, yield farm id, reward currency, claimed amount)>`
+	#[allow(clippy::type_complexity)]
+	fn remove_lp_shares(
+		who: AccountId,
+		deposit_id: DepositId,
+		shares_amount: Self::Balance,
+	) -> Result<Vec<(GlobalFarmId, YieldFarmId, AssetId, Self::Balance)>, Self::Error>;
+
 	/// Claim rewards for given deposit.
 	///
 	/// Returns: `(GlobalFarmId, reward currency, claimed amount, unclaimable amount)`
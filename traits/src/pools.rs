@@ -1,3 +1,8 @@
+use crate::Source;
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::weights::Weight;
+use sp_arithmetic::{FixedU128, Permill};
+
 pub trait SpotPriceProvider<AssetId> {
 	type Price;
 
@@ -22,3 +27,136 @@ pub trait DustRemovalAccountWhitelist<AccountId> {
 	/// Remove an account from the list.
 	fn remove_account(account: &AccountId) -> Result<(), Self::Error>;
 }
+
+/// Handler used by AMM pools to enforce per block limits of trades and liquidity changes of a pool
+/// (e.g. circuit breaker).
+///
+/// A pool is identified by the source of the pool and the pool's account.
+pub trait PoolLimitHandler<Origin, AccountId, AssetId, Balance> {
+	/// Ensure that a trade does not exceed the trade volume limits of the pool's assets.
+	///
+	/// Reserves are the pool's reserves before the trade.
+	#[allow(clippy::too_many_arguments)]
+	fn ensure_trade_limit(
+		origin: Origin,
+		source: Source,
+		pool: AccountId,
+		asset_in: AssetId,
+		asset_in_reserve: Balance,
+		amount_in: Balance,
+		asset_out: AssetId,
+		asset_out_reserve: Balance,
+		amount_out: Balance,
+	) -> Result<Weight, DispatchError>;
+
+	/// Ensure that added liquidity does not exceed the add liquidity limit of the pool's asset.
+	fn ensure_add_liquidity_limit(
+		origin: Origin,
+		source: Source,
+		pool: AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		added_liquidity: Balance,
+	) -> Result<Weight, DispatchError>;
+
+	/// Ensure that removed liquidity does not exceed the remove liquidity limit of the pool's asset.
+	fn ensure_remove_liquidity_limit(
+		origin: Origin,
+		source: Source,
+		pool: AccountId,
+		asset_id: AssetId,
+		initial_liquidity: Balance,
+		removed_liquidity: Balance,
+	) -> Result<Weight, DispatchError>;
+
+	/// Known overhead of `ensure_trade_limit`, including the overhead in `on_finalize`.
+	fn ensure_trade_limit_weight() -> Weight;
+
+	/// Known overhead of `ensure_add_liquidity_limit` or `ensure_remove_liquidity_limit`, including the overhead in `on_finalize`.
+	fn ensure_liquidity_limit_weight() -> Weight;
+}
+
+impl<Origin, AccountId, AssetId, Balance> PoolLimitHandler<Origin, AccountId, AssetId, Balance> for () {
+	#[allow(clippy::too_many_arguments)]
+	fn ensure_trade_limit(
+		_origin: Origin,
+		_source: Source,
+		_pool: AccountId,
+		_asset_in: AssetId,
+		_asset_in_reserve: Balance,
+		_amount_in: Balance,
+		_asset_out: AssetId,
+		_asset_out_reserve: Balance,
+		_amount_out: Balance,
+	) -> Result<Weight, DispatchError> {
+		Ok(Weight::zero())
+	}
+
+	fn ensure_add_liquidity_limit(
+		_origin: Origin,
+		_source: Source,
+		_pool: AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		_added_liquidity: Balance,
+	) -> Result<Weight, DispatchError> {
+		Ok(Weight::zero())
+	}
+
+	fn ensure_remove_liquidity_limit(
+		_origin: Origin,
+		_source: Source,
+		_pool: AccountId,
+		_asset_id: AssetId,
+		_initial_liquidity: Balance,
+		_removed_liquidity: Balance,
+	) -> Result<Weight, DispatchError> {
+		Ok(Weight::zero())
+	}
+
+	fn ensure_trade_limit_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn ensure_liquidity_limit_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Handler used to list an asset sold in a liquidity bootstrapping pool in another pool once the sale ends.
+pub trait GraduationHandler<AccountId, AssetId, Balance> {
+	/// Move `amount` of `asset` from the `from` account to the pool and list it at `price`.
+	///
+	/// `price` is the price of the `asset` denominated in `price_asset`.
+	/// Liquidity position is assigned to `position_owner`, or to the protocol if `None`.
+	fn graduate(
+		from: AccountId,
+		asset: AssetId,
+		amount: Balance,
+		price_asset: AssetId,
+		price: FixedU128,
+		weight_cap: Permill,
+		position_owner: Option<AccountId>,
+	) -> DispatchResult;
+
+	/// Known overhead of `graduate`.
+	fn graduate_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Balance> GraduationHandler<AccountId, AssetId, Balance> for () {
+	fn graduate(
+		_from: AccountId,
+		_asset: AssetId,
+		_amount: Balance,
+		_price_asset: AssetId,
+		_price: FixedU128,
+		_weight_cap: Permill,
+		_position_owner: Option<AccountId>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Graduation is not supported"))
+	}
+
+	fn graduate_weight() -> Weight {
+		Weight::zero()
+	}
+}